@@ -33,6 +33,12 @@ fn main() {
         "--ansi" => Box::new(AnsiDialect {}),
         "--postgres" => Box::new(PostgreSqlDialect {}),
         "--ms" => Box::new(MsSqlDialect {}),
+        "--bigquery" => Box::new(BigQueryDialect {}),
+        "--clickhouse" => Box::new(ClickHouseDialect {}),
+        "--hive" => Box::new(HiveDialect {}),
+        "--snowflake" => Box::new(SnowflakeDialect {}),
+        "--redshift" => Box::new(RedshiftSqlDialect {}),
+        "--sqlite" => Box::new(SqliteDialect {}),
         "--generic" | "" => Box::new(GenericDialect {}),
         s => panic!("Unexpected parameter: {}", s),
     };
@@ -0,0 +1,408 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Best-effort, purely syntactic analysis of parsed queries
+//!
+//! Nothing here consults a catalog: names are resolved only against the
+//! relations and aliases that appear in the query text itself.
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+use crate::ast::visit::{self, Visit};
+use crate::ast::*;
+
+/// A column of a relation that an output column is computed from
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SourceColumn {
+    /// The relation the column belongs to, as named in the `FROM` clause:
+    /// a table, a CTE, or the alias of a derived table. `None` if the
+    /// column is unqualified and more than one relation is in scope.
+    pub relation: Option<ObjectName>,
+    pub column: Ident,
+}
+
+/// A column in the output of a query
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum OutputColumn {
+    /// A column computed by an expression
+    Expr {
+        /// The name of the column, if it is aliased or is a plain column
+        /// reference
+        name: Option<Ident>,
+        expr: Expr,
+        /// The columns referenced by `expr`, excluding those referenced
+        /// only inside subqueries
+        sources: Vec<SourceColumn>,
+    },
+    /// All the columns of some relations, from `*` or `<relation>.*`
+    Wildcard { relations: Vec<ObjectName> },
+}
+
+/// The tables a query reads and where each of its output columns comes from
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QueryLineage {
+    /// Every table referenced anywhere in the query, including in CTEs and
+    /// subqueries, in order of first appearance. References to CTEs are
+    /// not tables and are excluded.
+    pub input_tables: Vec<ObjectName>,
+    /// The output columns, in order. For set operations, the sources of
+    /// each branch are merged column by column, and names are taken from
+    /// the left-most branch.
+    pub columns: Vec<OutputColumn>,
+}
+
+/// Compute the lineage of `query`
+pub fn query_lineage(query: &Query) -> QueryLineage {
+    let mut ctes = CteCollector { names: vec![] };
+    ctes.visit_query(query);
+    let mut tables = TableCollector {
+        ctes: ctes.names,
+        tables: vec![],
+    };
+    tables.visit_query(query);
+    QueryLineage {
+        input_tables: tables.tables,
+        columns: set_expr_columns(&query.body),
+    }
+}
+
+struct CteCollector<'a> {
+    names: Vec<&'a Ident>,
+}
+
+impl<'ast> Visit<'ast> for CteCollector<'ast> {
+    fn visit_cte(&mut self, cte: &'ast Cte) {
+        self.names.push(&cte.alias.name);
+        visit::visit_cte(self, cte);
+    }
+}
+
+struct TableCollector<'a> {
+    ctes: Vec<&'a Ident>,
+    tables: Vec<ObjectName>,
+}
+
+impl<'ast> Visit<'ast> for TableCollector<'ast> {
+    fn visit_table_factor(&mut self, table_factor: &'ast TableFactor) {
+        if let TableFactor::Table { name, args, .. } = table_factor {
+            let is_cte = match name.0.as_slice() {
+                [ident] => self.ctes.iter().any(|cte| idents_match(cte, ident)),
+                _ => false,
+            };
+            // Table-valued functions are not tables
+            if !is_cte && args.is_empty() && !self.tables.contains(name) {
+                self.tables.push(name.clone());
+            }
+        }
+        visit::visit_table_factor(self, table_factor);
+    }
+}
+
+/// Collects the column references in an expression, without descending
+/// into subqueries
+struct ColumnCollector<'a> {
+    scope: &'a [(Ident, ObjectName)],
+    columns: Vec<SourceColumn>,
+}
+
+impl<'a, 'ast> Visit<'ast> for ColumnCollector<'a> {
+    fn visit_expr(&mut self, expr: &'ast Expr) {
+        let column = match expr {
+            Expr::Identifier(column) => SourceColumn {
+                relation: match self.scope {
+                    [(_, relation)] => Some(relation.clone()),
+                    _ => None,
+                },
+                column: column.clone(),
+            },
+            Expr::CompoundIdentifier(idents) if idents.len() > 1 => {
+                let (column, qualifier) = idents.split_last().unwrap();
+                SourceColumn {
+                    relation: Some(resolve_relation(self.scope, qualifier)),
+                    column: column.clone(),
+                }
+            }
+            _ => return visit::visit_expr(self, expr),
+        };
+        if !self.columns.contains(&column) {
+            self.columns.push(column);
+        }
+    }
+
+    fn visit_query(&mut self, _query: &'ast Query) {}
+}
+
+fn set_expr_columns(set_expr: &SetExpr) -> Vec<OutputColumn> {
+    match set_expr {
+        SetExpr::Select(select) => select_columns(select),
+        SetExpr::Query(query) => set_expr_columns(&query.body),
+        SetExpr::SetOperation { left, right, .. } => {
+            let mut columns = set_expr_columns(left);
+            for (column, other) in columns.iter_mut().zip(set_expr_columns(right)) {
+                merge_columns(column, other);
+            }
+            columns
+        }
+        SetExpr::Values(values) => match values.0.first() {
+            Some(row) => row
+                .iter()
+                .map(|expr| OutputColumn::Expr {
+                    name: None,
+                    expr: expr.clone(),
+                    sources: vec![],
+                })
+                .collect(),
+            None => vec![],
+        },
+    }
+}
+
+fn merge_columns(column: &mut OutputColumn, other: OutputColumn) {
+    match (column, other) {
+        (
+            OutputColumn::Expr { sources, .. },
+            OutputColumn::Expr {
+                sources: other_sources,
+                ..
+            },
+        ) => {
+            for source in other_sources {
+                if !sources.contains(&source) {
+                    sources.push(source);
+                }
+            }
+        }
+        (
+            OutputColumn::Wildcard { relations },
+            OutputColumn::Wildcard {
+                relations: other_relations,
+            },
+        ) => {
+            for relation in other_relations {
+                if !relations.contains(&relation) {
+                    relations.push(relation);
+                }
+            }
+        }
+        // A wildcard lined up against an expression: the column positions
+        // can't be matched up without a catalog
+        _ => (),
+    }
+}
+
+fn select_columns(select: &Select) -> Vec<OutputColumn> {
+    let mut scope = vec![];
+    for table in &select.from {
+        collect_scope(&table.relation, &mut scope);
+        for join in &table.joins {
+            collect_scope(&join.relation, &mut scope);
+        }
+    }
+
+    select
+        .projection
+        .iter()
+        .map(|item| match item {
+            SelectItem::UnnamedExpr(expr) => OutputColumn::Expr {
+                name: match expr {
+                    Expr::Identifier(ident) => Some(ident.clone()),
+                    Expr::CompoundIdentifier(idents) => idents.last().cloned(),
+                    _ => None,
+                },
+                expr: expr.clone(),
+                sources: expr_sources(&scope, expr),
+            },
+            SelectItem::ExprWithAlias { expr, alias } => OutputColumn::Expr {
+                name: Some(alias.clone()),
+                expr: expr.clone(),
+                sources: expr_sources(&scope, expr),
+            },
+            SelectItem::QualifiedWildcard(name, _) => OutputColumn::Wildcard {
+                relations: vec![resolve_relation(&scope, &name.0)],
+            },
+            SelectItem::Wildcard(_) => OutputColumn::Wildcard {
+                relations: scope.iter().map(|(_, relation)| relation.clone()).collect(),
+            },
+        })
+        .collect()
+}
+
+/// Record the relations a table factor brings into scope, keyed by the name
+/// they are referred to by in the rest of the query
+fn collect_scope(table_factor: &TableFactor, scope: &mut Vec<(Ident, ObjectName)>) {
+    match table_factor {
+        TableFactor::Table { name, alias, .. } => {
+            let key = match alias {
+                Some(alias) => alias.name.clone(),
+                None => match name.item() {
+                    Some(ident) => ident.clone(),
+                    None => return,
+                },
+            };
+            scope.push((key, name.clone()));
+        }
+        TableFactor::Derived {
+            alias: Some(alias), ..
+        } => scope.push((alias.name.clone(), ObjectName(vec![alias.name.clone()]))),
+        TableFactor::Derived { alias: None, .. } => (),
+        TableFactor::JsonTable {
+            alias: Some(alias), ..
+        } => scope.push((alias.name.clone(), ObjectName(vec![alias.name.clone()]))),
+        TableFactor::JsonTable { alias: None, .. } => (),
+        TableFactor::XmlTable {
+            alias: Some(alias), ..
+        } => scope.push((alias.name.clone(), ObjectName(vec![alias.name.clone()]))),
+        TableFactor::XmlTable { alias: None, .. } => (),
+        TableFactor::NestedJoin(table) => {
+            collect_scope(&table.relation, scope);
+            for join in &table.joins {
+                collect_scope(&join.relation, scope);
+            }
+        }
+    }
+}
+
+fn expr_sources(scope: &[(Ident, ObjectName)], expr: &Expr) -> Vec<SourceColumn> {
+    let mut collector = ColumnCollector {
+        scope,
+        columns: vec![],
+    };
+    collector.visit_expr(expr);
+    collector.columns
+}
+
+/// Resolve a relation qualifier, like the `t` in `t.a`, against the
+/// relations in scope. Qualifiers that match nothing are returned as-is.
+fn resolve_relation(scope: &[(Ident, ObjectName)], qualifier: &[Ident]) -> ObjectName {
+    if let [ident] = qualifier {
+        if let Some((_, relation)) = scope.iter().find(|(key, _)| idents_match(key, ident)) {
+            return relation.clone();
+        }
+    }
+    ObjectName(qualifier.to_vec())
+}
+
+/// Compare identifiers the way most databases do: unquoted identifiers are
+/// case-insensitive, quoted ones are not
+fn idents_match(a: &Ident, b: &Ident) -> bool {
+    if a.quote_style.is_none() && b.quote_style.is_none() {
+        a.value.eq_ignore_ascii_case(&b.value)
+    } else {
+        a.value == b.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialect::GenericDialect;
+    use crate::parser::Parser;
+
+    fn parse_lineage(sql: &str) -> QueryLineage {
+        match Parser::parse_sql(&GenericDialect {}, sql.to_string())
+            .unwrap()
+            .pop()
+            .unwrap()
+        {
+            Statement::Query(query) => query_lineage(&query),
+            _ => unreachable!(),
+        }
+    }
+
+    fn name(parts: &[&str]) -> ObjectName {
+        ObjectName(parts.iter().map(|p| Ident::new(*p)).collect())
+    }
+
+    fn source(relation: Option<&[&str]>, column: &str) -> SourceColumn {
+        SourceColumn {
+            relation: relation.map(name),
+            column: Ident::new(column),
+        }
+    }
+
+    fn sources(column: &OutputColumn) -> &[SourceColumn] {
+        match column {
+            OutputColumn::Expr { sources, .. } => sources,
+            OutputColumn::Wildcard { .. } => panic!("unexpected wildcard"),
+        }
+    }
+
+    #[test]
+    fn lineage_resolves_aliases() {
+        let lineage = parse_lineage(
+            "SELECT o.id, c.name AS customer, o.total * 2 AS doubled, count(*) \
+             FROM sales.orders AS o JOIN customers c ON o.customer_id = c.id",
+        );
+        assert_eq!(
+            vec![name(&["sales", "orders"]), name(&["customers"])],
+            lineage.input_tables
+        );
+        assert_eq!(4, lineage.columns.len());
+        match &lineage.columns[1] {
+            OutputColumn::Expr { name, sources, .. } => {
+                assert_eq!(&Some(Ident::new("customer")), name);
+                assert_eq!(&[source(Some(&["customers"]), "name")], sources.as_slice());
+            }
+            _ => unreachable!(),
+        }
+        assert_eq!(
+            &[source(Some(&["sales", "orders"]), "total")],
+            sources(&lineage.columns[2])
+        );
+        assert!(sources(&lineage.columns[3]).is_empty());
+    }
+
+    #[test]
+    fn lineage_unqualified_columns() {
+        let lineage = parse_lineage("SELECT a, b + 1 FROM t WHERE c > 0");
+        assert_eq!(&[source(Some(&["t"]), "a")], sources(&lineage.columns[0]));
+        assert_eq!(&[source(Some(&["t"]), "b")], sources(&lineage.columns[1]));
+
+        // ambiguous without a catalog
+        let lineage = parse_lineage("SELECT a FROM t, u");
+        assert_eq!(&[source(None, "a")], sources(&lineage.columns[0]));
+    }
+
+    #[test]
+    fn lineage_ctes_subqueries_and_wildcards() {
+        let lineage = parse_lineage(
+            "WITH recent AS (SELECT * FROM events WHERE ts > now()) \
+             SELECT r.*, (SELECT max(x) FROM other) AS m, d.k \
+             FROM recent AS r, (SELECT k FROM keys) AS d",
+        );
+        assert_eq!(
+            vec![name(&["events"]), name(&["other"]), name(&["keys"])],
+            lineage.input_tables
+        );
+        assert_eq!(
+            OutputColumn::Wildcard {
+                relations: vec![name(&["recent"])]
+            },
+            lineage.columns[0]
+        );
+        // columns referenced only inside the scalar subquery are not sources
+        assert!(sources(&lineage.columns[1]).is_empty());
+        assert_eq!(&[source(Some(&["d"]), "k")], sources(&lineage.columns[2]));
+    }
+
+    #[test]
+    fn lineage_set_operations() {
+        let lineage = parse_lineage("SELECT a FROM t UNION SELECT b FROM u");
+        assert_eq!(vec![name(&["t"]), name(&["u"])], lineage.input_tables);
+        assert_eq!(
+            &[source(Some(&["t"]), "a"), source(Some(&["u"]), "b")],
+            sources(&lineage.columns[0])
+        );
+    }
+}
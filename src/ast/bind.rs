@@ -0,0 +1,233 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Substitution of `$n` parameters with literal values
+//!
+//! Binding happens on the AST rather than on the SQL text, so the values
+//! can't change the structure of the statement: the bound statement's
+//! `Display` output contains each value as a single, properly escaped
+//! literal.
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+};
+use core::fmt;
+
+use super::visit_mut::{self, VisitMut};
+use super::{Expr, Statement, Value};
+
+/// An error that occurred while binding parameters
+#[derive(Debug, Clone, PartialEq)]
+pub enum BindError {
+    /// The statement refers to `$n`, but fewer than `n` values were provided
+    MissingParameter(usize),
+    /// The value bound to `$n` could not be represented as a SQL literal
+    InvalidValue(usize, String),
+}
+
+impl fmt::Display for BindError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BindError::MissingParameter(n) => write!(f, "no value provided for parameter ${}", n),
+            BindError::InvalidValue(n, msg) => {
+                write!(f, "invalid value for parameter ${}: {}", n, msg)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BindError {}
+
+/// Return a copy of `statement` with each `$n` parameter replaced by
+/// `params[n - 1]`
+///
+/// Values that are not used by the statement are ignored.
+pub fn bind_parameters(statement: &Statement, params: &[Value]) -> Result<Statement, BindError> {
+    let mut statement = statement.clone();
+    let mut binder = Binder {
+        params,
+        error: None,
+    };
+    binder.visit_statement(&mut statement);
+    match binder.error {
+        Some(err) => Err(err),
+        None => Ok(statement),
+    }
+}
+
+struct Binder<'a> {
+    params: &'a [Value],
+    error: Option<BindError>,
+}
+
+impl<'a, 'ast> VisitMut<'ast> for Binder<'a> {
+    fn visit_expr(&mut self, expr: &'ast mut Expr) {
+        if self.error.is_some() {
+            return;
+        }
+        match expr {
+            Expr::Parameter(n) => match self.bind(*n) {
+                Ok(bound) => *expr = bound,
+                Err(err) => self.error = Some(err),
+            },
+            _ => visit_mut::visit_expr(self, expr),
+        }
+    }
+}
+
+impl<'a> Binder<'a> {
+    fn bind(&self, n: usize) -> Result<Expr, BindError> {
+        let value = match n.checked_sub(1).and_then(|i| self.params.get(i)) {
+            Some(value) => value,
+            None => return Err(BindError::MissingParameter(n)),
+        };
+        validate_value(value).map_err(|msg| BindError::InvalidValue(n, msg))?;
+        let expr = Expr::Value(value.clone());
+        // A negative number displays with a leading `-`, which could bind
+        // to a neighbouring operator differently than the parameter did
+        if value.to_string().starts_with('-') {
+            Ok(Expr::Nested(Box::new(expr)))
+        } else {
+            Ok(expr)
+        }
+    }
+}
+
+/// Check that `value` displays as the literal it represents
+///
+/// Quoted strings are escaped on display, but numbers and hex strings are
+/// written verbatim.
+fn validate_value(value: &Value) -> Result<(), String> {
+    match value {
+        #[cfg(not(feature = "bigdecimal"))]
+        Value::Number(n) if !is_numeric_literal(n) => {
+            Err(format!("{:?} is not a numeric literal", n))
+        }
+        Value::HexStringLiteral(s) if !s.chars().all(|c| c.is_ascii_hexdigit()) => {
+            Err(format!("{:?} is not a hex string", s))
+        }
+        Value::Array(values) => values.iter().try_for_each(validate_value),
+        _ => Ok(()),
+    }
+}
+
+/// Whether `s` matches `-?digits[.digits][(e|E)[+|-]digits]`, with at
+/// least one digit before the exponent
+#[cfg(not(feature = "bigdecimal"))]
+fn is_numeric_literal(s: &str) -> bool {
+    let s = s.strip_prefix('-').unwrap_or(s);
+    let (mantissa, exponent) = match s.find(&['e', 'E'][..]) {
+        Some(i) => (&s[..i], Some(&s[i + 1..])),
+        None => (s, None),
+    };
+    let mut parts = mantissa.splitn(2, '.');
+    let int = parts.next().unwrap_or("");
+    let frac = parts.next().unwrap_or("");
+    let all_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+    if int.len() + frac.len() == 0 || !all_digits(int) || !all_digits(frac) {
+        return false;
+    }
+    match exponent {
+        Some(e) => {
+            let e = e.strip_prefix(&['+', '-'][..]).unwrap_or(e);
+            !e.is_empty() && all_digits(e)
+        }
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn bind_values() {
+        let statement = all_dialects()
+            .verified_stmt("SELECT * FROM t WHERE a = $2 AND b IN ($1, $3) AND c = $2 - $4");
+        let bound = bind_parameters(
+            &statement,
+            &[
+                Value::SingleQuotedString("it's".to_string()),
+                number("1"),
+                Value::Null,
+                number("-2"),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            "SELECT * FROM t WHERE a = 1 AND b IN ('it''s', NULL) AND c = 1 - (-2)",
+            bound.to_string()
+        );
+        // The original statement is untouched
+        assert_eq!(
+            "SELECT * FROM t WHERE a = $2 AND b IN ($1, $3) AND c = $2 - $4",
+            statement.to_string()
+        );
+    }
+
+    #[test]
+    fn bind_values_are_escaped() {
+        let statement = all_dialects().verified_stmt("INSERT INTO t VALUES ($1, $2)");
+        let bound = bind_parameters(
+            &statement,
+            &[
+                Value::SingleQuotedString("'); DROP TABLE t; --".to_string()),
+                Value::NationalStringLiteral("x' OR '1".to_string()),
+            ],
+        )
+        .unwrap();
+        let sql = bound.to_string();
+        assert_eq!(
+            "INSERT INTO t VALUES ('''); DROP TABLE t; --', N'x'' OR ''1')",
+            sql
+        );
+        assert_eq!(bound, all_dialects().verified_stmt(&sql));
+    }
+
+    #[test]
+    fn bind_errors() {
+        let statement = all_dialects().verified_stmt("SELECT $1, $3");
+        assert_eq!(
+            bind_parameters(&statement, &[Value::Null, Value::Null]),
+            Err(BindError::MissingParameter(3))
+        );
+        assert_eq!(
+            bind_parameters(&all_dialects().verified_stmt("SELECT $0"), &[Value::Null]),
+            Err(BindError::MissingParameter(0))
+        );
+        assert!(matches!(
+            bind_parameters(&statement, &[Value::HexStringLiteral("0g".to_string())]),
+            Err(BindError::InvalidValue(1, _))
+        ));
+        #[cfg(not(feature = "bigdecimal"))]
+        assert!(matches!(
+            bind_parameters(&statement, &[number("1; DROP TABLE t")]),
+            Err(BindError::InvalidValue(1, _))
+        ));
+    }
+
+    #[test]
+    #[cfg(not(feature = "bigdecimal"))]
+    fn numeric_literals() {
+        for n in &["1", "-1", "1.", ".5", "1.5e10", "1E-3", "-0.5e+2"] {
+            assert!(is_numeric_literal(n), "{}", n);
+        }
+        for n in &["", "-", ".", "1e", "e5", "1.2.3", "1 OR 1", "0x10", "--1"] {
+            assert!(!is_numeric_literal(n), "{}", n);
+        }
+    }
+}
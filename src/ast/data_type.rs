@@ -10,16 +10,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::ObjectName;
-use std::fmt;
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+use super::{display_comma_separated, ObjectName};
+use core::fmt;
 
 /// SQL data types
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum DataType {
     /// Fixed-length character type e.g. CHAR(10)
-    Char(Option<u64>),
+    Char(Option<CharacterLength>),
     /// Variable-length character type e.g. VARCHAR(10)
-    Varchar(Option<u64>),
+    Varchar(Option<CharacterLength>),
+    /// Fixed-length national character type e.g. NCHAR(10)
+    Nchar(Option<CharacterLength>),
+    /// Variable-length national character type e.g. NVARCHAR(10)
+    Nvarchar(Option<CharacterLength>),
     /// Uuid type
     Uuid,
     /// Large character object e.g. CLOB(1000)
@@ -30,8 +38,16 @@ pub enum DataType {
     Varbinary(u64),
     /// Large binary object e.g. BLOB(1000)
     Blob(u64),
-    /// Decimal type with optional precision and scale e.g. DECIMAL(10,2)
-    Decimal(Option<u64>, Option<u64>),
+    /// Exact numeric type with optional precision and scale e.g.
+    /// DECIMAL(10,2)
+    Decimal {
+        /// Which of the synonymous names the type is written with
+        name: DecimalName,
+        precision: Option<u64>,
+        /// The number of digits after the decimal point, which can only be
+        /// written after a precision, so it isn't displayed without one
+        scale: Option<u64>,
+    },
     /// Floating point with optional precision e.g. FLOAT(8)
     Float(Option<u64>),
     /// Small integer
@@ -48,14 +64,18 @@ pub enum DataType {
     Boolean,
     /// Date
     Date,
-    /// Time without time zone
-    Time,
-    /// Time with time zone
-    TimeTz,
-    /// Timestamp without time zone
-    Timestamp,
-    /// Timestamp with time zone
-    TimestampTz,
+    /// Time with optional fractional seconds precision, e.g. TIME(3) or
+    /// TIME WITH TIME ZONE
+    Time {
+        precision: Option<u64>,
+        with_time_zone: bool,
+    },
+    /// Timestamp with optional fractional seconds precision, e.g.
+    /// TIMESTAMP(6) WITH TIME ZONE
+    Timestamp {
+        precision: Option<u64>,
+        with_time_zone: bool,
+    },
     /// Interval
     Interval,
     /// Regclass used in postgresql serial
@@ -64,29 +84,52 @@ pub enum DataType {
     Text,
     /// Bytea
     Bytea,
-    /// Custom type such as enums
-    Custom(ObjectName),
-    /// Arrays
-    Array(Box<DataType>),
+    /// Custom type such as enums, with the type modifiers that follow its
+    /// name, as in `geometry(point, 4326)`, kept as written
+    Custom(ObjectName, Vec<String>),
+    /// Arrays, with the size given between the brackets, if any. Postgres
+    /// ignores array sizes, and accepts `ARRAY` as a synonym for `[]`.
+    Array(Box<DataType>, Option<u64>),
+    /// A type written differently from how it is displayed, like `INTEGER`
+    /// for [DataType::Int], along with the text it was written as, which is
+    /// displayed instead. The parser only produces these with
+    /// [ParseOptions::preserve_type_names](crate::parser::ParseOptions::preserve_type_names).
+    Spelled(Box<DataType>, String),
+}
+
+impl DataType {
+    /// Return the type, leaving out how it was spelled if it is
+    /// [DataType::Spelled]. The elements of arrays keep their spelling.
+    pub fn without_spelling(&self) -> &DataType {
+        match self {
+            DataType::Spelled(data_type, _) => data_type,
+            data_type => data_type,
+        }
+    }
 }
 
 impl fmt::Display for DataType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            DataType::Char(size) => format_type_with_optional_length(f, "char", size),
-            DataType::Varchar(size) => {
-                format_type_with_optional_length(f, "character varying", size)
-            }
+            DataType::Char(size) => format_character_string_type(f, "char", size),
+            DataType::Varchar(size) => format_character_string_type(f, "character varying", size),
+            DataType::Nchar(size) => format_character_string_type(f, "nchar", size),
+            DataType::Nvarchar(size) => format_character_string_type(f, "nvarchar", size),
             DataType::Uuid => write!(f, "uuid"),
             DataType::Clob(size) => write!(f, "clob({})", size),
             DataType::Binary(size) => write!(f, "binary({})", size),
             DataType::Varbinary(size) => write!(f, "varbinary({})", size),
             DataType::Blob(size) => write!(f, "blob({})", size),
-            DataType::Decimal(precision, scale) => {
-                if let Some(scale) = scale {
-                    write!(f, "numeric({},{})", precision.unwrap(), scale)
-                } else {
-                    format_type_with_optional_length(f, "numeric", precision)
+            DataType::Decimal {
+                name,
+                precision,
+                scale,
+            } => {
+                write!(f, "{}", name)?;
+                match (precision, scale) {
+                    (Some(precision), Some(scale)) => write!(f, "({},{})", precision, scale),
+                    (Some(precision), None) => write!(f, "({})", precision),
+                    (None, _) => Ok(()),
                 }
             }
             DataType::Float(size) => format_type_with_optional_length(f, "float", size),
@@ -97,20 +140,49 @@ impl fmt::Display for DataType {
             DataType::Double => write!(f, "double"),
             DataType::Boolean => write!(f, "boolean"),
             DataType::Date => write!(f, "date"),
-            DataType::Time => write!(f, "time"),
-            DataType::TimeTz => write!(f, "time with time zone"),
-            DataType::Timestamp => write!(f, "timestamp"),
-            DataType::TimestampTz => write!(f, "timestamp with time zone"),
+            DataType::Time {
+                precision,
+                with_time_zone,
+            } => format_time_type(f, "time", precision, *with_time_zone),
+            DataType::Timestamp {
+                precision,
+                with_time_zone,
+            } => format_time_type(f, "timestamp", precision, *with_time_zone),
             DataType::Interval => write!(f, "interval"),
             DataType::Regclass => write!(f, "regclass"),
             DataType::Text => write!(f, "text"),
             DataType::Bytea => write!(f, "bytea"),
-            DataType::Array(ty) => write!(f, "{}[]", ty),
-            DataType::Custom(ty) => write!(f, "{}", ty),
+            DataType::Array(ty, size) => {
+                write!(f, "{}[", ty)?;
+                if let Some(size) = size {
+                    write!(f, "{}", size)?;
+                }
+                write!(f, "]")
+            }
+            DataType::Custom(ty, modifiers) => {
+                write!(f, "{}", ty)?;
+                if !modifiers.is_empty() {
+                    write!(f, "({})", display_comma_separated(modifiers))?;
+                }
+                Ok(())
+            }
+            DataType::Spelled(_, spelling) => f.write_str(spelling),
         }
     }
 }
 
+fn format_character_string_type(
+    f: &mut fmt::Formatter,
+    sql_type: &'static str,
+    len: &Option<CharacterLength>,
+) -> fmt::Result {
+    write!(f, "{}", sql_type)?;
+    if let Some(len) = len {
+        write!(f, "({})", len)?;
+    }
+    Ok(())
+}
+
 fn format_type_with_optional_length(
     f: &mut fmt::Formatter,
     sql_type: &'static str,
@@ -122,3 +194,78 @@ fn format_type_with_optional_length(
     }
     Ok(())
 }
+
+fn format_time_type(
+    f: &mut fmt::Formatter,
+    sql_type: &'static str,
+    precision: &Option<u64>,
+    with_time_zone: bool,
+) -> fmt::Result {
+    format_type_with_optional_length(f, sql_type, precision)?;
+    if with_time_zone {
+        write!(f, " with time zone")?;
+    }
+    Ok(())
+}
+
+/// The name of a [DataType::Decimal]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecimalName {
+    Numeric,
+    Decimal,
+    Dec,
+}
+
+impl fmt::Display for DecimalName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            DecimalName::Numeric => "numeric",
+            DecimalName::Decimal => "decimal",
+            DecimalName::Dec => "dec",
+        })
+    }
+}
+
+/// The length of a character string type, e.g. the `100 CHARACTERS` in
+/// `VARCHAR(100 CHARACTERS)`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CharacterLength {
+    IntegerLength {
+        length: u64,
+        /// The unit of the length, if given explicitly
+        unit: Option<CharLengthUnits>,
+    },
+    /// `MAX`, the largest length the database supports (MS SQL)
+    Max,
+}
+
+impl fmt::Display for CharacterLength {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CharacterLength::IntegerLength { length, unit } => {
+                write!(f, "{}", length)?;
+                if let Some(unit) = unit {
+                    write!(f, " {}", unit)?;
+                }
+                Ok(())
+            }
+            CharacterLength::Max => f.write_str("max"),
+        }
+    }
+}
+
+/// The unit of a [CharacterLength]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CharLengthUnits {
+    Characters,
+    Octets,
+}
+
+impl fmt::Display for CharLengthUnits {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            CharLengthUnits::Characters => "characters",
+            CharLengthUnits::Octets => "octets",
+        })
+    }
+}
@@ -12,8 +12,12 @@
 
 //! AST types specific to CREATE/ALTER variants of [Statement]
 //! (commonly referred to as Data Definition Language, or DDL)
-use super::{display_comma_separated, DataType, Expr, Ident, ObjectName};
-use std::fmt;
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+use super::value::escape_single_quote_string;
+use super::{display_comma_separated, DataType, Expr, Ident, ObjectName, SqlOption};
+use core::fmt;
 
 /// An `ALTER TABLE` (`Statement::AlterTable`) operation
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -22,6 +26,13 @@ pub enum AlterTableOperation {
     AddConstraint(TableConstraint),
     /// TODO: implement `DROP CONSTRAINT <name>`
     DropConstraint { name: Ident },
+    /// `ALTER [ COLUMN ] <column_name> <operation>`
+    AlterColumn {
+        column_name: Ident,
+        op: AlterColumnOperation,
+    },
+    /// `OWNER TO <new_owner>`
+    OwnerTo { new_owner: Ident },
 }
 
 impl fmt::Display for AlterTableOperation {
@@ -29,6 +40,50 @@ impl fmt::Display for AlterTableOperation {
         match self {
             AlterTableOperation::AddConstraint(c) => write!(f, "ADD {}", c),
             AlterTableOperation::DropConstraint { name } => write!(f, "DROP CONSTRAINT {}", name),
+            AlterTableOperation::AlterColumn { column_name, op } => {
+                write!(f, "ALTER COLUMN {} {}", column_name, op)
+            }
+            AlterTableOperation::OwnerTo { new_owner } => write!(f, "OWNER TO {}", new_owner),
+        }
+    }
+}
+
+/// An `ALTER COLUMN` (`Statement::AlterTable`) operation
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AlterColumnOperation {
+    /// `SET DEFAULT <expr>`, e.g. `SET DEFAULT nextval('t_id_seq'::regclass)`
+    SetDefault(Expr),
+    /// `DROP DEFAULT`
+    DropDefault,
+}
+
+impl fmt::Display for AlterColumnOperation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AlterColumnOperation::SetDefault(expr) => write!(f, "SET DEFAULT {}", expr),
+            AlterColumnOperation::DropDefault => write!(f, "DROP DEFAULT"),
+        }
+    }
+}
+
+/// A change to the `WITH` options of an object (`Statement::AlterViewOptions`)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AlterOptionsOperation {
+    /// `SET ( <option> [, ...] )`
+    Set(Vec<SqlOption>),
+    /// `RESET ( <option_name> [, ...] )`
+    Reset(Vec<Ident>),
+}
+
+impl fmt::Display for AlterOptionsOperation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AlterOptionsOperation::Set(options) => {
+                write!(f, "SET ({})", display_comma_separated(options))
+            }
+            AlterOptionsOperation::Reset(names) => {
+                write!(f, "RESET ({})", display_comma_separated(names))
+            }
         }
     }
 }
@@ -43,6 +98,7 @@ pub enum TableConstraint {
         columns: Vec<Ident>,
         /// Whether this is a `PRIMARY KEY` or just a `UNIQUE` constraint
         is_primary: bool,
+        characteristics: Option<ConstraintCharacteristics>,
     },
     /// A referential integrity constraint (`[ CONSTRAINT <name> ] FOREIGN KEY (<columns>)
     /// REFERENCES <foreign_table> (<referred_columns>)`)
@@ -51,11 +107,19 @@ pub enum TableConstraint {
         columns: Vec<Ident>,
         foreign_table: ObjectName,
         referred_columns: Vec<Ident>,
+        on_delete: Option<ReferentialAction>,
+        on_update: Option<ReferentialAction>,
+        characteristics: Option<ConstraintCharacteristics>,
     },
-    /// `[ CONSTRAINT <name> ] CHECK (<expr>)`
+    /// `[ CONSTRAINT <name> ] CHECK (<expr>) [ NO INHERIT ]
+    /// [ <constraint_characteristics> ]`
     Check {
         name: Option<Ident>,
         expr: Box<Expr>,
+        /// Postgres' `NO INHERIT`, which keeps the constraint from
+        /// propagating to child tables
+        no_inherit: bool,
+        characteristics: Option<ConstraintCharacteristics>,
     },
 }
 
@@ -66,29 +130,52 @@ impl fmt::Display for TableConstraint {
                 name,
                 columns,
                 is_primary,
+                characteristics,
             } => write!(
                 f,
-                "{}{} ({})",
+                "{}{} ({}){}",
                 display_constraint_name(name),
                 if *is_primary { "PRIMARY KEY" } else { "UNIQUE" },
-                display_comma_separated(columns)
+                display_comma_separated(columns),
+                display_characteristics(characteristics)
             ),
             TableConstraint::ForeignKey {
                 name,
                 columns,
                 foreign_table,
                 referred_columns,
+                on_delete,
+                on_update,
+                characteristics,
+            } => {
+                write!(
+                    f,
+                    "{}FOREIGN KEY ({}) REFERENCES {}({})",
+                    display_constraint_name(name),
+                    display_comma_separated(columns),
+                    foreign_table,
+                    display_comma_separated(referred_columns)
+                )?;
+                write!(
+                    f,
+                    "{}{}",
+                    display_referential_actions(*on_delete, *on_update),
+                    display_characteristics(characteristics)
+                )
+            }
+            TableConstraint::Check {
+                name,
+                expr,
+                no_inherit,
+                characteristics,
             } => write!(
                 f,
-                "{}FOREIGN KEY ({}) REFERENCES {}({})",
+                "{}CHECK ({}){}{}",
                 display_constraint_name(name),
-                display_comma_separated(columns),
-                foreign_table,
-                display_comma_separated(referred_columns)
+                expr,
+                if *no_inherit { " NO INHERIT" } else { "" },
+                display_characteristics(characteristics)
             ),
-            TableConstraint::Check { name, expr } => {
-                write!(f, "{}CHECK ({})", display_constraint_name(name), expr)
-            }
         }
     }
 }
@@ -105,6 +192,9 @@ pub struct ColumnDef {
 impl fmt::Display for ColumnDef {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{} {}", self.name, self.data_type)?;
+        if let Some(collation) = &self.collation {
+            write!(f, " COLLATE {}", collation)?;
+        }
         for option in &self.options {
             write!(f, " {}", option)?;
         }
@@ -112,6 +202,24 @@ impl fmt::Display for ColumnDef {
     }
 }
 
+/// A column of a `CREATE VIEW`, which renames a column of the view's query
+/// and, in some dialects, declares its type
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ViewColumnDef {
+    pub name: Ident,
+    pub data_type: Option<DataType>,
+}
+
+impl fmt::Display for ViewColumnDef {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some(data_type) = &self.data_type {
+            write!(f, " {}", data_type)?;
+        }
+        Ok(())
+    }
+}
+
 /// An optionally-named `ColumnOption`: `[ CONSTRAINT <name> ] <column-option>`.
 ///
 /// Note that implementations are substantially more permissive than the ANSI
@@ -150,18 +258,34 @@ pub enum ColumnOption {
     NotNull,
     /// `DEFAULT <restricted-expr>`
     Default(Expr),
-    /// `{ PRIMARY KEY | UNIQUE }`
+    /// `{ PRIMARY KEY | UNIQUE } [ <constraint_characteristics> ]`
     Unique {
         is_primary: bool,
+        characteristics: Option<ConstraintCharacteristics>,
     },
     /// A referential integrity constraint (`[FOREIGN KEY REFERENCES
-    /// <foreign_table> (<referred_columns>)`).
+    /// <foreign_table> (<referred_columns>)
+    /// [ ON DELETE <referential_action> ] [ ON UPDATE <referential_action> ]
+    /// [ <constraint_characteristics> ]`).
     ForeignKey {
         foreign_table: ObjectName,
         referred_columns: Vec<Ident>,
+        on_delete: Option<ReferentialAction>,
+        on_update: Option<ReferentialAction>,
+        characteristics: Option<ConstraintCharacteristics>,
+    },
+    /// `CHECK (<expr>) [ <constraint_characteristics> ]`
+    Check {
+        expr: Expr,
+        characteristics: Option<ConstraintCharacteristics>,
+    },
+    /// SQLite's `AUTOINCREMENT`, which follows `PRIMARY KEY`
+    Autoincrement,
+    /// Redshift's `IDENTITY(<seed>, <increment>)`
+    Identity {
+        seed: u64,
+        increment: u64,
     },
-    // `CHECK (<expr>)`
-    Check(Expr),
 }
 
 impl fmt::Display for ColumnOption {
@@ -171,23 +295,290 @@ impl fmt::Display for ColumnOption {
             Null => write!(f, "NULL"),
             NotNull => write!(f, "NOT NULL"),
             Default(expr) => write!(f, "DEFAULT {}", expr),
-            Unique { is_primary } => {
-                write!(f, "{}", if *is_primary { "PRIMARY KEY" } else { "UNIQUE" })
-            }
+            Unique {
+                is_primary,
+                characteristics,
+            } => write!(
+                f,
+                "{}{}",
+                if *is_primary { "PRIMARY KEY" } else { "UNIQUE" },
+                display_characteristics(characteristics)
+            ),
             ForeignKey {
                 foreign_table,
                 referred_columns,
+                on_delete,
+                on_update,
+                characteristics,
             } => write!(
                 f,
-                "REFERENCES {} ({})",
+                "REFERENCES {} ({}){}{}",
                 foreign_table,
-                display_comma_separated(referred_columns)
+                display_comma_separated(referred_columns),
+                display_referential_actions(*on_delete, *on_update),
+                display_characteristics(characteristics)
+            ),
+            Check {
+                expr,
+                characteristics,
+            } => write!(
+                f,
+                "CHECK ({}){}",
+                expr,
+                display_characteristics(characteristics)
             ),
-            Check(expr) => write!(f, "CHECK ({})", expr),
+            Autoincrement => write!(f, "AUTOINCREMENT"),
+            Identity { seed, increment } => write!(f, "IDENTITY({}, {})", seed, increment),
         }
     }
 }
 
+/// An option of a `CREATE SEQUENCE` or `ALTER SEQUENCE` statement
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SequenceOption {
+    /// `AS <data_type>`
+    As(DataType),
+    /// `INCREMENT [ BY ] <increment>`
+    IncrementBy(Expr),
+    /// `MINVALUE <minvalue>`, or `NO MINVALUE` if `None`
+    MinValue(Option<Expr>),
+    /// `MAXVALUE <maxvalue>`, or `NO MAXVALUE` if `None`
+    MaxValue(Option<Expr>),
+    /// `START [ WITH ] <start>`
+    StartWith(Expr),
+    /// `RESTART [ [ WITH ] <restart> ]`; only valid in `ALTER SEQUENCE`
+    Restart(Option<Expr>),
+    /// `CACHE <cache>`
+    Cache(Expr),
+    /// `CYCLE`, or `NO CYCLE` if `false`
+    Cycle(bool),
+    /// `OWNED BY <table>.<column>`, or `OWNED BY NONE` if `None`
+    OwnedBy(Option<ObjectName>),
+}
+
+impl fmt::Display for SequenceOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SequenceOption::As(data_type) => write!(f, "AS {}", data_type),
+            SequenceOption::IncrementBy(expr) => write!(f, "INCREMENT BY {}", expr),
+            SequenceOption::MinValue(Some(expr)) => write!(f, "MINVALUE {}", expr),
+            SequenceOption::MinValue(None) => write!(f, "NO MINVALUE"),
+            SequenceOption::MaxValue(Some(expr)) => write!(f, "MAXVALUE {}", expr),
+            SequenceOption::MaxValue(None) => write!(f, "NO MAXVALUE"),
+            SequenceOption::StartWith(expr) => write!(f, "START WITH {}", expr),
+            SequenceOption::Restart(Some(expr)) => write!(f, "RESTART WITH {}", expr),
+            SequenceOption::Restart(None) => write!(f, "RESTART"),
+            SequenceOption::Cache(expr) => write!(f, "CACHE {}", expr),
+            SequenceOption::Cycle(true) => write!(f, "CYCLE"),
+            SequenceOption::Cycle(false) => write!(f, "NO CYCLE"),
+            SequenceOption::OwnedBy(Some(name)) => write!(f, "OWNED BY {}", name),
+            SequenceOption::OwnedBy(None) => write!(f, "OWNED BY NONE"),
+        }
+    }
+}
+
+/// An option of a `CREATE ROLE` or `ALTER ROLE` statement
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RoleOption {
+    /// `SUPERUSER` or `NOSUPERUSER`
+    SuperUser(bool),
+    /// `CREATEDB` or `NOCREATEDB`
+    CreateDb(bool),
+    /// `CREATEROLE` or `NOCREATEROLE`
+    CreateRole(bool),
+    /// `INHERIT` or `NOINHERIT`
+    Inherit(bool),
+    /// `LOGIN` or `NOLOGIN`
+    Login(bool),
+    /// `REPLICATION` or `NOREPLICATION`
+    Replication(bool),
+    /// `BYPASSRLS` or `NOBYPASSRLS`
+    BypassRls(bool),
+    /// `CONNECTION LIMIT <limit>`
+    ConnectionLimit(Expr),
+    /// `[ ENCRYPTED ] PASSWORD '<password>'`, or `PASSWORD NULL` if
+    /// `password` is `None`
+    Password {
+        encrypted: bool,
+        password: Option<String>,
+    },
+    /// `VALID UNTIL '<timestamp>'`
+    ValidUntil(String),
+    /// `IN ROLE <role> [, ...]`
+    InRole(Vec<Ident>),
+    /// `ROLE <role> [, ...]`
+    Role(Vec<Ident>),
+    /// `ADMIN <role> [, ...]`
+    Admin(Vec<Ident>),
+}
+
+impl fmt::Display for RoleOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fn flag(f: &mut fmt::Formatter, enabled: bool, name: &str) -> fmt::Result {
+            write!(f, "{}{}", if enabled { "" } else { "NO" }, name)
+        }
+        match self {
+            RoleOption::SuperUser(enabled) => flag(f, *enabled, "SUPERUSER"),
+            RoleOption::CreateDb(enabled) => flag(f, *enabled, "CREATEDB"),
+            RoleOption::CreateRole(enabled) => flag(f, *enabled, "CREATEROLE"),
+            RoleOption::Inherit(enabled) => flag(f, *enabled, "INHERIT"),
+            RoleOption::Login(enabled) => flag(f, *enabled, "LOGIN"),
+            RoleOption::Replication(enabled) => flag(f, *enabled, "REPLICATION"),
+            RoleOption::BypassRls(enabled) => flag(f, *enabled, "BYPASSRLS"),
+            RoleOption::ConnectionLimit(expr) => write!(f, "CONNECTION LIMIT {}", expr),
+            RoleOption::Password {
+                encrypted,
+                password,
+            } => {
+                if *encrypted {
+                    write!(f, "ENCRYPTED ")?;
+                }
+                match password {
+                    Some(password) => {
+                        write!(f, "PASSWORD '{}'", escape_single_quote_string(password))
+                    }
+                    None => write!(f, "PASSWORD NULL"),
+                }
+            }
+            RoleOption::ValidUntil(timestamp) => {
+                write!(f, "VALID UNTIL '{}'", escape_single_quote_string(timestamp))
+            }
+            RoleOption::InRole(roles) => write!(f, "IN ROLE {}", display_comma_separated(roles)),
+            RoleOption::Role(roles) => write!(f, "ROLE {}", display_comma_separated(roles)),
+            RoleOption::Admin(roles) => write!(f, "ADMIN {}", display_comma_separated(roles)),
+        }
+    }
+}
+
+/// `<referential_action> =
+/// { RESTRICT | CASCADE | SET NULL | NO ACTION | SET DEFAULT }`
+///
+/// Used in foreign key constraints in `ON UPDATE` and `ON DELETE` options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReferentialAction {
+    Restrict,
+    Cascade,
+    SetNull,
+    NoAction,
+    SetDefault,
+}
+
+impl fmt::Display for ReferentialAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            ReferentialAction::Restrict => "RESTRICT",
+            ReferentialAction::Cascade => "CASCADE",
+            ReferentialAction::SetNull => "SET NULL",
+            ReferentialAction::NoAction => "NO ACTION",
+            ReferentialAction::SetDefault => "SET DEFAULT",
+        })
+    }
+}
+
+fn display_referential_actions(
+    on_delete: Option<ReferentialAction>,
+    on_update: Option<ReferentialAction>,
+) -> impl fmt::Display {
+    struct ReferentialActions(Option<ReferentialAction>, Option<ReferentialAction>);
+    impl fmt::Display for ReferentialActions {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            if let Some(action) = self.0 {
+                write!(f, " ON DELETE {}", action)?;
+            }
+            if let Some(action) = self.1 {
+                write!(f, " ON UPDATE {}", action)?;
+            }
+            Ok(())
+        }
+    }
+    ReferentialActions(on_delete, on_update)
+}
+
+/// `<constraint_characteristics> = [ [ NOT ] DEFERRABLE ]
+/// [ INITIALLY { DEFERRED | IMMEDIATE } ] [ [ NOT ] ENFORCED ]`
+///
+/// Controls when, and whether, a constraint is checked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConstraintCharacteristics {
+    /// `DEFERRABLE` (`Some(true)`) or `NOT DEFERRABLE` (`Some(false)`)
+    pub deferrable: Option<bool>,
+    /// `INITIALLY { DEFERRED | IMMEDIATE }`
+    pub initially: Option<DeferrableInitial>,
+    /// `ENFORCED` (`Some(true)`) or `NOT ENFORCED` (`Some(false)`)
+    pub enforced: Option<bool>,
+}
+
+impl fmt::Display for ConstraintCharacteristics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut delim = "";
+        if let Some(deferrable) = self.deferrable {
+            let deferrable = if deferrable {
+                "DEFERRABLE"
+            } else {
+                "NOT DEFERRABLE"
+            };
+            write!(f, "{}", deferrable)?;
+            delim = " ";
+        }
+        if let Some(initially) = self.initially {
+            write!(f, "{}INITIALLY {}", delim, initially)?;
+            delim = " ";
+        }
+        if let Some(enforced) = self.enforced {
+            let enforced = if enforced { "ENFORCED" } else { "NOT ENFORCED" };
+            write!(f, "{}{}", delim, enforced)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeferrableInitial {
+    Immediate,
+    Deferred,
+}
+
+impl fmt::Display for DeferrableInitial {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            DeferrableInitial::Immediate => "IMMEDIATE",
+            DeferrableInitial::Deferred => "DEFERRED",
+        })
+    }
+}
+
+fn display_characteristics<'a>(
+    characteristics: &'a Option<ConstraintCharacteristics>,
+) -> impl fmt::Display + 'a {
+    struct Characteristics<'a>(&'a Option<ConstraintCharacteristics>);
+    impl<'a> fmt::Display for Characteristics<'a> {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            if let Some(characteristics) = self.0 {
+                write!(f, " {}", characteristics)?;
+            }
+            Ok(())
+        }
+    }
+    Characteristics(characteristics)
+}
+
+/// ClickHouse's `ENGINE = <name> [ ( <parameters> ) ]` table engine clause
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TableEngine {
+    pub name: Ident,
+    pub parameters: Option<Vec<Expr>>,
+}
+
+impl fmt::Display for TableEngine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some(parameters) = &self.parameters {
+            write!(f, "({})", display_comma_separated(parameters))?;
+        }
+        Ok(())
+    }
+}
+
 fn display_constraint_name<'a>(name: &'a Option<Ident>) -> impl fmt::Display + 'a {
     struct ConstraintName<'a>(&'a Option<Ident>);
     impl<'a> fmt::Display for ConstraintName<'a> {
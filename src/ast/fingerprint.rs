@@ -0,0 +1,124 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[cfg(not(feature = "std"))]
+use alloc::string::{String, ToString};
+
+use super::redact::redact_literals;
+use super::simplify::{simplify_statement, SimplifyOptions};
+use super::visit_mut::VisitMut;
+use super::{Ident, Statement};
+use crate::dialect::Dialect;
+
+impl Statement {
+    /// Return the SQL text of this statement with its literals replaced by
+    /// parameters, its unquoted identifiers
+    /// [case-folded](Dialect::fold_identifier_case) as `dialect` does and
+    /// redundant parentheses removed
+    ///
+    /// Statements that differ only in those respects, or in whitespace and
+    /// keyword case, normalize to the same text.
+    pub fn normalized(&self, dialect: &dyn Dialect) -> String {
+        let mut statement = self.clone();
+        redact_literals(&mut statement);
+        FoldIdents(dialect).visit_statement(&mut statement);
+        let options = SimplifyOptions {
+            remove_nested: true,
+            fold_literals: false,
+            flatten_conjunctions: false,
+        };
+        simplify_statement(&mut statement, &options);
+        statement.to_string()
+    }
+
+    /// Return a hash of [Statement::normalized], for identifying statements
+    /// that differ only in their constants
+    ///
+    /// The hash is FNV-1a, so it is stable across platforms, compiler
+    /// versions and processes. It does change if the normalized text of the
+    /// statement changes between versions of this crate.
+    pub fn fingerprint(&self, dialect: &dyn Dialect) -> u64 {
+        fnv1a(self.normalized(dialect).as_bytes())
+    }
+}
+
+struct FoldIdents<'a>(&'a dyn Dialect);
+
+impl<'a, 'ast> VisitMut<'ast> for FoldIdents<'a> {
+    fn visit_ident(&mut self, ident: &'ast mut Ident) {
+        *ident = ident.normalized(self.0);
+    }
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    bytes.iter().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialect::{AnsiDialect, MySqlDialect, PostgreSqlDialect};
+    use crate::test_utils::*;
+
+    #[test]
+    fn fingerprint_normalizes() {
+        let dialect = PostgreSqlDialect {};
+        let a = all_dialects()
+            .unverified_stmt("SELECT a, \"B\" FROM t WHERE (x) = 1 AND y IN ('a', 'b')");
+        let b = all_dialects()
+            .unverified_stmt("select A,\n  \"B\" from T where X = 42 and Y in ('c', 'd')");
+        assert_eq!(
+            "SELECT a, \"B\" FROM t WHERE x = $1 AND y IN ($2, $3)",
+            a.normalized(&dialect)
+        );
+        assert_eq!(a.normalized(&dialect), b.normalized(&dialect));
+        assert_eq!(a.fingerprint(&dialect), b.fingerprint(&dialect));
+
+        // Quoted identifiers, the number of literals, and the structure of
+        // the statement all matter
+        for sql in &[
+            "SELECT a, \"b\" FROM t WHERE x = 1 AND y IN ('a', 'b')",
+            "SELECT a, \"B\" FROM t WHERE x = 1 AND y IN ('a')",
+            "SELECT a, \"B\" FROM t WHERE x = 1 OR y IN ('a', 'b')",
+        ] {
+            assert_ne!(
+                a.fingerprint(&dialect),
+                all_dialects().verified_stmt(sql).fingerprint(&dialect),
+                "{}",
+                sql
+            );
+        }
+
+        // Identifiers are folded the way the dialect folds them
+        assert_eq!(
+            "SELECT A, \"B\" FROM T WHERE X = $1 AND Y IN ($2, $3)",
+            a.normalized(&AnsiDialect {})
+        );
+        let mysql = MySqlDialect {};
+        assert_ne!(a.fingerprint(&mysql), b.fingerprint(&mysql));
+    }
+
+    #[test]
+    fn fingerprint_is_stable() {
+        assert_eq!(0xcbf2_9ce4_8422_2325, fnv1a(b""));
+        assert_eq!(0xaf63_dc4c_8601_ec8c, fnv1a(b"a"));
+        let statement = all_dialects().verified_stmt("SELECT 'secret'");
+        assert_eq!(
+            fnv1a(b"SELECT $1"),
+            statement.fingerprint(&PostgreSqlDialect {})
+        );
+    }
+}
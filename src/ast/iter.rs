@@ -0,0 +1,142 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "std")]
+use std::vec;
+
+use super::visit::{self, Visit};
+use super::{Expr, Query, Statement, TableFactor};
+
+/// An iterator over the expressions in an AST node, as returned by
+/// [Statement::iter_exprs] and friends
+#[derive(Debug, Clone)]
+pub struct Exprs<'ast>(vec::IntoIter<&'ast Expr>);
+
+impl<'ast> Iterator for Exprs<'ast> {
+    type Item = &'ast Expr;
+
+    fn next(&mut self) -> Option<&'ast Expr> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+/// An iterator over the relations in an AST node, as returned by
+/// [Statement::iter_relations] and friends
+#[derive(Debug, Clone)]
+pub struct Relations<'ast>(vec::IntoIter<&'ast TableFactor>);
+
+impl<'ast> Iterator for Relations<'ast> {
+    type Item = &'ast TableFactor;
+
+    fn next(&mut self) -> Option<&'ast TableFactor> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+/// Collects nodes in the order [Visit] reaches them, which is the order
+/// they appear in the SQL text
+#[derive(Default)]
+struct Collector<'ast> {
+    exprs: Vec<&'ast Expr>,
+    relations: Vec<&'ast TableFactor>,
+}
+
+impl<'ast> Visit<'ast> for Collector<'ast> {
+    fn visit_expr(&mut self, expr: &'ast Expr) {
+        self.exprs.push(expr);
+        visit::visit_expr(self, expr);
+    }
+
+    fn visit_table_factor(&mut self, table_factor: &'ast TableFactor) {
+        self.relations.push(table_factor);
+        visit::visit_table_factor(self, table_factor);
+    }
+}
+
+macro_rules! impl_iter {
+    ($ty:ident, $visit:ident) => {
+        impl $ty {
+            /// Iterate over every expression in this node, depth first in
+            /// the order they appear in the SQL text. Each expression is
+            /// yielded before its subexpressions, and expressions inside
+            /// subqueries are included.
+            pub fn iter_exprs(&self) -> Exprs<'_> {
+                let mut collector = Collector::default();
+                collector.$visit(self);
+                Exprs(collector.exprs.into_iter())
+            }
+
+            /// Iterate over every relation in the `FROM` clauses of this
+            /// node, including those of subqueries, depth first in the order
+            /// they appear in the SQL text. A derived table or nested join is
+            /// yielded before the relations inside it.
+            pub fn iter_relations(&self) -> Relations<'_> {
+                let mut collector = Collector::default();
+                collector.$visit(self);
+                Relations(collector.relations.into_iter())
+            }
+        }
+    };
+}
+
+impl_iter!(Statement, visit_statement);
+impl_iter!(Query, visit_query);
+impl_iter!(Expr, visit_expr);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn iter_exprs_in_document_order() {
+        let statement =
+            all_dialects().verified_stmt("SELECT a + 1 FROM t WHERE b IN (SELECT c FROM u)");
+        let exprs: Vec<_> = statement.iter_exprs().map(|e| e.to_string()).collect();
+        assert_eq!(
+            vec!["a + 1", "a", "1", "b IN (SELECT c FROM u)", "b", "c"],
+            exprs
+        );
+        assert!(statement
+            .iter_exprs()
+            .any(|e| matches!(e, Expr::InSubquery { .. })));
+    }
+
+    #[test]
+    fn iter_relations_in_document_order() {
+        let statement = all_dialects().verified_stmt(
+            "SELECT * FROM t JOIN (SELECT * FROM u, v) AS d ON true \
+             WHERE EXISTS (SELECT 1 FROM w)",
+        );
+        let relations: Vec<_> = statement
+            .iter_relations()
+            .map(|r| match r {
+                TableFactor::Table { name, .. } => name.to_string(),
+                TableFactor::Derived { .. } => "derived".to_string(),
+                TableFactor::JsonTable { .. } => "json_table".to_string(),
+                TableFactor::XmlTable { .. } => "xml_table".to_string(),
+                TableFactor::NestedJoin(_) => "nested".to_string(),
+            })
+            .collect();
+        assert_eq!(vec!["t", "derived", "u", "v", "w"], relations);
+    }
+}
@@ -12,10 +12,19 @@
 
 //! SQL Abstract Syntax Tree (AST) types
 
+pub mod bind;
 mod data_type;
 mod ddl;
+mod fingerprint;
+mod iter;
+pub mod normalize;
 mod operator;
+pub mod options;
+mod params;
 mod query;
+pub mod redact;
+pub mod simplify;
+pub mod validate;
 mod value;
 #[macro_use]
 mod visit_macro;
@@ -38,20 +47,44 @@ pub mod visit_mut {
     make_visitor!(VisitMut: &mut);
 }
 
-use std::fmt;
+#[cfg(not(feature = "std"))]
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use core::fmt;
 
-pub use self::data_type::DataType;
+use crate::dialect::keywords::{Keyword, IDENTIFIER_SAFE_KEYWORDS};
+use crate::dialect::Dialect;
+use crate::tokenizer::Token;
+
+pub use self::data_type::{CharLengthUnits, CharacterLength, DataType, DecimalName};
 pub use self::ddl::{
-    AlterTableOperation, ColumnDef, ColumnOption, ColumnOptionDef, TableConstraint,
+    AlterColumnOperation, AlterOptionsOperation, AlterTableOperation, ColumnDef, ColumnOption,
+    ColumnOptionDef, ConstraintCharacteristics, DeferrableInitial, ReferentialAction, RoleOption,
+    SequenceOption, TableConstraint, TableEngine, ViewColumnDef,
 };
+pub use self::iter::{Exprs, Relations};
 pub use self::operator::{BinaryOperator, UnaryOperator};
+pub use self::params::{ParamContext, ParamRef};
 pub use self::query::{
-    Cte, Fetch, Join, JoinConstraint, JoinOperator, OrderByExpr, Query, Select, SelectItem,
-    SetExpr, SetOperator, TableAlias, TableFactor, TableWithJoins, Values,
+    Cte, Fetch, Join, JoinConstraint, JoinOperator, JsonTableColumn, JsonTableColumnErrorHandling,
+    JsonTableNamedColumn, LockClause, LockType, NonBlock, OrderByExpr, Query, Select, SelectItem,
+    SetExpr, SetOperator, TableAlias, TableAliasColumn, TableFactor, TableVersion, TableWithJoins,
+    Values, WildcardOptions, WildcardReplacement, With, XmlNamespace, XmlTableColumn,
+    XmlTableNamedColumn,
+};
+use self::value::escape_copy_text;
+pub use self::value::{
+    escape_string_literal, unescape_string_literal, unescape_unicode_string_literal,
+    EscapeStringLiteral,
 };
 pub use self::value::{
-    DateTimeField, ExtractField, Interval, IntervalValue, ParsedDate, ParsedDateTime,
-    ParsedTimestamp, Value,
+    DateTimeField, DollarQuotedString, ExtractField, Interval, IntervalValue, ParsedDate,
+    ParsedDateTime, ParsedTimestamp, SignedDuration, Value,
 };
 
 struct DisplaySeparated<'a, T>
@@ -125,6 +158,72 @@ impl Ident {
             quote_style: Some(quote),
         }
     }
+
+    /// Create an identifier that refers to `value` in `dialect`, quoted if
+    /// needed, or fail if no identifier can. Only empty values and values
+    /// containing a NUL character are rejected; see
+    /// [Ident::quote_if_needed] for when quotes are added.
+    pub fn new_checked<S>(value: S, dialect: &dyn Dialect) -> Result<Self, ParserError>
+    where
+        S: Into<String>,
+    {
+        let value = value.into();
+        if value.is_empty() {
+            return Err(ParserError::ParserError(
+                "Identifiers can't be empty".to_string(),
+            ));
+        }
+        if value.contains('\0') {
+            return Err(ParserError::ParserError(format!(
+                "Identifiers can't contain NUL characters: {:?}",
+                value
+            )));
+        }
+        Ok(Ident::quote_if_needed(value, dialect))
+    }
+
+    /// Create an identifier that refers to `value` in `dialect`, quoted
+    /// with the first of `"`, `` ` `` and `[` that the dialect accepts
+    /// unless it can be written as is. An unquoted identifier must consist
+    /// of characters the dialect allows in one, must not be
+    /// [case-folded](Dialect::fold_identifier_case) to a different name,
+    /// and must not be a keyword of the dialect other than one of the
+    /// [IDENTIFIER_SAFE_KEYWORDS].
+    pub fn quote_if_needed<S>(value: S, dialect: &dyn Dialect) -> Self
+    where
+        S: Into<String>,
+    {
+        let value = value.into();
+        let mut chars = value.chars();
+        let plain = matches!(chars.next(), Some(ch) if dialect.is_identifier_start(ch))
+            && chars.all(|ch| dialect.is_identifier_part(ch))
+            && dialect.fold_identifier_case(&value) == value
+            && match Token::make_word(&value, None) {
+                Token::Word(w) if w.keyword != Keyword::NoKeyword => {
+                    !dialect.is_keyword(w.keyword) || IDENTIFIER_SAFE_KEYWORDS.contains(&w.keyword)
+                }
+                _ => true,
+            };
+        if plain {
+            return Ident::new(value);
+        }
+        let quote = ['"', '`', '[']
+            .iter()
+            .copied()
+            .find(|&q| dialect.is_delimited_identifier_start(q))
+            .unwrap_or('"');
+        Ident::with_quote(quote, value)
+    }
+
+    /// Return this identifier as `dialect` resolves it: quoted identifiers
+    /// are kept exactly as written, while unquoted ones are
+    /// [case-folded](Dialect::fold_identifier_case)
+    pub fn normalized(&self, dialect: &dyn Dialect) -> Ident {
+        match self.quote_style {
+            Some(_) => self.clone(),
+            None => Ident::new(dialect.fold_identifier_case(&self.value)),
+        }
+    }
 }
 
 impl From<&str> for Ident {
@@ -138,19 +237,109 @@ impl From<&str> for Ident {
 
 impl fmt::Display for Ident {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self.quote_style {
-            Some(q) if q == '"' || q == '\'' || q == '`' => write!(f, "{}{}{}", q, self.value, q),
-            Some(q) if q == '[' => write!(f, "[{}]", self.value),
-            None => f.write_str(&self.value),
+        let end = match self.quote_style {
+            Some(q) if q == '"' || q == '\'' || q == '`' => q,
+            Some('[') => ']',
+            None => return f.write_str(&self.value),
             _ => panic!("unexpected quote style"),
+        };
+        // A closing quote inside a quoted identifier is escaped by doubling it
+        write!(f, "{}", self.quote_style.unwrap())?;
+        for ch in self.value.chars() {
+            if ch == end {
+                write!(f, "{}", end)?;
+            }
+            write!(f, "{}", ch)?;
         }
+        write!(f, "{}", end)
+    }
+}
+
+/// The location of a piece of syntax in the query it was parsed from, as a
+/// range of byte offsets
+///
+/// A span records where something was written rather than what it means, so
+/// all spans compare equal and hash alike: two nodes that differ only in
+/// where they appeared are equal.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Span {
+    /// The offset of the first byte
+    pub start: usize,
+    /// The offset just past the last byte
+    pub end: usize,
+}
+
+impl PartialEq for Span {
+    fn eq(&self, _other: &Span) -> bool {
+        true
     }
 }
 
+impl Eq for Span {}
+
+impl core::hash::Hash for Span {
+    fn hash<H: core::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 /// A name of a table, view, custom type, etc., possibly multi-part, i.e. db.schema.obj
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ObjectName(pub Vec<Ident>);
 
+impl ObjectName {
+    /// Return this name with each part [normalized](Ident::normalized)
+    /// according to `dialect`
+    pub fn normalized(&self, dialect: &dyn Dialect) -> ObjectName {
+        ObjectName(
+            self.0
+                .iter()
+                .map(|ident| ident.normalized(dialect))
+                .collect(),
+        )
+    }
+
+    /// Return the last part of this name, which names the object itself,
+    /// like `t` in `db.schema.t`
+    pub fn item(&self) -> Option<&Ident> {
+        self.part_from_end(0)
+    }
+
+    /// Return the part of this name before the [item](ObjectName::item),
+    /// like `schema` in `db.schema.t`, if any
+    pub fn schema(&self) -> Option<&Ident> {
+        self.part_from_end(1)
+    }
+
+    /// Return the part of this name before the [schema](ObjectName::schema),
+    /// like `db` in `db.schema.t`, if any
+    pub fn database(&self) -> Option<&Ident> {
+        self.part_from_end(2)
+    }
+
+    fn part_from_end(&self, n: usize) -> Option<&Ident> {
+        let i = self.0.len().checked_sub(n + 1)?;
+        Some(&self.0[i])
+    }
+
+    /// Determine if this name and `other` can refer to the same object in
+    /// `dialect`, as when resolving a partly qualified name: each part of
+    /// the shorter name must match the corresponding part at the end of the
+    /// longer one once both are [normalized](Ident::normalized). So `t`
+    /// matches `schema.t` and `db.schema.t`, but not `other.t`.
+    pub fn matches(&self, other: &ObjectName, dialect: &dyn Dialect) -> bool {
+        self.0
+            .iter()
+            .rev()
+            .zip(other.0.iter().rev())
+            .all(|(a, b)| a.normalized(dialect).value == b.normalized(dialect).value)
+    }
+
+    /// Return the name made of the parts of this name followed by those of
+    /// `other`, like a schema name joined with the name of a table in it
+    pub fn join(&self, other: &ObjectName) -> ObjectName {
+        ObjectName(self.0.iter().chain(&other.0).cloned().collect())
+    }
+}
+
 impl fmt::Display for ObjectName {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", display_separated(&self.0, "."))
@@ -163,11 +352,12 @@ impl fmt::Display for ObjectName {
 /// (e.g. boolean vs string), so the caller must handle expressions of
 /// inappropriate type, like `WHERE 1` or `SELECT 1=1`, as necessary.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum Expr {
     /// Identifier e.g. table name or column name
     Identifier(Ident),
     /// Unqualified wildcard (`*`). SQL allows this in limited contexts, such as:
-    /// - right after `SELECT` (which is represented as a [SelectItem::Wildcard] instead)
+    /// - right after `SELECT` (which is represented as a [SelectItem::Wildcard](query::SelectItem::Wildcard) instead)
     /// - or as part of an aggregate function, e.g. `COUNT(*)`,
     ///
     /// ...but we currently also accept it in contexts where it doesn't make
@@ -180,6 +370,13 @@ pub enum Expr {
     CompoundIdentifier(Vec<Ident>),
     /// A positional parameter, e.g., `$1` or `$42`
     Parameter(usize),
+    /// A reference to an item of the select list by its 1-based position,
+    /// as in `ORDER BY 1` or `GROUP BY 2`
+    ///
+    /// The parser only produces this for a lone integer in a position where
+    /// the dialect interprets it as an ordinal. Elsewhere, integers are
+    /// [Value::Number]s.
+    Ordinal(u64),
     /// `IS NULL` expression
     IsNull(Box<Expr>),
     /// `IS NOT NULL` expression
@@ -203,12 +400,41 @@ pub enum Expr {
         low: Box<Expr>,
         high: Box<Expr>,
     },
+    /// `<expr> [ NOT ] LIKE <pattern> [ ESCAPE <escape_char> ]`
+    Like {
+        expr: Box<Expr>,
+        negated: bool,
+        pattern: Box<Expr>,
+        escape_char: Option<char>,
+    },
+    /// `<expr> [ NOT ] ILIKE <pattern> [ ESCAPE <escape_char> ]`, a
+    /// case-insensitive LIKE
+    ILike {
+        expr: Box<Expr>,
+        negated: bool,
+        pattern: Box<Expr>,
+        escape_char: Option<char>,
+    },
+    /// `<expr> [ NOT ] SIMILAR TO <pattern> [ ESCAPE <escape_char> ]`
+    SimilarTo {
+        expr: Box<Expr>,
+        negated: bool,
+        pattern: Box<Expr>,
+        escape_char: Option<char>,
+    },
     /// Binary operation e.g. `1 + 1` or `foo > bar`
     BinaryOp {
         left: Box<Expr>,
         op: BinaryOperator,
         right: Box<Expr>,
     },
+    /// A chain of two or more operands joined by the same `AND` or `OR`
+    /// operator, e.g. `a AND b AND c`. Never produced by the parser, only by
+    /// [simplify] when flattening conjunctions is requested.
+    Conjunction {
+        op: BinaryOperator,
+        operands: Vec<Expr>,
+    },
     /// Unary operation e.g. `NOT foo`
     UnaryOp { op: UnaryOperator, expr: Box<Expr> },
     /// CAST an expression to a different data type e.g. `CAST(foo AS VARCHAR(123))`
@@ -220,13 +446,55 @@ pub enum Expr {
         field: ExtractField,
         expr: Box<Expr>,
     },
+    /// An interval whose value is an arbitrary expression rather than a
+    /// string literal, e.g. MySQL's `INTERVAL 1 DAY`. Intervals given as
+    /// string literals are [`Value::Interval`]s.
+    Interval {
+        value: Box<Expr>,
+        field: DateTimeField,
+    },
+    /// `TRIM([BOTH | LEADING | TRAILING] [<chars>] FROM <expr>)`, or
+    /// `TRIM(<expr>)`
+    Trim {
+        expr: Box<Expr>,
+        trim_where: Option<TrimWhereField>,
+        trim_what: Option<Box<Expr>>,
+    },
+    /// `OVERLAY(<expr> PLACING <expr> FROM <expr> [FOR <expr>])`
+    Overlay {
+        expr: Box<Expr>,
+        overlay_what: Box<Expr>,
+        overlay_from: Box<Expr>,
+        overlay_for: Option<Box<Expr>>,
+    },
+    /// `CONVERT(<expr>, <type>)`, `CONVERT(<expr> USING <charset>)`, or, in
+    /// dialects that put the type first, `CONVERT(<type>, <expr> [, <style>])`
+    Convert {
+        expr: Box<Expr>,
+        data_type: Option<DataType>,
+        charset: Option<ObjectName>,
+        /// Whether the type is written before the expression
+        target_before_value: bool,
+        styles: Vec<Expr>,
+    },
     /// `expr COLLATE collation`
     Collate {
         expr: Box<Expr>,
         collation: ObjectName,
     },
+    /// `DEFAULT`, standing for a column's default value in the VALUES rows
+    /// of an INSERT or in an UPDATE assignment
+    Default,
+    /// Snowflake's access into a semi-structured value, e.g. `src:a.b[0]`
+    VariantAccess {
+        expr: Box<Expr>,
+        path: Vec<VariantPathElement>,
+    },
     /// Nested expression e.g. `(foo > bar)` or `(1)`
     Nested(Box<Expr>),
+    /// A row constructor `(a, b, ...)`; currently only parsed as the value of
+    /// a multi-column UPDATE assignment, e.g. `SET (a, b) = (1, 2)`
+    Row(Vec<Expr>),
     /// A literal value, such as string, number, date or NULL
     Value(Value),
     /// Scalar function call e.g. `LEFT(foo, 5)`
@@ -238,16 +506,21 @@ pub enum Expr {
     /// <https://jakewheat.github.io/sql-overview/sql-2011-foundation-grammar.html#simple-when-clause>
     Case {
         operand: Option<Box<Expr>>,
-        conditions: Vec<Expr>,
-        results: Vec<Expr>,
+        when_clauses: Vec<WhenClause>,
         else_result: Option<Box<Expr>>,
+        /// Where `ELSE <result>` was written, when the expression was
+        /// parsed from a string and has an `ELSE`; empty otherwise
+        else_span: Span,
     },
-    /// An exists expression `EXISTS(SELECT ...)`, used in expressions like
+    /// An exists expression `[NOT] EXISTS(SELECT ...)`, used in expressions like
     /// `WHERE EXISTS (SELECT ...)`.
-    Exists(Box<Query>),
+    Exists { subquery: Box<Query>, negated: bool },
     /// A parenthesized subquery `(SELECT ...)`, used in expression like
     /// `SELECT (subquery) AS x` or `WHERE (subquery) = x`
     Subquery(Box<Query>),
+    /// An array constructed from the rows of a subquery, e.g.
+    /// `ARRAY(SELECT x FROM t)`
+    ArraySubquery(Box<Query>),
     /// `<expr> <op> ANY/SOME (<query>)`
     Any {
         left: Box<Expr>,
@@ -271,6 +544,7 @@ impl fmt::Display for Expr {
             Expr::QualifiedWildcard(q) => write!(f, "{}.*", display_separated(q, ".")),
             Expr::CompoundIdentifier(s) => write!(f, "{}", display_separated(s, ".")),
             Expr::Parameter(n) => write!(f, "${}", n),
+            Expr::Ordinal(n) => write!(f, "{}", n),
             Expr::IsNull(ast) => write!(f, "{} IS NULL", ast),
             Expr::IsNotNull(ast) => write!(f, "{} IS NOT NULL", ast),
             Expr::InList {
@@ -308,26 +582,124 @@ impl fmt::Display for Expr {
                 low,
                 high
             ),
+            Expr::Like {
+                expr,
+                negated,
+                pattern,
+                escape_char,
+            } => display_pattern_match(f, expr, *negated, "LIKE", pattern, *escape_char),
+            Expr::ILike {
+                expr,
+                negated,
+                pattern,
+                escape_char,
+            } => display_pattern_match(f, expr, *negated, "ILIKE", pattern, *escape_char),
+            Expr::SimilarTo {
+                expr,
+                negated,
+                pattern,
+                escape_char,
+            } => display_pattern_match(f, expr, *negated, "SIMILAR TO", pattern, *escape_char),
             Expr::BinaryOp { left, op, right } => write!(f, "{} {} {}", left, op, right),
+            Expr::Conjunction { op, operands } => {
+                let mut delim = "";
+                for operand in operands {
+                    write!(f, "{}{}", delim, operand)?;
+                    delim = if *op == BinaryOperator::Or {
+                        " OR "
+                    } else {
+                        " AND "
+                    };
+                }
+                Ok(())
+            }
             Expr::UnaryOp { op, expr } => write!(f, "{} {}", op, expr),
             Expr::Cast { expr, data_type } => write!(f, "CAST({} AS {})", expr, data_type),
             Expr::Extract { field, expr } => write!(f, "EXTRACT({} FROM {})", field, expr),
+            Expr::Interval { value, field } => write!(f, "INTERVAL {} {}", value, field),
+            Expr::Trim {
+                expr,
+                trim_where,
+                trim_what,
+            } => {
+                f.write_str("TRIM(")?;
+                if let Some(trim_where) = trim_where {
+                    write!(f, "{} ", trim_where)?;
+                }
+                if let Some(trim_what) = trim_what {
+                    write!(f, "{} ", trim_what)?;
+                }
+                if trim_where.is_some() || trim_what.is_some() {
+                    f.write_str("FROM ")?;
+                }
+                write!(f, "{})", expr)
+            }
+            Expr::Overlay {
+                expr,
+                overlay_what,
+                overlay_from,
+                overlay_for,
+            } => {
+                write!(
+                    f,
+                    "OVERLAY({} PLACING {} FROM {}",
+                    expr, overlay_what, overlay_from
+                )?;
+                if let Some(overlay_for) = overlay_for {
+                    write!(f, " FOR {}", overlay_for)?;
+                }
+                f.write_str(")")
+            }
+            Expr::Convert {
+                expr,
+                data_type,
+                charset,
+                target_before_value,
+                styles,
+            } => {
+                f.write_str("CONVERT(")?;
+                match (data_type, charset) {
+                    (Some(data_type), _) if *target_before_value => {
+                        write!(f, "{}, {}", data_type, expr)?
+                    }
+                    (Some(data_type), _) => write!(f, "{}, {}", expr, data_type)?,
+                    (None, Some(charset)) => write!(f, "{} USING {}", expr, charset)?,
+                    (None, None) => write!(f, "{}", expr)?,
+                }
+                if !styles.is_empty() {
+                    write!(f, ", {}", display_comma_separated(styles))?;
+                }
+                f.write_str(")")
+            }
             Expr::Collate { expr, collation } => write!(f, "{} COLLATE {}", expr, collation),
+            Expr::Default => f.write_str("DEFAULT"),
+            Expr::VariantAccess { expr, path } => {
+                write!(f, "{}:", expr)?;
+                for (i, element) in path.iter().enumerate() {
+                    match element {
+                        VariantPathElement::Field(field) if i == 0 => write!(f, "{}", field)?,
+                        VariantPathElement::Field(field) => write!(f, ".{}", field)?,
+                        VariantPathElement::Index(index) => write!(f, "[{}]", index)?,
+                    }
+                }
+                Ok(())
+            }
             Expr::Nested(ast) => write!(f, "({})", ast),
+            Expr::Row(exprs) => write!(f, "({})", display_comma_separated(exprs)),
             Expr::Value(v) => write!(f, "{}", v),
             Expr::Function(fun) => write!(f, "{}", fun),
             Expr::Case {
                 operand,
-                conditions,
-                results,
+                when_clauses,
                 else_result,
+                else_span: _,
             } => {
                 f.write_str("CASE")?;
                 if let Some(operand) = operand {
                     write!(f, " {}", operand)?;
                 }
-                for (c, r) in conditions.iter().zip(results) {
-                    write!(f, " WHEN {} THEN {}", c, r)?;
+                for when_clause in when_clauses {
+                    write!(f, " {}", when_clause)?;
                 }
 
                 if let Some(else_result) = else_result {
@@ -335,8 +707,14 @@ impl fmt::Display for Expr {
                 }
                 f.write_str(" END")
             }
-            Expr::Exists(s) => write!(f, "EXISTS ({})", s),
+            Expr::Exists { subquery, negated } => write!(
+                f,
+                "{}EXISTS ({})",
+                if *negated { "NOT " } else { "" },
+                subquery
+            ),
             Expr::Subquery(s) => write!(f, "({})", s),
+            Expr::ArraySubquery(s) => write!(f, "ARRAY({})", s),
             Expr::Any {
                 left,
                 op,
@@ -355,6 +733,59 @@ impl fmt::Display for Expr {
     }
 }
 
+fn display_pattern_match(
+    f: &mut fmt::Formatter,
+    expr: &Expr,
+    negated: bool,
+    op: &str,
+    pattern: &Expr,
+    escape_char: Option<char>,
+) -> fmt::Result {
+    write!(
+        f,
+        "{} {}{} {}",
+        expr,
+        if negated { "NOT " } else { "" },
+        op,
+        pattern
+    )?;
+    if let Some(c) = escape_char {
+        write!(
+            f,
+            " ESCAPE '{}'",
+            value::escape_single_quote_string(c.encode_utf8(&mut [0; 4]))
+        )?;
+    }
+    Ok(())
+}
+
+/// The characters a [`Expr::Trim`] removes from
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TrimWhereField {
+    Both,
+    Leading,
+    Trailing,
+}
+
+impl fmt::Display for TrimWhereField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            TrimWhereField::Both => "BOTH",
+            TrimWhereField::Leading => "LEADING",
+            TrimWhereField::Trailing => "TRAILING",
+        })
+    }
+}
+
+/// An element of the path in an [`Expr::VariantAccess`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum VariantPathElement {
+    /// `.field`, or the `field` right after the colon
+    Field(Ident),
+    /// `[index]`
+    Index(Expr),
+}
+
 /// A window specification (i.e. `OVER (PARTITION BY .. ORDER BY .. etc.)`)
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct WindowSpec {
@@ -391,6 +822,9 @@ impl fmt::Display for WindowSpec {
                 f.write_str(delim)?;
                 write!(f, "{} {}", window_frame.units, window_frame.start_bound)?;
             }
+            if let Some(exclude) = &window_frame.exclude {
+                write!(f, " {}", exclude)?;
+            }
         }
         Ok(())
     }
@@ -400,7 +834,8 @@ impl fmt::Display for WindowSpec {
 /// `RANGE UNBOUNDED PRECEDING` or `ROWS BETWEEN 5 PRECEDING AND CURRENT ROW`.
 ///
 /// Note: The parser does not validate the specified bounds; the caller should
-/// reject invalid bounds like `ROWS UNBOUNDED FOLLOWING` before execution.
+/// reject invalid bounds like `ROWS UNBOUNDED FOLLOWING` before execution,
+/// e.g. with [WindowFrame::validate].
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct WindowFrame {
     pub units: WindowFrameUnits,
@@ -409,7 +844,8 @@ pub struct WindowFrame {
     /// indicates the shorthand form (e.g. `ROWS 1 PRECEDING`), which must
     /// behave the same as `end_bound = WindowFrameBound::CurrentRow`.
     pub end_bound: Option<WindowFrameBound>,
-    // TBD: EXCLUDE
+    /// The rows of the `EXCLUDE` clause, which are left out of the frame
+    pub exclude: Option<WindowFrameExclusion>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -445,6 +881,92 @@ impl FromStr for WindowFrameUnits {
     }
 }
 
+impl WindowFrame {
+    /// Check the bounds of this frame against the rules of the SQL
+    /// standard: the frame can't start with `UNBOUNDED FOLLOWING` or end
+    /// with `UNBOUNDED PRECEDING`, and the kind of its start bound can't
+    /// come after that of its end bound, as in `BETWEEN CURRENT ROW AND 1
+    /// PRECEDING`. Offsets are not compared, so `BETWEEN 1 PRECEDING AND 2
+    /// PRECEDING`, which selects no rows, is valid.
+    pub fn validate(&self) -> Result<(), WindowFrameError> {
+        let end_bound = self
+            .end_bound
+            .as_ref()
+            .unwrap_or(&WindowFrameBound::CurrentRow);
+        match (&self.start_bound, end_bound) {
+            (WindowFrameBound::Following(None), _) => {
+                Err(WindowFrameError::StartUnboundedFollowing)
+            }
+            (_, WindowFrameBound::Preceding(None)) => Err(WindowFrameError::EndUnboundedPreceding),
+            (start, end) if start.rank() > end.rank() => Err(WindowFrameError::StartAfterEnd {
+                start: start.clone(),
+                end: end.clone(),
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A problem with the bounds of a [WindowFrame], as reported by
+/// [WindowFrame::validate]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum WindowFrameError {
+    /// The frame starts with `UNBOUNDED FOLLOWING`
+    StartUnboundedFollowing,
+    /// The frame ends with `UNBOUNDED PRECEDING`
+    EndUnboundedPreceding,
+    /// The start bound is of a kind that comes after the end bound, like
+    /// `CURRENT ROW` before `1 PRECEDING`. An omitted end bound is reported
+    /// as `CURRENT ROW`.
+    StartAfterEnd {
+        start: WindowFrameBound,
+        end: WindowFrameBound,
+    },
+}
+
+impl fmt::Display for WindowFrameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WindowFrameError::StartUnboundedFollowing => {
+                f.write_str("frame start cannot be UNBOUNDED FOLLOWING")
+            }
+            WindowFrameError::EndUnboundedPreceding => {
+                f.write_str("frame end cannot be UNBOUNDED PRECEDING")
+            }
+            WindowFrameError::StartAfterEnd { start, end } => {
+                write!(f, "frame starting from {} cannot end with {}", start, end)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WindowFrameError {}
+
+/// Specifies the rows [WindowFrame]'s `EXCLUDE` clause leaves out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WindowFrameExclusion {
+    /// `EXCLUDE CURRENT ROW`
+    CurrentRow,
+    /// `EXCLUDE GROUP`, the current row and its peers
+    Group,
+    /// `EXCLUDE TIES`, the peers of the current row but not the row itself
+    Ties,
+    /// `EXCLUDE NO OTHERS`, which leaves out nothing, like no clause at all
+    NoOthers,
+}
+
+impl fmt::Display for WindowFrameExclusion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            WindowFrameExclusion::CurrentRow => "EXCLUDE CURRENT ROW",
+            WindowFrameExclusion::Group => "EXCLUDE GROUP",
+            WindowFrameExclusion::Ties => "EXCLUDE TIES",
+            WindowFrameExclusion::NoOthers => "EXCLUDE NO OTHERS",
+        })
+    }
+}
+
 /// Specifies [WindowFrame]'s `start_bound` and `end_bound`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum WindowFrameBound {
@@ -456,6 +978,21 @@ pub enum WindowFrameBound {
     Following(Option<u64>),
 }
 
+impl WindowFrameBound {
+    /// The position of this kind of bound in the order `UNBOUNDED
+    /// PRECEDING`, `<N> PRECEDING`, `CURRENT ROW`, `<N> FOLLOWING`,
+    /// `UNBOUNDED FOLLOWING`
+    fn rank(&self) -> u8 {
+        match self {
+            WindowFrameBound::Preceding(None) => 0,
+            WindowFrameBound::Preceding(Some(_)) => 1,
+            WindowFrameBound::CurrentRow => 2,
+            WindowFrameBound::Following(Some(_)) => 3,
+            WindowFrameBound::Following(None) => 4,
+        }
+    }
+}
+
 impl fmt::Display for WindowFrameBound {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -487,44 +1024,92 @@ impl fmt::Display for Stage {
     }
 }
 
+/// The rows a [Statement::Fetch] returns
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FetchDirection {
+    /// `NEXT`, or `FORWARD` without a count
+    Next,
+    /// `<n>` or `FORWARD <n>`
+    Count(u64),
+    /// `ALL` or `FORWARD ALL`
+    All,
+}
+
+impl fmt::Display for FetchDirection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FetchDirection::Next => f.write_str("NEXT"),
+            FetchDirection::Count(n) => write!(f, "{}", n),
+            FetchDirection::All => f.write_str("ALL"),
+        }
+    }
+}
+
 /// A top-level statement (SELECT, INSERT, CREATE, etc.)
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum Statement {
     /// `SELECT`
     Query(Box<Query>),
-    /// `INSERT`
+    /// `INSERT`, or MySQL's `REPLACE`
     Insert {
+        /// MySQL's `INSERT IGNORE` or `REPLACE`, handling rows that
+        /// duplicate a unique key
+        conflict: Option<InsertConflict>,
         /// TABLE
         table_name: ObjectName,
+        /// `AS alias`, naming the target table in the rest of the statement
+        alias: Option<Ident>,
+        /// MSSQL-specific table hints
+        with_hints: Vec<Expr>,
         /// COLUMNS
         columns: Vec<Ident>,
-        /// A SQL query that specifies what to insert
-        source: Box<Query>,
+        /// `OVERRIDING { SYSTEM | USER } VALUE`, for identity columns
+        overriding: Option<InsertOverriding>,
+        /// MSSQL-specific `OUTPUT` clause
+        output: Vec<SelectItem>,
+        /// A SQL query that specifies what to insert, or `DEFAULT VALUES`
+        source: InsertSource,
     },
+    /// `COPY`
     Copy {
-        /// TABLE
-        table_name: ObjectName,
-        /// COLUMNS
-        columns: Vec<Ident>,
-        /// VALUES a vector of values to be copied
-        values: Vec<Option<String>>,
+        /// The table and columns, or the query, that are copied
+        relation: CopyRelation,
+        /// `FROM STDIN` or `TO STDOUT`
+        target: CopyTarget,
+        /// `WITH ( <option>, ... )`
+        options: Vec<CopyOption>,
+        /// VALUES a vector of rows to be copied, where `None` is NULL. Only
+        /// `COPY ... FROM STDIN` in the text or CSV format is followed by
+        /// rows, starting with the header line if there is one.
+        values: Vec<Vec<Option<String>>>,
     },
     /// `UPDATE`
     Update {
         /// TABLE
         table_name: ObjectName,
+        /// `[ AS ] alias`, naming the target table in the rest of the
+        /// statement
+        alias: Option<Ident>,
         /// Column assignments
         assignments: Vec<Assignment>,
+        /// MSSQL-specific `OUTPUT` clause
+        output: Vec<SelectItem>,
         /// WHERE
-        selection: Option<Expr>,
+        selection: Option<DmlSelection>,
     },
     /// `DELETE`
     Delete {
         /// `FROM`
         table_name: ObjectName,
+        /// `[ AS ] alias`, naming the target table in the rest of the
+        /// statement
+        alias: Option<Ident>,
+        /// MSSQL-specific `OUTPUT` clause
+        output: Vec<SelectItem>,
         /// `WHERE`
-        selection: Option<Expr>,
+        selection: Option<DmlSelection>,
     },
     /// `CREATE SOURCE`
     CreateSource {
@@ -555,8 +1140,10 @@ pub enum Statement {
     CreateView {
         /// View name
         name: ObjectName,
-        columns: Vec<Ident>,
+        columns: Vec<ViewColumnDef>,
         query: Box<Query>,
+        /// Whether the view is `TEMPORARY`, dropped at the end of the session
+        temporary: bool,
         materialized: bool,
         with_options: Vec<SqlOption>,
     },
@@ -571,6 +1158,12 @@ pub enum Statement {
         external: bool,
         file_format: Option<FileFormat>,
         location: Option<String>,
+        /// SQLite's `WITHOUT ROWID` table option
+        without_rowid: bool,
+        /// ClickHouse's `ENGINE = ...` clause
+        engine: Option<TableEngine>,
+        /// ClickHouse's `ORDER BY` sorting key
+        order_by: Vec<Expr>,
     },
     /// `CREATE INDEX`
     CreateIndex {
@@ -583,10 +1176,68 @@ pub enum Statement {
     },
     /// `ALTER TABLE`
     AlterTable {
+        /// Whether the name is preceded by `ONLY`, which keeps the change
+        /// from applying to descendant tables
+        only: bool,
         /// Table name
         name: ObjectName,
         operation: AlterTableOperation,
     },
+    /// `ALTER <object_type> [ IF EXISTS ] <name> OWNER TO <new_owner>`, for
+    /// objects other than tables, whose owner is changed by
+    /// [AlterTableOperation::OwnerTo]
+    AlterOwner {
+        object_type: ObjectType,
+        if_exists: bool,
+        name: ObjectName,
+        new_owner: Ident,
+    },
+    /// `ALTER VIEW [ IF EXISTS ] <name> { SET | RESET } ( ... )`
+    AlterViewOptions {
+        if_exists: bool,
+        name: ObjectName,
+        operation: AlterOptionsOperation,
+    },
+    /// `CREATE SEQUENCE`
+    CreateSequence {
+        /// Sequence name
+        name: ObjectName,
+        if_not_exists: bool,
+        options: Vec<SequenceOption>,
+    },
+    /// `CREATE EXTENSION`
+    CreateExtension {
+        name: Ident,
+        if_not_exists: bool,
+        /// The schema to install the extension's objects in
+        schema: Option<Ident>,
+        version: Option<String>,
+        /// Whether to also install the extensions this one depends on
+        cascade: bool,
+    },
+    /// `ALTER SEQUENCE`
+    AlterSequence {
+        /// Sequence name
+        name: ObjectName,
+        if_exists: bool,
+        options: Vec<SequenceOption>,
+    },
+    /// `CREATE ROLE` or `CREATE USER`
+    CreateRole {
+        /// Role name
+        name: Ident,
+        /// Whether this was spelled `CREATE USER`, which implies `LOGIN`
+        is_user: bool,
+        options: Vec<RoleOption>,
+    },
+    /// `ALTER ROLE` or `ALTER USER`
+    AlterRole {
+        /// Role name
+        name: Ident,
+        /// Whether this was spelled `ALTER USER`
+        is_user: bool,
+        options: Vec<RoleOption>,
+    },
     /// `DROP`
     Drop {
         /// The type of the object to drop: TABLE, VIEW, etc.
@@ -632,6 +1283,11 @@ pub enum Statement {
         table_name: ObjectName,
         filter: Option<ShowStatementFilter>,
     },
+    /// `SHOW CONSTRAINTS`
+    ShowConstraints {
+        table_name: ObjectName,
+        filter: Option<ShowStatementFilter>,
+    },
     /// `SHOW COLUMNS`
     ///
     /// Note: this is a MySQL-specific statement.
@@ -657,8 +1313,172 @@ pub enum Statement {
     Peek { name: ObjectName, immediate: bool },
     /// `TAIL`
     Tail { name: ObjectName },
-    /// `EXPLAIN [ DATAFLOW | PLAN ] FOR`
-    Explain { stage: Stage, query: Box<Query> },
+    /// `EXPLAIN [ DATAFLOW | PLAN ] FOR <statement>`, where the statement is
+    /// a query, `INSERT`, `UPDATE` or `DELETE`
+    Explain {
+        stage: Stage,
+        statement: Box<Statement>,
+    },
+    /// `DECLARE <name> CURSOR [ WITH HOLD ] FOR <statement>`, where the
+    /// statement is a query or `TAIL`
+    Declare {
+        name: Ident,
+        hold: bool,
+        stmt: Box<Statement>,
+    },
+    /// `FETCH [ <direction> ] [ FROM ] <name>`
+    Fetch {
+        name: Ident,
+        direction: Option<FetchDirection>,
+    },
+    /// `CLOSE <name>`
+    Close { name: Ident },
+    /// `LISTEN <channel>`
+    Listen { channel: Ident },
+    /// `NOTIFY <channel> [ , '<payload>' ]`
+    Notify {
+        channel: Ident,
+        payload: Option<String>,
+    },
+    /// `UNLISTEN { <channel> | * }`, where `None` means all channels
+    Unlisten { channel: Option<Ident> },
+    /// `PREPARE <name> [ ( <data_type> [, ...] ) ] AS <statement>`, where the
+    /// statement is a query, `INSERT`, `UPDATE` or `DELETE`
+    Prepare {
+        name: Ident,
+        data_types: Vec<DataType>,
+        statement: Box<Statement>,
+    },
+    /// `DO [ LANGUAGE <language> ] <body>`, an anonymous code block
+    DoBlock {
+        language: Option<Ident>,
+        /// The code to run, a single-quoted or dollar-quoted string
+        body: Value,
+    },
+    /// A psql meta-command, like `\connect db`: the text of its line,
+    /// starting with the backslash
+    MetaCommand(String),
+}
+
+/// The broad category a [Statement] belongs to, as returned by
+/// [Statement::classification]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatementKind {
+    /// A query that returns rows: `SELECT`, `PEEK`, `TAIL`, ...
+    Query,
+    /// Data manipulation: `INSERT`, `UPDATE`, `DELETE`, `COPY`
+    Dml,
+    /// Data definition: `CREATE`, `ALTER`, `DROP`
+    Ddl,
+    /// Transaction control: `START TRANSACTION`, `COMMIT`, ...
+    Transaction,
+    /// Everything else: `SET`, `SHOW`, `EXPLAIN`, `FLUSH`, ...
+    Utility,
+}
+
+impl Statement {
+    /// Return the broad category of this statement
+    pub fn classification(&self) -> StatementKind {
+        // Deliberately exhaustive, so that adding a `Statement` variant
+        // forces a decision about its category.
+        match self {
+            Statement::Query(_)
+            | Statement::Peek { .. }
+            | Statement::Tail { .. }
+            | Statement::Fetch { .. } => StatementKind::Query,
+            Statement::Insert { .. }
+            | Statement::Copy { .. }
+            | Statement::Update { .. }
+            | Statement::Delete { .. } => StatementKind::Dml,
+            Statement::CreateSource { .. }
+            | Statement::CreateSources { .. }
+            | Statement::CreateSink { .. }
+            | Statement::CreateView { .. }
+            | Statement::CreateTable { .. }
+            | Statement::CreateIndex { .. }
+            | Statement::AlterTable { .. }
+            | Statement::AlterOwner { .. }
+            | Statement::AlterViewOptions { .. }
+            | Statement::CreateSequence { .. }
+            | Statement::CreateExtension { .. }
+            | Statement::AlterSequence { .. }
+            | Statement::CreateRole { .. }
+            | Statement::AlterRole { .. }
+            | Statement::Drop { .. } => StatementKind::Ddl,
+            Statement::StartTransaction { .. }
+            | Statement::SetTransaction { .. }
+            | Statement::Commit { .. }
+            | Statement::Rollback { .. } => StatementKind::Transaction,
+            Statement::FlushSource { .. }
+            | Statement::FlushAllSources
+            | Statement::SetVariable { .. }
+            | Statement::ShowVariable { .. }
+            | Statement::ShowObjects { .. }
+            | Statement::ShowIndexes { .. }
+            | Statement::ShowConstraints { .. }
+            | Statement::ShowColumns { .. }
+            | Statement::ShowCreateView { .. }
+            | Statement::ShowCreateSource { .. }
+            | Statement::Explain { .. }
+            | Statement::Declare { .. }
+            | Statement::Close { .. }
+            | Statement::Listen { .. }
+            | Statement::Notify { .. }
+            | Statement::Unlisten { .. }
+            | Statement::Prepare { .. }
+            | Statement::DoBlock { .. }
+            | Statement::MetaCommand(_) => StatementKind::Utility,
+        }
+    }
+
+    /// Report whether this statement can be planned ahead of execution, as
+    /// the body of `EXPLAIN` or `PREPARE`
+    pub fn is_plannable(&self) -> bool {
+        matches!(
+            self,
+            Statement::Query(_)
+                | Statement::Insert { .. }
+                | Statement::Update { .. }
+                | Statement::Delete { .. }
+        )
+    }
+
+    /// Report whether this statement only reads data: it modifies neither
+    /// data, schema nor session state, and takes no row locks
+    /// (`SELECT ... FOR UPDATE` is not read-only).
+    pub fn is_readonly(&self) -> bool {
+        match self {
+            Statement::Query(query) => query.locks.is_empty(),
+            Statement::Copy { target, .. } => *target == CopyTarget::Stdout,
+            Statement::Peek { .. }
+            | Statement::Tail { .. }
+            | Statement::Declare { .. }
+            | Statement::Fetch { .. }
+            | Statement::Close { .. }
+            | Statement::ShowVariable { .. }
+            | Statement::ShowObjects { .. }
+            | Statement::ShowIndexes { .. }
+            | Statement::ShowConstraints { .. }
+            | Statement::ShowColumns { .. }
+            | Statement::ShowCreateView { .. }
+            | Statement::ShowCreateSource { .. }
+            | Statement::Explain { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Report whether this is a `COPY ... FROM STDIN` whose rows follow it
+    /// in the SQL text, ending with a `\.` line instead of a `;`
+    pub(crate) fn has_copy_payload(&self) -> bool {
+        match self {
+            Statement::Copy {
+                target: CopyTarget::Stdin,
+                options,
+                ..
+            } => CopyFormat::of(options).name != CopyFormatName::Binary,
+            _ => false,
+        }
+    }
 }
 
 impl fmt::Display for Statement {
@@ -669,51 +1489,87 @@ impl fmt::Display for Statement {
         match self {
             Statement::Query(s) => write!(f, "{}", s),
             Statement::Insert {
+                conflict,
                 table_name,
+                alias,
+                with_hints,
                 columns,
+                overriding,
+                output,
                 source,
             } => {
-                write!(f, "INSERT INTO {} ", table_name)?;
+                match conflict {
+                    None => f.write_str("INSERT")?,
+                    Some(InsertConflict::Ignore) => f.write_str("INSERT IGNORE")?,
+                    Some(InsertConflict::Replace) => f.write_str("REPLACE")?,
+                }
+                write!(f, " INTO {} ", table_name)?;
+                if let Some(alias) = alias {
+                    write!(f, "AS {} ", alias)?;
+                }
+                if !with_hints.is_empty() {
+                    write!(f, "WITH ({}) ", display_comma_separated(with_hints))?;
+                }
                 if !columns.is_empty() {
                     write!(f, "({}) ", display_comma_separated(columns))?;
                 }
+                if let Some(overriding) = overriding {
+                    write!(f, "OVERRIDING {} VALUE ", overriding)?;
+                }
+                if !output.is_empty() {
+                    write!(f, "OUTPUT {} ", display_comma_separated(output))?;
+                }
                 write!(f, "{}", source)
             }
             Statement::Copy {
-                table_name,
-                columns,
+                relation,
+                target,
+                options,
                 values,
             } => {
-                write!(f, "COPY {}", table_name)?;
-                if !columns.is_empty() {
-                    write!(f, " ({})", display_comma_separated(columns))?;
+                write!(f, "COPY {} {}", relation, target)?;
+                if !options.is_empty() {
+                    write!(f, " WITH ({})", display_comma_separated(options))?;
                 }
-                write!(f, " FROM stdin; ")?;
-                if !values.is_empty() {
-                    writeln!(f)?;
-                    let mut delim = "";
-                    for v in values {
-                        write!(f, "{}", delim)?;
-                        delim = "\t";
-                        if let Some(v) = v {
-                            write!(f, "{}", v)?;
-                        } else {
-                            write!(f, "\\N")?;
+                if !self.has_copy_payload() {
+                    return Ok(());
+                }
+                writeln!(f, ";")?;
+                let format = CopyFormat::of(options);
+                for row in values {
+                    let mut delim = None;
+                    for v in row {
+                        if let Some(delim) = delim {
+                            write!(f, "{}", delim)?;
+                        }
+                        delim = Some(format.delimiter);
+                        match v {
+                            Some(v) => format.write_value(f, v)?,
+                            None => f.write_str(&format.null)?,
                         }
                     }
+                    writeln!(f)?;
                 }
-                write!(f, "\n\\.")
+                write!(f, "\\.")
             }
             Statement::Update {
                 table_name,
+                alias,
                 assignments,
+                output,
                 selection,
             } => {
                 write!(f, "UPDATE {}", table_name)?;
+                if let Some(alias) = alias {
+                    write!(f, " AS {}", alias)?;
+                }
                 if !assignments.is_empty() {
                     write!(f, " SET ")?;
                     write!(f, "{}", display_comma_separated(assignments))?;
                 }
+                if !output.is_empty() {
+                    write!(f, " OUTPUT {}", display_comma_separated(output))?;
+                }
                 if let Some(selection) = selection {
                     write!(f, " WHERE {}", selection)?;
                 }
@@ -721,9 +1577,17 @@ impl fmt::Display for Statement {
             }
             Statement::Delete {
                 table_name,
+                alias,
+                output,
                 selection,
             } => {
                 write!(f, "DELETE FROM {}", table_name)?;
+                if let Some(alias) = alias {
+                    write!(f, " AS {}", alias)?;
+                }
+                if !output.is_empty() {
+                    write!(f, " OUTPUT {}", display_comma_separated(output))?;
+                }
                 if let Some(selection) = selection {
                     write!(f, " WHERE {}", selection)?;
                 }
@@ -803,24 +1667,28 @@ impl fmt::Display for Statement {
                 name,
                 columns,
                 query,
+                temporary,
                 materialized,
                 with_options,
             } => {
                 write!(f, "CREATE")?;
+                if *temporary {
+                    write!(f, " TEMPORARY")?;
+                }
                 if *materialized {
                     write!(f, " MATERIALIZED")?;
                 }
 
                 write!(f, " VIEW {}", name)?;
 
-                if !with_options.is_empty() {
-                    write!(f, " WITH ({})", display_comma_separated(with_options))?;
-                }
-
                 if !columns.is_empty() {
                     write!(f, " ({})", display_comma_separated(columns))?;
                 }
 
+                if !with_options.is_empty() {
+                    write!(f, " WITH ({})", display_comma_separated(with_options))?;
+                }
+
                 write!(f, " AS {}", query)
             }
             Statement::CreateTable {
@@ -831,6 +1699,9 @@ impl fmt::Display for Statement {
                 external,
                 file_format,
                 location,
+                without_rowid,
+                engine,
+                order_by,
             } => {
                 write!(
                     f,
@@ -839,10 +1710,21 @@ impl fmt::Display for Statement {
                     name,
                     display_comma_separated(columns)
                 )?;
-                if !constraints.is_empty() {
-                    write!(f, ", {}", display_comma_separated(constraints))?;
+                if !columns.is_empty() && !constraints.is_empty() {
+                    f.write_str(", ")?;
+                }
+                write!(f, "{})", display_comma_separated(constraints))?;
+                if *without_rowid {
+                    write!(f, " WITHOUT ROWID")?;
+                }
+                if let Some(engine) = engine {
+                    write!(f, " ENGINE = {}", engine)?;
+                }
+                match order_by.as_slice() {
+                    [] => (),
+                    [expr] => write!(f, " ORDER BY {}", expr)?,
+                    exprs => write!(f, " ORDER BY ({})", display_comma_separated(exprs))?,
                 }
-                write!(f, ")")?;
 
                 if *external {
                     write!(
@@ -871,8 +1753,135 @@ impl fmt::Display for Statement {
                 )?;
                 Ok(())
             }
-            Statement::AlterTable { name, operation } => {
-                write!(f, "ALTER TABLE {} {}", name, operation)
+            Statement::AlterTable {
+                only,
+                name,
+                operation,
+            } => {
+                write!(f, "ALTER TABLE ")?;
+                if *only {
+                    write!(f, "ONLY ")?;
+                }
+                write!(f, "{} {}", name, operation)
+            }
+            Statement::AlterOwner {
+                object_type,
+                if_exists,
+                name,
+                new_owner,
+            } => write!(
+                f,
+                "ALTER {} {}{} OWNER TO {}",
+                object_type,
+                if *if_exists { "IF EXISTS " } else { "" },
+                name,
+                new_owner
+            ),
+            Statement::AlterViewOptions {
+                if_exists,
+                name,
+                operation,
+            } => write!(
+                f,
+                "ALTER VIEW {}{} {}",
+                if *if_exists { "IF EXISTS " } else { "" },
+                name,
+                operation
+            ),
+            Statement::CreateSequence {
+                name,
+                if_not_exists,
+                options,
+            } => {
+                write!(
+                    f,
+                    "CREATE SEQUENCE {}{}",
+                    if *if_not_exists { "IF NOT EXISTS " } else { "" },
+                    name
+                )?;
+                for option in options {
+                    write!(f, " {}", option)?;
+                }
+                Ok(())
+            }
+            Statement::CreateExtension {
+                name,
+                if_not_exists,
+                schema,
+                version,
+                cascade,
+            } => {
+                write!(
+                    f,
+                    "CREATE EXTENSION {}{}",
+                    if *if_not_exists { "IF NOT EXISTS " } else { "" },
+                    name
+                )?;
+                if schema.is_some() || version.is_some() || *cascade {
+                    write!(f, " WITH")?;
+                }
+                if let Some(schema) = schema {
+                    write!(f, " SCHEMA {}", schema)?;
+                }
+                if let Some(version) = version {
+                    write!(
+                        f,
+                        " VERSION '{}'",
+                        value::escape_single_quote_string(version)
+                    )?;
+                }
+                if *cascade {
+                    write!(f, " CASCADE")?;
+                }
+                Ok(())
+            }
+            Statement::CreateRole {
+                name,
+                is_user,
+                options,
+            } => {
+                write!(
+                    f,
+                    "CREATE {} {}",
+                    if *is_user { "USER" } else { "ROLE" },
+                    name
+                )?;
+                for option in options {
+                    write!(f, " {}", option)?;
+                }
+                Ok(())
+            }
+            Statement::AlterRole {
+                name,
+                is_user,
+                options,
+            } => {
+                write!(
+                    f,
+                    "ALTER {} {}",
+                    if *is_user { "USER" } else { "ROLE" },
+                    name
+                )?;
+                for option in options {
+                    write!(f, " {}", option)?;
+                }
+                Ok(())
+            }
+            Statement::AlterSequence {
+                name,
+                if_exists,
+                options,
+            } => {
+                write!(
+                    f,
+                    "ALTER SEQUENCE {}{}",
+                    if *if_exists { "IF EXISTS " } else { "" },
+                    name
+                )?;
+                for option in options {
+                    write!(f, " {}", option)?;
+                }
+                Ok(())
             }
             Statement::Drop {
                 object_type,
@@ -912,7 +1921,14 @@ impl fmt::Display for Statement {
                         View => "VIEWS",
                         Source => "SOURCES",
                         Sink => "SINKS",
-                        Index => unreachable!(),
+                        Index => "INDEXES",
+                        Schema => "SCHEMAS",
+                        Database => "DATABASES",
+                        Type => "TYPES",
+                        Role => "ROLES",
+                        Sequence => "SEQUENCES",
+                        Extension => "EXTENSIONS",
+                        Custom(name) => &name.value,
                     }
                 )?;
                 if let Some(filter) = filter {
@@ -927,6 +1943,13 @@ impl fmt::Display for Statement {
                 }
                 Ok(())
             }
+            Statement::ShowConstraints { table_name, filter } => {
+                write!(f, "SHOW CONSTRAINTS FROM {}", table_name)?;
+                if let Some(filter) = filter {
+                    write!(f, " {}", filter)?;
+                }
+                Ok(())
+            }
             Statement::ShowColumns {
                 extended,
                 full,
@@ -982,23 +2005,342 @@ impl fmt::Display for Statement {
                 write!(f, "{}", name)
             }
             Statement::Tail { name } => write!(f, "TAIL {}", name),
-            Statement::Explain { stage, query } => write!(f, "EXPLAIN {} FOR {}", stage, query),
+            Statement::Explain { stage, statement } => {
+                write!(f, "EXPLAIN {} FOR {}", stage, statement)
+            }
             Statement::FlushSource { name } => write!(f, "FLUSH SOURCE {}", name),
             Statement::FlushAllSources => write!(f, "FLUSH ALL SOURCES"),
+            Statement::Declare { name, hold, stmt } => write!(
+                f,
+                "DECLARE {} CURSOR {}FOR {}",
+                name,
+                if *hold { "WITH HOLD " } else { "" },
+                stmt
+            ),
+            Statement::Fetch { name, direction } => {
+                f.write_str("FETCH ")?;
+                if let Some(direction) = direction {
+                    write!(f, "{} FROM ", direction)?;
+                }
+                write!(f, "{}", name)
+            }
+            Statement::Close { name } => write!(f, "CLOSE {}", name),
+            Statement::Listen { channel } => write!(f, "LISTEN {}", channel),
+            Statement::Notify { channel, payload } => {
+                write!(f, "NOTIFY {}", channel)?;
+                if let Some(payload) = payload {
+                    write!(f, ", '{}'", value::escape_single_quote_string(payload))?;
+                }
+                Ok(())
+            }
+            Statement::Unlisten { channel } => match channel {
+                Some(channel) => write!(f, "UNLISTEN {}", channel),
+                None => f.write_str("UNLISTEN *"),
+            },
+            Statement::Prepare {
+                name,
+                data_types,
+                statement,
+            } => {
+                write!(f, "PREPARE {} ", name)?;
+                if !data_types.is_empty() {
+                    write!(f, "({}) ", display_comma_separated(data_types))?;
+                }
+                write!(f, "AS {}", statement)
+            }
+            Statement::DoBlock { language, body } => {
+                write!(f, "DO {}", body)?;
+                if let Some(language) = language {
+                    write!(f, " LANGUAGE {}", language)?;
+                }
+                Ok(())
+            }
+            Statement::MetaCommand(command) => f.write_str(command),
         }
     }
 }
 
+/// The rows inserted by an INSERT statement
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum InsertSource {
+    /// A query, e.g. `VALUES (1, 2)` or `SELECT ...`
+    Query(Box<Query>),
+    /// `DEFAULT VALUES`, a single row made of the columns' defaults
+    DefaultValues,
+}
+
+impl fmt::Display for InsertSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InsertSource::Query(query) => write!(f, "{}", query),
+            InsertSource::DefaultValues => f.write_str("DEFAULT VALUES"),
+        }
+    }
+}
+
+/// Which values take precedence over an identity column's generated value
+/// in `INSERT ... OVERRIDING { SYSTEM | USER } VALUE`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InsertOverriding {
+    /// The supplied values override `GENERATED ALWAYS` identity columns
+    System,
+    /// The supplied values are ignored in favor of the generated ones
+    User,
+}
+
+impl fmt::Display for InsertOverriding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            InsertOverriding::System => "SYSTEM",
+            InsertOverriding::User => "USER",
+        })
+    }
+}
+
+/// How MySQL's `INSERT IGNORE` and `REPLACE` handle a row that has the same
+/// value for a primary or unique key as an existing row, where a plain
+/// `INSERT` fails
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InsertConflict {
+    /// `INSERT IGNORE`: the row is skipped
+    Ignore,
+    /// `REPLACE`: the existing row is deleted first
+    Replace,
+}
+
+/// What a `COPY` statement copies
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CopyRelation {
+    /// A table, or some of its columns
+    Table {
+        name: ObjectName,
+        columns: Vec<Ident>,
+    },
+    /// The results of a query, which can only be copied `TO STDOUT`
+    Query(Box<Query>),
+}
+
+impl fmt::Display for CopyRelation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CopyRelation::Table { name, columns } => {
+                write!(f, "{}", name)?;
+                if !columns.is_empty() {
+                    write!(f, " ({})", display_comma_separated(columns))?;
+                }
+                Ok(())
+            }
+            CopyRelation::Query(query) => write!(f, "({})", query),
+        }
+    }
+}
+
+/// Where a `COPY` statement copies rows from or to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CopyTarget {
+    /// `FROM STDIN`, from the rows that follow the statement
+    Stdin,
+    /// `TO STDOUT`
+    Stdout,
+}
+
+impl fmt::Display for CopyTarget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            CopyTarget::Stdin => "FROM stdin",
+            CopyTarget::Stdout => "TO STDOUT",
+        })
+    }
+}
+
+/// An option of a `COPY` statement
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CopyOption {
+    /// `FORMAT { text | csv | binary }`
+    Format(CopyFormatName),
+    /// `DELIMITER '<char>'`, separating the values of a row
+    Delimiter(char),
+    /// `NULL '<string>'`, standing for NULL
+    Null(String),
+    /// `HEADER [ TRUE | FALSE ]`, whether the first row names the columns
+    Header(bool),
+    /// `QUOTE '<char>'`, quoting CSV values
+    Quote(char),
+    /// `ESCAPE '<char>'`, escaping quotes in quoted CSV values
+    Escape(char),
+}
+
+impl fmt::Display for CopyOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let quoted = |s: String| Value::SingleQuotedString(s);
+        match self {
+            CopyOption::Format(name) => write!(f, "FORMAT {}", name),
+            CopyOption::Delimiter(c) => write!(f, "DELIMITER {}", quoted(c.to_string())),
+            CopyOption::Null(s) => write!(f, "NULL {}", quoted(s.clone())),
+            CopyOption::Header(true) => f.write_str("HEADER"),
+            CopyOption::Header(false) => f.write_str("HEADER false"),
+            CopyOption::Quote(c) => write!(f, "QUOTE {}", quoted(c.to_string())),
+            CopyOption::Escape(c) => write!(f, "ESCAPE {}", quoted(c.to_string())),
+        }
+    }
+}
+
+/// The format of the rows a `COPY` statement copies
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CopyFormatName {
+    /// Tab separated values with backslash escapes, the default
+    Text,
+    /// Comma separated values
+    Csv,
+    /// PostgreSQL's binary format, which can't be written in SQL text
+    Binary,
+}
+
+impl fmt::Display for CopyFormatName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            CopyFormatName::Text => "text",
+            CopyFormatName::Csv => "csv",
+            CopyFormatName::Binary => "binary",
+        })
+    }
+}
+
+/// How the values of the rows following `COPY ... FROM STDIN` are written,
+/// according to its options
+pub(crate) struct CopyFormat {
+    pub name: CopyFormatName,
+    pub delimiter: char,
+    pub null: String,
+    pub quote: char,
+    pub escape: char,
+}
+
+impl CopyFormat {
+    pub fn of(options: &[CopyOption]) -> CopyFormat {
+        let name = options
+            .iter()
+            .rev()
+            .find_map(|option| match option {
+                CopyOption::Format(name) => Some(*name),
+                _ => None,
+            })
+            .unwrap_or(CopyFormatName::Text);
+        let csv = name == CopyFormatName::Csv;
+        let mut format = CopyFormat {
+            name,
+            delimiter: if csv { ',' } else { '\t' },
+            null: if csv { "" } else { "\\N" }.to_string(),
+            quote: '"',
+            escape: '"',
+        };
+        let mut escape = None;
+        for option in options {
+            match option {
+                CopyOption::Delimiter(c) => format.delimiter = *c,
+                CopyOption::Null(s) => format.null = s.clone(),
+                CopyOption::Quote(c) => format.quote = *c,
+                CopyOption::Escape(c) => escape = Some(*c),
+                CopyOption::Format(_) | CopyOption::Header(_) => (),
+            }
+        }
+        // The escape character defaults to the quote character
+        format.escape = escape.unwrap_or(format.quote);
+        format
+    }
+
+    fn write_value(&self, f: &mut fmt::Formatter, value: &str) -> fmt::Result {
+        if self.name != CopyFormatName::Csv {
+            for c in value.chars() {
+                if c == self.delimiter && c != '\t' {
+                    write!(f, "\\{}", c)?;
+                } else {
+                    write!(f, "{}", escape_copy_text(c.encode_utf8(&mut [0; 4])))?;
+                }
+            }
+            return Ok(());
+        }
+        let needs_quotes = value == self.null
+            || value == "\\."
+            || value.contains(&[self.delimiter, self.quote, '\n', '\r'][..]);
+        if !needs_quotes {
+            return f.write_str(value);
+        }
+        write!(f, "{}", self.quote)?;
+        for c in value.chars() {
+            if c == self.quote || c == self.escape {
+                write!(f, "{}", self.escape)?;
+            }
+            write!(f, "{}", c)?;
+        }
+        write!(f, "{}", self.quote)
+    }
+}
+
 /// SQL assignment `foo = expr` as used in SQLUpdate
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Assignment {
-    pub id: Ident,
+    pub target: AssignmentTarget,
     pub value: Expr,
 }
 
 impl fmt::Display for Assignment {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{} = {}", self.id, self.value)
+        write!(f, "{} = {}", self.target, self.value)
+    }
+}
+
+/// The left-hand side of an [`Assignment`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AssignmentTarget {
+    /// A single, possibly qualified, column, e.g. `a` or `t.a`
+    ColumnName(ObjectName),
+    /// A parenthesized list of columns, e.g. `(a, b)`, which is assigned a
+    /// row or a subquery in PostgreSQL
+    Tuple(Vec<ObjectName>),
+}
+
+impl fmt::Display for AssignmentTarget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AssignmentTarget::ColumnName(column) => write!(f, "{}", column),
+            AssignmentTarget::Tuple(columns) => write!(f, "({})", display_comma_separated(columns)),
+        }
+    }
+}
+
+/// The `WHERE` clause of an UPDATE or DELETE statement
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DmlSelection {
+    /// A search condition, e.g. `WHERE a = 1`
+    Expr(Expr),
+    /// `WHERE CURRENT OF cursor`, which modifies the row the cursor is
+    /// positioned on
+    CurrentOf(Ident),
+}
+
+impl fmt::Display for DmlSelection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DmlSelection::Expr(expr) => write!(f, "{}", expr),
+            DmlSelection::CurrentOf(cursor) => write!(f, "CURRENT OF {}", cursor),
+        }
+    }
+}
+
+/// `WHEN <condition> THEN <result>` in a `CASE` expression
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WhenClause {
+    pub condition: Expr,
+    pub result: Expr,
+    /// Where the clause was written, when it was parsed from a string;
+    /// empty otherwise
+    pub span: Span,
+}
+
+impl fmt::Display for WhenClause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "WHEN {} THEN {}", self.condition, self.result)
     }
 }
 
@@ -1010,10 +2352,16 @@ pub struct Function {
     pub over: Option<WindowSpec>,
     // aggregate functions may specify eg `COUNT(DISTINCT x)`
     pub distinct: bool,
+    /// Whether this is one of the SQL-standard niladic functions, such as
+    /// `CURRENT_TIMESTAMP`, written without parentheses
+    pub special: bool,
 }
 
 impl fmt::Display for Function {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.special {
+            return write!(f, "{}", self.name);
+        }
         write!(
             f,
             "{}({}{})",
@@ -1067,7 +2415,7 @@ impl fmt::Display for FileFormat {
 }
 
 use crate::parser::ParserError;
-use std::str::FromStr;
+use core::str::FromStr;
 impl FromStr for FileFormat {
     type Err = ParserError;
 
@@ -1089,13 +2437,23 @@ impl FromStr for FileFormat {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ObjectType {
     Table,
     View,
     Source,
     Sink,
     Index,
+    Schema,
+    Database,
+    Type,
+    Role,
+    Sequence,
+    Extension,
+    /// A kind of object this crate doesn't know about, which only
+    /// [Statement::ShowObjects] refers to, named as in the statement, like
+    /// `SECRETS`. See [Dialect::show_object_types](crate::dialect::Dialect::show_object_types).
+    Custom(Ident),
 }
 
 impl fmt::Display for ObjectType {
@@ -1106,19 +2464,51 @@ impl fmt::Display for ObjectType {
             ObjectType::Source => "SOURCE",
             ObjectType::Sink => "SINK",
             ObjectType::Index => "INDEX",
+            ObjectType::Schema => "SCHEMA",
+            ObjectType::Database => "DATABASE",
+            ObjectType::Type => "TYPE",
+            ObjectType::Role => "ROLE",
+            ObjectType::Sequence => "SEQUENCE",
+            ObjectType::Extension => "EXTENSION",
+            ObjectType::Custom(name) => return write!(f, "{}", name),
         })
     }
 }
 
+/// An option in a `WITH (...)` list
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SqlOption {
-    pub name: Ident,
-    pub value: Value,
+pub enum SqlOption {
+    /// `<name> = <value>`
+    Value { name: Ident, value: Value },
+    /// A bare `<name>`, which means `<name> = TRUE`
+    Flag { name: Ident },
+    /// `<name> = <object_name>`, like `check_option = local`
+    ObjectName {
+        name: Ident,
+        object_name: ObjectName,
+    },
+}
+
+impl SqlOption {
+    /// Return the name of the option
+    pub fn name(&self) -> &Ident {
+        match self {
+            SqlOption::Value { name, .. }
+            | SqlOption::Flag { name }
+            | SqlOption::ObjectName { name, .. } => name,
+        }
+    }
 }
 
 impl fmt::Display for SqlOption {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{} = {}", self.name, self.value)
+        match self {
+            SqlOption::Value { name, value } => write!(f, "{} = {}", name, value),
+            SqlOption::Flag { name } => write!(f, "{}", name),
+            SqlOption::ObjectName { name, object_name } => {
+                write!(f, "{} = {}", name, object_name)
+            }
+        }
     }
 }
 
@@ -1174,9 +2564,13 @@ impl fmt::Display for TransactionIsolationLevel {
     }
 }
 
+/// The optional filter at the end of the `SHOW` statements that list
+/// objects, shared by all of them
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ShowStatementFilter {
+    /// `LIKE '<pattern>'`, matched against the name of each object
     Like(String),
+    /// `WHERE <expr>`, evaluated against each row of the listing
     Where(Expr),
 }
 
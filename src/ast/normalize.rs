@@ -0,0 +1,135 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversion between equivalent spellings of the same expression
+//!
+//! Unlike [simplify](super::simplify), these rewrites pick one of several
+//! spellings that a database treats alike, which planners may want to
+//! handle in a single form. The spellings are not always interchangeable
+//! in every database: in PostgreSQL 14 and later, `EXTRACT` returns
+//! `numeric` while `date_part` returns `double precision`.
+
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, string::ToString, vec};
+
+use super::visit_mut::{self, VisitMut};
+use super::{Expr, ExtractField, Function, Ident, ObjectName, Statement, Value};
+
+/// The spelling of date part extraction that [normalize_date_parts]
+/// rewrites to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DatePartStyle {
+    /// `EXTRACT(HOUR FROM ts)`
+    Extract,
+    /// `date_part('hour', ts)`
+    DatePart,
+}
+
+/// Rewrite every date part extraction in `statement` to `style`
+pub fn normalize_date_parts(statement: &mut Statement, style: DatePartStyle) {
+    DatePartNormalizer { style }.visit_statement(statement);
+}
+
+/// Convert `EXTRACT(<field> FROM <expr>)` to `date_part('<field>', <expr>)`,
+/// or return `None` if `expr` is not an `EXTRACT`
+pub fn extract_to_date_part(expr: &Expr) -> Option<Expr> {
+    match expr {
+        Expr::Extract { field, expr } => Some(Expr::Function(Function {
+            name: ObjectName(vec![Ident::new("date_part")]),
+            args: vec![
+                Expr::Value(Value::SingleQuotedString(
+                    field.to_string().to_ascii_lowercase(),
+                )),
+                (**expr).clone(),
+            ],
+            over: None,
+            distinct: false,
+            special: false,
+        })),
+        _ => None,
+    }
+}
+
+/// Convert `date_part('<field>', <expr>)` to `EXTRACT(<field> FROM <expr>)`,
+/// or return `None` if `expr` is not a call to `date_part` with a literal
+/// field that `EXTRACT` accepts
+pub fn date_part_to_extract(expr: &Expr) -> Option<Expr> {
+    let func = match expr {
+        Expr::Function(func) => func,
+        _ => return None,
+    };
+    let is_date_part = match func.name.0.as_slice() {
+        [name] => name.quote_style.is_none() && name.value.eq_ignore_ascii_case("date_part"),
+        _ => false,
+    };
+    if !is_date_part || func.over.is_some() || func.distinct || func.special {
+        return None;
+    }
+    match func.args.as_slice() {
+        [Expr::Value(Value::SingleQuotedString(field)), expr] => Some(Expr::Extract {
+            field: field.parse::<ExtractField>().ok()?,
+            expr: Box::new(expr.clone()),
+        }),
+        _ => None,
+    }
+}
+
+struct DatePartNormalizer {
+    style: DatePartStyle,
+}
+
+impl<'ast> VisitMut<'ast> for DatePartNormalizer {
+    fn visit_expr(&mut self, expr: &'ast mut Expr) {
+        visit_mut::visit_expr(self, expr);
+        let converted = match self.style {
+            DatePartStyle::Extract => date_part_to_extract(expr),
+            DatePartStyle::DatePart => extract_to_date_part(expr),
+        };
+        if let Some(converted) = converted {
+            *expr = converted;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn normalize_date_part_styles() {
+        let sql = "SELECT EXTRACT(HOUR FROM ts), DATE_PART('dow', t.ts) FROM t \
+                   WHERE date_part(f, ts) = extract(YEAR FROM date_part('x', ts))";
+
+        let mut statement = all_dialects().unverified_stmt(sql);
+        normalize_date_parts(&mut statement, DatePartStyle::DatePart);
+        assert_eq!(
+            "SELECT date_part('hour', ts), DATE_PART('dow', t.ts) FROM t \
+             WHERE date_part(f, ts) = date_part('year', date_part('x', ts))",
+            statement.to_string()
+        );
+
+        let mut statement = all_dialects().unverified_stmt(sql);
+        normalize_date_parts(&mut statement, DatePartStyle::Extract);
+        assert_eq!(
+            "SELECT EXTRACT(HOUR FROM ts), EXTRACT(DOW FROM t.ts) FROM t \
+             WHERE date_part(f, ts) = EXTRACT(YEAR FROM date_part('x', ts))",
+            statement.to_string()
+        );
+
+        // Converting back and forth is lossless
+        let mut round_trip = statement.clone();
+        normalize_date_parts(&mut round_trip, DatePartStyle::DatePart);
+        normalize_date_parts(&mut round_trip, DatePartStyle::Extract);
+        assert_eq!(statement, round_trip);
+    }
+}
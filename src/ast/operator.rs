@@ -10,7 +10,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::fmt;
+use core::fmt;
 
 /// Unary operators
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -46,8 +46,6 @@ pub enum BinaryOperator {
     NotEq,
     And,
     Or,
-    Like,
-    NotLike,
     JsonGet,
     JsonGetAsText,
     JsonGetPath,
@@ -61,6 +59,10 @@ pub enum BinaryOperator {
     JsonDeletePath,
     JsonContainsPath,
     JsonApplyPathPredicate,
+    /// PostgreSQL's `^`
+    Exponent,
+    /// MySQL's `^`
+    BitwiseXor,
 }
 
 impl fmt::Display for BinaryOperator {
@@ -79,8 +81,6 @@ impl fmt::Display for BinaryOperator {
             BinaryOperator::NotEq => "<>",
             BinaryOperator::And => "AND",
             BinaryOperator::Or => "OR",
-            BinaryOperator::Like => "LIKE",
-            BinaryOperator::NotLike => "NOT LIKE",
             BinaryOperator::JsonGet => "->",
             BinaryOperator::JsonGetAsText => "->>",
             BinaryOperator::JsonGetPath => "#>",
@@ -94,6 +94,76 @@ impl fmt::Display for BinaryOperator {
             BinaryOperator::JsonDeletePath => "#-",
             BinaryOperator::JsonContainsPath => "@?",
             BinaryOperator::JsonApplyPathPredicate => "@@",
+            BinaryOperator::Exponent | BinaryOperator::BitwiseXor => "^",
         })
     }
 }
+
+impl BinaryOperator {
+    /// Every binary operator, in declaration order
+    pub(crate) const ALL: &'static [BinaryOperator] = &[
+        BinaryOperator::Plus,
+        BinaryOperator::Minus,
+        BinaryOperator::Multiply,
+        BinaryOperator::Divide,
+        BinaryOperator::Modulus,
+        BinaryOperator::Gt,
+        BinaryOperator::Lt,
+        BinaryOperator::GtEq,
+        BinaryOperator::LtEq,
+        BinaryOperator::Eq,
+        BinaryOperator::NotEq,
+        BinaryOperator::And,
+        BinaryOperator::Or,
+        BinaryOperator::JsonGet,
+        BinaryOperator::JsonGetAsText,
+        BinaryOperator::JsonGetPath,
+        BinaryOperator::JsonGetPathAsText,
+        BinaryOperator::JsonContainsJson,
+        BinaryOperator::JsonContainedInJson,
+        BinaryOperator::JsonContainsField,
+        BinaryOperator::JsonContainsAnyFields,
+        BinaryOperator::JsonContainsAllFields,
+        BinaryOperator::JsonConcat,
+        BinaryOperator::JsonDeletePath,
+        BinaryOperator::JsonContainsPath,
+        BinaryOperator::JsonApplyPathPredicate,
+        BinaryOperator::Exponent,
+        BinaryOperator::BitwiseXor,
+    ];
+
+    /// Return the binding power of this operator in the generic dialect,
+    /// where higher values bind more tightly. Dialects can adjust it via
+    /// [`Dialect::binary_operator_precedence`](crate::dialect::Dialect::binary_operator_precedence).
+    pub fn precedence(&self) -> u8 {
+        match self {
+            BinaryOperator::Or => 5,
+            BinaryOperator::And => 10,
+            BinaryOperator::Eq
+            | BinaryOperator::NotEq
+            | BinaryOperator::Gt
+            | BinaryOperator::GtEq
+            | BinaryOperator::Lt
+            | BinaryOperator::LtEq => 20,
+            BinaryOperator::Plus | BinaryOperator::Minus => 30,
+            BinaryOperator::Multiply | BinaryOperator::Divide | BinaryOperator::Modulus => 40,
+            // Both PostgreSQL and MySQL bind `^` more tightly than `*`, but
+            // less tightly than unary `-`
+            BinaryOperator::Exponent | BinaryOperator::BitwiseXor => 45,
+            // TODO(jamii) it's not clear what precedence postgres gives to json operators
+            BinaryOperator::JsonGet
+            | BinaryOperator::JsonGetAsText
+            | BinaryOperator::JsonGetPath
+            | BinaryOperator::JsonGetPathAsText
+            | BinaryOperator::JsonContainsJson
+            | BinaryOperator::JsonContainedInJson
+            | BinaryOperator::JsonContainsField
+            | BinaryOperator::JsonContainsAnyFields
+            | BinaryOperator::JsonContainsAllFields
+            | BinaryOperator::JsonConcat
+            | BinaryOperator::JsonDeletePath
+            | BinaryOperator::JsonContainsPath
+            | BinaryOperator::JsonApplyPathPredicate => 1,
+        }
+    }
+}
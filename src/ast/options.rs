@@ -0,0 +1,315 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Validation of `WITH (...)` options against a schema
+//!
+//! The parser accepts any option names and values, leaving it to
+//! the caller to decide which ones a statement understands. A
+//! [SqlOptionSchema] describes those options once, so that every problem
+//! with a statement's options can be reported together.
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+use super::{Ident, SqlOption, Statement, Value};
+
+/// The type of value a `WITH` option takes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SqlOptionType {
+    /// A string literal, like `'avro'`, or a bare word, like `local`
+    String,
+    /// A numeric literal, like `10`
+    Number,
+    /// `TRUE` or `FALSE`
+    Boolean,
+}
+
+impl SqlOptionType {
+    fn matches(self, option: &SqlOption) -> bool {
+        match option {
+            SqlOption::Value { value, .. } => matches!(
+                (self, value),
+                (SqlOptionType::String, Value::SingleQuotedString(_))
+                    | (SqlOptionType::String, Value::BackslashEscapedString(_))
+                    | (SqlOptionType::String, Value::NationalStringLiteral(_))
+                    | (SqlOptionType::String, Value::UnicodeStringLiteral(_))
+                    | (SqlOptionType::String, Value::DollarQuotedString(_))
+                    | (SqlOptionType::Number, Value::Number(_))
+                    | (SqlOptionType::Boolean, Value::Boolean(_))
+            ),
+            SqlOption::Flag { .. } => self == SqlOptionType::Boolean,
+            // Like Postgres, treat `name = word` as `name = 'word'`
+            SqlOption::ObjectName { .. } => self == SqlOptionType::String,
+        }
+    }
+}
+
+impl fmt::Display for SqlOptionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            SqlOptionType::String => "string",
+            SqlOptionType::Number => "number",
+            SqlOptionType::Boolean => "boolean",
+        })
+    }
+}
+
+/// A problem with one of a statement's `WITH` options
+#[derive(Debug, Clone, PartialEq)]
+pub enum SqlOptionError {
+    /// The option is not in the schema
+    Unknown { name: Ident },
+    /// The option's value is not of the type the schema requires
+    WrongType {
+        name: Ident,
+        expected: SqlOptionType,
+        /// The value that was given, as SQL text
+        value: String,
+    },
+    /// The option was given more than once
+    Duplicate { name: Ident },
+    /// A required option was not given
+    Missing { name: String },
+}
+
+impl fmt::Display for SqlOptionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SqlOptionError::Unknown { name } => write!(f, "unknown option {}", name),
+            SqlOptionError::WrongType {
+                name,
+                expected,
+                value,
+            } => write!(f, "option {} must be a {}, found {}", name, expected, value),
+            SqlOptionError::Duplicate { name } => {
+                write!(f, "option {} specified more than once", name)
+            }
+            SqlOptionError::Missing { name } => write!(f, "missing required option {}", name),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SqlOptionError {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct OptionSpec {
+    name: String,
+    ty: SqlOptionType,
+    required: bool,
+}
+
+/// The `WITH` options a statement accepts, and the types of their values
+///
+/// Option names match case-insensitively, like the unquoted identifiers
+/// they are usually written as.
+///
+/// ```
+/// use sqlparser::ast::options::{SqlOptionSchema, SqlOptionType};
+///
+/// let schema = SqlOptionSchema::new()
+///     .required("format", SqlOptionType::String)
+///     .optional("partitions", SqlOptionType::Number);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct SqlOptionSchema {
+    options: Vec<OptionSpec>,
+}
+
+impl SqlOptionSchema {
+    /// Create a schema that accepts no options
+    pub fn new() -> Self {
+        SqlOptionSchema::default()
+    }
+
+    /// Accept an option named `name` with a value of type `ty`
+    pub fn optional(mut self, name: &str, ty: SqlOptionType) -> Self {
+        self.options.push(OptionSpec {
+            name: name.to_string(),
+            ty,
+            required: false,
+        });
+        self
+    }
+
+    /// Require an option named `name` with a value of type `ty`
+    pub fn required(mut self, name: &str, ty: SqlOptionType) -> Self {
+        self.options.push(OptionSpec {
+            name: name.to_string(),
+            ty,
+            required: true,
+        });
+        self
+    }
+
+    /// Check `options` against this schema, returning every problem found
+    pub fn validate(&self, options: &[SqlOption]) -> Result<(), Vec<SqlOptionError>> {
+        let mut errors = Vec::new();
+        for (i, option) in options.iter().enumerate() {
+            let spec = self
+                .options
+                .iter()
+                .find(|spec| spec.name.eq_ignore_ascii_case(&option.name().value));
+            let spec = match spec {
+                Some(spec) => spec,
+                None => {
+                    errors.push(SqlOptionError::Unknown {
+                        name: option.name().clone(),
+                    });
+                    continue;
+                }
+            };
+            if options[..i]
+                .iter()
+                .any(|prev| prev.name().value.eq_ignore_ascii_case(&option.name().value))
+            {
+                errors.push(SqlOptionError::Duplicate {
+                    name: option.name().clone(),
+                });
+            } else if !spec.ty.matches(option) {
+                errors.push(SqlOptionError::WrongType {
+                    name: option.name().clone(),
+                    expected: spec.ty,
+                    value: match option {
+                        SqlOption::Value { value, .. } => value.to_string(),
+                        SqlOption::Flag { .. } => "true".to_string(),
+                        SqlOption::ObjectName { object_name, .. } => object_name.to_string(),
+                    },
+                });
+            }
+        }
+        for spec in self.options.iter().filter(|spec| spec.required) {
+            if !options
+                .iter()
+                .any(|option| spec.name.eq_ignore_ascii_case(&option.name().value))
+            {
+                errors.push(SqlOptionError::Missing {
+                    name: spec.name.clone(),
+                });
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl Statement {
+    /// Check the `WITH` options of this statement against `schema`
+    ///
+    /// Statements that take no `WITH` clause are validated as if it were
+    /// empty, so they only fail if the schema requires some option.
+    pub fn validate_with_options(
+        &self,
+        schema: &SqlOptionSchema,
+    ) -> Result<(), Vec<SqlOptionError>> {
+        let with_options: &[SqlOption] = match self {
+            Statement::CreateSource { with_options, .. }
+            | Statement::CreateSources { with_options, .. }
+            | Statement::CreateSink { with_options, .. }
+            | Statement::CreateView { with_options, .. }
+            | Statement::CreateTable { with_options, .. } => with_options,
+            _ => &[],
+        };
+        schema.validate(with_options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    fn kafka_schema() -> SqlOptionSchema {
+        SqlOptionSchema::new()
+            .required("format", SqlOptionType::String)
+            .optional("partitions", SqlOptionType::Number)
+            .optional("compact", SqlOptionType::Boolean)
+    }
+
+    #[test]
+    fn validate_valid_options() {
+        let statement = all_dialects().verified_stmt(
+            "CREATE SOURCE foo FROM 'kafka://host/topic' \
+             WITH (FORMAT = 'avro', partitions = 3, compact = true)",
+        );
+        assert_eq!(Ok(()), statement.validate_with_options(&kafka_schema()));
+    }
+
+    #[test]
+    fn validate_flag_options() {
+        let statement = all_dialects().verified_stmt(
+            "CREATE SOURCE foo FROM 'kafka://host/topic' WITH (FORMAT = 'avro', compact)",
+        );
+        assert_eq!(Ok(()), statement.validate_with_options(&kafka_schema()));
+        let statement = all_dialects()
+            .verified_stmt("CREATE SOURCE foo FROM 'kafka://host/topic' WITH (FORMAT, compact)");
+        assert_eq!(
+            Err(vec![SqlOptionError::WrongType {
+                name: Ident::new("FORMAT"),
+                expected: SqlOptionType::String,
+                value: "true".to_string(),
+            }]),
+            statement.validate_with_options(&kafka_schema())
+        );
+    }
+
+    #[test]
+    fn validate_reports_every_error() {
+        let statement = all_dialects().verified_stmt(
+            "CREATE SINK foo FROM bar INTO 'kafka://host/topic' \
+             WITH (partitions = 'many', topic = 'x', compact = true, compact = false)",
+        );
+        let errors = statement
+            .validate_with_options(&kafka_schema())
+            .unwrap_err();
+        assert_eq!(
+            vec![
+                SqlOptionError::WrongType {
+                    name: Ident::new("partitions"),
+                    expected: SqlOptionType::Number,
+                    value: "'many'".to_string(),
+                },
+                SqlOptionError::Unknown {
+                    name: Ident::new("topic"),
+                },
+                SqlOptionError::Duplicate {
+                    name: Ident::new("compact"),
+                },
+                SqlOptionError::Missing {
+                    name: "format".to_string(),
+                },
+            ],
+            errors
+        );
+        assert_eq!(
+            "option partitions must be a number, found 'many'",
+            errors[0].to_string()
+        );
+    }
+
+    #[test]
+    fn validate_statement_without_options() {
+        let statement = all_dialects().verified_stmt("SELECT 1");
+        assert_eq!(
+            Ok(()),
+            statement.validate_with_options(&SqlOptionSchema::new())
+        );
+        assert!(statement.validate_with_options(&kafka_schema()).is_err());
+    }
+}
@@ -0,0 +1,282 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Collection of the `$n` parameters referenced by a statement
+//!
+//! Expressions don't record their position in the SQL text, so a parameter
+//! is identified by its number and the order in which it appears, not by a
+//! span.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::visit::{self, Visit};
+use super::{
+    Assignment, AssignmentTarget, BinaryOperator, DataType, Expr, Ident, InsertConflict,
+    InsertOverriding, InsertSource, ObjectName, Query, SelectItem, Statement, Values,
+};
+
+/// A reference to a `$n` parameter, as returned by [Statement::parameters]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParamRef {
+    /// The parameter's number, `n` in `$n`
+    pub index: usize,
+    /// Where the parameter appears, which hints at the type of its value
+    pub context: ParamContext,
+}
+
+/// The position of a parameter in its statement
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ParamContext {
+    /// An operand of a comparison, `BETWEEN`, `IN` list or pattern match,
+    /// compared against the given expression, e.g. `a` in `a = $1`
+    Compared(Expr),
+    /// The value assigned to a column by `UPDATE ... SET`
+    Assigned(ObjectName),
+    /// The value of a column in the `VALUES` of an `INSERT` with an
+    /// explicit column list
+    Inserted(Ident),
+    /// The operand of a cast to the given type, e.g. `$1::int`
+    Cast(DataType),
+    /// Any other position
+    Unknown,
+}
+
+impl Statement {
+    /// Return every parameter referenced by this statement, in the order in
+    /// which they appear
+    ///
+    /// A parameter that is referenced more than once is listed once per
+    /// reference. The number of values to bind is the highest `index`.
+    pub fn parameters(&self) -> Vec<ParamRef> {
+        let mut collector = ParamCollector::default();
+        collector.visit_statement(self);
+        collector.params
+    }
+}
+
+#[derive(Default)]
+struct ParamCollector {
+    params: Vec<ParamRef>,
+    /// The context of the expression that is about to be visited
+    context: Option<ParamContext>,
+    /// The columns of the `INSERT` whose source is about to be visited
+    insert_columns: Option<Vec<Ident>>,
+    /// The columns of the `INSERT` whose `VALUES` are about to be visited
+    values_columns: Option<Vec<Ident>>,
+}
+
+impl ParamCollector {
+    fn visit_in_context(&mut self, expr: &Expr, context: ParamContext) {
+        self.context = Some(context);
+        self.visit_expr(expr);
+    }
+
+    fn visit_compared(&mut self, expr: &Expr, other: &Expr) {
+        self.visit_in_context(expr, ParamContext::Compared(other.clone()))
+    }
+}
+
+impl<'ast> Visit<'ast> for ParamCollector {
+    fn visit_expr(&mut self, expr: &'ast Expr) {
+        let context = self.context.take();
+        match expr {
+            Expr::Parameter(n) => self.params.push(ParamRef {
+                index: *n,
+                context: context.unwrap_or(ParamContext::Unknown),
+            }),
+            _ => visit::visit_expr(self, expr),
+        }
+    }
+
+    fn visit_binary_op(&mut self, left: &'ast Expr, op: &'ast BinaryOperator, right: &'ast Expr) {
+        match op {
+            BinaryOperator::Eq
+            | BinaryOperator::NotEq
+            | BinaryOperator::Lt
+            | BinaryOperator::LtEq
+            | BinaryOperator::Gt
+            | BinaryOperator::GtEq => {
+                self.visit_compared(left, right);
+                self.visit_compared(right, left);
+            }
+            _ => visit::visit_binary_op(self, left, op, right),
+        }
+    }
+
+    fn visit_between(
+        &mut self,
+        expr: &'ast Expr,
+        low: &'ast Expr,
+        high: &'ast Expr,
+        _negated: bool,
+    ) {
+        self.visit_compared(expr, low);
+        self.visit_compared(low, expr);
+        self.visit_compared(high, expr);
+    }
+
+    fn visit_in_list(&mut self, expr: &'ast Expr, list: &'ast [Expr], _negated: bool) {
+        match list.first() {
+            Some(first) => self.visit_compared(expr, first),
+            None => self.visit_expr(expr),
+        }
+        for item in list {
+            self.visit_compared(item, expr);
+        }
+    }
+
+    fn visit_like(
+        &mut self,
+        expr: &'ast Expr,
+        pattern: &'ast Expr,
+        _escape_char: Option<char>,
+        _negated: bool,
+    ) {
+        self.visit_compared(expr, pattern);
+        self.visit_compared(pattern, expr);
+    }
+
+    fn visit_ilike(
+        &mut self,
+        expr: &'ast Expr,
+        pattern: &'ast Expr,
+        _escape_char: Option<char>,
+        _negated: bool,
+    ) {
+        self.visit_compared(expr, pattern);
+        self.visit_compared(pattern, expr);
+    }
+
+    fn visit_cast(&mut self, expr: &'ast Expr, data_type: &'ast DataType) {
+        self.visit_in_context(expr, ParamContext::Cast(data_type.clone()));
+        self.visit_type(data_type);
+    }
+
+    fn visit_assignment(&mut self, assignment: &'ast Assignment) {
+        match &assignment.target {
+            AssignmentTarget::ColumnName(name) => {
+                self.visit_object_name(name);
+                self.visit_in_context(&assignment.value, ParamContext::Assigned(name.clone()));
+            }
+            AssignmentTarget::Tuple(_) => visit::visit_assignment(self, assignment),
+        }
+    }
+
+    fn visit_insert(
+        &mut self,
+        conflict: Option<InsertConflict>,
+        table_name: &'ast ObjectName,
+        alias: Option<&'ast Ident>,
+        with_hints: &'ast [Expr],
+        columns: &'ast [Ident],
+        overriding: Option<InsertOverriding>,
+        output: &'ast [SelectItem],
+        source: &'ast InsertSource,
+    ) {
+        if !columns.is_empty() {
+            self.insert_columns = Some(columns.to_vec());
+        }
+        visit::visit_insert(
+            self, conflict, table_name, alias, with_hints, columns, overriding, output, source,
+        );
+        self.insert_columns = None;
+    }
+
+    fn visit_query(&mut self, query: &'ast Query) {
+        // Only the `VALUES` directly under the `INSERT` line up with its
+        // columns, not those of any nested query
+        self.values_columns = self.insert_columns.take();
+        visit::visit_query(self, query);
+        self.values_columns = None;
+    }
+
+    fn visit_values(&mut self, values: &'ast Values) {
+        let columns = match self.values_columns.take() {
+            Some(columns) => columns,
+            None => return visit::visit_values(self, values),
+        };
+        for row in &values.0 {
+            for (expr, column) in row.iter().zip(&columns) {
+                self.visit_in_context(expr, ParamContext::Inserted(column.clone()));
+            }
+            for expr in row.iter().skip(columns.len()) {
+                self.visit_expr(expr);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    fn column(name: &str) -> Expr {
+        Expr::Identifier(Ident::new(name))
+    }
+
+    #[test]
+    fn collect_parameters() {
+        let statement = all_dialects().unverified_stmt(
+            "SELECT $1::int FROM t WHERE a = $2 AND b BETWEEN $3 AND c AND d IN ($4, $2) \
+             AND f($5)",
+        );
+        let contexts: Vec<_> = statement
+            .parameters()
+            .into_iter()
+            .map(|p| (p.index, p.context))
+            .collect();
+        assert_eq!(
+            contexts,
+            vec![
+                (1, ParamContext::Cast(DataType::Int)),
+                (2, ParamContext::Compared(column("a"))),
+                (3, ParamContext::Compared(column("b"))),
+                (4, ParamContext::Compared(column("d"))),
+                (2, ParamContext::Compared(column("d"))),
+                (5, ParamContext::Unknown),
+            ]
+        );
+
+        let statement =
+            all_dialects().verified_stmt("INSERT INTO t (a, b) VALUES ($1, $2), ($3, (SELECT $4))");
+        let contexts: Vec<_> = statement
+            .parameters()
+            .into_iter()
+            .map(|p| (p.index, p.context))
+            .collect();
+        assert_eq!(
+            contexts,
+            vec![
+                (1, ParamContext::Inserted(Ident::new("a"))),
+                (2, ParamContext::Inserted(Ident::new("b"))),
+                (3, ParamContext::Inserted(Ident::new("a"))),
+                (4, ParamContext::Unknown),
+            ]
+        );
+
+        let statement = all_dialects().verified_stmt("UPDATE t SET a = $1 WHERE $2 <> b");
+        let contexts: Vec<_> = statement
+            .parameters()
+            .into_iter()
+            .map(|p| (p.index, p.context))
+            .collect();
+        assert_eq!(
+            contexts,
+            vec![
+                (1, ParamContext::Assigned(ObjectName(vec![Ident::new("a")]))),
+                (2, ParamContext::Compared(column("b"))),
+            ]
+        );
+    }
+}
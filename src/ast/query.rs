@@ -10,6 +10,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, vec::Vec};
+
 use super::*;
 
 /// The most complete variant of a `SELECT` query expression, optionally
@@ -17,7 +20,7 @@ use super::*;
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Query {
     /// WITH (common table expressions, or CTEs)
-    pub ctes: Vec<Cte>,
+    pub with: Option<With>,
     /// SELECT or UNION / EXCEPT / INTECEPT
     pub body: SetExpr,
     /// ORDER BY
@@ -28,12 +31,27 @@ pub struct Query {
     pub offset: Option<Expr>,
     /// `FETCH { FIRST | NEXT } <N> [ PERCENT ] { ROW | ROWS } | { ONLY | WITH TIES }`
     pub fetch: Option<Fetch>,
+    /// `FOR { UPDATE | SHARE | ... } [ OF <table> ] [ NOWAIT | SKIP LOCKED ]`
+    pub locks: Vec<LockClause>,
+    /// ClickHouse's `FORMAT <format>` output format
+    pub format: Option<Ident>,
+}
+
+impl Query {
+    /// Return the rows of this query if it is a plain `VALUES` list,
+    /// possibly with `ORDER BY`, `LIMIT` and the like
+    pub fn as_values(&self) -> Option<&Values> {
+        match &self.body {
+            SetExpr::Values(values) if self.with.is_none() => Some(values),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for Query {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if !self.ctes.is_empty() {
-            write!(f, "WITH {} ", display_comma_separated(&self.ctes))?;
+        if let Some(with) = &self.with {
+            write!(f, "{} ", with)?;
         }
         write!(f, "{}", self.body)?;
         if !self.order_by.is_empty() {
@@ -48,6 +66,12 @@ impl fmt::Display for Query {
         if let Some(ref fetch) = self.fetch {
             write!(f, " {}", fetch)?;
         }
+        for lock in &self.locks {
+            write!(f, " {}", lock)?;
+        }
+        if let Some(ref format) = self.format {
+            write!(f, " FORMAT {}", format)?;
+        }
         Ok(())
     }
 }
@@ -65,6 +89,9 @@ pub enum SetExpr {
     SetOperation {
         op: SetOperator,
         all: bool,
+        /// Whether `DISTINCT` was written; it is the default, so this only
+        /// records the spelling
+        distinct: bool,
         left: Box<SetExpr>,
         right: Box<SetExpr>,
     },
@@ -83,9 +110,33 @@ impl fmt::Display for SetExpr {
                 right,
                 op,
                 all,
+                distinct,
             } => {
-                let all_str = if *all { " ALL" } else { "" };
-                write!(f, "{} {}{} {}", left, op, all_str, right)
+                // The parser represents explicit parentheses as `Query`
+                // operands, but ASTs built by hand or parsed with a dialect
+                // that uses non-standard precedence may nest operations in
+                // a way that the standard precedence doesn't reproduce.
+                let parenthesize = |operand: &SetExpr, min_precedence: u8| match operand {
+                    SetExpr::SetOperation { op, .. } => op.precedence() < min_precedence,
+                    _ => false,
+                };
+                if parenthesize(left, op.precedence()) {
+                    write!(f, "({})", left)?;
+                } else {
+                    write!(f, "{}", left)?;
+                }
+                write!(f, " {}", op)?;
+                if *all {
+                    write!(f, " ALL")?;
+                } else if *distinct {
+                    write!(f, " DISTINCT")?;
+                }
+                // Set operations associate to the left
+                if parenthesize(right, op.precedence() + 1) {
+                    write!(f, " ({})", right)
+                } else {
+                    write!(f, " {}", right)
+                }
             }
         }
     }
@@ -98,6 +149,24 @@ pub enum SetOperator {
     Intersect,
 }
 
+impl SetOperator {
+    /// Every set operator, in declaration order
+    pub(crate) const ALL: &'static [SetOperator] = &[
+        SetOperator::Union,
+        SetOperator::Except,
+        SetOperator::Intersect,
+    ];
+
+    /// Return the binding power of this operator in standard SQL, where
+    /// INTERSECT binds more tightly than UNION and EXCEPT
+    pub fn precedence(&self) -> u8 {
+        match self {
+            SetOperator::Union | SetOperator::Except => 10,
+            SetOperator::Intersect => 20,
+        }
+    }
+}
+
 impl fmt::Display for SetOperator {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.write_str(match self {
@@ -116,6 +185,9 @@ pub struct Select {
     pub distinct: bool,
     /// projection expressions
     pub projection: Vec<SelectItem>,
+    /// Where each item of `projection` was written, when the query was
+    /// parsed from a string; empty otherwise
+    pub projection_spans: Vec<Span>,
     /// FROM
     pub from: Vec<TableWithJoins>,
     /// WHERE
@@ -124,6 +196,16 @@ pub struct Select {
     pub group_by: Vec<Expr>,
     /// HAVING
     pub having: Option<Expr>,
+    /// Snowflake's `QUALIFY`, filtering on the results of window functions
+    pub qualify: Option<Expr>,
+    /// Hive's `CLUSTER BY`, short for `DISTRIBUTE BY` and `SORT BY` the
+    /// same expressions
+    pub cluster_by: Vec<Expr>,
+    /// Hive's `DISTRIBUTE BY`, sending the rows with equal values to the
+    /// same reducer
+    pub distribute_by: Vec<Expr>,
+    /// Hive's `SORT BY`, ordering the rows within each reducer
+    pub sort_by: Vec<OrderByExpr>,
 }
 
 impl fmt::Display for Select {
@@ -146,10 +228,52 @@ impl fmt::Display for Select {
         if let Some(ref having) = self.having {
             write!(f, " HAVING {}", having)?;
         }
+        if let Some(ref qualify) = self.qualify {
+            write!(f, " QUALIFY {}", qualify)?;
+        }
+        if !self.cluster_by.is_empty() {
+            write!(
+                f,
+                " CLUSTER BY {}",
+                display_comma_separated(&self.cluster_by)
+            )?;
+        }
+        if !self.distribute_by.is_empty() {
+            write!(
+                f,
+                " DISTRIBUTE BY {}",
+                display_comma_separated(&self.distribute_by)
+            )?;
+        }
+        if !self.sort_by.is_empty() {
+            write!(f, " SORT BY {}", display_comma_separated(&self.sort_by))?;
+        }
         Ok(())
     }
 }
 
+/// The `WITH [ RECURSIVE ] <cte>, ...` clause at the start of a query
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct With {
+    /// Whether the CTEs may refer to themselves
+    pub recursive: bool,
+    pub ctes: Vec<Cte>,
+    /// Where the clause was written, when it was parsed from a string;
+    /// empty otherwise
+    pub span: Span,
+}
+
+impl fmt::Display for With {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "WITH {}{}",
+            if self.recursive { "RECURSIVE " } else { "" },
+            display_comma_separated(&self.ctes)
+        )
+    }
+}
+
 /// A single CTE (used after `WITH`): `alias [(col1, col2, ...)] AS ( query )`
 /// The names in the column list before `AS`, when specified, replace the names
 /// of the columns returned by the query. The parser does not validate that the
@@ -173,10 +297,11 @@ pub enum SelectItem {
     UnnamedExpr(Expr),
     /// An expression, followed by `[ AS ] alias`
     ExprWithAlias { expr: Expr, alias: Ident },
-    /// `alias.*` or even `schema.table.*`
-    QualifiedWildcard(ObjectName),
-    /// An unqualified `*`
-    Wildcard,
+    /// `alias.*` or even `schema.table.*`, possibly with BigQuery's
+    /// `EXCEPT`/`REPLACE` modifiers
+    QualifiedWildcard(ObjectName, WildcardOptions),
+    /// An unqualified `*`, possibly with BigQuery's `EXCEPT`/`REPLACE` modifiers
+    Wildcard(WildcardOptions),
 }
 
 impl fmt::Display for SelectItem {
@@ -184,9 +309,46 @@ impl fmt::Display for SelectItem {
         match &self {
             SelectItem::UnnamedExpr(expr) => write!(f, "{}", expr),
             SelectItem::ExprWithAlias { expr, alias } => write!(f, "{} AS {}", expr, alias),
-            SelectItem::QualifiedWildcard(prefix) => write!(f, "{}.*", prefix),
-            SelectItem::Wildcard => write!(f, "*"),
+            SelectItem::QualifiedWildcard(prefix, options) => {
+                write!(f, "{}.*{}", prefix, options)
+            }
+            SelectItem::Wildcard(options) => write!(f, "*{}", options),
+        }
+    }
+}
+
+/// The modifiers of a `SELECT *` or `SELECT t.*`, e.g.
+/// `* EXCEPT (a) REPLACE (b + 1 AS b)`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct WildcardOptions {
+    /// `EXCEPT (col, ...)`: the columns to leave out
+    pub except: Vec<Ident>,
+    /// `REPLACE (expr AS col, ...)`: the columns whose values are replaced
+    pub replace: Vec<WildcardReplacement>,
+}
+
+impl fmt::Display for WildcardOptions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !self.except.is_empty() {
+            write!(f, " EXCEPT ({})", display_comma_separated(&self.except))?;
+        }
+        if !self.replace.is_empty() {
+            write!(f, " REPLACE ({})", display_comma_separated(&self.replace))?;
         }
+        Ok(())
+    }
+}
+
+/// `expr AS col` in the `REPLACE` modifier of a `SELECT *`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WildcardReplacement {
+    pub expr: Expr,
+    pub column_name: Ident,
+}
+
+impl fmt::Display for WildcardReplacement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} AS {}", self.expr, self.column_name)
     }
 }
 
@@ -206,11 +368,47 @@ impl fmt::Display for TableWithJoins {
     }
 }
 
+/// The `FOR SYSTEM_TIME` clause of a table reference, which selects the
+/// versions of a system-versioned table's rows that were current at some
+/// point or during some period
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TableVersion {
+    /// `FOR SYSTEM_TIME AS OF <point>`
+    AsOf(Box<Expr>),
+    /// `FOR SYSTEM_TIME FROM <start> TO <end>`, excluding `<end>`
+    FromTo(Box<Expr>, Box<Expr>),
+    /// `FOR SYSTEM_TIME BETWEEN <start> AND <end>`, including `<end>`
+    Between(Box<Expr>, Box<Expr>),
+}
+
+impl fmt::Display for TableVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TableVersion::AsOf(point) => write!(f, "FOR SYSTEM_TIME AS OF {}", point),
+            TableVersion::FromTo(start, end) => {
+                write!(f, "FOR SYSTEM_TIME FROM {} TO {}", start, end)
+            }
+            TableVersion::Between(start, end) => {
+                write!(f, "FOR SYSTEM_TIME BETWEEN {} AND {}", start, end)
+            }
+        }
+    }
+}
+
 /// A table name or a parenthesized subquery with an optional alias
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum TableFactor {
     Table {
+        /// Whether the name is preceded by `ONLY`, which excludes the
+        /// table's descendants in Postgres table inheritance
+        only: bool,
         name: ObjectName,
+        /// Whether the name is followed by `*`, which explicitly includes
+        /// the table's descendants in Postgres table inheritance
+        descendants: bool,
+        /// SQL:2011 `FOR SYSTEM_TIME ...`, which queries the past versions
+        /// of the rows of a system-versioned table
+        version: Option<TableVersion>,
         alias: Option<TableAlias>,
         /// Arguments of a table-valued function, as supported by Postgres
         /// and MSSQL. Note that deprecated MSSQL `FROM foo (NOLOCK)` syntax
@@ -218,12 +416,35 @@ pub enum TableFactor {
         args: Vec<Expr>,
         /// MSSQL-specific `WITH (...)` hints such as NOLOCK.
         with_hints: Vec<Expr>,
+        /// Whether a table-valued function is followed by `WITH ORDINALITY`,
+        /// which adds a column numbering its rows
+        with_ordinality: bool,
     },
     Derived {
         lateral: bool,
         subquery: Box<Query>,
         alias: Option<TableAlias>,
     },
+    /// SQL/JSON `JSON_TABLE(<json>, <path> COLUMNS (...))`, which shreds a
+    /// JSON document into rows
+    JsonTable {
+        json_expr: Box<Expr>,
+        /// The path of the rows within the document, e.g. `'$.items[*]'`
+        json_path: Value,
+        columns: Vec<JsonTableColumn>,
+        alias: Option<TableAlias>,
+    },
+    /// SQL/XML `XMLTABLE([XMLNAMESPACES(...), ] <row> PASSING <xml> COLUMNS ...)`,
+    /// which shreds an XML document into rows
+    XmlTable {
+        namespaces: Vec<XmlNamespace>,
+        /// The XPath expression that finds the rows within the document
+        row_expr: Box<Expr>,
+        /// The document, which follows `PASSING`
+        passing: Box<Expr>,
+        columns: Vec<XmlTableColumn>,
+        alias: Option<TableAlias>,
+    },
     /// Represents a parenthesized join expression, such as
     /// `(foo <JOIN> bar [ <JOIN> baz ... ])`.
     /// The inner `TableWithJoins` can have no joins only if its
@@ -235,15 +456,31 @@ impl fmt::Display for TableFactor {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             TableFactor::Table {
+                only,
                 name,
+                descendants,
+                version,
                 alias,
                 args,
                 with_hints,
+                with_ordinality,
             } => {
+                if *only {
+                    write!(f, "ONLY ")?;
+                }
                 write!(f, "{}", name)?;
+                if *descendants {
+                    write!(f, " *")?;
+                }
                 if !args.is_empty() {
                     write!(f, "({})", display_comma_separated(args))?;
                 }
+                if *with_ordinality {
+                    write!(f, " WITH ORDINALITY")?;
+                }
+                if let Some(version) = version {
+                    write!(f, " {}", version)?;
+                }
                 if let Some(alias) = alias {
                     write!(f, " AS {}", alias)?;
                 }
@@ -266,15 +503,206 @@ impl fmt::Display for TableFactor {
                 }
                 Ok(())
             }
+            TableFactor::JsonTable {
+                json_expr,
+                json_path,
+                columns,
+                alias,
+            } => {
+                write!(
+                    f,
+                    "JSON_TABLE({}, {} COLUMNS ({}))",
+                    json_expr,
+                    json_path,
+                    display_comma_separated(columns)
+                )?;
+                if let Some(alias) = alias {
+                    write!(f, " AS {}", alias)?;
+                }
+                Ok(())
+            }
+            TableFactor::XmlTable {
+                namespaces,
+                row_expr,
+                passing,
+                columns,
+                alias,
+            } => {
+                write!(f, "XMLTABLE(")?;
+                if !namespaces.is_empty() {
+                    write!(
+                        f,
+                        "XMLNAMESPACES({}), ",
+                        display_comma_separated(namespaces)
+                    )?;
+                }
+                write!(
+                    f,
+                    "{} PASSING {} COLUMNS {})",
+                    row_expr,
+                    passing,
+                    display_comma_separated(columns)
+                )?;
+                if let Some(alias) = alias {
+                    write!(f, " AS {}", alias)?;
+                }
+                Ok(())
+            }
             TableFactor::NestedJoin(table_reference) => write!(f, "({})", table_reference),
         }
     }
 }
 
+/// A column definition in the `COLUMNS` clause of `JSON_TABLE`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JsonTableColumn {
+    /// `<name> <type> [EXISTS] PATH <path> [<handling> ON EMPTY] [<handling> ON ERROR]`
+    Named(Box<JsonTableNamedColumn>),
+    /// `<name> FOR ORDINALITY`, which numbers the rows
+    ForOrdinality(Ident),
+    /// `NESTED [PATH] <path> COLUMNS (...)`, which joins the rows found at
+    /// `<path>` within each row
+    Nested {
+        path: Value,
+        columns: Vec<JsonTableColumn>,
+    },
+}
+
+impl fmt::Display for JsonTableColumn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JsonTableColumn::Named(column) => write!(f, "{}", column),
+            JsonTableColumn::ForOrdinality(name) => write!(f, "{} FOR ORDINALITY", name),
+            JsonTableColumn::Nested { path, columns } => write!(
+                f,
+                "NESTED PATH {} COLUMNS ({})",
+                path,
+                display_comma_separated(columns)
+            ),
+        }
+    }
+}
+
+/// A `JSON_TABLE` column whose value is extracted from each row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonTableNamedColumn {
+    pub name: Ident,
+    pub data_type: DataType,
+    pub path: Value,
+    /// Whether the column is `EXISTS PATH`, which is true if `path` matches
+    /// anything rather than the value it matches
+    pub exists: bool,
+    pub on_empty: Option<JsonTableColumnErrorHandling>,
+    pub on_error: Option<JsonTableColumnErrorHandling>,
+}
+
+impl fmt::Display for JsonTableNamedColumn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} ", self.name, self.data_type)?;
+        if self.exists {
+            write!(f, "EXISTS ")?;
+        }
+        write!(f, "PATH {}", self.path)?;
+        if let Some(on_empty) = &self.on_empty {
+            write!(f, " {} ON EMPTY", on_empty)?;
+        }
+        if let Some(on_error) = &self.on_error {
+            write!(f, " {} ON ERROR", on_error)?;
+        }
+        Ok(())
+    }
+}
+
+/// What a `JSON_TABLE` column contains when its path matches nothing
+/// (`ON EMPTY`) or can't be converted to the column's type (`ON ERROR`)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JsonTableColumnErrorHandling {
+    Null,
+    Default(Value),
+    Error,
+}
+
+impl fmt::Display for JsonTableColumnErrorHandling {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JsonTableColumnErrorHandling::Null => write!(f, "NULL"),
+            JsonTableColumnErrorHandling::Default(value) => write!(f, "DEFAULT {}", value),
+            JsonTableColumnErrorHandling::Error => write!(f, "ERROR"),
+        }
+    }
+}
+
+/// A namespace declared in the `XMLNAMESPACES` clause of `XMLTABLE`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct XmlNamespace {
+    pub uri: Expr,
+    /// The prefix that refers to the namespace, or `None` for the
+    /// `DEFAULT` namespace
+    pub name: Option<Ident>,
+}
+
+impl fmt::Display for XmlNamespace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "{} AS {}", self.uri, name),
+            None => write!(f, "DEFAULT {}", self.uri),
+        }
+    }
+}
+
+/// A column definition in the `COLUMNS` clause of `XMLTABLE`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum XmlTableColumn {
+    /// `<name> <type> [PATH <path>] [DEFAULT <default>] [[NOT] NULL]`
+    Named(Box<XmlTableNamedColumn>),
+    /// `<name> FOR ORDINALITY`, which numbers the rows
+    ForOrdinality(Ident),
+}
+
+impl fmt::Display for XmlTableColumn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            XmlTableColumn::Named(column) => write!(f, "{}", column),
+            XmlTableColumn::ForOrdinality(name) => write!(f, "{} FOR ORDINALITY", name),
+        }
+    }
+}
+
+/// An `XMLTABLE` column whose value is extracted from each row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct XmlTableNamedColumn {
+    pub name: Ident,
+    pub data_type: DataType,
+    /// The XPath expression that finds the value within the row, which
+    /// defaults to the column's name
+    pub path: Option<Expr>,
+    /// The value of the column when `path` matches nothing
+    pub default: Option<Expr>,
+    /// `Some(false)` for `NOT NULL`, `Some(true)` for an explicit `NULL`
+    pub nullable: Option<bool>,
+}
+
+impl fmt::Display for XmlTableNamedColumn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.name, self.data_type)?;
+        if let Some(path) = &self.path {
+            write!(f, " PATH {}", path)?;
+        }
+        if let Some(default) = &self.default {
+            write!(f, " DEFAULT {}", default)?;
+        }
+        match self.nullable {
+            Some(true) => write!(f, " NULL"),
+            Some(false) => write!(f, " NOT NULL"),
+            None => Ok(()),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TableAlias {
     pub name: Ident,
-    pub columns: Vec<Ident>,
+    pub columns: Vec<TableAliasColumn>,
 }
 
 impl fmt::Display for TableAlias {
@@ -287,6 +715,39 @@ impl fmt::Display for TableAlias {
     }
 }
 
+/// A column in a [TableAlias], optionally with a type, as in
+/// `FROM jsonb_to_recordset(x) AS t (a int, b text)`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TableAliasColumn {
+    pub name: Ident,
+    pub data_type: Option<DataType>,
+}
+
+impl fmt::Display for TableAliasColumn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some(data_type) = &self.data_type {
+            write!(f, " {}", data_type)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Ident> for TableAliasColumn {
+    fn from(name: Ident) -> Self {
+        TableAliasColumn {
+            name,
+            data_type: None,
+        }
+    }
+}
+
+impl From<&str> for TableAliasColumn {
+    fn from(name: &str) -> Self {
+        Ident::from(name).into()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Join {
     pub relation: TableFactor,
@@ -408,6 +869,65 @@ impl fmt::Display for Fetch {
     }
 }
 
+/// A row-locking clause, e.g. `FOR UPDATE OF t SKIP LOCKED`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LockClause {
+    pub lock_type: LockType,
+    /// The tables to lock; all tables of the query if empty
+    pub of: Vec<ObjectName>,
+    /// What to do when a row is already locked, instead of waiting
+    pub nonblock: Option<NonBlock>,
+}
+
+impl fmt::Display for LockClause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FOR {}", self.lock_type)?;
+        if !self.of.is_empty() {
+            write!(f, " OF {}", display_comma_separated(&self.of))?;
+        }
+        if let Some(ref nonblock) = self.nonblock {
+            write!(f, " {}", nonblock)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LockType {
+    Update,
+    NoKeyUpdate,
+    Share,
+    KeyShare,
+}
+
+impl fmt::Display for LockType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            LockType::Update => "UPDATE",
+            LockType::NoKeyUpdate => "NO KEY UPDATE",
+            LockType::Share => "SHARE",
+            LockType::KeyShare => "KEY SHARE",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NonBlock {
+    /// `NOWAIT`: fail if a row can't be locked immediately
+    Nowait,
+    /// `SKIP LOCKED`: leave out the rows that can't be locked immediately
+    SkipLocked,
+}
+
+impl fmt::Display for NonBlock {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            NonBlock::Nowait => "NOWAIT",
+            NonBlock::SkipLocked => "SKIP LOCKED",
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Values(pub Vec<Vec<Expr>>);
 
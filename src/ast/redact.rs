@@ -0,0 +1,128 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Replacement of literal values with `$n` parameters
+//!
+//! This is the inverse of [bind](super::bind): it normalizes statements that
+//! differ only in their constants, in the manner of `pg_stat_statements`, and
+//! makes statements safe to log when their constants may contain sensitive
+//! data.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::mem;
+
+use super::visit::Visit;
+use super::visit_mut::{self, VisitMut};
+use super::{Expr, Statement, Value};
+
+/// Replace every literal value in `statement` with a `$n` parameter, and
+/// return the replaced values in order
+///
+/// Parameters are numbered in the order the literals appear in the
+/// statement's SQL text, starting after the highest parameter already in
+/// the statement. If the statement had no parameters, `values[n - 1]` is the
+/// value `$n` replaced, so that [bind_parameters](super::bind::bind_parameters)
+/// with the returned values restores the original statement.
+///
+/// Only literals in expression position are replaced. Values that the
+/// grammar requires to be literals, like the options of `CREATE SOURCE` or
+/// the value of a `SET` statement, are left as is.
+pub fn redact_literals(statement: &mut Statement) -> Vec<Value> {
+    let mut max_parameter = MaxParameter(0);
+    max_parameter.visit_statement(statement);
+    let mut redactor = Redactor {
+        first_parameter: max_parameter.0 + 1,
+        values: Vec::new(),
+    };
+    redactor.visit_statement(statement);
+    redactor.values
+}
+
+struct MaxParameter(usize);
+
+impl<'ast> Visit<'ast> for MaxParameter {
+    fn visit_parameter(&mut self, n: usize) {
+        self.0 = self.0.max(n);
+    }
+}
+
+struct Redactor {
+    first_parameter: usize,
+    values: Vec<Value>,
+}
+
+impl<'ast> VisitMut<'ast> for Redactor {
+    fn visit_expr(&mut self, expr: &'ast mut Expr) {
+        match expr {
+            Expr::Value(_) => {
+                let n = self.first_parameter + self.values.len();
+                if let Expr::Value(value) = mem::replace(expr, Expr::Parameter(n)) {
+                    self.values.push(value);
+                }
+            }
+            _ => visit_mut::visit_expr(self, expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::bind::bind_parameters;
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn redact_in_text_order() {
+        let sql = "WITH c AS (SELECT 'a') \
+                   SELECT f(1, 'b'), CASE WHEN x = 2 THEN 'c' WHEN x = 3 THEN 'd' ELSE 'e' END \
+                   FROM t JOIN u ON t.a = 4 \
+                   WHERE t.b BETWEEN 5 AND 6 AND t.c IN (7, DATE '2020-01-01') \
+                   GROUP BY 8 HAVING count(*) > 9 \
+                   ORDER BY 10 LIMIT 11 OFFSET 12 ROWS";
+        let original = generic().verified_stmt(sql);
+        let mut statement = original.clone();
+        let values = redact_literals(&mut statement);
+        assert_eq!(
+            "WITH c AS (SELECT $1) \
+             SELECT f($2, $3), CASE WHEN x = $4 THEN $5 WHEN x = $6 THEN $7 ELSE $8 END \
+             FROM t JOIN u ON t.a = $9 \
+             WHERE t.b BETWEEN $10 AND $11 AND t.c IN ($12, $13) \
+             GROUP BY 8 HAVING count(*) > $14 \
+             ORDER BY 10 LIMIT $15 OFFSET $16 ROWS",
+            statement.to_string()
+        );
+        // Ordinals are column references, not literals
+        assert_eq!(16, values.len());
+        assert_eq!(Value::SingleQuotedString("a".to_string()), values[0]);
+        assert_eq!(original, bind_parameters(&statement, &values).unwrap());
+    }
+
+    #[test]
+    fn redact_after_existing_parameters() {
+        let mut statement =
+            generic().verified_stmt("UPDATE t SET a = 'x', b = $2 WHERE c = $1 AND d = 1");
+        let values = redact_literals(&mut statement);
+        assert_eq!(
+            "UPDATE t SET a = $3, b = $2 WHERE c = $1 AND d = $4",
+            statement.to_string()
+        );
+        assert_eq!(2, values.len());
+    }
+
+    #[test]
+    fn redact_leaves_non_expression_literals() {
+        let mut statement = generic().verified_stmt("SET search_path = 'public'");
+        assert!(redact_literals(&mut statement).is_empty());
+        assert_eq!("SET search_path = 'public'", statement.to_string());
+    }
+}
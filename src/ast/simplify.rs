@@ -0,0 +1,341 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Purely syntactic simplification of expressions
+//!
+//! Every rewrite here preserves the meaning of the expression, including
+//! under SQL's three-valued logic, and keeps its `Display` output parsing
+//! back to the same tree.
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::ToString, vec};
+
+use core::mem;
+
+use super::visit_mut::{self, VisitMut};
+use super::{BinaryOperator, Expr, Query, Statement, UnaryOperator, Value};
+
+/// Which rewrites [simplify_expr] and friends perform
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SimplifyOptions {
+    /// Remove `Nested` wrappers that don't affect parsing, e.g. `(a)` and
+    /// `f((a + b))`
+    pub remove_nested: bool,
+    /// Fold trivial boolean literals: `NOT TRUE`, `NOT NOT x`, `x AND TRUE`,
+    /// `x OR FALSE`, ...
+    pub fold_literals: bool,
+    /// Flatten chains of `AND` or `OR` into [Expr::Conjunction]
+    pub flatten_conjunctions: bool,
+}
+
+impl Default for SimplifyOptions {
+    fn default() -> Self {
+        SimplifyOptions {
+            remove_nested: true,
+            fold_literals: true,
+            flatten_conjunctions: false,
+        }
+    }
+}
+
+/// Simplify `expr` in place, including any subqueries it contains
+pub fn simplify_expr(expr: &mut Expr, options: &SimplifyOptions) {
+    Simplifier { options }.visit_expr(expr);
+    if options.remove_nested {
+        // Parentheses around a whole expression are always redundant
+        unnest(expr);
+    }
+}
+
+/// Simplify every expression in `query` in place
+pub fn simplify_query(query: &mut Query, options: &SimplifyOptions) {
+    Simplifier { options }.visit_query(query);
+}
+
+/// Simplify every expression in `statement` in place
+pub fn simplify_statement(statement: &mut Statement, options: &SimplifyOptions) {
+    Simplifier { options }.visit_statement(statement);
+}
+
+struct Simplifier<'a> {
+    options: &'a SimplifyOptions,
+}
+
+impl<'a, 'ast> VisitMut<'ast> for Simplifier<'a> {
+    fn visit_expr(&mut self, expr: &'ast mut Expr) {
+        // Simplify bottom-up, so that each rewrite sees simplified operands
+        visit_mut::visit_expr(self, expr);
+
+        if self.options.remove_nested {
+            remove_nested(expr);
+        }
+        if self.options.fold_literals {
+            fold_literals(expr);
+        }
+        if self.options.flatten_conjunctions {
+            flatten_conjunction(expr);
+        }
+    }
+}
+
+/// Whether `expr` displays as a single syntactic unit, so that wrapping it
+/// in parentheses never changes how it parses
+fn is_atom(expr: &Expr) -> bool {
+    match expr {
+        Expr::Identifier(_)
+        | Expr::Wildcard
+        | Expr::QualifiedWildcard(_)
+        | Expr::CompoundIdentifier(_)
+        | Expr::Parameter(_)
+        | Expr::Cast { .. }
+        | Expr::Extract { .. }
+        | Expr::Trim { .. }
+        | Expr::Overlay { .. }
+        | Expr::Convert { .. }
+        | Expr::Nested(_)
+        | Expr::Row(_)
+        | Expr::Function(_)
+        | Expr::Case { .. }
+        | Expr::Exists { negated: false, .. }
+        | Expr::Subquery(_)
+        | Expr::ArraySubquery(_) => true,
+        // A negative number can't follow another operator unparenthesized
+        Expr::Value(v) => !v.to_string().starts_with('-'),
+        _ => false,
+    }
+}
+
+/// Strip any number of `Nested` wrappers from `expr`
+fn unnest(expr: &mut Expr) {
+    while let Expr::Nested(inner) = expr {
+        let inner = mem::replace(&mut **inner, Expr::Wildcard);
+        *expr = inner;
+    }
+}
+
+/// Strip a single `Nested` wrapper from `expr` if it wraps an atom
+fn unnest_atom(expr: &mut Expr) {
+    if let Expr::Nested(inner) = expr {
+        if is_atom(inner) {
+            let inner = mem::replace(&mut **inner, Expr::Wildcard);
+            *expr = inner;
+        }
+    }
+}
+
+fn remove_nested(expr: &mut Expr) {
+    match expr {
+        // `((x))` is `(x)`, and `(x)` is `x` for atoms
+        Expr::Nested(_) => {
+            unnest_atom(expr);
+            if let Expr::Nested(inner) = expr {
+                if let Expr::Nested(_) = **inner {
+                    unnest(inner);
+                }
+            }
+        }
+        // Arguments are delimited by commas, so need no parentheses
+        Expr::Function(func) => func.args.iter_mut().for_each(unnest),
+        Expr::Row(exprs) => exprs.iter_mut().for_each(unnest),
+        Expr::BinaryOp { left, right, .. } => {
+            unnest_atom(left);
+            unnest_atom(right);
+        }
+        Expr::Conjunction { operands, .. } => operands.iter_mut().for_each(unnest_atom),
+        Expr::UnaryOp { expr, .. }
+        | Expr::IsNull(expr)
+        | Expr::IsNotNull(expr)
+        | Expr::Collate { expr, .. } => unnest_atom(expr),
+        // The operand of a cast is delimited by `CAST(` and `AS`
+        Expr::Cast { expr, .. } | Expr::Extract { expr, .. } => unnest(expr),
+        _ => (),
+    }
+}
+
+fn as_bool(expr: &Expr) -> Option<bool> {
+    match expr {
+        Expr::Value(Value::Boolean(b)) => Some(*b),
+        _ => None,
+    }
+}
+
+fn fold_literals(expr: &mut Expr) {
+    let folded = match expr {
+        Expr::UnaryOp {
+            op: UnaryOperator::Not,
+            expr: operand,
+        } => match &mut **operand {
+            // NOT TRUE => FALSE, NOT FALSE => TRUE
+            Expr::Value(Value::Boolean(b)) => Expr::Value(Value::Boolean(!*b)),
+            // NOT NOT x => x
+            Expr::UnaryOp {
+                op: UnaryOperator::Not,
+                expr: inner,
+            } => mem::replace(&mut **inner, Expr::Wildcard),
+            _ => return,
+        },
+        Expr::BinaryOp {
+            left,
+            op: op @ BinaryOperator::And,
+            right,
+        }
+        | Expr::BinaryOp {
+            left,
+            op: op @ BinaryOperator::Or,
+            right,
+        } => {
+            // TRUE is the identity of AND and FALSE absorbs it; the reverse
+            // holds for OR. Both hold for NULL operands as well.
+            let identity = *op == BinaryOperator::And;
+            match (as_bool(left), as_bool(right)) {
+                (Some(b), _) if b == identity => mem::replace(&mut **right, Expr::Wildcard),
+                (_, Some(b)) if b == identity => mem::replace(&mut **left, Expr::Wildcard),
+                (Some(_), _) | (_, Some(_)) => Expr::Value(Value::Boolean(!identity)),
+                (None, None) => return,
+            }
+        }
+        _ => return,
+    };
+    *expr = folded;
+}
+
+fn flatten_conjunction(expr: &mut Expr) {
+    let (left, op, right) = match expr {
+        Expr::BinaryOp { left, op, right }
+            if *op == BinaryOperator::And || *op == BinaryOperator::Or =>
+        {
+            (left, op, right)
+        }
+        _ => return,
+    };
+    let mut operands = vec![];
+    for operand in [&mut **left, &mut **right] {
+        let mut operand = mem::replace(operand, Expr::Wildcard);
+        // The operators are associative, so parentheses around an operand
+        // using the same operator are redundant
+        if let Expr::Nested(inner) = &operand {
+            if let Expr::Conjunction { op: inner_op, .. } = &**inner {
+                if inner_op == op {
+                    unnest(&mut operand);
+                }
+            }
+        }
+        match operand {
+            Expr::Conjunction {
+                op: ref inner_op,
+                operands: ref mut inner,
+            } if inner_op == op => operands.append(inner),
+            operand => operands.push(operand),
+        }
+    }
+    *expr = Expr::Conjunction {
+        op: op.clone(),
+        operands,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialect::GenericDialect;
+    use crate::parser::Parser;
+    use crate::tokenizer::Tokenizer;
+
+    fn parse_expr(sql: &str) -> Expr {
+        let dialect = GenericDialect {};
+        let tokens = Tokenizer::new(&dialect, sql).tokenize().unwrap();
+        Parser::new_with_dialect(tokens, &dialect)
+            .parse_expr()
+            .unwrap()
+    }
+
+    /// Simplify `sql` and check the result against `expected`, and that the
+    /// result parses back to the same tree
+    fn check(sql: &str, options: &SimplifyOptions, expected: &str) {
+        let mut expr = parse_expr(sql);
+        simplify_expr(&mut expr, options);
+        assert_eq!(expected, expr.to_string());
+        if !options.flatten_conjunctions {
+            let mut reparsed = parse_expr(expected);
+            simplify_expr(&mut reparsed, options);
+            assert_eq!(expr, reparsed);
+        }
+    }
+
+    #[test]
+    fn simplify_nested() {
+        let options = SimplifyOptions::default();
+        check("((a))", &options, "a");
+        check("((a + b)) * (c)", &options, "(a + b) * c");
+        check("f((a + b), (c))", &options, "f(a + b, c)");
+        check("NOT (a)", &options, "NOT a");
+        check("(a OR b) AND c", &options, "(a OR b) AND c");
+        check("CAST((a + 1) AS int)", &options, "CAST(a + 1 AS int)");
+        check("((SELECT 1))", &options, "(SELECT 1)");
+        check(
+            "a IN (SELECT (b) FROM t)",
+            &options,
+            "a IN (SELECT b FROM t)",
+        );
+    }
+
+    #[test]
+    fn simplify_literals() {
+        let options = SimplifyOptions::default();
+        check("NOT TRUE", &options, "false");
+        check("NOT NOT a", &options, "a");
+        check("a AND TRUE", &options, "a");
+        check("FALSE AND a", &options, "false");
+        check("a OR (NOT TRUE)", &options, "a");
+        check("a OR TRUE", &options, "true");
+        check("(a AND b) AND TRUE", &options, "a AND b");
+    }
+
+    #[test]
+    fn simplify_conjunctions() {
+        let options = SimplifyOptions {
+            flatten_conjunctions: true,
+            ..SimplifyOptions::default()
+        };
+        let mut expr = parse_expr("a AND (b AND c) AND (d OR e OR f)");
+        simplify_expr(&mut expr, &options);
+        assert_eq!("a AND b AND c AND (d OR e OR f)", expr.to_string());
+        match &expr {
+            Expr::Conjunction { op, operands } => {
+                assert_eq!(&BinaryOperator::And, op);
+                assert_eq!(4, operands.len());
+                match &operands[3] {
+                    Expr::Nested(inner) => match &**inner {
+                        Expr::Conjunction { op, operands } => {
+                            assert_eq!(&BinaryOperator::Or, op);
+                            assert_eq!(3, operands.len());
+                        }
+                        _ => unreachable!(),
+                    },
+                    _ => unreachable!(),
+                }
+            }
+            _ => unreachable!(),
+        }
+
+        check("a OR b AND c", &options, "a OR b AND c");
+        check("a = 1", &options, "a = 1");
+    }
+
+    #[test]
+    fn simplify_whole_statement() {
+        let sql = "SELECT (a) FROM t WHERE (b = 1) AND TRUE";
+        let mut statements = Parser::parse_sql(&GenericDialect {}, sql.to_string()).unwrap();
+        simplify_statement(&mut statements[0], &SimplifyOptions::default());
+        assert_eq!("SELECT a FROM t WHERE (b = 1)", statements[0].to_string());
+    }
+}
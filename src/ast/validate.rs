@@ -0,0 +1,310 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Syntactic checks for violations of the SQL standard that the parser
+//! accepts
+//!
+//! The parser is lenient so that it can handle what the dialects accept, and
+//! leaves it to the database to reject statements that are well-formed but
+//! invalid. The checks here catch some of those ahead of time, for linting.
+//! Nothing here consults a catalog, so a statement without violations may
+//! still be rejected.
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+use core::fmt;
+
+use super::visit::{self, Visit};
+use super::visit_mut::{self, VisitMut};
+use super::{
+    Expr, Function, Ident, ObjectName, Query, SelectItem, SetExpr, Statement, TableAlias,
+    TableFactor,
+};
+
+/// The aggregate functions of the SQL standard, and common ones of the
+/// dialects, in upper case
+const AGGREGATE_FUNCTIONS: &[&str] = &[
+    "ANY_VALUE",
+    "ARRAY_AGG",
+    "AVG",
+    "BOOL_AND",
+    "BOOL_OR",
+    "COUNT",
+    "EVERY",
+    "LISTAGG",
+    "MAX",
+    "MIN",
+    "STDDEV_POP",
+    "STDDEV_SAMP",
+    "STRING_AGG",
+    "SUM",
+    "VAR_POP",
+    "VAR_SAMP",
+];
+
+/// A violation of the SQL standard found by [validate]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Violation {
+    /// A subquery in `FROM` without an alias, which the standard, and
+    /// dialects like MySQL, require. See [alias_derived_tables].
+    DerivedTableWithoutAlias,
+    /// A call of the named aggregate function in a `WHERE` clause, which
+    /// filters rows before they are aggregated
+    AggregateInWhere(ObjectName),
+    /// An `ORDER BY <n>` where `n` is not the position of an item of the
+    /// select list, which has `columns` items
+    OrdinalOutOfRange { ordinal: u64, columns: usize },
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Violation::DerivedTableWithoutAlias => {
+                f.write_str("subquery in FROM must have an alias")
+            }
+            Violation::AggregateInWhere(name) => {
+                write!(f, "aggregate function {} is not allowed in WHERE", name)
+            }
+            Violation::OrdinalOutOfRange { ordinal, columns } => write!(
+                f,
+                "ORDER BY position {} is not in the select list of {} columns",
+                ordinal, columns
+            ),
+        }
+    }
+}
+
+/// Return the violations of the SQL standard in `statement`, in the order
+/// in which they appear
+pub fn validate(statement: &Statement) -> Vec<Violation> {
+    let mut validator = Validator(Vec::new());
+    validator.visit_statement(statement);
+    validator.0
+}
+
+/// Give each subquery in `FROM` that has no alias one, so that `statement`
+/// no longer has [Violation::DerivedTableWithoutAlias]
+///
+/// The aliases are `derived_1`, `derived_2` and so on, skipping the names of
+/// the tables and aliases already in the statement.
+pub fn alias_derived_tables(statement: &mut Statement) {
+    let mut names = RelationNames(Vec::new());
+    names.visit_statement(statement);
+    let mut aliaser = Aliaser {
+        taken: names.0,
+        next: 1,
+    };
+    aliaser.visit_statement(statement);
+}
+
+struct Validator(Vec<Violation>);
+
+impl<'ast> Visit<'ast> for Validator {
+    fn visit_query(&mut self, query: &'ast Query) {
+        if let Some(columns) = select_list_len(&query.body) {
+            for order_by in &query.order_by {
+                if let Expr::Ordinal(ordinal) = order_by.expr {
+                    if ordinal == 0 || ordinal > columns as u64 {
+                        self.0
+                            .push(Violation::OrdinalOutOfRange { ordinal, columns });
+                    }
+                }
+            }
+        }
+        visit::visit_query(self, query);
+    }
+
+    fn visit_derived_table_factor(
+        &mut self,
+        lateral: bool,
+        subquery: &'ast Query,
+        alias: Option<&'ast TableAlias>,
+    ) {
+        if alias.is_none() {
+            self.0.push(Violation::DerivedTableWithoutAlias);
+        }
+        visit::visit_derived_table_factor(self, lateral, subquery, alias);
+    }
+
+    fn visit_where(&mut self, expr: &'ast Expr) {
+        let mut aggregates = Aggregates(Vec::new());
+        aggregates.visit_expr(expr);
+        self.0.extend(
+            aggregates
+                .0
+                .into_iter()
+                .map(|name| Violation::AggregateInWhere(name.clone())),
+        );
+        visit::visit_where(self, expr);
+    }
+}
+
+/// Return the number of items in the select list of `body`, unless it
+/// depends on the tables because of a wildcard
+fn select_list_len(body: &SetExpr) -> Option<usize> {
+    match body {
+        SetExpr::Select(select) => {
+            let wildcard = select.projection.iter().any(|item| {
+                matches!(
+                    item,
+                    SelectItem::Wildcard(_) | SelectItem::QualifiedWildcard(..)
+                )
+            });
+            if wildcard {
+                None
+            } else {
+                Some(select.projection.len())
+            }
+        }
+        SetExpr::Query(query) => select_list_len(&query.body),
+        SetExpr::SetOperation { left, .. } => select_list_len(left),
+        SetExpr::Values(values) => values.0.first().map(|row| row.len()),
+    }
+}
+
+/// The names of the aggregate functions called in an expression, outside of
+/// window functions and subqueries
+struct Aggregates<'a>(Vec<&'a ObjectName>);
+
+impl<'ast> Visit<'ast> for Aggregates<'ast> {
+    fn visit_query(&mut self, _query: &'ast Query) {
+        // Subqueries aggregate their own rows
+    }
+
+    fn visit_function(&mut self, func: &'ast Function) {
+        let aggregate = match func.name.0.as_slice() {
+            [name] => AGGREGATE_FUNCTIONS.contains(&name.value.to_ascii_uppercase().as_str()),
+            _ => false,
+        };
+        if aggregate && func.over.is_none() {
+            self.0.push(&func.name);
+        }
+        visit::visit_function(self, func);
+    }
+}
+
+/// The names, in upper case, of the tables and aliases in a statement
+struct RelationNames(Vec<String>);
+
+impl<'ast> Visit<'ast> for RelationNames {
+    fn visit_table_alias(&mut self, alias: &'ast TableAlias) {
+        self.0.push(alias.name.value.to_ascii_uppercase());
+        visit::visit_table_alias(self, alias);
+    }
+
+    fn visit_table_factor(&mut self, table_factor: &'ast TableFactor) {
+        if let TableFactor::Table { name, .. } = table_factor {
+            if let Some(last) = name.0.last() {
+                self.0.push(last.value.to_ascii_uppercase());
+            }
+        }
+        visit::visit_table_factor(self, table_factor);
+    }
+}
+
+struct Aliaser {
+    taken: Vec<String>,
+    next: usize,
+}
+
+impl<'ast> VisitMut<'ast> for Aliaser {
+    fn visit_table_factor(&mut self, table_factor: &'ast mut TableFactor) {
+        if let TableFactor::Derived { alias: None, .. } = table_factor {
+            let name = loop {
+                let name = format!("derived_{}", self.next);
+                self.next += 1;
+                if !self.taken.contains(&name.to_ascii_uppercase()) {
+                    break name;
+                }
+            };
+            if let TableFactor::Derived { alias, .. } = table_factor {
+                *alias = Some(TableAlias {
+                    name: Ident::new(name),
+                    columns: Vec::new(),
+                });
+            }
+        }
+        visit_mut::visit_table_factor(self, table_factor);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    fn violations(sql: &str) -> Vec<String> {
+        validate(&all_dialects().verified_stmt(sql))
+            .iter()
+            .map(|v| v.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn validate_derived_table_aliases() {
+        assert_eq!(
+            vec!["subquery in FROM must have an alias"],
+            violations("SELECT * FROM (SELECT 1) JOIN (SELECT 2) AS b ON true")
+        );
+        assert!(violations("SELECT * FROM (SELECT 1) AS a").is_empty());
+    }
+
+    #[test]
+    fn validate_aggregates_in_where() {
+        assert_eq!(
+            vec![
+                "aggregate function count is not allowed in WHERE",
+                "aggregate function SUM is not allowed in WHERE",
+            ],
+            violations("DELETE FROM t WHERE count(*) > 1 OR a = SUM(b)")
+        );
+        // Aggregates in subqueries, window functions, HAVING and the select
+        // list are fine
+        let sql = "SELECT max(a) FROM t \
+                   WHERE b > (SELECT avg(b) FROM u) AND c = sum(c) OVER () \
+                   GROUP BY d HAVING count(*) > 1";
+        assert!(violations(sql).is_empty());
+        // Only ASCII letters are folded, so `ſum` with a long s is not SUM
+        let statement =
+            TestedDialects::named(&["mysql"]).verified_stmt("DELETE FROM t WHERE a = ſum(b)");
+        assert!(validate(&statement).is_empty());
+    }
+
+    #[test]
+    fn validate_order_by_ordinals() {
+        assert_eq!(
+            vec![
+                "ORDER BY position 3 is not in the select list of 2 columns",
+                "ORDER BY position 0 is not in the select list of 2 columns",
+            ],
+            violations("SELECT a, b FROM t UNION SELECT c, d FROM u ORDER BY 2, 3, 0")
+        );
+        assert_eq!(
+            vec!["ORDER BY position 2 is not in the select list of 1 columns"],
+            violations("SELECT * FROM (SELECT a FROM t ORDER BY 2) AS x ORDER BY 5")
+        );
+    }
+
+    #[test]
+    fn alias_derived_tables_skips_taken_names() {
+        let mut statement = all_dialects().verified_stmt(
+            "SELECT * FROM (SELECT 1), derived_1, (SELECT 2) AS derived_2, (SELECT 3)",
+        );
+        alias_derived_tables(&mut statement);
+        assert_eq!(
+            "SELECT * FROM (SELECT 1) AS derived_3, derived_1, \
+             (SELECT 2) AS derived_2, (SELECT 3) AS derived_4",
+            statement.to_string()
+        );
+        assert!(validate(&statement).is_empty());
+    }
+}
@@ -10,19 +10,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
 #[cfg(feature = "bigdecimal")]
 use bigdecimal::BigDecimal;
-use std::fmt;
+use core::fmt;
+
+use crate::dialect::Dialect;
 
 mod datetime;
 pub use datetime::{
     DateTimeField, ExtractField, Interval, IntervalValue, ParsedDate, ParsedDateTime,
-    ParsedTimestamp,
+    ParsedTimestamp, SignedDuration,
 };
 
 #[derive(Debug)]
 pub struct ValueError(String);
 
+#[cfg(feature = "std")]
 impl std::error::Error for ValueError {}
 
 impl fmt::Display for ValueError {
@@ -32,19 +37,35 @@ impl fmt::Display for ValueError {
 }
 
 /// Primitive SQL values such as number and string
+///
+/// String values hold the string itself, with the quotes and escape
+/// sequences of the literal they were parsed from decoded. Displaying a
+/// value writes a standard SQL literal, in which only quotes are escaped,
+/// except for a [Value::BackslashEscapedString]; use [escape_string_literal]
+/// to write a literal for a dialect with other escaping rules.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Value {
-    /// Numeric literal
+    /// Numeric literal, as written in the SQL text
     #[cfg(not(feature = "bigdecimal"))]
     Number(String),
+    /// Numeric literal, parsed when the `bigdecimal` feature is enabled
     #[cfg(feature = "bigdecimal")]
     Number(BigDecimal),
     /// 'string value'
     SingleQuotedString(String),
+    /// 'string value' containing a backslash, in a dialect like MySQL where
+    /// backslashes start escape sequences. It is displayed with its
+    /// backslashes escaped, so that the dialect reads it back the same.
+    BackslashEscapedString(String),
     /// N'string value'
     NationalStringLiteral(String),
+    /// U&'string value', whose Unicode escapes, like `\0061`, have been
+    /// decoded
+    UnicodeStringLiteral(String),
     /// X'hex value'
     HexStringLiteral(String),
+    /// $$string value$$ or $tag$string value$tag$
+    DollarQuotedString(DollarQuotedString),
     /// Boolean value true or false
     Boolean(bool),
     /// `DATE '...'` literals
@@ -79,8 +100,26 @@ impl fmt::Display for Value {
         match self {
             Value::Number(v) => write!(f, "{}", v),
             Value::SingleQuotedString(v) => write!(f, "'{}'", escape_single_quote_string(v)),
-            Value::NationalStringLiteral(v) => write!(f, "N'{}'", v),
-            Value::HexStringLiteral(v) => write!(f, "X'{}'", v),
+            Value::BackslashEscapedString(v) => write!(
+                f,
+                "'{}'",
+                EscapeStringLiteral {
+                    s: v,
+                    backslash_escapes: true
+                }
+            ),
+            Value::NationalStringLiteral(v) => {
+                write!(f, "N'{}'", escape_single_quote_string(v))
+            }
+            Value::UnicodeStringLiteral(v) => {
+                write!(
+                    f,
+                    "U&'{}'",
+                    escape_single_quote_string(&v.replace('\\', "\\\\"))
+                )
+            }
+            Value::HexStringLiteral(v) => write!(f, "X'{}'", escape_single_quote_string(v)),
+            Value::DollarQuotedString(v) => write!(f, "{}", v),
             Value::Boolean(v) => write!(f, "{}", v),
             Value::Date(v, _) => write!(f, "DATE '{}'", escape_single_quote_string(v)),
             Value::Time(v) => write!(f, "TIME '{}'", escape_single_quote_string(v)),
@@ -151,6 +190,22 @@ impl fmt::Display for Value {
     }
 }
 
+/// A PostgreSQL dollar-quoted string, like `$$it's$$` or `$fn$it's$fn$`,
+/// whose text is taken as written: it has no escape sequences
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DollarQuotedString {
+    pub value: String,
+    /// The tag between the opening dollar signs, if any
+    pub tag: Option<String>,
+}
+
+impl fmt::Display for DollarQuotedString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let tag = self.tag.as_deref().unwrap_or("");
+        write!(f, "${}${}${}$", tag, self.value, tag)
+    }
+}
+
 pub struct EscapeSingleQuoteString<'a>(&'a str);
 
 impl<'a> fmt::Display for EscapeSingleQuoteString<'a> {
@@ -170,6 +225,148 @@ pub fn escape_single_quote_string(s: &str) -> EscapeSingleQuoteString<'_> {
     EscapeSingleQuoteString(s)
 }
 
+/// Escapes a string for a single-quoted literal in a particular dialect, as
+/// returned by [escape_string_literal]
+pub struct EscapeStringLiteral<'a> {
+    s: &'a str,
+    backslash_escapes: bool,
+}
+
+impl<'a> fmt::Display for EscapeStringLiteral<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for c in self.s.chars() {
+            match c {
+                '\'' => write!(f, "''")?,
+                '\\' if self.backslash_escapes => write!(f, "\\\\")?,
+                _ => write!(f, "{}", c)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Escapes `s` so that, between single quotes, it is a literal that
+/// `dialect` reads back as `s`. This is the inverse of
+/// [unescape_string_literal].
+pub fn escape_string_literal<'a>(s: &'a str, dialect: &dyn Dialect) -> EscapeStringLiteral<'a> {
+    EscapeStringLiteral {
+        s,
+        backslash_escapes: dialect.supports_backslash_escapes(),
+    }
+}
+
+/// Decodes the text between the quotes of a string literal, as written in
+/// `dialect`. Doubled quotes always stand for a single quote. In dialects
+/// with backslash escapes, `\0`, `\b`, `\n`, `\r`, `\t` and `\Z` stand
+/// for control characters, `\%` and `\_` are kept as written for use in
+/// `LIKE` patterns, and a backslash before any other character stands for
+/// that character.
+pub fn unescape_string_literal(raw: &str, dialect: &dyn Dialect) -> String {
+    let backslash_escapes = dialect.supports_backslash_escapes();
+    let mut s = String::with_capacity(raw.len());
+    let mut chars = raw.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                s.push('\'');
+                if chars.peek() == Some(&'\'') {
+                    chars.next();
+                }
+            }
+            '\\' if backslash_escapes => match chars.next() {
+                Some('0') => s.push('\0'),
+                Some('b') => s.push('\u{8}'),
+                Some('n') => s.push('\n'),
+                Some('r') => s.push('\r'),
+                Some('t') => s.push('\t'),
+                Some('Z') => s.push('\u{1a}'),
+                Some(c @ '%') | Some(c @ '_') => {
+                    s.push('\\');
+                    s.push(c);
+                }
+                Some(c) => s.push(c),
+                None => s.push('\\'),
+            },
+            _ => s.push(c),
+        }
+    }
+    s
+}
+
+/// Decodes the text between the quotes of a `U&'...'` literal, in which
+/// `escape` followed by four hex digits, or by `+` and six hex digits,
+/// stands for the character with that code point, and a doubled `escape`
+/// stands for itself. The escape character is `\` unless a `UESCAPE`
+/// clause chooses another one.
+pub fn unescape_unicode_string_literal(raw: &str, escape: char) -> Result<String, ValueError> {
+    let mut s = String::with_capacity(raw.len());
+    let mut chars = raw.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\'' {
+            s.push(c);
+            if chars.peek() == Some(&'\'') {
+                chars.next();
+            }
+            continue;
+        } else if c != escape {
+            s.push(c);
+            continue;
+        }
+        let digits = match chars.peek() {
+            Some(&c) if c == escape => {
+                chars.next();
+                s.push(escape);
+                continue;
+            }
+            Some('+') => {
+                chars.next();
+                6
+            }
+            _ => 4,
+        };
+        let hex: String = chars.by_ref().take(digits).collect();
+        let decoded = if hex.len() == digits && hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            u32::from_str_radix(&hex, 16)
+                .ok()
+                .and_then(core::char::from_u32)
+        } else {
+            None
+        };
+        match decoded {
+            Some(decoded) => s.push(decoded),
+            None => {
+                return Err(ValueError(format!(
+                    "Invalid Unicode escape '{}{}'",
+                    escape, hex
+                )))
+            }
+        }
+    }
+    Ok(s)
+}
+
+/// Escapes a value of a COPY payload in the PostgreSQL text format
+pub struct EscapeCopyText<'a>(&'a str);
+
+impl<'a> fmt::Display for EscapeCopyText<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for c in self.0.chars() {
+            match c {
+                '\\' => write!(f, "\\\\")?,
+                '\n' => write!(f, "\\n")?,
+                '\r' => write!(f, "\\r")?,
+                '\t' => write!(f, "\\t")?,
+                _ => write!(f, "{}", c)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn escape_copy_text(s: &str) -> EscapeCopyText<'_> {
+    EscapeCopyText(s)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -186,6 +383,32 @@ mod test {
         }
     }
 
+    #[test]
+    fn string_literal_escapes() {
+        use crate::dialect::{GenericDialect, MySqlDialect};
+
+        assert_eq!("it's", unescape_string_literal("it''s", &GenericDialect {}));
+        assert_eq!(
+            "a\\nb",
+            unescape_string_literal("a\\nb", &GenericDialect {})
+        );
+        assert_eq!(
+            "it's\n\\%",
+            unescape_string_literal("it\\'s\\n\\%", &MySqlDialect {})
+        );
+
+        for s in &["it's", "a\\b", "\\%_", "\t\n"] {
+            for dialect in &[&GenericDialect {} as &dyn Dialect, &MySqlDialect {}] {
+                let escaped = escape_string_literal(s, *dialect).to_string();
+                assert_eq!(*s, unescape_string_literal(&escaped, *dialect));
+            }
+        }
+        assert_eq!(
+            "a\\\\b''",
+            escape_string_literal("a\\b'", &MySqlDialect {}).to_string()
+        );
+    }
+
     #[test]
     fn interval_values() {
         let mut iv = ivalue();
@@ -196,6 +419,34 @@ mod test {
         }
     }
 
+    #[test]
+    fn signed_durations() {
+        use core::time::Duration;
+
+        let a = SignedDuration::new(1, 500_000_000);
+        let b = SignedDuration::from_secs(2);
+        assert_eq!(a - b, SignedDuration::from_nanos(-500_000_000));
+        assert_eq!(a + -b, a - b);
+        assert!(a - b < SignedDuration::default());
+        assert!(-b < a);
+        assert_eq!((b - a).as_secs(), 0);
+        assert_eq!((a - b).subsec_nanos(), -500_000_000);
+        assert_eq!((a - b).unsigned_abs(), Duration::from_millis(500));
+        assert_eq!((a - b).to_unsigned(), None);
+        assert_eq!(SignedDuration::from(Duration::new(1, 500_000_000)), a);
+        assert_eq!(
+            -Interval::Duration(a),
+            Interval::Duration(SignedDuration::new(-2, 500_000_000))
+        );
+        assert_eq!(-Interval::Months(3), Interval::Months(-3));
+        assert!(Interval::Months(-3) < Interval::Months(1));
+        assert!(Interval::Duration(a) > Interval::Duration(a - b));
+        assert_eq!(
+            Interval::Months(1).partial_cmp(&Interval::Duration(a)),
+            None
+        );
+    }
+
     #[test]
     fn iterate_datetimefield() {
         use DateTimeField::*;
@@ -1,5 +1,16 @@
-use std::fmt;
-use std::time::Duration;
+#[cfg(not(feature = "std"))]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::ops::{Add, Neg, Sub};
+use core::time::Duration;
 
 use super::ValueError;
 
@@ -89,9 +100,12 @@ impl IntervalValue {
                 ))),
             },
             durationlike_field => {
-                let mut seconds = 0u64;
-                match self.units_of(&durationlike_field) {
-                    Some(time) => seconds += time * seconds_multiplier(&durationlike_field),
+                let mut seconds = 0i128;
+                match self.units_of(durationlike_field) {
+                    Some(time) => {
+                        seconds +=
+                            i128::from(time) * i128::from(seconds_multiplier(durationlike_field))
+                    }
                     None => {
                         return Err(ValueError(format!(
                             "No {} provided in value string for {}",
@@ -109,17 +123,19 @@ impl IntervalValue {
                     .take_while(|f| f <= min_field)
                 {
                     if let Some(time) = self.units_of(&field) {
-                        seconds += time * seconds_multiplier(&field);
+                        seconds += i128::from(time) * i128::from(seconds_multiplier(&field));
                     }
                 }
-                let duration = match (min_field, self.parsed.nano) {
-                    (DateTimeField::Second, Some(nanos)) => Duration::new(seconds, nanos),
-                    (_, _) => Duration::from_secs(seconds),
-                };
-                Ok(Interval::Duration {
-                    is_positive: self.parsed.is_positive,
-                    duration,
-                })
+                let mut nanos = seconds * NANOS_PER_SEC;
+                if let (DateTimeField::Second, Some(frac)) = (min_field, self.parsed.nano) {
+                    nanos += i128::from(frac);
+                }
+                let duration = SignedDuration::from_nanos(nanos);
+                Ok(Interval::Duration(if self.parsed.is_positive {
+                    duration
+                } else {
+                    -duration
+                }))
             }
         }
     }
@@ -159,7 +175,7 @@ impl IntervalValue {
         let mut extra_leading_fields = vec![];
         let mut extra_trailing_fields = vec![];
         // check for more data in the input string than was requested in <FIELD> TO <FIELD>
-        for field in std::iter::once(DateTimeField::Year).chain(DateTimeField::Year.into_iter()) {
+        for field in core::iter::once(DateTimeField::Year).chain(DateTimeField::Year) {
             if self.units_of(&field).is_none() {
                 continue;
             }
@@ -213,7 +229,7 @@ impl IntervalValue {
 
     fn present_fields(&self) -> String {
         fields_msg(
-            std::iter::once(DateTimeField::Year)
+            core::iter::once(DateTimeField::Year)
                 .chain(DateTimeField::Year.into_iter())
                 .filter(|field| self.units_of(&field).is_some()),
         )
@@ -245,15 +261,138 @@ fn seconds_multiplier(field: &DateTimeField) -> u64 {
 ///
 /// Intervals of unit [`DateTimeField::Day`] or smaller are semantically a
 /// multiple of seconds.
-#[derive(Debug, Clone, Copy, PartialEq)]
+///
+/// As the length of a month depends on the date it is added to, months and
+/// durations neither mix nor compare: intervals of the same kind are ordered
+/// like their amounts, but there is no `Add` or `Sub`, whose result wouldn't
+/// be an `Interval` when the kinds differ. Use the [`SignedDuration`]
+/// arithmetic instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Interval {
     /// A possibly negative number of months for field types like `YEAR`
     Months(i64),
     /// An actual timespan, possibly negative, because why not
-    Duration {
-        is_positive: bool,
-        duration: Duration,
-    },
+    Duration(SignedDuration),
+}
+
+impl PartialOrd for Interval {
+    fn partial_cmp(&self, other: &Interval) -> Option<Ordering> {
+        match (self, other) {
+            (Interval::Months(a), Interval::Months(b)) => a.partial_cmp(b),
+            (Interval::Duration(a), Interval::Duration(b)) => a.partial_cmp(b),
+            _ => None,
+        }
+    }
+}
+
+impl Neg for Interval {
+    type Output = Interval;
+
+    fn neg(self) -> Interval {
+        match self {
+            Interval::Months(months) => Interval::Months(-months),
+            Interval::Duration(duration) => Interval::Duration(-duration),
+        }
+    }
+}
+
+const NANOS_PER_SEC: i128 = 1_000_000_000;
+
+/// A possibly negative span of time with nanosecond precision
+///
+/// Unlike [`Duration`], this can be negated, added, subtracted and compared
+/// without special-casing the sign.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SignedDuration {
+    nanos: i128,
+}
+
+impl SignedDuration {
+    pub fn from_nanos(nanos: i128) -> SignedDuration {
+        SignedDuration { nanos }
+    }
+
+    pub fn from_secs(secs: i64) -> SignedDuration {
+        SignedDuration::from_nanos(i128::from(secs) * NANOS_PER_SEC)
+    }
+
+    /// Construct a duration of `secs` seconds plus `nanos` nanoseconds, e.g.
+    /// `new(-1, 500_000_000)` is half a second in the past
+    pub fn new(secs: i64, nanos: u32) -> SignedDuration {
+        SignedDuration::from_nanos(i128::from(secs) * NANOS_PER_SEC + i128::from(nanos))
+    }
+
+    /// The total number of nanoseconds in this duration
+    pub fn as_nanos(&self) -> i128 {
+        self.nanos
+    }
+
+    /// The number of whole seconds in this duration, rounded toward zero
+    pub fn as_secs(&self) -> i128 {
+        self.nanos / NANOS_PER_SEC
+    }
+
+    /// The fractional part of this duration in nanoseconds, with the same
+    /// sign as the duration
+    pub fn subsec_nanos(&self) -> i32 {
+        (self.nanos % NANOS_PER_SEC) as i32
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.nanos < 0
+    }
+
+    /// The magnitude of this duration, discarding its sign
+    ///
+    /// # Panics
+    ///
+    /// If the magnitude is too large to be represented by a [`Duration`].
+    pub fn unsigned_abs(&self) -> Duration {
+        let nanos = self.nanos.abs();
+        let secs = u64::try_from(nanos / NANOS_PER_SEC).expect("duration overflow");
+        Duration::new(secs, (nanos % NANOS_PER_SEC) as u32)
+    }
+
+    /// Convert to a [`Duration`], or `None` if this duration is negative
+    pub fn to_unsigned(&self) -> Option<Duration> {
+        if self.is_negative() {
+            None
+        } else {
+            Some(self.unsigned_abs())
+        }
+    }
+}
+
+impl From<Duration> for SignedDuration {
+    fn from(duration: Duration) -> SignedDuration {
+        SignedDuration::from_nanos(
+            i128::from(duration.as_secs()) * NANOS_PER_SEC + i128::from(duration.subsec_nanos()),
+        )
+    }
+}
+
+impl Neg for SignedDuration {
+    type Output = SignedDuration;
+
+    fn neg(self) -> SignedDuration {
+        SignedDuration::from_nanos(-self.nanos)
+    }
+}
+
+impl Add for SignedDuration {
+    type Output = SignedDuration;
+
+    fn add(self, other: SignedDuration) -> SignedDuration {
+        SignedDuration::from_nanos(self.nanos + other.nanos)
+    }
+}
+
+impl Sub for SignedDuration {
+    type Output = SignedDuration;
+
+    fn sub(self, other: SignedDuration) -> SignedDuration {
+        SignedDuration::from_nanos(self.nanos - other.nanos)
+    }
 }
 
 /// The fields of a Date
@@ -456,12 +595,12 @@ impl fmt::Display for ExtractField {
     }
 }
 
-use std::str::FromStr;
+use core::str::FromStr;
 
 impl FromStr for ExtractField {
     type Err = ValueError;
     fn from_str(s: &str) -> Result<ExtractField, Self::Err> {
-        Ok(match &*s.to_uppercase() {
+        Ok(match &*s.to_ascii_uppercase() {
             "MILLENIUM" => ExtractField::Millenium,
             "CENTURY" => ExtractField::Century,
             "DECADE" => ExtractField::Decade,
@@ -43,6 +43,8 @@ macro_rules! make_option_ext {
 macro_rules! make_visitor {
     ($name:ident: &$($mut:tt)*) => {
         use crate::ast::*;
+        #[cfg(not(feature = "std"))]
+        use alloc::{boxed::Box, string::String, vec::Vec};
 
         make_option_ext!($($mut)*);
 
@@ -61,6 +63,14 @@ macro_rules! make_visitor {
                 visit_query(self, query)
             }
 
+            fn visit_lock_clause(&mut self, lock_clause: &'ast $($mut)* LockClause) {
+                visit_lock_clause(self, lock_clause)
+            }
+
+            fn visit_with(&mut self, with: &'ast $($mut)* With) {
+                visit_with(self, with)
+            }
+
             fn visit_cte(&mut self, cte: &'ast $($mut)* Cte) {
                 visit_cte(self, cte)
             }
@@ -83,12 +93,30 @@ macro_rules! make_visitor {
 
             fn visit_table_table_factor(
                 &mut self,
+                only: bool,
                 name: &'ast $($mut)* ObjectName,
+                descendants: bool,
+                version: Option<&'ast $($mut)* TableVersion>,
                 alias: Option<&'ast $($mut)* TableAlias>,
                 args: &'ast $($mut)* [Expr],
                 with_hints: &'ast $($mut)* [Expr],
+                with_ordinality: bool,
             ) {
-                visit_table_table_factor(self, name, alias, args, with_hints)
+                visit_table_table_factor(
+                    self,
+                    only,
+                    name,
+                    descendants,
+                    version,
+                    alias,
+                    args,
+                    with_hints,
+                    with_ordinality,
+                )
+            }
+
+            fn visit_table_version(&mut self, version: &'ast $($mut)* TableVersion) {
+                visit_table_version(self, version)
             }
 
             fn visit_derived_table_factor(
@@ -100,6 +128,35 @@ macro_rules! make_visitor {
                 visit_derived_table_factor(self, lateral, subquery, alias)
             }
 
+            fn visit_json_table_factor(
+                &mut self,
+                json_expr: &'ast $($mut)* Expr,
+                json_path: &'ast $($mut)* Value,
+                columns: &'ast $($mut)* [JsonTableColumn],
+                alias: Option<&'ast $($mut)* TableAlias>,
+            ) {
+                visit_json_table_factor(self, json_expr, json_path, columns, alias)
+            }
+
+            fn visit_json_table_column(&mut self, column: &'ast $($mut)* JsonTableColumn) {
+                visit_json_table_column(self, column)
+            }
+
+            fn visit_xml_table_factor(
+                &mut self,
+                namespaces: &'ast $($mut)* [XmlNamespace],
+                row_expr: &'ast $($mut)* Expr,
+                passing: &'ast $($mut)* Expr,
+                columns: &'ast $($mut)* [XmlTableColumn],
+                alias: Option<&'ast $($mut)* TableAlias>,
+            ) {
+                visit_xml_table_factor(self, namespaces, row_expr, passing, columns, alias)
+            }
+
+            fn visit_xml_table_column(&mut self, column: &'ast $($mut)* XmlTableColumn) {
+                visit_xml_table_column(self, column)
+            }
+
             fn visit_nested_join_table_factor(&mut self, table_with_joins: &'ast $($mut)* TableWithJoins) {
                 visit_nested_join_table_factor(self, table_with_joins)
             }
@@ -132,6 +189,22 @@ macro_rules! make_visitor {
                 visit_having(self, expr)
             }
 
+            fn visit_qualify(&mut self, expr: &'ast $($mut)* Expr) {
+                visit_qualify(self, expr)
+            }
+
+            fn visit_cluster_by(&mut self, exprs: &'ast $($mut)* [Expr]) {
+                visit_cluster_by(self, exprs)
+            }
+
+            fn visit_distribute_by(&mut self, exprs: &'ast $($mut)* [Expr]) {
+                visit_distribute_by(self, exprs)
+            }
+
+            fn visit_sort_by(&mut self, sort_by: &'ast $($mut)* [OrderByExpr]) {
+                visit_sort_by(self, sort_by)
+            }
+
             fn visit_set_expr(&mut self, set_expr: &'ast $($mut)* SetExpr) {
                 visit_set_expr(self, set_expr)
             }
@@ -156,6 +229,14 @@ macro_rules! make_visitor {
                 visit_limit(self, expr)
             }
 
+            fn visit_offset(&mut self, expr: &'ast $($mut)* Expr) {
+                visit_offset(self, expr)
+            }
+
+            fn visit_fetch(&mut self, fetch: &'ast $($mut)* Fetch) {
+                visit_fetch(self, fetch)
+            }
+
             fn visit_type(&mut self, _data_type: &'ast $($mut)* DataType) {}
 
             fn visit_expr(&mut self, expr: &'ast $($mut)* Expr) {
@@ -182,12 +263,24 @@ macro_rules! make_visitor {
 
             fn visit_wildcard(&mut self) {}
 
+            fn visit_wildcard_options(&mut self, options: &'ast $($mut)* WildcardOptions) {
+                visit_wildcard_options(self, options)
+            }
+
+            fn visit_wildcard_replacement(&mut self, replacement: &'ast $($mut)* WildcardReplacement) {
+                visit_wildcard_replacement(self, replacement)
+            }
+
+            fn visit_default(&mut self) {}
+
             fn visit_qualified_wildcard(&mut self, idents: &'ast $($mut)* [Ident]) {
                 visit_qualified_wildcard(self, idents)
             }
 
             fn visit_parameter(&mut self, _n: usize) {}
 
+            fn visit_ordinal(&mut self, _n: u64) {}
+
             fn visit_is_null(&mut self, expr: &'ast $($mut)* Expr) {
                 visit_is_null(self, expr)
             }
@@ -214,12 +307,46 @@ macro_rules! make_visitor {
                 visit_between(self, expr, low, high, negated)
             }
 
+            fn visit_like(
+                &mut self,
+                expr: &'ast $($mut)* Expr,
+                pattern: &'ast $($mut)* Expr,
+                escape_char: Option<char>,
+                negated: bool,
+            ) {
+                visit_like(self, expr, pattern, escape_char, negated)
+            }
+
+            fn visit_ilike(
+                &mut self,
+                expr: &'ast $($mut)* Expr,
+                pattern: &'ast $($mut)* Expr,
+                escape_char: Option<char>,
+                negated: bool,
+            ) {
+                visit_ilike(self, expr, pattern, escape_char, negated)
+            }
+
+            fn visit_similar_to(
+                &mut self,
+                expr: &'ast $($mut)* Expr,
+                pattern: &'ast $($mut)* Expr,
+                escape_char: Option<char>,
+                negated: bool,
+            ) {
+                visit_similar_to(self, expr, pattern, escape_char, negated)
+            }
+
             fn visit_binary_op(&mut self, left: &'ast $($mut)* Expr, op: &'ast $($mut)* BinaryOperator, right: &'ast $($mut)* Expr) {
                 visit_binary_op(self, left, op, right)
             }
 
             fn visit_binary_operator(&mut self, _op: &'ast $($mut)* BinaryOperator) {}
 
+            fn visit_conjunction(&mut self, op: &'ast $($mut)* BinaryOperator, operands: &'ast $($mut)* [Expr]) {
+                visit_conjunction(self, op, operands)
+            }
+
             fn visit_unary_op(&mut self, expr: &'ast $($mut)* Expr, op: &'ast $($mut)* UnaryOperator) {
                 visit_unary_op(self, expr, op)
             }
@@ -238,14 +365,62 @@ macro_rules! make_visitor {
                 visit_extract(self, field, expr)
             }
 
+            fn visit_interval(&mut self, value: &'ast $($mut)* Expr, field: &'ast $($mut)* DateTimeField) {
+                visit_interval(self, value, field)
+            }
+
             fn visit_date_time_field(&mut self, _field: &'ast $($mut)* DateTimeField) {}
 
             fn visit_extract_field(&mut self, _field: &'ast $($mut)* ExtractField) {}
 
+            fn visit_trim(
+                &mut self,
+                expr: &'ast $($mut)* Expr,
+                trim_where: Option<&'ast $($mut)* TrimWhereField>,
+                trim_what: Option<&'ast $($mut)* Expr>,
+            ) {
+                visit_trim(self, expr, trim_where, trim_what)
+            }
+
+            fn visit_trim_where_field(&mut self, _field: &'ast $($mut)* TrimWhereField) {}
+
+            fn visit_overlay(
+                &mut self,
+                expr: &'ast $($mut)* Expr,
+                overlay_what: &'ast $($mut)* Expr,
+                overlay_from: &'ast $($mut)* Expr,
+                overlay_for: Option<&'ast $($mut)* Expr>,
+            ) {
+                visit_overlay(self, expr, overlay_what, overlay_from, overlay_for)
+            }
+
+            fn visit_convert(
+                &mut self,
+                expr: &'ast $($mut)* Expr,
+                data_type: Option<&'ast $($mut)* DataType>,
+                charset: Option<&'ast $($mut)* ObjectName>,
+                target_before_value: bool,
+                styles: &'ast $($mut)* [Expr],
+            ) {
+                visit_convert(self, expr, data_type, charset, target_before_value, styles)
+            }
+
             fn visit_nested(&mut self, expr: &'ast $($mut)* Expr) {
                 visit_nested(self, expr)
             }
 
+            fn visit_variant_access(&mut self, expr: &'ast $($mut)* Expr, path: &'ast $($mut)* [VariantPathElement]) {
+                visit_variant_access(self, expr, path)
+            }
+
+            fn visit_variant_path_element(&mut self, element: &'ast $($mut)* VariantPathElement) {
+                visit_variant_path_element(self, element)
+            }
+
+            fn visit_row(&mut self, exprs: &'ast $($mut)* [Expr]) {
+                visit_row(self, exprs)
+            }
+
             fn visit_value(&mut self, _val: &'ast $($mut)* Value) {}
 
             fn visit_function(&mut self, func: &'ast $($mut)* Function) {
@@ -264,24 +439,33 @@ macro_rules! make_visitor {
 
             fn visit_window_frame_bound(&mut self, _window_frame_bound: &'ast $($mut)* WindowFrameBound) {}
 
+            fn visit_window_frame_exclusion(&mut self, _window_frame_exclusion: &'ast $($mut)* WindowFrameExclusion) {}
+
             fn visit_case(
                 &mut self,
                 operand: Option<&'ast $($mut)* Expr>,
-                conditions: &'ast $($mut)* [Expr],
-                results: &'ast $($mut)* [Expr],
+                when_clauses: &'ast $($mut)* [WhenClause],
                 else_result: Option<&'ast $($mut)* Expr>,
             ) {
-                visit_case(self, operand, conditions, results, else_result)
+                visit_case(self, operand, when_clauses, else_result)
+            }
+
+            fn visit_when_clause(&mut self, when_clause: &'ast $($mut)* WhenClause) {
+                visit_when_clause(self, when_clause)
             }
 
-            fn visit_exists(&mut self, subquery: &'ast $($mut)* Query) {
-                visit_exists(self, subquery)
+            fn visit_exists(&mut self, subquery: &'ast $($mut)* Query, negated: bool) {
+                visit_exists(self, subquery, negated)
             }
 
             fn visit_subquery(&mut self, subquery: &'ast $($mut)* Query) {
                 visit_subquery(self, subquery)
             }
 
+            fn visit_array_subquery(&mut self, subquery: &'ast $($mut)* Query) {
+                visit_array_subquery(self, subquery)
+            }
+
             fn visit_any(&mut self, left: &'ast $($mut)* Expr, op: &'ast $($mut)* BinaryOperator, right: &'ast $($mut)* Query) {
                 visit_any(self, left, op, right)
             }
@@ -292,11 +476,24 @@ macro_rules! make_visitor {
 
             fn visit_insert(
                 &mut self,
+                conflict: Option<InsertConflict>,
                 table_name: &'ast $($mut)* ObjectName,
+                alias: Option<&'ast $($mut)* Ident>,
+                with_hints: &'ast $($mut)* [Expr],
                 columns: &'ast $($mut)* [Ident],
-                source: &'ast $($mut)* Query,
+                overriding: Option<InsertOverriding>,
+                output: &'ast $($mut)* [SelectItem],
+                source: &'ast $($mut)* InsertSource,
             ) {
-                visit_insert(self, table_name, columns, source)
+                visit_insert(self, conflict, table_name, alias, with_hints, columns, overriding, output, source)
+            }
+
+            fn visit_insert_conflict(&mut self, _conflict: InsertConflict) {}
+
+            fn visit_insert_overriding(&mut self, _overriding: InsertOverriding) {}
+
+            fn visit_insert_source(&mut self, source: &'ast $($mut)* InsertSource) {
+                visit_insert_source(self, source)
             }
 
             fn visit_values(&mut self, values: &'ast $($mut)* Values) {
@@ -309,34 +506,63 @@ macro_rules! make_visitor {
 
             fn visit_copy(
                 &mut self,
-                table_name: &'ast $($mut)* ObjectName,
-                columns: &'ast $($mut)* [Ident],
-                values: &'ast $($mut)* [Option<String>],
+                relation: &'ast $($mut)* CopyRelation,
+                target: CopyTarget,
+                options: &'ast $($mut)* [CopyOption],
+                values: &'ast $($mut)* [Vec<Option<String>>],
             ) {
-                visit_copy(self, table_name, columns, values)
+                visit_copy(self, relation, target, options, values)
             }
 
-            fn visit_copy_values(&mut self, values: &'ast $($mut)* [Option<String>]) {
+            fn visit_copy_relation(&mut self, relation: &'ast $($mut)* CopyRelation) {
+                visit_copy_relation(self, relation)
+            }
+
+            fn visit_copy_target(&mut self, _target: CopyTarget) {}
+
+            fn visit_copy_option(&mut self, _option: &'ast $($mut)* CopyOption) {}
+
+            fn visit_copy_values(&mut self, values: &'ast $($mut)* [Vec<Option<String>>]) {
                 visit_copy_values(self, values)
             }
 
-            fn visit_copy_values_row(&mut self, _row: Option<&$($mut)* String>) {}
+            fn visit_copy_values_row(&mut self, row: &'ast $($mut)* [Option<String>]) {
+                visit_copy_values_row(self, row)
+            }
+
+            fn visit_copy_value(&mut self, _value: Option<&'ast $($mut)* String>) {}
 
             fn visit_update(
                 &mut self,
                 table_name: &'ast $($mut)* ObjectName,
+                alias: Option<&'ast $($mut)* Ident>,
                 assignments: &'ast $($mut)* [Assignment],
-                selection: Option<&'ast $($mut)* Expr>,
+                output: &'ast $($mut)* [SelectItem],
+                selection: Option<&'ast $($mut)* DmlSelection>,
             ) {
-                visit_update(self, table_name, assignments, selection)
+                visit_update(self, table_name, alias, assignments, output, selection)
             }
 
             fn visit_assignment(&mut self, assignment: &'ast $($mut)* Assignment) {
                 visit_assignment(self, assignment)
             }
 
-            fn visit_delete(&mut self, table_name: &'ast $($mut)* ObjectName, selection: Option<&'ast $($mut)* Expr>) {
-                visit_delete(self, table_name, selection)
+            fn visit_assignment_target(&mut self, target: &'ast $($mut)* AssignmentTarget) {
+                visit_assignment_target(self, target)
+            }
+
+            fn visit_dml_selection(&mut self, selection: &'ast $($mut)* DmlSelection) {
+                visit_dml_selection(self, selection)
+            }
+
+            fn visit_delete(
+                &mut self,
+                table_name: &'ast $($mut)* ObjectName,
+                alias: Option<&'ast $($mut)* Ident>,
+                output: &'ast $($mut)* [SelectItem],
+                selection: Option<&'ast $($mut)* DmlSelection>,
+            ) {
+                visit_delete(self, table_name, alias, output, selection)
             }
 
             fn visit_literal_string(&mut self, _string: &'ast $($mut)* String) {}
@@ -378,12 +604,17 @@ macro_rules! make_visitor {
             fn visit_create_view(
                 &mut self,
                 name: &'ast $($mut)* ObjectName,
-                columns: &'ast $($mut)* [Ident],
+                columns: &'ast $($mut)* [ViewColumnDef],
                 query: &'ast $($mut)* Query,
+                temporary: bool,
                 materialized: bool,
                 with_options: &'ast $($mut)* [SqlOption],
             ) {
-                visit_create_view(self, name, columns, query, materialized, with_options)
+                visit_create_view(self, name, columns, query, temporary, materialized, with_options)
+            }
+
+            fn visit_view_column_def(&mut self, column_def: &'ast $($mut)* ViewColumnDef) {
+                visit_view_column_def(self, column_def)
             }
 
             fn visit_create_index(
@@ -404,6 +635,8 @@ macro_rules! make_visitor {
                 external: bool,
                 file_format: &'ast $($mut)* Option<FileFormat>,
                 location: &'ast $($mut)* Option<String>,
+                engine: Option<&'ast $($mut)* TableEngine>,
+                order_by: &'ast $($mut)* [Expr],
             ) {
                 visit_create_table(
                     self,
@@ -414,9 +647,70 @@ macro_rules! make_visitor {
                     external,
                     file_format,
                     location,
+                    engine,
+                    order_by,
                 )
             }
 
+            fn visit_table_engine(&mut self, engine: &'ast $($mut)* TableEngine) {
+                visit_table_engine(self, engine)
+            }
+
+            fn visit_create_sequence(
+                &mut self,
+                name: &'ast $($mut)* ObjectName,
+                if_not_exists: bool,
+                options: &'ast $($mut)* [SequenceOption],
+            ) {
+                visit_create_sequence(self, name, if_not_exists, options)
+            }
+
+            fn visit_create_extension(
+                &mut self,
+                name: &'ast $($mut)* Ident,
+                if_not_exists: bool,
+                schema: Option<&'ast $($mut)* Ident>,
+                version: Option<&'ast $($mut)* String>,
+                cascade: bool,
+            ) {
+                visit_create_extension(self, name, if_not_exists, schema, version, cascade)
+            }
+
+            fn visit_alter_sequence(
+                &mut self,
+                name: &'ast $($mut)* ObjectName,
+                if_exists: bool,
+                options: &'ast $($mut)* [SequenceOption],
+            ) {
+                visit_alter_sequence(self, name, if_exists, options)
+            }
+
+            fn visit_sequence_option(&mut self, option: &'ast $($mut)* SequenceOption) {
+                visit_sequence_option(self, option)
+            }
+
+            fn visit_create_role(
+                &mut self,
+                name: &'ast $($mut)* Ident,
+                is_user: bool,
+                options: &'ast $($mut)* [RoleOption],
+            ) {
+                visit_create_role(self, name, is_user, options)
+            }
+
+            fn visit_alter_role(
+                &mut self,
+                name: &'ast $($mut)* Ident,
+                is_user: bool,
+                options: &'ast $($mut)* [RoleOption],
+            ) {
+                visit_alter_role(self, name, is_user, options)
+            }
+
+            fn visit_role_option(&mut self, option: &'ast $($mut)* RoleOption) {
+                visit_role_option(self, option)
+            }
+
             fn visit_column_def(&mut self, column_def: &'ast $($mut)* ColumnDef) {
                 visit_column_def(self, column_def)
             }
@@ -437,7 +731,7 @@ macro_rules! make_visitor {
 
             fn visit_drop(
                 &mut self,
-                object_type: ObjectType,
+                object_type: &'ast $($mut)* ObjectType,
                 if_exists: bool,
                 names: &'ast $($mut)* [ObjectName],
                 cascade: bool,
@@ -445,16 +739,43 @@ macro_rules! make_visitor {
                 visit_drop(self, object_type, if_exists, names, cascade)
             }
 
-            fn visit_object_type(&mut self, _object_type: ObjectType) {}
+            fn visit_object_type(&mut self, _object_type: &'ast $($mut)* ObjectType) {}
+
+            fn visit_alter_table(&mut self, only: bool, name: &'ast $($mut)* ObjectName, operation: &'ast $($mut)* AlterTableOperation) {
+                visit_alter_table(self, only, name, operation)
+            }
+
+            fn visit_alter_owner(
+                &mut self,
+                object_type: &'ast $($mut)* ObjectType,
+                if_exists: bool,
+                name: &'ast $($mut)* ObjectName,
+                new_owner: &'ast $($mut)* Ident,
+            ) {
+                visit_alter_owner(self, object_type, if_exists, name, new_owner)
+            }
 
-            fn visit_alter_table(&mut self, name: &'ast $($mut)* ObjectName, operation: &'ast $($mut)* AlterTableOperation) {
-                visit_alter_table(self, name, operation)
+            fn visit_alter_view_options(
+                &mut self,
+                if_exists: bool,
+                name: &'ast $($mut)* ObjectName,
+                operation: &'ast $($mut)* AlterOptionsOperation,
+            ) {
+                visit_alter_view_options(self, if_exists, name, operation)
             }
 
             fn visit_alter_table_operation(&mut self, operation: &'ast $($mut)* AlterTableOperation) {
                 visit_alter_table_operation(self, operation)
             }
 
+            fn visit_alter_column(
+                &mut self,
+                column_name: &'ast $($mut)* Ident,
+                op: &'ast $($mut)* AlterColumnOperation,
+            ) {
+                visit_alter_column(self, column_name, op)
+            }
+
             fn visit_alter_add_constraint(&mut self, table_constraint: &'ast $($mut)* TableConstraint) {
                 visit_alter_add_constraint(self, table_constraint)
             }
@@ -507,7 +828,7 @@ macro_rules! make_visitor {
                 visit_show_variable(self, variable)
             }
 
-            fn visit_show_objects(&mut self, object_type: ObjectType, filter: Option<&'ast $($mut)* ShowStatementFilter>) {
+            fn visit_show_objects(&mut self, object_type: &'ast $($mut)* ObjectType, filter: Option<&'ast $($mut)* ShowStatementFilter>) {
                 visit_show_objects(self, object_type, filter)
             }
 
@@ -515,6 +836,10 @@ macro_rules! make_visitor {
                 visit_show_indexes(self, table_name, filter)
             }
 
+            fn visit_show_constraints(&mut self, table_name: &'ast $($mut)* ObjectName, filter: Option<&'ast $($mut)* ShowStatementFilter>) {
+                visit_show_constraints(self, table_name, filter)
+            }
+
             fn visit_show_columns(
                 &mut self,
                 extended: bool,
@@ -576,8 +901,8 @@ macro_rules! make_visitor {
                 visit_tail(self, name)
             }
 
-            fn visit_explain(&mut self, stage: &'ast $($mut)* Stage, query: &'ast $($mut)* Query) {
-                visit_explain(self, stage, query)
+            fn visit_explain(&mut self, stage: &'ast $($mut)* Stage, statement: &'ast $($mut)* Statement) {
+                visit_explain(self, stage, statement)
             }
             fn visit_flush(&mut self, name: &'ast $($mut)* ObjectName) {
                 visit_flush(self, name)
@@ -585,30 +910,96 @@ macro_rules! make_visitor {
             fn visit_flush_all(&mut self) {
                 visit_flush_all(self)
             }
+
+            fn visit_declare(&mut self, name: &'ast $($mut)* Ident, hold: bool, stmt: &'ast $($mut)* Statement) {
+                visit_declare(self, name, hold, stmt)
+            }
+
+            fn visit_fetch_cursor(&mut self, name: &'ast $($mut)* Ident, direction: Option<&'ast $($mut)* FetchDirection>) {
+                visit_fetch_cursor(self, name, direction)
+            }
+
+            fn visit_fetch_direction(&mut self, _direction: &'ast $($mut)* FetchDirection) {}
+
+            fn visit_close(&mut self, name: &'ast $($mut)* Ident) {
+                visit_close(self, name)
+            }
+
+            fn visit_listen(&mut self, channel: &'ast $($mut)* Ident) {
+                visit_listen(self, channel)
+            }
+
+            fn visit_notify(&mut self, channel: &'ast $($mut)* Ident, payload: Option<&'ast $($mut)* String>) {
+                visit_notify(self, channel, payload)
+            }
+
+            fn visit_unlisten(&mut self, channel: Option<&'ast $($mut)* Ident>) {
+                visit_unlisten(self, channel)
+            }
+
+            fn visit_prepare(&mut self, name: &'ast $($mut)* Ident, data_types: &'ast $($mut)* Vec<DataType>, statement: &'ast $($mut)* Statement) {
+                visit_prepare(self, name, data_types, statement)
+            }
+
+            fn visit_do_block(&mut self, language: Option<&'ast $($mut)* Ident>, body: &'ast $($mut)* Value) {
+                visit_do_block(self, language, body)
+            }
+
+            fn visit_meta_command(&mut self, _command: &'ast $($mut)* String) {}
         }
 
         pub fn visit_statement<'ast, V: $name<'ast> + ?Sized>(visitor: &mut V, statement: &'ast $($mut)* Statement) {
             match statement {
                 Statement::Query(query) => visitor.visit_query(query),
                 Statement::Insert {
+                    conflict,
                     table_name,
+                    alias,
+                    with_hints,
                     columns,
+                    overriding,
+                    output,
                     source,
-                } => visitor.visit_insert(table_name, columns, source),
-                Statement::Copy {
+                } => visitor.visit_insert(
+                    *conflict,
                     table_name,
+                    alias.as_auto_ref(),
+                    with_hints,
                     columns,
+                    *overriding,
+                    output,
+                    source,
+                ),
+                Statement::Copy {
+                    relation,
+                    target,
+                    options,
                     values,
-                } => visitor.visit_copy(table_name, columns, values),
+                } => visitor.visit_copy(relation, *target, options, values),
                 Statement::Update {
                     table_name,
+                    alias,
                     assignments,
+                    output,
                     selection,
-                } => visitor.visit_update(table_name, assignments, selection.as_auto_ref()),
+                } => visitor.visit_update(
+                    table_name,
+                    alias.as_auto_ref(),
+                    assignments,
+                    output,
+                    selection.as_auto_ref(),
+                ),
                 Statement::Delete {
                     table_name,
+                    alias,
+                    output,
                     selection,
-                } => visitor.visit_delete(table_name, selection.as_auto_ref()),
+                } => visitor.visit_delete(
+                    table_name,
+                    alias.as_auto_ref(),
+                    output,
+                    selection.as_auto_ref(),
+                ),
                 Statement::CreateSource {
                     name,
                     url,
@@ -631,9 +1022,17 @@ macro_rules! make_visitor {
                     name,
                     columns,
                     query,
+                    temporary,
                     materialized,
                     with_options,
-                } => visitor.visit_create_view(name, columns, query, *materialized, with_options),
+                } => visitor.visit_create_view(
+                    name,
+                    columns,
+                    query,
+                    *temporary,
+                    *materialized,
+                    with_options,
+                ),
                 Statement::CreateIndex {
                     name,
                     on_name,
@@ -644,7 +1043,7 @@ macro_rules! make_visitor {
                     if_exists,
                     names,
                     cascade,
-                } => visitor.visit_drop(*object_type, *if_exists, names, *cascade),
+                } => visitor.visit_drop(object_type, *if_exists, names, *cascade),
                 Statement::CreateTable {
                     name,
                     columns,
@@ -653,6 +1052,9 @@ macro_rules! make_visitor {
                     with_options,
                     file_format,
                     location,
+                    without_rowid: _,
+                    engine,
+                    order_by,
                 } => visitor.visit_create_table(
                     name,
                     columns,
@@ -661,8 +1063,58 @@ macro_rules! make_visitor {
                     *external,
                     file_format,
                     location,
+                    engine.as_auto_ref(),
+                    order_by,
+                ),
+                Statement::AlterTable {
+                    only,
+                    name,
+                    operation,
+                } => visitor.visit_alter_table(*only, name, operation),
+                Statement::AlterOwner {
+                    object_type,
+                    if_exists,
+                    name,
+                    new_owner,
+                } => visitor.visit_alter_owner(object_type, *if_exists, name, new_owner),
+                Statement::AlterViewOptions {
+                    if_exists,
+                    name,
+                    operation,
+                } => visitor.visit_alter_view_options(*if_exists, name, operation),
+                Statement::CreateSequence {
+                    name,
+                    if_not_exists,
+                    options,
+                } => visitor.visit_create_sequence(name, *if_not_exists, options),
+                Statement::CreateExtension {
+                    name,
+                    if_not_exists,
+                    schema,
+                    version,
+                    cascade,
+                } => visitor.visit_create_extension(
+                    name,
+                    *if_not_exists,
+                    schema.as_auto_ref(),
+                    version.as_auto_ref(),
+                    *cascade,
                 ),
-                Statement::AlterTable { name, operation } => visitor.visit_alter_table(name, operation),
+                Statement::AlterSequence {
+                    name,
+                    if_exists,
+                    options,
+                } => visitor.visit_alter_sequence(name, *if_exists, options),
+                Statement::CreateRole {
+                    name,
+                    is_user,
+                    options,
+                } => visitor.visit_create_role(name, *is_user, options),
+                Statement::AlterRole {
+                    name,
+                    is_user,
+                    options,
+                } => visitor.visit_alter_role(name, *is_user, options),
                 Statement::SetVariable {
                     local,
                     variable,
@@ -670,11 +1122,14 @@ macro_rules! make_visitor {
                 } => visitor.visit_set_variable(*local, variable, value),
                 Statement::ShowVariable { variable } => visitor.visit_show_variable(variable),
                 Statement::ShowObjects { object_type, filter } => {
-                    visitor.visit_show_objects(*object_type, filter.as_auto_ref())
+                    visitor.visit_show_objects(object_type, filter.as_auto_ref())
                 }
                 Statement::ShowIndexes { table_name, filter } => {
                     visitor.visit_show_indexes(table_name, filter.as_auto_ref())
                 }
+                Statement::ShowConstraints { table_name, filter } => {
+                    visitor.visit_show_constraints(table_name, filter.as_auto_ref())
+                }
                 Statement::ShowColumns {
                     extended,
                     full,
@@ -693,15 +1148,34 @@ macro_rules! make_visitor {
                 Statement::Tail { name } => {
                     visitor.visit_tail(name);
                 }
-                Statement::Explain { stage, query } => visitor.visit_explain(stage, query),
+                Statement::Explain { stage, statement } => visitor.visit_explain(stage, statement),
                 Statement::FlushSource { name } => visitor.visit_flush(name),
                 Statement::FlushAllSources => visitor.visit_flush_all(),
+                Statement::Declare { name, hold, stmt } => visitor.visit_declare(name, *hold, stmt),
+                Statement::Fetch { name, direction } => {
+                    visitor.visit_fetch_cursor(name, direction.as_auto_ref())
+                }
+                Statement::Close { name } => visitor.visit_close(name),
+                Statement::Listen { channel } => visitor.visit_listen(channel),
+                Statement::Notify { channel, payload } => {
+                    visitor.visit_notify(channel, payload.as_auto_ref())
+                }
+                Statement::Unlisten { channel } => visitor.visit_unlisten(channel.as_auto_ref()),
+                Statement::Prepare {
+                    name,
+                    data_types,
+                    statement,
+                } => visitor.visit_prepare(name, data_types, statement),
+                Statement::DoBlock { language, body } => {
+                    visitor.visit_do_block(language.as_auto_ref(), body)
+                }
+                Statement::MetaCommand(command) => visitor.visit_meta_command(command),
             }
         }
 
         pub fn visit_query<'ast, V: $name<'ast> + ?Sized>(visitor: &mut V, query: &'ast $($mut)* Query) {
-            for cte in &$($mut)* query.ctes {
-                visitor.visit_cte(cte);
+            if let Some(with) = &$($mut)* query.with {
+                visitor.visit_with(with);
             }
             visitor.visit_set_expr(&$($mut)* query.body);
             for order_by in &$($mut)* query.order_by {
@@ -710,6 +1184,33 @@ macro_rules! make_visitor {
             if let Some(limit) = &$($mut)* query.limit {
                 visitor.visit_limit(limit);
             }
+            if let Some(offset) = &$($mut)* query.offset {
+                visitor.visit_offset(offset);
+            }
+            if let Some(fetch) = &$($mut)* query.fetch {
+                visitor.visit_fetch(fetch);
+            }
+            for lock_clause in &$($mut)* query.locks {
+                visitor.visit_lock_clause(lock_clause);
+            }
+            if let Some(format) = &$($mut)* query.format {
+                visitor.visit_ident(format);
+            }
+        }
+
+        pub fn visit_lock_clause<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            lock_clause: &'ast $($mut)* LockClause,
+        ) {
+            for object_name in &$($mut)* lock_clause.of {
+                visitor.visit_object_name(object_name);
+            }
+        }
+
+        pub fn visit_with<'ast, V: $name<'ast> + ?Sized>(visitor: &mut V, with: &'ast $($mut)* With) {
+            for cte in &$($mut)* with.ctes {
+                visitor.visit_cte(cte);
+            }
         }
 
         pub fn visit_cte<'ast, V: $name<'ast> + ?Sized>(visitor: &mut V, cte: &'ast $($mut)* Cte) {
@@ -733,6 +1234,18 @@ macro_rules! make_visitor {
             if let Some(having) = &$($mut)* select.having {
                 visitor.visit_having(having);
             }
+            if let Some(qualify) = &$($mut)* select.qualify {
+                visitor.visit_qualify(qualify);
+            }
+            if !select.cluster_by.is_empty() {
+                visitor.visit_cluster_by(&$($mut)* select.cluster_by);
+            }
+            if !select.distribute_by.is_empty() {
+                visitor.visit_distribute_by(&$($mut)* select.distribute_by);
+            }
+            if !select.sort_by.is_empty() {
+                visitor.visit_sort_by(&$($mut)* select.sort_by);
+            }
         }
 
         pub fn visit_select_item<'ast, V: $name<'ast> + ?Sized>(
@@ -742,13 +1255,37 @@ macro_rules! make_visitor {
             match select_item {
                 SelectItem::UnnamedExpr(expr) => visitor.visit_unnamed_expr(expr),
                 SelectItem::ExprWithAlias { expr, alias } => visitor.visit_expr_with_alias(expr, alias),
-                SelectItem::QualifiedWildcard(object_name) => {
-                    visitor.visit_qualified_wildcard(&$($mut)* object_name.0)
+                SelectItem::QualifiedWildcard(object_name, options) => {
+                    visitor.visit_qualified_wildcard(&$($mut)* object_name.0);
+                    visitor.visit_wildcard_options(options);
+                }
+                SelectItem::Wildcard(options) => {
+                    visitor.visit_wildcard();
+                    visitor.visit_wildcard_options(options);
                 }
-                SelectItem::Wildcard => visitor.visit_wildcard(),
             }
         }
 
+        pub fn visit_wildcard_options<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            options: &'ast $($mut)* WildcardOptions,
+        ) {
+            for ident in &$($mut)* options.except {
+                visitor.visit_ident(ident);
+            }
+            for replacement in &$($mut)* options.replace {
+                visitor.visit_wildcard_replacement(replacement);
+            }
+        }
+
+        pub fn visit_wildcard_replacement<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            replacement: &'ast $($mut)* WildcardReplacement,
+        ) {
+            visitor.visit_expr(&$($mut)* replacement.expr);
+            visitor.visit_ident(&$($mut)* replacement.column_name);
+        }
+
         pub fn visit_table_with_joins<'ast, V: $name<'ast> + ?Sized>(
             visitor: &mut V,
             table_with_joins: &'ast $($mut)* TableWithJoins,
@@ -765,16 +1302,42 @@ macro_rules! make_visitor {
         ) {
             match table_factor {
                 TableFactor::Table {
+                    only,
                     name,
+                    descendants,
+                    version,
                     alias,
                     args,
                     with_hints,
-                } => visitor.visit_table_table_factor(name, alias.as_auto_ref(), args, with_hints),
-                TableFactor::Derived {
+                    with_ordinality,
+                } => visitor.visit_table_table_factor(
+                    *only,
+                    name,
+                    *descendants,
+                    version.as_auto_ref(),
+                    alias.as_auto_ref(),
+                    args,
+                    with_hints,
+                    *with_ordinality,
+                ),
+                TableFactor::Derived {
                     lateral,
                     subquery,
                     alias,
                 } => visitor.visit_derived_table_factor(*lateral, subquery, alias.as_auto_ref()),
+                TableFactor::JsonTable {
+                    json_expr,
+                    json_path,
+                    columns,
+                    alias,
+                } => visitor.visit_json_table_factor(json_expr, json_path, columns, alias.as_auto_ref()),
+                TableFactor::XmlTable {
+                    namespaces,
+                    row_expr,
+                    passing,
+                    columns,
+                    alias,
+                } => visitor.visit_xml_table_factor(namespaces, row_expr, passing, columns, alias.as_auto_ref()),
                 TableFactor::NestedJoin(table_with_joins) => {
                     visitor.visit_nested_join_table_factor(table_with_joins)
                 }
@@ -783,15 +1346,22 @@ macro_rules! make_visitor {
 
         pub fn visit_table_table_factor<'ast, V: $name<'ast> + ?Sized>(
             visitor: &mut V,
+            _only: bool,
             name: &'ast $($mut)* ObjectName,
+            _descendants: bool,
+            version: Option<&'ast $($mut)* TableVersion>,
             alias: Option<&'ast $($mut)* TableAlias>,
             args: &'ast $($mut)* [Expr],
             with_hints: &'ast $($mut)* [Expr],
+            _with_ordinality: bool,
         ) {
             visitor.visit_object_name(name);
             for expr in args {
                 visitor.visit_expr(expr);
             }
+            if let Some(version) = version {
+                visitor.visit_table_version(version);
+            }
             if let Some(alias) = alias {
                 visitor.visit_table_alias(alias);
             }
@@ -800,6 +1370,19 @@ macro_rules! make_visitor {
             }
         }
 
+        pub fn visit_table_version<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            version: &'ast $($mut)* TableVersion,
+        ) {
+            match version {
+                TableVersion::AsOf(point) => visitor.visit_expr(point),
+                TableVersion::FromTo(start, end) | TableVersion::Between(start, end) => {
+                    visitor.visit_expr(start);
+                    visitor.visit_expr(end);
+                }
+            }
+        }
+
         pub fn visit_derived_table_factor<'ast, V: $name<'ast> + ?Sized>(
             visitor: &mut V,
             _lateral: bool,
@@ -812,6 +1395,91 @@ macro_rules! make_visitor {
             }
         }
 
+        pub fn visit_json_table_factor<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            json_expr: &'ast $($mut)* Expr,
+            json_path: &'ast $($mut)* Value,
+            columns: &'ast $($mut)* [JsonTableColumn],
+            alias: Option<&'ast $($mut)* TableAlias>,
+        ) {
+            visitor.visit_expr(json_expr);
+            visitor.visit_value(json_path);
+            for column in columns {
+                visitor.visit_json_table_column(column);
+            }
+            if let Some(alias) = alias {
+                visitor.visit_table_alias(alias);
+            }
+        }
+
+        pub fn visit_json_table_column<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            column: &'ast $($mut)* JsonTableColumn,
+        ) {
+            match column {
+                JsonTableColumn::Named(column) => {
+                    visitor.visit_ident(&$($mut)* column.name);
+                    visitor.visit_type(&$($mut)* column.data_type);
+                    visitor.visit_value(&$($mut)* column.path);
+                    for handling in [&$($mut)* column.on_empty, &$($mut)* column.on_error] {
+                        if let Some(JsonTableColumnErrorHandling::Default(value)) = handling {
+                            visitor.visit_value(value);
+                        }
+                    }
+                }
+                JsonTableColumn::ForOrdinality(name) => visitor.visit_ident(name),
+                JsonTableColumn::Nested { path, columns } => {
+                    visitor.visit_value(path);
+                    for column in columns {
+                        visitor.visit_json_table_column(column);
+                    }
+                }
+            }
+        }
+
+        pub fn visit_xml_table_factor<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            namespaces: &'ast $($mut)* [XmlNamespace],
+            row_expr: &'ast $($mut)* Expr,
+            passing: &'ast $($mut)* Expr,
+            columns: &'ast $($mut)* [XmlTableColumn],
+            alias: Option<&'ast $($mut)* TableAlias>,
+        ) {
+            for namespace in namespaces {
+                visitor.visit_expr(&$($mut)* namespace.uri);
+                if let Some(name) = &$($mut)* namespace.name {
+                    visitor.visit_ident(name);
+                }
+            }
+            visitor.visit_expr(row_expr);
+            visitor.visit_expr(passing);
+            for column in columns {
+                visitor.visit_xml_table_column(column);
+            }
+            if let Some(alias) = alias {
+                visitor.visit_table_alias(alias);
+            }
+        }
+
+        pub fn visit_xml_table_column<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            column: &'ast $($mut)* XmlTableColumn,
+        ) {
+            match column {
+                XmlTableColumn::Named(column) => {
+                    visitor.visit_ident(&$($mut)* column.name);
+                    visitor.visit_type(&$($mut)* column.data_type);
+                    if let Some(path) = &$($mut)* column.path {
+                        visitor.visit_expr(path);
+                    }
+                    if let Some(default) = &$($mut)* column.default {
+                        visitor.visit_expr(default);
+                    }
+                }
+                XmlTableColumn::ForOrdinality(name) => visitor.visit_ident(name),
+            }
+        }
+
         pub fn visit_nested_join_table_factor<'ast, V: $name<'ast> + ?Sized>(
             visitor: &mut V,
             table_with_joins: &'ast $($mut)* TableWithJoins,
@@ -822,7 +1490,10 @@ macro_rules! make_visitor {
         pub fn visit_table_alias<'ast, V: $name<'ast> + ?Sized>(visitor: &mut V, alias: &'ast $($mut)* TableAlias) {
             visitor.visit_ident(&$($mut)* alias.name);
             for column in &$($mut)* alias.columns {
-                visitor.visit_ident(column);
+                visitor.visit_ident(&$($mut)* column.name);
+                if let Some(data_type) = &$($mut)* column.data_type {
+                    visitor.visit_type(data_type);
+                }
             }
         }
 
@@ -870,6 +1541,28 @@ macro_rules! make_visitor {
             visitor.visit_expr(expr);
         }
 
+        pub fn visit_qualify<'ast, V: $name<'ast> + ?Sized>(visitor: &mut V, expr: &'ast $($mut)* Expr) {
+            visitor.visit_expr(expr);
+        }
+
+        pub fn visit_cluster_by<'ast, V: $name<'ast> + ?Sized>(visitor: &mut V, exprs: &'ast $($mut)* [Expr]) {
+            for expr in exprs {
+                visitor.visit_expr(expr);
+            }
+        }
+
+        pub fn visit_distribute_by<'ast, V: $name<'ast> + ?Sized>(visitor: &mut V, exprs: &'ast $($mut)* [Expr]) {
+            for expr in exprs {
+                visitor.visit_expr(expr);
+            }
+        }
+
+        pub fn visit_sort_by<'ast, V: $name<'ast> + ?Sized>(visitor: &mut V, sort_by: &'ast $($mut)* [OrderByExpr]) {
+            for order_by in sort_by {
+                visitor.visit_order_by(order_by);
+            }
+        }
+
         pub fn visit_set_expr<'ast, V: $name<'ast> + ?Sized>(visitor: &mut V, set_expr: &'ast $($mut)* SetExpr) {
             match set_expr {
                 SetExpr::Select(select) => visitor.visit_select(select),
@@ -880,6 +1573,7 @@ macro_rules! make_visitor {
                     op,
                     right,
                     all,
+                    ..
                 } => visitor.visit_set_operation(left, op, right, *all),
             }
         }
@@ -904,6 +1598,16 @@ macro_rules! make_visitor {
             visitor.visit_expr(expr)
         }
 
+        pub fn visit_offset<'ast, V: $name<'ast> + ?Sized>(visitor: &mut V, expr: &'ast $($mut)* Expr) {
+            visitor.visit_expr(expr)
+        }
+
+        pub fn visit_fetch<'ast, V: $name<'ast> + ?Sized>(visitor: &mut V, fetch: &'ast $($mut)* Fetch) {
+            if let Some(quantity) = &$($mut)* fetch.quantity {
+                visitor.visit_expr(quantity);
+            }
+        }
+
         pub fn visit_expr<'ast, V: $name<'ast> + ?Sized>(visitor: &mut V, expr: &'ast $($mut)* Expr) {
             match expr {
                 Expr::Identifier(ident) => visitor.visit_ident(ident),
@@ -911,6 +1615,7 @@ macro_rules! make_visitor {
                 Expr::QualifiedWildcard(idents) => visitor.visit_qualified_wildcard(idents),
                 Expr::CompoundIdentifier(idents) => visitor.visit_compound_identifier(idents),
                 Expr::Parameter(n) => visitor.visit_parameter(*n),
+                Expr::Ordinal(n) => visitor.visit_ordinal(*n),
                 Expr::IsNull(expr) => visitor.visit_is_null(expr),
                 Expr::IsNotNull(expr) => visitor.visit_is_not_null(expr),
                 Expr::InList {
@@ -929,27 +1634,83 @@ macro_rules! make_visitor {
                     low,
                     high,
                 } => visitor.visit_between(expr, low, high, *negated),
+                Expr::Like {
+                    expr,
+                    negated,
+                    pattern,
+                    escape_char,
+                } => visitor.visit_like(expr, pattern, *escape_char, *negated),
+                Expr::ILike {
+                    expr,
+                    negated,
+                    pattern,
+                    escape_char,
+                } => visitor.visit_ilike(expr, pattern, *escape_char, *negated),
+                Expr::SimilarTo {
+                    expr,
+                    negated,
+                    pattern,
+                    escape_char,
+                } => visitor.visit_similar_to(expr, pattern, *escape_char, *negated),
                 Expr::BinaryOp { left, op, right } => visitor.visit_binary_op(left, op, right),
+                Expr::Conjunction { op, operands } => visitor.visit_conjunction(op, operands),
                 Expr::UnaryOp { expr, op } => visitor.visit_unary_op(expr, op),
                 Expr::Cast { expr, data_type } => visitor.visit_cast(expr, data_type),
                 Expr::Collate { expr, collation } => visitor.visit_collate(expr, collation),
                 Expr::Extract { field, expr } => visitor.visit_extract(field, expr),
+                Expr::Interval { value, field } => visitor.visit_interval(value, field),
+                Expr::Trim {
+                    expr,
+                    trim_where,
+                    trim_what,
+                } => visitor.visit_trim(
+                    expr,
+                    trim_where.as_auto_ref(),
+                    trim_what.as_auto_ref().map(|w| w.as_auto_ref()),
+                ),
+                Expr::Overlay {
+                    expr,
+                    overlay_what,
+                    overlay_from,
+                    overlay_for,
+                } => visitor.visit_overlay(
+                    expr,
+                    overlay_what,
+                    overlay_from,
+                    overlay_for.as_auto_ref().map(|f| f.as_auto_ref()),
+                ),
+                Expr::Convert {
+                    expr,
+                    data_type,
+                    charset,
+                    target_before_value,
+                    styles,
+                } => visitor.visit_convert(
+                    expr,
+                    data_type.as_auto_ref(),
+                    charset.as_auto_ref(),
+                    *target_before_value,
+                    styles,
+                ),
+                Expr::Default => visitor.visit_default(),
+                Expr::VariantAccess { expr, path } => visitor.visit_variant_access(expr, path),
                 Expr::Nested(expr) => visitor.visit_nested(expr),
+                Expr::Row(exprs) => visitor.visit_row(exprs),
                 Expr::Value(val) => visitor.visit_value(val),
                 Expr::Function(func) => visitor.visit_function(func),
                 Expr::Case {
                     operand,
-                    conditions,
-                    results,
+                    when_clauses,
                     else_result,
+                    else_span: _,
                 } => visitor.visit_case(
                     operand.as_auto_ref().map(|o| o.as_auto_ref()),
-                    conditions,
-                    results,
+                    when_clauses,
                     else_result.as_auto_ref().map(|r| r.as_auto_ref()),
                 ),
-                Expr::Exists(query) => visitor.visit_exists(query),
+                Expr::Exists { subquery, negated } => visitor.visit_exists(subquery, *negated),
                 Expr::Subquery(query) => visitor.visit_subquery(query),
+                Expr::ArraySubquery(query) => visitor.visit_array_subquery(query),
                 Expr::Any{left, op, right, some: _} => visitor.visit_any(left, op, right),
                 Expr::All{left, op, right} => visitor.visit_all(left, op, right),
             }
@@ -1002,6 +1763,13 @@ macro_rules! make_visitor {
             visitor.visit_parameter(n)
         }
 
+        pub fn visit_ordinal<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            n: u64,
+        ) {
+            visitor.visit_ordinal(n)
+        }
+
         pub fn visit_is_null<'ast, V: $name<'ast> + ?Sized>(visitor: &mut V, expr: &'ast $($mut)* Expr) {
             visitor.visit_expr(expr);
         }
@@ -1044,6 +1812,39 @@ macro_rules! make_visitor {
             visitor.visit_expr(high);
         }
 
+        pub fn visit_like<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            expr: &'ast $($mut)* Expr,
+            pattern: &'ast $($mut)* Expr,
+            _escape_char: Option<char>,
+            _negated: bool,
+        ) {
+            visitor.visit_expr(expr);
+            visitor.visit_expr(pattern);
+        }
+
+        pub fn visit_ilike<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            expr: &'ast $($mut)* Expr,
+            pattern: &'ast $($mut)* Expr,
+            _escape_char: Option<char>,
+            _negated: bool,
+        ) {
+            visitor.visit_expr(expr);
+            visitor.visit_expr(pattern);
+        }
+
+        pub fn visit_similar_to<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            expr: &'ast $($mut)* Expr,
+            pattern: &'ast $($mut)* Expr,
+            _escape_char: Option<char>,
+            _negated: bool,
+        ) {
+            visitor.visit_expr(expr);
+            visitor.visit_expr(pattern);
+        }
+
         pub fn visit_binary_op<'ast, V: $name<'ast> + ?Sized>(
             visitor: &mut V,
             left: &'ast $($mut)* Expr,
@@ -1055,6 +1856,17 @@ macro_rules! make_visitor {
             visitor.visit_expr(right);
         }
 
+        pub fn visit_conjunction<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            op: &'ast $($mut)* BinaryOperator,
+            operands: &'ast $($mut)* [Expr],
+        ) {
+            visitor.visit_binary_operator(op);
+            for operand in operands {
+                visitor.visit_expr(operand);
+            }
+        }
+
         pub fn visit_unary_op<'ast, V: $name<'ast> + ?Sized>(
             visitor: &mut V,
             expr: &'ast $($mut)* Expr,
@@ -1091,10 +1903,103 @@ macro_rules! make_visitor {
             visitor.visit_expr(expr);
         }
 
+        pub fn visit_interval<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            value: &'ast $($mut)* Expr,
+            field: &'ast $($mut)* DateTimeField,
+        ) {
+            visitor.visit_expr(value);
+            visitor.visit_date_time_field(field);
+        }
+
+        pub fn visit_trim<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            expr: &'ast $($mut)* Expr,
+            trim_where: Option<&'ast $($mut)* TrimWhereField>,
+            trim_what: Option<&'ast $($mut)* Expr>,
+        ) {
+            if let Some(trim_where) = trim_where {
+                visitor.visit_trim_where_field(trim_where);
+            }
+            if let Some(trim_what) = trim_what {
+                visitor.visit_expr(trim_what);
+            }
+            visitor.visit_expr(expr);
+        }
+
+        pub fn visit_overlay<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            expr: &'ast $($mut)* Expr,
+            overlay_what: &'ast $($mut)* Expr,
+            overlay_from: &'ast $($mut)* Expr,
+            overlay_for: Option<&'ast $($mut)* Expr>,
+        ) {
+            visitor.visit_expr(expr);
+            visitor.visit_expr(overlay_what);
+            visitor.visit_expr(overlay_from);
+            if let Some(overlay_for) = overlay_for {
+                visitor.visit_expr(overlay_for);
+            }
+        }
+
+        pub fn visit_convert<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            expr: &'ast $($mut)* Expr,
+            data_type: Option<&'ast $($mut)* DataType>,
+            charset: Option<&'ast $($mut)* ObjectName>,
+            target_before_value: bool,
+            styles: &'ast $($mut)* [Expr],
+        ) {
+            match data_type {
+                Some(data_type) if target_before_value => {
+                    visitor.visit_type(data_type);
+                    visitor.visit_expr(expr);
+                }
+                Some(data_type) => {
+                    visitor.visit_expr(expr);
+                    visitor.visit_type(data_type);
+                }
+                None => visitor.visit_expr(expr),
+            }
+            if let Some(charset) = charset {
+                visitor.visit_object_name(charset);
+            }
+            for style in styles {
+                visitor.visit_expr(style);
+            }
+        }
+
         pub fn visit_nested<'ast, V: $name<'ast> + ?Sized>(visitor: &mut V, expr: &'ast $($mut)* Expr) {
             visitor.visit_expr(expr);
         }
 
+        pub fn visit_variant_access<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            expr: &'ast $($mut)* Expr,
+            path: &'ast $($mut)* [VariantPathElement],
+        ) {
+            visitor.visit_expr(expr);
+            for element in path {
+                visitor.visit_variant_path_element(element);
+            }
+        }
+
+        pub fn visit_variant_path_element<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            element: &'ast $($mut)* VariantPathElement,
+        ) {
+            match element {
+                VariantPathElement::Field(field) => visitor.visit_ident(field),
+                VariantPathElement::Index(index) => visitor.visit_expr(index),
+            }
+        }
+
+        pub fn visit_row<'ast, V: $name<'ast> + ?Sized>(visitor: &mut V, exprs: &'ast $($mut)* [Expr]) {
+            for expr in exprs {
+                visitor.visit_expr(expr);
+            }
+        }
+
         pub fn visit_function<'ast, V: $name<'ast> + ?Sized>(visitor: &mut V, func: &'ast $($mut)* Function) {
             visitor.visit_object_name(&$($mut)* func.name);
             for arg in &$($mut)* func.args {
@@ -1129,30 +2034,41 @@ macro_rules! make_visitor {
             if let Some(end_bound) = &$($mut)* window_frame.end_bound {
                 visitor.visit_window_frame_bound(end_bound);
             }
+            if let Some(exclude) = &$($mut)* window_frame.exclude {
+                visitor.visit_window_frame_exclusion(exclude);
+            }
         }
 
         pub fn visit_case<'ast, V: $name<'ast> + ?Sized>(
             visitor: &mut V,
             operand: Option<&'ast $($mut)* Expr>,
-            conditions: &'ast $($mut)* [Expr],
-            results: &'ast $($mut)* [Expr],
+            when_clauses: &'ast $($mut)* [WhenClause],
             else_result: Option<&'ast $($mut)* Expr>,
         ) {
             if let Some(operand) = operand {
                 visitor.visit_expr(operand);
             }
-            for cond in conditions {
-                visitor.visit_expr(cond);
-            }
-            for res in results {
-                visitor.visit_expr(res);
+            for when_clause in when_clauses {
+                visitor.visit_when_clause(when_clause);
             }
             if let Some(else_result) = else_result {
                 visitor.visit_expr(else_result);
             }
         }
 
-        pub fn visit_exists<'ast, V: $name<'ast> + ?Sized>(visitor: &mut V, subquery: &'ast $($mut)* Query) {
+        pub fn visit_when_clause<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            when_clause: &'ast $($mut)* WhenClause,
+        ) {
+            visitor.visit_expr(&$($mut)* when_clause.condition);
+            visitor.visit_expr(&$($mut)* when_clause.result);
+        }
+
+        pub fn visit_exists<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            subquery: &'ast $($mut)* Query,
+            _negated: bool,
+        ) {
             visitor.visit_query(subquery)
         }
 
@@ -1160,6 +2076,10 @@ macro_rules! make_visitor {
             visitor.visit_query(subquery)
         }
 
+        pub fn visit_array_subquery<'ast, V: $name<'ast> + ?Sized>(visitor: &mut V, subquery: &'ast $($mut)* Query) {
+            visitor.visit_query(subquery)
+        }
+
         pub fn visit_any<'ast, V: $name<'ast> + ?Sized>(visitor: &mut V, left: &'ast $($mut)* Expr, op: &'ast $($mut)* BinaryOperator, right: &'ast $($mut)* Query) {
             visitor.visit_expr(left);
             visitor.visit_binary_operator(op);
@@ -1174,15 +2094,45 @@ macro_rules! make_visitor {
 
         pub fn visit_insert<'ast, V: $name<'ast> + ?Sized>(
             visitor: &mut V,
+            conflict: Option<InsertConflict>,
             table_name: &'ast $($mut)* ObjectName,
+            alias: Option<&'ast $($mut)* Ident>,
+            with_hints: &'ast $($mut)* [Expr],
             columns: &'ast $($mut)* [Ident],
-            source: &'ast $($mut)* Query,
+            overriding: Option<InsertOverriding>,
+            output: &'ast $($mut)* [SelectItem],
+            source: &'ast $($mut)* InsertSource,
         ) {
+            if let Some(conflict) = conflict {
+                visitor.visit_insert_conflict(conflict);
+            }
             visitor.visit_object_name(table_name);
+            if let Some(alias) = alias {
+                visitor.visit_ident(alias);
+            }
+            for expr in with_hints {
+                visitor.visit_expr(expr);
+            }
             for column in columns {
                 visitor.visit_ident(column);
             }
-            visitor.visit_query(source);
+            if let Some(overriding) = overriding {
+                visitor.visit_insert_overriding(overriding);
+            }
+            for select_item in output {
+                visitor.visit_select_item(select_item);
+            }
+            visitor.visit_insert_source(source);
+        }
+
+        pub fn visit_insert_source<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            source: &'ast $($mut)* InsertSource,
+        ) {
+            match source {
+                InsertSource::Query(query) => visitor.visit_query(query),
+                InsertSource::DefaultValues => (),
+            }
         }
 
         pub fn visit_values<'ast, V: $name<'ast> + ?Sized>(visitor: &mut V, values: &'ast $($mut)* Values) {
@@ -1199,38 +2149,72 @@ macro_rules! make_visitor {
 
         pub fn visit_copy<'ast, V: $name<'ast> + ?Sized>(
             visitor: &mut V,
-            table_name: &'ast $($mut)* ObjectName,
-            columns: &'ast $($mut)* [Ident],
-            values: &'ast $($mut)* [Option<String>],
+            relation: &'ast $($mut)* CopyRelation,
+            target: CopyTarget,
+            options: &'ast $($mut)* [CopyOption],
+            values: &'ast $($mut)* [Vec<Option<String>>],
         ) {
-            visitor.visit_object_name(table_name);
-            for column in columns {
-                visitor.visit_ident(column);
+            visitor.visit_copy_relation(relation);
+            visitor.visit_copy_target(target);
+            for option in options {
+                visitor.visit_copy_option(option);
             }
             visitor.visit_copy_values(values);
         }
 
+        pub fn visit_copy_relation<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            relation: &'ast $($mut)* CopyRelation,
+        ) {
+            match relation {
+                CopyRelation::Table { name, columns } => {
+                    visitor.visit_object_name(name);
+                    for column in columns {
+                        visitor.visit_ident(column);
+                    }
+                }
+                CopyRelation::Query(query) => visitor.visit_query(query),
+            }
+        }
+
         pub fn visit_copy_values<'ast, V: $name<'ast> + ?Sized>(
             visitor: &mut V,
-            values: &'ast $($mut)* [Option<String>],
+            values: &'ast $($mut)* [Vec<Option<String>>],
+        ) {
+            for row in values {
+                visitor.visit_copy_values_row(row);
+            }
+        }
+
+        pub fn visit_copy_values_row<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            row: &'ast $($mut)* [Option<String>],
         ) {
-            for value in values {
-                visitor.visit_copy_values_row(value.as_auto_ref());
+            for value in row {
+                visitor.visit_copy_value(value.as_auto_ref());
             }
         }
 
         pub fn visit_update<'ast, V: $name<'ast> + ?Sized>(
             visitor: &mut V,
             table_name: &'ast $($mut)* ObjectName,
+            alias: Option<&'ast $($mut)* Ident>,
             assignments: &'ast $($mut)* [Assignment],
-            selection: Option<&'ast $($mut)* Expr>,
+            output: &'ast $($mut)* [SelectItem],
+            selection: Option<&'ast $($mut)* DmlSelection>,
         ) {
             visitor.visit_object_name(table_name);
+            if let Some(alias) = alias {
+                visitor.visit_ident(alias);
+            }
             for assignment in assignments {
                 visitor.visit_assignment(assignment);
             }
+            for select_item in output {
+                visitor.visit_select_item(select_item);
+            }
             if let Some(selection) = selection {
-                visitor.visit_where(selection);
+                visitor.visit_dml_selection(selection);
             }
         }
 
@@ -1238,18 +2222,50 @@ macro_rules! make_visitor {
             visitor: &mut V,
             assignment: &'ast $($mut)* Assignment,
         ) {
-            visitor.visit_ident(&$($mut)* assignment.id);
+            visitor.visit_assignment_target(&$($mut)* assignment.target);
             visitor.visit_expr(&$($mut)* assignment.value);
         }
 
+        pub fn visit_assignment_target<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            target: &'ast $($mut)* AssignmentTarget,
+        ) {
+            match target {
+                AssignmentTarget::ColumnName(column) => visitor.visit_object_name(column),
+                AssignmentTarget::Tuple(columns) => {
+                    for column in columns {
+                        visitor.visit_object_name(column);
+                    }
+                }
+            }
+        }
+
+        pub fn visit_dml_selection<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            selection: &'ast $($mut)* DmlSelection,
+        ) {
+            match selection {
+                DmlSelection::Expr(expr) => visitor.visit_where(expr),
+                DmlSelection::CurrentOf(cursor) => visitor.visit_ident(cursor),
+            }
+        }
+
         pub fn visit_delete<'ast, V: $name<'ast> + ?Sized>(
             visitor: &mut V,
             table_name: &'ast $($mut)* ObjectName,
-            selection: Option<&'ast $($mut)* Expr>,
+            alias: Option<&'ast $($mut)* Ident>,
+            output: &'ast $($mut)* [SelectItem],
+            selection: Option<&'ast $($mut)* DmlSelection>,
         ) {
             visitor.visit_object_name(table_name);
+            if let Some(alias) = alias {
+                visitor.visit_ident(alias);
+            }
+            for select_item in output {
+                visitor.visit_select_item(select_item);
+            }
             if let Some(selection) = selection {
-                visitor.visit_where(selection);
+                visitor.visit_dml_selection(selection);
             }
         }
 
@@ -1314,7 +2330,7 @@ macro_rules! make_visitor {
 
         pub fn visit_drop<'ast, V: $name<'ast> + ?Sized>(
             visitor: &mut V,
-            object_type: ObjectType,
+            object_type: &'ast $($mut)* ObjectType,
             _if_exists: bool,
             names: &'ast $($mut)* [ObjectName],
             _cascade: bool,
@@ -1328,14 +2344,15 @@ macro_rules! make_visitor {
         pub fn visit_create_view<'ast, V: $name<'ast> + ?Sized>(
             visitor: &mut V,
             name: &'ast $($mut)* ObjectName,
-            columns: &'ast $($mut)* [Ident],
+            columns: &'ast $($mut)* [ViewColumnDef],
             query: &'ast $($mut)* Query,
+            _temporary: bool,
             _materialized: bool,
             with_options: &'ast $($mut)* [SqlOption],
         ) {
             visitor.visit_object_name(name);
             for column in columns {
-                visitor.visit_ident(column);
+                visitor.visit_view_column_def(column);
             }
             for option in with_options {
                 visitor.visit_option(option);
@@ -1365,6 +2382,8 @@ macro_rules! make_visitor {
             _external: bool,
             file_format: &'ast $($mut)* Option<FileFormat>,
             location: &'ast $($mut)* Option<String>,
+            engine: Option<&'ast $($mut)* TableEngine>,
+            order_by: &'ast $($mut)* [Expr],
         ) {
             visitor.visit_object_name(name);
             for column in columns {
@@ -1382,6 +2401,134 @@ macro_rules! make_visitor {
             if let Some(location) = location {
                 visitor.visit_literal_string(location);
             }
+            if let Some(engine) = engine {
+                visitor.visit_table_engine(engine);
+            }
+            for expr in order_by {
+                visitor.visit_expr(expr);
+            }
+        }
+
+        pub fn visit_table_engine<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            engine: &'ast $($mut)* TableEngine,
+        ) {
+            visitor.visit_ident(&$($mut)* engine.name);
+            if let Some(parameters) = &$($mut)* engine.parameters {
+                for expr in parameters {
+                    visitor.visit_expr(expr);
+                }
+            }
+        }
+
+        pub fn visit_create_extension<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            name: &'ast $($mut)* Ident,
+            _if_not_exists: bool,
+            schema: Option<&'ast $($mut)* Ident>,
+            _version: Option<&'ast $($mut)* String>,
+            _cascade: bool,
+        ) {
+            visitor.visit_ident(name);
+            if let Some(schema) = schema {
+                visitor.visit_ident(schema);
+            }
+        }
+
+        pub fn visit_create_sequence<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            name: &'ast $($mut)* ObjectName,
+            _if_not_exists: bool,
+            options: &'ast $($mut)* [SequenceOption],
+        ) {
+            visitor.visit_object_name(name);
+            for option in options {
+                visitor.visit_sequence_option(option);
+            }
+        }
+
+        pub fn visit_alter_sequence<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            name: &'ast $($mut)* ObjectName,
+            _if_exists: bool,
+            options: &'ast $($mut)* [SequenceOption],
+        ) {
+            visitor.visit_object_name(name);
+            for option in options {
+                visitor.visit_sequence_option(option);
+            }
+        }
+
+        pub fn visit_sequence_option<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            option: &'ast $($mut)* SequenceOption,
+        ) {
+            match option {
+                SequenceOption::As(data_type) => visitor.visit_type(data_type),
+                SequenceOption::IncrementBy(expr)
+                | SequenceOption::StartWith(expr)
+                | SequenceOption::Cache(expr)
+                | SequenceOption::MinValue(Some(expr))
+                | SequenceOption::MaxValue(Some(expr))
+                | SequenceOption::Restart(Some(expr)) => visitor.visit_expr(expr),
+                SequenceOption::OwnedBy(Some(name)) => visitor.visit_object_name(name),
+                SequenceOption::MinValue(None)
+                | SequenceOption::MaxValue(None)
+                | SequenceOption::Restart(None)
+                | SequenceOption::Cycle(_)
+                | SequenceOption::OwnedBy(None) => (),
+            }
+        }
+
+        pub fn visit_create_role<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            name: &'ast $($mut)* Ident,
+            _is_user: bool,
+            options: &'ast $($mut)* [RoleOption],
+        ) {
+            visitor.visit_ident(name);
+            for option in options {
+                visitor.visit_role_option(option);
+            }
+        }
+
+        pub fn visit_alter_role<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            name: &'ast $($mut)* Ident,
+            _is_user: bool,
+            options: &'ast $($mut)* [RoleOption],
+        ) {
+            visitor.visit_ident(name);
+            for option in options {
+                visitor.visit_role_option(option);
+            }
+        }
+
+        pub fn visit_role_option<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            option: &'ast $($mut)* RoleOption,
+        ) {
+            match option {
+                RoleOption::ConnectionLimit(expr) => visitor.visit_expr(expr),
+                RoleOption::Password {
+                    password: Some(password),
+                    ..
+                } => visitor.visit_literal_string(password),
+                RoleOption::ValidUntil(timestamp) => visitor.visit_literal_string(timestamp),
+                RoleOption::InRole(roles) | RoleOption::Role(roles) | RoleOption::Admin(roles) => {
+                    for role in roles {
+                        visitor.visit_ident(role);
+                    }
+                }
+                RoleOption::SuperUser(_)
+                | RoleOption::CreateDb(_)
+                | RoleOption::CreateRole(_)
+                | RoleOption::Inherit(_)
+                | RoleOption::Login(_)
+                | RoleOption::Replication(_)
+                | RoleOption::BypassRls(_)
+                | RoleOption::Password { password: None, .. } => (),
+            }
         }
 
         pub fn visit_column_def<'ast, V: $name<'ast> + ?Sized>(
@@ -1395,6 +2542,16 @@ macro_rules! make_visitor {
             }
         }
 
+        pub fn visit_view_column_def<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            column_def: &'ast $($mut)* ViewColumnDef,
+        ) {
+            visitor.visit_ident(&$($mut)* column_def.name);
+            if let Some(data_type) = &$($mut)* column_def.data_type {
+                visitor.visit_type(data_type);
+            }
+        }
+
         pub fn visit_column_option_def<'ast, V: $name<'ast> + ?Sized>(
             visitor: &mut V,
             column_option_def: &'ast $($mut)* ColumnOptionDef,
@@ -1410,11 +2567,16 @@ macro_rules! make_visitor {
             column_option: &'ast $($mut)* ColumnOption,
         ) {
             match column_option {
-                ColumnOption::Null | ColumnOption::NotNull | ColumnOption::Unique { .. } => (),
-                ColumnOption::Default(expr) | ColumnOption::Check(expr) => visitor.visit_expr(expr),
+                ColumnOption::Null
+                | ColumnOption::NotNull
+                | ColumnOption::Unique { .. }
+                | ColumnOption::Autoincrement
+                | ColumnOption::Identity { .. } => (),
+                ColumnOption::Default(expr) | ColumnOption::Check { expr, .. } => visitor.visit_expr(expr),
                 ColumnOption::ForeignKey {
                     foreign_table,
                     referred_columns,
+                    ..
                 } => {
                     visitor.visit_object_name(foreign_table);
                     for column in referred_columns {
@@ -1425,12 +2587,22 @@ macro_rules! make_visitor {
         }
 
         pub fn visit_option<'ast, V: $name<'ast> + ?Sized>(visitor: &mut V, option: &'ast $($mut)* SqlOption) {
-            visitor.visit_ident(&$($mut)* option.name);
-            visitor.visit_value(&$($mut)* option.value);
+            match option {
+                SqlOption::Value { name, value } => {
+                    visitor.visit_ident(name);
+                    visitor.visit_value(value);
+                }
+                SqlOption::Flag { name } => visitor.visit_ident(name),
+                SqlOption::ObjectName { name, object_name } => {
+                    visitor.visit_ident(name);
+                    visitor.visit_object_name(object_name);
+                }
+            }
         }
 
         pub fn visit_alter_table<'ast, V: $name<'ast> + ?Sized>(
             visitor: &mut V,
+            _only: bool,
             name: &'ast $($mut)* ObjectName,
             operation: &'ast $($mut)* AlterTableOperation,
         ) {
@@ -1447,6 +2619,55 @@ macro_rules! make_visitor {
                     visitor.visit_alter_add_constraint(table_constraint)
                 }
                 AlterTableOperation::DropConstraint { name } => visitor.visit_alter_drop_constraint(name),
+                AlterTableOperation::AlterColumn { column_name, op } => {
+                    visitor.visit_alter_column(column_name, op)
+                }
+                AlterTableOperation::OwnerTo { new_owner } => visitor.visit_ident(new_owner),
+            }
+        }
+
+        pub fn visit_alter_owner<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            object_type: &'ast $($mut)* ObjectType,
+            _if_exists: bool,
+            name: &'ast $($mut)* ObjectName,
+            new_owner: &'ast $($mut)* Ident,
+        ) {
+            visitor.visit_object_type(object_type);
+            visitor.visit_object_name(name);
+            visitor.visit_ident(new_owner);
+        }
+
+        pub fn visit_alter_view_options<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            _if_exists: bool,
+            name: &'ast $($mut)* ObjectName,
+            operation: &'ast $($mut)* AlterOptionsOperation,
+        ) {
+            visitor.visit_object_name(name);
+            match operation {
+                AlterOptionsOperation::Set(options) => {
+                    for option in options {
+                        visitor.visit_option(option);
+                    }
+                }
+                AlterOptionsOperation::Reset(names) => {
+                    for name in names {
+                        visitor.visit_ident(name);
+                    }
+                }
+            }
+        }
+
+        pub fn visit_alter_column<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            column_name: &'ast $($mut)* Ident,
+            op: &'ast $($mut)* AlterColumnOperation,
+        ) {
+            visitor.visit_ident(column_name);
+            match op {
+                AlterColumnOperation::SetDefault(expr) => visitor.visit_expr(expr),
+                AlterColumnOperation::DropDefault => (),
             }
         }
 
@@ -1466,19 +2687,21 @@ macro_rules! make_visitor {
                     name,
                     columns,
                     is_primary,
+                    ..
                 } => visitor.visit_table_constraint_unique(name.as_auto_ref(), columns, *is_primary),
                 TableConstraint::ForeignKey {
                     name,
                     columns,
                     foreign_table,
                     referred_columns,
+                    ..
                 } => visitor.visit_table_constraint_foreign_key(
                     name.as_auto_ref(),
                     columns,
                     foreign_table,
                     referred_columns,
                 ),
-                TableConstraint::Check { name, expr } => {
+                TableConstraint::Check { name, expr, .. } => {
                     visitor.visit_table_constraint_check(name.as_auto_ref(), expr)
                 }
             }
@@ -1561,7 +2784,7 @@ macro_rules! make_visitor {
 
         pub fn visit_show_objects<'ast, V: $name<'ast> + ?Sized>(
             visitor: &mut V,
-            object_type: ObjectType,
+            object_type: &'ast $($mut)* ObjectType,
             filter: Option<&'ast $($mut)* ShowStatementFilter>
         ) {
             visitor.visit_object_type(object_type);
@@ -1581,6 +2804,17 @@ macro_rules! make_visitor {
             }
         }
 
+        pub fn visit_show_constraints<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            table_name: &'ast $($mut)* ObjectName,
+            filter: Option<&'ast $($mut)* ShowStatementFilter>
+        ) {
+            visitor.visit_object_name(table_name);
+            if let Some(filter) = filter {
+                visitor.visit_show_statement_filter(filter);
+            }
+        }
+
         pub fn visit_show_columns<'ast, V: $name<'ast> + ?Sized>(
             visitor: &mut V,
             _extended: bool,
@@ -1662,8 +2896,8 @@ macro_rules! make_visitor {
             visitor.visit_object_name(name);
         }
 
-        pub fn visit_explain<'ast, V: $name<'ast> + ?Sized>(visitor: &mut V, _stage: &'ast $($mut)* Stage, query: &'ast $($mut)* Query) {
-            visitor.visit_query(query);
+        pub fn visit_explain<'ast, V: $name<'ast> + ?Sized>(visitor: &mut V, _stage: &'ast $($mut)* Stage, statement: &'ast $($mut)* Statement) {
+            visitor.visit_statement(statement);
         }
 
         pub fn visit_flush<'ast, V: $name<'ast> + ?Sized>(visitor: &mut V, name: &'ast $($mut)* ObjectName) {
@@ -1671,6 +2905,76 @@ macro_rules! make_visitor {
         }
 
         pub fn visit_flush_all<'ast, V: $name<'ast> + ?Sized>(_visitor: &mut V) {}
+
+        pub fn visit_declare<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            name: &'ast $($mut)* Ident,
+            _hold: bool,
+            stmt: &'ast $($mut)* Statement,
+        ) {
+            visitor.visit_ident(name);
+            visitor.visit_statement(stmt);
+        }
+
+        pub fn visit_fetch_cursor<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            name: &'ast $($mut)* Ident,
+            direction: Option<&'ast $($mut)* FetchDirection>,
+        ) {
+            if let Some(direction) = direction {
+                visitor.visit_fetch_direction(direction);
+            }
+            visitor.visit_ident(name);
+        }
+
+        pub fn visit_close<'ast, V: $name<'ast> + ?Sized>(visitor: &mut V, name: &'ast $($mut)* Ident) {
+            visitor.visit_ident(name);
+        }
+
+        pub fn visit_listen<'ast, V: $name<'ast> + ?Sized>(visitor: &mut V, channel: &'ast $($mut)* Ident) {
+            visitor.visit_ident(channel);
+        }
+
+        pub fn visit_notify<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            channel: &'ast $($mut)* Ident,
+            _payload: Option<&'ast $($mut)* String>,
+        ) {
+            visitor.visit_ident(channel);
+        }
+
+        pub fn visit_do_block<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            language: Option<&'ast $($mut)* Ident>,
+            body: &'ast $($mut)* Value,
+        ) {
+            if let Some(language) = language {
+                visitor.visit_ident(language);
+            }
+            visitor.visit_value(body);
+        }
+
+        pub fn visit_unlisten<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            channel: Option<&'ast $($mut)* Ident>,
+        ) {
+            if let Some(channel) = channel {
+                visitor.visit_ident(channel);
+            }
+        }
+
+        pub fn visit_prepare<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            name: &'ast $($mut)* Ident,
+            data_types: &'ast $($mut)* Vec<DataType>,
+            statement: &'ast $($mut)* Statement,
+        ) {
+            visitor.visit_ident(name);
+            for data_type in data_types {
+                visitor.visit_type(data_type);
+            }
+            visitor.visit_statement(statement);
+        }
     }
 }
 
@@ -1680,11 +2984,10 @@ mod tests {
     use crate::ast::visit_mut::VisitMut;
     use crate::ast::Ident;
     use crate::dialect::GenericDialect;
-    use crate::parser::Parser;
-    use std::error::Error;
+    use crate::parser::{Parser, ParserError};
 
     #[test]
-    fn test_basic_visitor() -> Result<(), Box<dyn Error>> {
+    fn test_basic_visitor() -> Result<(), ParserError> {
         struct Visitor<'a> {
             seen_idents: Vec<&'a str>,
         }
@@ -0,0 +1,49 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+use crate::dialect::keywords::{Keyword, DIALECT_SPECIFIC_KEYWORDS};
+use crate::dialect::Dialect;
+
+#[derive(Debug)]
+pub struct ClickHouseDialect {}
+
+impl Dialect for ClickHouseDialect {
+    fn is_delimited_identifier_start(&self, ch: char) -> bool {
+        ch == '"' || ch == '`'
+    }
+
+    // See https://clickhouse.tech/docs/en/sql-reference/syntax/#syntax-identifiers
+    fn is_identifier_start(&self, ch: char) -> bool {
+        ch.is_ascii_alphabetic() || ch == '_'
+    }
+
+    fn is_identifier_part(&self, ch: char) -> bool {
+        ch.is_ascii_alphanumeric() || ch == '_'
+    }
+
+    fn is_keyword(&self, keyword: Keyword) -> bool {
+        [Keyword::FORMAT, Keyword::ENGINE].contains(&keyword)
+            || !DIALECT_SPECIFIC_KEYWORDS.contains(&keyword)
+    }
+
+    fn max_decimal_precision(&self) -> Option<u64> {
+        Some(76)
+    }
+
+    // Identifiers are case-sensitive
+    fn fold_identifier_case(&self, ident: &str) -> String {
+        String::from(ident)
+    }
+}
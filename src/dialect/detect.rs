@@ -0,0 +1,175 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::keywords::{Keyword, DIALECT_SPECIFIC_KEYWORDS};
+use super::{dialect_from_str, Dialect, DIALECT_NAMES};
+use crate::tokenizer::{Token, Tokenizer};
+
+/// Suggest the dialects `sql` is likely written in, most likely first
+///
+/// Each dialect scores a point for every token that only some dialects
+/// accept and it is one of, like a `` `backquoted` `` identifier, a
+/// `[bracketed]` one, `SELECT TOP`, a `::` cast, a `$1` parameter, or a
+/// dialect-specific keyword such as `QUALIFY`. Dialects that score no
+/// points are left out, so the result is empty for SQL that any dialect
+/// could have produced, or that can't be tokenized. Dialects with equal
+/// scores are listed in the order of [DIALECT_NAMES].
+///
+/// ```
+/// use sqlparser::dialect::detect;
+///
+/// assert_eq!("mssql", detect("SELECT TOP 10 [name] FROM users")[0].0);
+/// ```
+pub fn detect(sql: &str) -> Vec<(&'static str, u32)> {
+    let tokens = match Tokenizer::new(&DetectDialect {}, sql).tokenize() {
+        Ok(tokens) => tokens,
+        Err(_) => return Vec::new(),
+    };
+    let tokens: Vec<_> = tokens
+        .into_iter()
+        .filter(|token| !matches!(token, Token::Whitespace(_)))
+        .collect();
+
+    let mut scores: Vec<(&'static str, u32)> =
+        DIALECT_NAMES.iter().map(|name| (*name, 0)).collect();
+    let mut vote = |names: &[&str]| {
+        for (name, score) in scores.iter_mut() {
+            if names.contains(name) {
+                *score += 1;
+            }
+        }
+    };
+    for (i, token) in tokens.iter().enumerate() {
+        let prev = if i > 0 { tokens.get(i - 1) } else { None };
+        match token {
+            Token::Word(w) if w.quote_style == Some('`') => {
+                vote(&["bigquery", "clickhouse", "hive", "mysql"])
+            }
+            Token::Word(w) if w.quote_style.is_none() && w.value.eq_ignore_ascii_case("TOP") => {
+                if let Some(Token::Word(prev)) = prev {
+                    if prev.keyword == Keyword::SELECT || prev.keyword == Keyword::DISTINCT {
+                        vote(&["mssql"]);
+                    }
+                }
+            }
+            Token::Word(w) if DIALECT_SPECIFIC_KEYWORDS.contains(&w.keyword) => {
+                let names: Vec<_> = DIALECT_NAMES
+                    .iter()
+                    .copied()
+                    .filter(|name| *name != "generic")
+                    .filter(|name| dialect_from_str(name).unwrap().is_keyword(w.keyword))
+                    .collect();
+                vote(&names);
+            }
+            // Unlike a Postgres array subscript, a bracketed identifier
+            // doesn't follow an expression
+            Token::LBracket if is_bracketed_identifier(prev, &tokens[i + 1..]) => vote(&["mssql"]),
+            Token::DoubleColon => vote(&["postgres", "redshift", "snowflake"]),
+            Token::Parameter(_) => vote(&["postgres", "redshift"]),
+            _ => (),
+        }
+    }
+
+    scores.retain(|(_, score)| *score > 0);
+    // A stable sort keeps ties in the order of DIALECT_NAMES
+    scores.sort_by(|(_, a), (_, b)| b.cmp(a));
+    scores
+}
+
+fn is_bracketed_identifier(prev: Option<&Token>, rest: &[Token]) -> bool {
+    let follows_expression = match prev {
+        Some(Token::Word(w)) => w.keyword == Keyword::NoKeyword || w.keyword == Keyword::ARRAY,
+        Some(Token::RParen) | Some(Token::RBracket) => true,
+        _ => false,
+    };
+    !follows_expression
+        && matches!(rest, [Token::Word(w), Token::RBracket, ..] if w.quote_style.is_none())
+}
+
+/// A dialect that tokenizes the syntax of every dialect [detect] looks for,
+/// leaving `[` as a token of its own
+#[derive(Debug)]
+struct DetectDialect {}
+
+impl Dialect for DetectDialect {
+    fn is_delimited_identifier_start(&self, ch: char) -> bool {
+        ch == '"' || ch == '`'
+    }
+
+    fn is_keyword(&self, _keyword: Keyword) -> bool {
+        true
+    }
+
+    fn is_identifier_start(&self, ch: char) -> bool {
+        ch.is_ascii_alphabetic() || ch == '_'
+    }
+
+    fn is_identifier_part(&self, ch: char) -> bool {
+        ch.is_ascii_alphanumeric() || ch == '_' || ch == '$' || ch == '#' || ch == '@'
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(sql: &str) -> Vec<&'static str> {
+        detect(sql).into_iter().map(|(name, _)| name).collect()
+    }
+
+    #[test]
+    fn detect_dialects() {
+        assert_eq!(
+            vec![
+                ("bigquery", 2),
+                ("clickhouse", 2),
+                ("hive", 2),
+                ("mysql", 2)
+            ],
+            detect("SELECT `a` FROM `t`")
+        );
+        assert_eq!(
+            vec!["mssql"],
+            names("SELECT DISTINCT TOP 10 [name] FROM [dbo].[users]")
+        );
+        assert_eq!(
+            vec!["postgres", "redshift", "snowflake"],
+            names("SELECT a::int, b[1], ARRAY[c] FROM t WHERE d = $1")
+        );
+        assert_eq!(
+            vec!["bigquery", "snowflake"],
+            names("SELECT a FROM t QUALIFY a = 1")
+        );
+        assert_eq!(
+            vec![("hive", 2)],
+            detect("SELECT a FROM t DISTRIBUTE BY a SORT BY b")
+        );
+        assert_eq!(
+            vec![
+                ("snowflake", 2),
+                ("bigquery", 1),
+                ("postgres", 1),
+                ("redshift", 1)
+            ],
+            detect("SELECT a::int FROM t QUALIFY a = 1")
+        );
+    }
+
+    #[test]
+    fn detect_without_evidence() {
+        assert!(detect("SELECT a FROM t WHERE b = 'c'").is_empty());
+        assert!(detect("SELECT 'unterminated").is_empty());
+    }
+}
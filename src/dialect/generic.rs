@@ -10,6 +10,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::dialect::keywords::Keyword;
 use crate::dialect::Dialect;
 
 #[derive(Debug)]
@@ -29,4 +30,25 @@ impl Dialect for GenericDialect {
             || ch == '#'
             || ch == '_'
     }
+
+    fn supports_string_literal_concatenation(&self) -> bool {
+        true
+    }
+
+    fn string_literal_concatenation_requires_newline(&self) -> bool {
+        false
+    }
+
+    fn supports_interval_expressions(&self) -> bool {
+        true
+    }
+
+    fn supports_variant_access(&self) -> bool {
+        true
+    }
+
+    // Accept the dialect-specific clauses of every dialect
+    fn is_keyword(&self, _keyword: Keyword) -> bool {
+        true
+    }
 }
@@ -10,19 +10,32 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-///! This module defines
-/// 1) a list of constants for every keyword that
-/// can appear in [Word::keyword]:
-///    pub const KEYWORD = "KEYWORD"
-/// 2) an `ALL_KEYWORDS` array with every keyword in it
-///     This is not a list of *reserved* keywords: some of these can be
-///     parsed as identifiers if the parser decides so. This means that
-///     new keywords can be added here without affecting the parse result.
-///
-///     As a matter of fact, most of these keywords are not used at all
-///     and could be removed.
-/// 3) a `RESERVED_FOR_TABLE_ALIAS` array with keywords reserved in a
-/// "table alias" context.
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::Dialect;
+
+// This module defines
+// 1) a list of constants for every keyword that
+// can appear in [Word::value]:
+//    pub const KEYWORD = "KEYWORD"
+// 2) an `ALL_KEYWORDS` array with every keyword in it
+//     This is not a list of *reserved* keywords: some of these can be
+//     parsed as identifiers if the parser decides so. This means that
+//     new keywords can be added here without affecting the parse result.
+//
+//     As a matter of fact, most of these keywords are not used at all
+//     and could be removed.
+// 3) a `Keyword` enum with a variant for every keyword, which is what
+// can appear in [Word::keyword], and an `ALL_KEYWORDS_INDEX` array
+// mapping each entry of `ALL_KEYWORDS` to its variant.
+// 4) a `RESERVED_FOR_TABLE_ALIAS` array with keywords reserved in a
+// "table alias" context.
+// 5) a `DIALECT_SPECIFIC_KEYWORDS` array with keywords that dialects
+// only recognize if they support the construct they introduce.
+// 6) `TYPE_NAME_KEYWORDS` and `FUNCTION_KEYWORDS` arrays, which together
+// with the reserved arrays sort keywords into a [KeywordCategory], for tools
+// like auto-completion.
 
 /// Defines a string constant for a single keyword: `kw_def!(SELECT);`
 /// expands to `pub const SELECT = "SELECT";`
@@ -35,12 +48,28 @@ macro_rules! kw_def {
     };
 }
 
-/// Expands to a list of `kw_def!()` invocations for each keyword
-/// and defines an ALL_KEYWORDS array of the defined constants.
+/// Expands to a list of `kw_def!()` invocations for each keyword,
+/// a `Keyword` enum with a variant per keyword, and the `ALL_KEYWORDS`
+/// and `ALL_KEYWORDS_INDEX` arrays.
+///
+/// The keywords must be listed in the byte order of their string form, as
+/// the tokenizer looks them up with a binary search.
 macro_rules! define_keywords {
     ($(
         $ident:ident $(= $string_keyword:expr)?
-    ),*) => {
+    ),* $(,)?) => {
+        #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[allow(non_camel_case_types)]
+        pub enum Keyword {
+            /// The word is not a keyword, or it was quoted
+            NoKeyword,
+            $($ident),*
+        }
+
+        pub const ALL_KEYWORDS_INDEX: &[Keyword] = &[
+            $(Keyword::$ident),*
+        ];
+
         $(kw_def!($ident $(= $string_keyword)?);)*
 
         pub const ALL_KEYWORDS: &[&str] = &[
@@ -51,7 +80,9 @@ macro_rules! define_keywords {
 
 define_keywords!(
     ABS,
+    ACTION,
     ADD,
+    ADMIN,
     ALL,
     ALLOCATE,
     ALTER,
@@ -69,6 +100,7 @@ define_keywords!(
     AT,
     ATOMIC,
     AUTHORIZATION,
+    AUTOINCREMENT,
     AVG,
     BEGIN,
     BEGIN_FRAME,
@@ -80,7 +112,9 @@ define_keywords!(
     BOOLEAN,
     BOTH,
     BY,
+    BYPASSRLS,
     BYTEA,
+    CACHE,
     CALL,
     CALLED,
     CARDINALITY,
@@ -94,11 +128,13 @@ define_keywords!(
     CHAIN,
     CHAR,
     CHARACTER,
+    CHARACTERS,
     CHARACTER_LENGTH,
     CHAR_LENGTH,
     CHECK,
     CLOB,
     CLOSE,
+    CLUSTER,
     COALESCE,
     COLLATE,
     COLLECT,
@@ -108,7 +144,9 @@ define_keywords!(
     COMMITTED,
     CONDITION,
     CONNECT,
+    CONNECTION,
     CONSTRAINT,
+    CONSTRAINTS,
     CONTAINS,
     CONVERT,
     COPY,
@@ -118,6 +156,8 @@ define_keywords!(
     COVAR_POP,
     COVAR_SAMP,
     CREATE,
+    CREATEDB,
+    CREATEROLE,
     CROSS,
     CSV,
     CUBE,
@@ -136,6 +176,8 @@ define_keywords!(
     CURRENT_USER,
     CURSOR,
     CYCLE,
+    DATABASE,
+    DATABASES,
     DATAFLOW,
     DATE,
     DAY,
@@ -145,6 +187,8 @@ define_keywords!(
     DECIMAL,
     DECLARE,
     DEFAULT,
+    DEFERRABLE,
+    DEFERRED,
     DELETE,
     DENSE_RANK,
     DEREF,
@@ -153,6 +197,8 @@ define_keywords!(
     DETERMINISTIC,
     DISCONNECT,
     DISTINCT,
+    DISTRIBUTE,
+    DO,
     DOUBLE,
     DOW,
     DOY,
@@ -161,20 +207,28 @@ define_keywords!(
     EACH,
     ELEMENT,
     ELSE,
+    EMPTY,
+    ENCRYPTED,
     END,
+    END_EXEC = "END-EXEC",
     END_FRAME,
     END_PARTITION,
+    ENFORCED,
+    ENGINE,
     EPOCH,
     EQUALS,
+    ERROR,
     ESCAPE,
     EVERY,
     EXCEPT,
+    EXCLUDE,
     EXEC,
     EXECUTE,
     EXISTS,
     EXP,
     EXPLAIN,
     EXTENDED,
+    EXTENSION,
     EXTERNAL,
     EXTRACT,
     FALSE,
@@ -189,6 +243,8 @@ define_keywords!(
     FOLLOWING,
     FOR,
     FOREIGN,
+    FORMAT,
+    FORWARD,
     FRAME_ROW,
     FREE,
     FROM,
@@ -207,11 +263,16 @@ define_keywords!(
     HOUR,
     IDENTITY,
     IF,
+    IGNORE,
+    ILIKE,
     IMMEDIATE,
     IN,
+    INCREMENT,
     INDEX,
     INDEXES,
     INDICATOR,
+    INHERIT,
+    INITIALLY,
     INNER,
     INOUT,
     INSENSITIVE,
@@ -227,6 +288,7 @@ define_keywords!(
     ISOLATION,
     ISOYEAR,
     JOIN,
+    JSON_TABLE,
     KEY,
     KEYS,
     LAG,
@@ -241,15 +303,19 @@ define_keywords!(
     LIKE,
     LIKE_REGEX,
     LIMIT,
+    LISTEN,
     LN,
     LOCAL,
     LOCALTIME,
     LOCALTIMESTAMP,
     LOCATION,
+    LOCKED,
+    LOGIN,
     LOWER,
     MATCH,
     MATERIALIZED,
     MAX,
+    MAXVALUE,
     MEMBER,
     MERGE,
     METHOD,
@@ -258,6 +324,7 @@ define_keywords!(
     MILLISECONDS,
     MIN,
     MINUTE,
+    MINVALUE,
     MOD,
     MODIFIES,
     MODULE,
@@ -267,19 +334,31 @@ define_keywords!(
     NATURAL,
     NCHAR,
     NCLOB,
+    NESTED,
     NEW,
     NEXT,
     NO,
+    NOBYPASSRLS,
+    NOCREATEDB,
+    NOCREATEROLE,
+    NOINHERIT,
+    NOLOGIN,
     NONE,
+    NOREPLICATION,
     NORMALIZE,
+    NOSUPERUSER,
     NOT,
+    NOTIFY,
+    NOWAIT,
     NTH_VALUE,
     NTILE,
     NULL,
     NULLIF,
     NUMERIC,
+    NVARCHAR,
     OBJECT,
     OCCURRENCES_REGEX,
+    OCTETS,
     OCTET_LENGTH,
     OF,
     OFFSET,
@@ -289,20 +368,30 @@ define_keywords!(
     OPEN,
     OR,
     ORDER,
+    ORDINALITY,
+    OTHERS,
     OUT,
     OUTER,
+    OUTPUT,
     OVER,
     OVERLAPS,
     OVERLAY,
+    OVERRIDING,
+    OWNED,
+    OWNER,
     PARAMETER,
     PARQUET,
     PARTITION,
+    PASSING,
+    PASSWORD,
+    PATH,
     PEEK,
     PERCENT,
-    PERCENT_RANK,
     PERCENTILE_CONT,
     PERCENTILE_DISC,
+    PERCENT_RANK,
     PERIOD,
+    PLACING,
     PLAN,
     PORTION,
     POSITION,
@@ -314,6 +403,7 @@ define_keywords!(
     PREPARE,
     PRIMARY,
     PROCEDURE,
+    QUALIFY,
     QUARTER,
     RANGE,
     RANK,
@@ -337,35 +427,48 @@ define_keywords!(
     REGR_SYY,
     RELEASE,
     REPEATABLE,
+    REPLACE,
+    REPLICATION,
+    RESET,
+    RESTART,
     RESTRICT,
     RESULT,
     RETURN,
     RETURNS,
     REVOKE,
     RIGHT,
+    ROLE,
+    ROLES,
     ROLLBACK,
     ROLLUP,
     ROW,
-    ROW_NUMBER,
+    ROWID,
     ROWS,
+    ROW_NUMBER,
     SAVEPOINT,
     SCHEMA,
+    SCHEMAS,
     SCOPE,
     SCROLL,
     SEARCH,
     SECOND,
     SELECT,
     SENSITIVE,
+    SEQUENCE,
+    SEQUENCES,
     SERIALIZABLE,
     SESSION,
     SESSION_USER,
     SET,
+    SHARE,
     SHOW,
     SIMILAR,
     SINK,
     SINKS,
+    SKIP,
     SMALLINT,
     SOME,
+    SORT,
     SOURCE,
     SOURCES,
     SPECIFIC,
@@ -380,12 +483,14 @@ define_keywords!(
     STDDEV_POP,
     STDDEV_SAMP,
     STDIN,
+    STDOUT,
     STORED,
     SUBMULTISET,
     SUBSTRING,
     SUBSTRING_REGEX,
     SUCCEEDS,
     SUM,
+    SUPERUSER,
     SYMMETRIC,
     SYSTEM,
     SYSTEM_TIME,
@@ -394,6 +499,8 @@ define_keywords!(
     TABLES,
     TABLESAMPLE,
     TAIL,
+    TEMP,
+    TEMPORARY,
     TEXT,
     THEN,
     TIES,
@@ -415,26 +522,32 @@ define_keywords!(
     TRIM_ARRAY,
     TRUE,
     TRUNCATE,
+    TYPE,
+    TYPES,
     UESCAPE,
     UNBOUNDED,
     UNCOMMITTED,
     UNION,
     UNIQUE,
     UNKNOWN,
+    UNLISTEN,
     UNNEST,
+    UNTIL,
     UPDATE,
     UPPER,
     USER,
     USING,
     UUID,
+    VALID,
     VALUE,
     VALUES,
     VALUE_OF,
-    VAR_POP,
-    VAR_SAMP,
     VARBINARY,
     VARCHAR,
     VARYING,
+    VAR_POP,
+    VAR_SAMP,
+    VERSION,
     VERSIONING,
     VIEW,
     VIEWS,
@@ -449,27 +562,362 @@ define_keywords!(
     WITHOUT,
     WORK,
     WRITE,
+    XMLNAMESPACES,
+    XMLTABLE,
     YEAR,
     ZONE,
-    END_EXEC = "END-EXEC"
 );
 
+/// Keywords that only introduce a construct in some dialects. By default,
+/// dialects don't recognize them as keywords, so that they remain usable as
+/// ordinary identifiers, including as aliases.
+pub const DIALECT_SPECIFIC_KEYWORDS: &[Keyword] = &[
+    // ClickHouse `SELECT ... FORMAT <format>` and
+    // `CREATE TABLE ... ENGINE = <engine>`
+    Keyword::FORMAT,
+    Keyword::ENGINE,
+    // Snowflake and BigQuery `SELECT ... QUALIFY <expr>`
+    Keyword::QUALIFY,
+    // Hive `SELECT ... CLUSTER BY | DISTRIBUTE BY ... SORT BY ...`
+    Keyword::CLUSTER,
+    Keyword::DISTRIBUTE,
+    Keyword::SORT,
+    // MSSQL `INSERT | UPDATE | DELETE ... OUTPUT <select items>`
+    Keyword::OUTPUT,
+];
+
 /// These keywords can't be used as a table alias, so that `FROM table_name alias`
 /// can be parsed unambiguously without looking ahead.
-pub const RESERVED_FOR_TABLE_ALIAS: &[&str] = &[
+pub const RESERVED_FOR_TABLE_ALIAS: &[Keyword] = &[
     // Reserved as both a table and a column alias:
-    WITH, SELECT, WHERE, GROUP, HAVING, ORDER, LIMIT, OFFSET, FETCH, UNION, EXCEPT, INTERSECT,
+    Keyword::WITH,
+    Keyword::SELECT,
+    Keyword::WHERE,
+    Keyword::GROUP,
+    Keyword::HAVING,
+    Keyword::ORDER,
+    Keyword::LIMIT,
+    Keyword::OFFSET,
+    Keyword::FETCH,
+    Keyword::FOR,
+    Keyword::QUALIFY,
+    Keyword::FORMAT,
+    Keyword::CLUSTER,
+    Keyword::DISTRIBUTE,
+    Keyword::SORT,
+    Keyword::UNION,
+    Keyword::EXCEPT,
+    Keyword::INTERSECT,
     // Reserved only as a table alias in the `FROM`/`JOIN` clauses:
-    ON, JOIN, INNER, CROSS, FULL, LEFT, RIGHT, NATURAL, USING,
+    Keyword::ON,
+    Keyword::JOIN,
+    Keyword::INNER,
+    Keyword::CROSS,
+    Keyword::FULL,
+    Keyword::LEFT,
+    Keyword::RIGHT,
+    Keyword::NATURAL,
+    Keyword::USING,
     // for MSSQL-specific OUTER APPLY (seems reserved in most dialects)
-    OUTER,
+    Keyword::OUTER,
 ];
 
 /// Can't be used as a column alias, so that `SELECT <expr> alias`
 /// can be parsed unambiguously without looking ahead.
-pub const RESERVED_FOR_COLUMN_ALIAS: &[&str] = &[
+pub const RESERVED_FOR_COLUMN_ALIAS: &[Keyword] = &[
     // Reserved as both a table and a column alias:
-    WITH, SELECT, WHERE, GROUP, HAVING, ORDER, LIMIT, OFFSET, FETCH, UNION, EXCEPT, INTERSECT,
-    // Reserved only as a column alias in the `SELECT` clause:
-    FROM,
+    Keyword::WITH,
+    Keyword::SELECT,
+    Keyword::WHERE,
+    Keyword::GROUP,
+    Keyword::HAVING,
+    Keyword::ORDER,
+    Keyword::LIMIT,
+    Keyword::OFFSET,
+    Keyword::FETCH,
+    Keyword::FOR,
+    Keyword::QUALIFY,
+    Keyword::FORMAT,
+    Keyword::CLUSTER,
+    Keyword::DISTRIBUTE,
+    Keyword::SORT,
+    Keyword::UNION,
+    Keyword::EXCEPT,
+    Keyword::INTERSECT,
+    // Reserved only as a column alias in the `SELECT` and MSSQL `OUTPUT` clauses:
+    Keyword::FROM,
+    Keyword::VALUES,
+];
+
+/// Keywords that name a data type, or start the name of one
+pub const TYPE_NAME_KEYWORDS: &[Keyword] = &[
+    Keyword::ARRAY,
+    Keyword::BIGINT,
+    Keyword::BINARY,
+    Keyword::BLOB,
+    Keyword::BOOLEAN,
+    Keyword::BYTEA,
+    Keyword::CHAR,
+    Keyword::CHARACTER,
+    Keyword::CLOB,
+    Keyword::DATE,
+    Keyword::DEC,
+    Keyword::DECIMAL,
+    Keyword::DOUBLE,
+    Keyword::FLOAT,
+    Keyword::INT,
+    Keyword::INTEGER,
+    Keyword::INTERVAL,
+    Keyword::NCHAR,
+    Keyword::NUMERIC,
+    Keyword::NVARCHAR,
+    Keyword::REAL,
+    Keyword::REGCLASS,
+    Keyword::SMALLINT,
+    Keyword::TEXT,
+    Keyword::TIME,
+    Keyword::TIMESTAMP,
+    Keyword::TIMESTAMPTZ,
+    Keyword::UUID,
+    Keyword::VARBINARY,
+    Keyword::VARCHAR,
+];
+
+/// Keywords that the parser treats as functions with a syntax of their own,
+/// like `CAST(a AS int)`, or that are called without parentheses, like
+/// `CURRENT_DATE`
+pub const FUNCTION_KEYWORDS: &[Keyword] = &[
+    Keyword::CAST,
+    Keyword::CONVERT,
+    Keyword::CURRENT_CATALOG,
+    Keyword::CURRENT_DATE,
+    Keyword::CURRENT_ROLE,
+    Keyword::CURRENT_SCHEMA,
+    Keyword::CURRENT_TIME,
+    Keyword::CURRENT_TIMESTAMP,
+    Keyword::CURRENT_USER,
+    Keyword::EXTRACT,
+    Keyword::LOCALTIME,
+    Keyword::LOCALTIMESTAMP,
+    Keyword::OVERLAY,
+    Keyword::SESSION_USER,
+    Keyword::TRIM,
 ];
+
+/// Keywords that can be written unquoted wherever an identifier is expected,
+/// like `CREATE TABLE t (type text)`, in every dialect. None of them are
+/// reserved in the SQL standard or in the major databases, so
+/// [Ident::quote_if_needed](crate::ast::Ident::quote_if_needed) leaves them
+/// unquoted, while it quotes every other keyword a dialect recognizes.
+pub const IDENTIFIER_SAFE_KEYWORDS: &[Keyword] = &[
+    Keyword::ACTION,
+    Keyword::ADMIN,
+    Keyword::APPLY,
+    Keyword::AUTOINCREMENT,
+    Keyword::BYPASSRLS,
+    Keyword::BYTEA,
+    Keyword::CACHE,
+    Keyword::CENTURY,
+    Keyword::CHAIN,
+    Keyword::CHARACTERS,
+    Keyword::COLUMNS,
+    Keyword::COMMITTED,
+    Keyword::CONNECTION,
+    Keyword::CONSTRAINTS,
+    Keyword::COPY,
+    Keyword::CREATEDB,
+    Keyword::CREATEROLE,
+    Keyword::CSV,
+    Keyword::DATAFLOW,
+    Keyword::DECADE,
+    Keyword::DEFERRED,
+    Keyword::DOW,
+    Keyword::DOY,
+    Keyword::ENCRYPTED,
+    Keyword::ENFORCED,
+    Keyword::EPOCH,
+    Keyword::ERROR,
+    Keyword::EXCLUDE,
+    Keyword::EXTENDED,
+    Keyword::EXTENSION,
+    Keyword::FIELDS,
+    Keyword::FIRST,
+    Keyword::FOLLOWING,
+    Keyword::FORWARD,
+    Keyword::HEADER,
+    Keyword::IMMEDIATE,
+    Keyword::INCREMENT,
+    Keyword::INDEXES,
+    Keyword::INHERIT,
+    Keyword::ISODOW,
+    Keyword::ISOLATION,
+    Keyword::ISOYEAR,
+    Keyword::LEVEL,
+    Keyword::LISTEN,
+    Keyword::LOCATION,
+    Keyword::LOCKED,
+    Keyword::LOGIN,
+    Keyword::MATERIALIZED,
+    Keyword::MICROSECONDS,
+    Keyword::MILLENIUM,
+    Keyword::MILLISECONDS,
+    Keyword::MINVALUE,
+    Keyword::NESTED,
+    Keyword::NEXT,
+    Keyword::NOBYPASSRLS,
+    Keyword::NOCREATEDB,
+    Keyword::NOCREATEROLE,
+    Keyword::NOINHERIT,
+    Keyword::NOLOGIN,
+    Keyword::NOREPLICATION,
+    Keyword::NOSUPERUSER,
+    Keyword::NOTIFY,
+    Keyword::NOWAIT,
+    Keyword::NVARCHAR,
+    Keyword::OBJECT,
+    Keyword::OCTETS,
+    Keyword::ORDINALITY,
+    Keyword::OTHERS,
+    Keyword::OVERRIDING,
+    Keyword::OWNED,
+    Keyword::OWNER,
+    Keyword::PARQUET,
+    Keyword::PASSING,
+    Keyword::PASSWORD,
+    Keyword::PATH,
+    Keyword::PEEK,
+    Keyword::PLAN,
+    Keyword::PRECEDING,
+    Keyword::QUARTER,
+    Keyword::REGCLASS,
+    Keyword::REGISTRY,
+    Keyword::REPEATABLE,
+    Keyword::REPLICATION,
+    Keyword::RESET,
+    Keyword::RESTART,
+    Keyword::ROLE,
+    Keyword::ROLES,
+    Keyword::ROWID,
+    Keyword::SEQUENCE,
+    Keyword::SEQUENCES,
+    Keyword::SERIALIZABLE,
+    Keyword::SESSION,
+    Keyword::SHARE,
+    Keyword::SINK,
+    Keyword::SINKS,
+    Keyword::SKIP,
+    Keyword::SOURCE,
+    Keyword::SOURCES,
+    Keyword::STDIN,
+    Keyword::STDOUT,
+    Keyword::STORED,
+    Keyword::SUPERUSER,
+    Keyword::TABLES,
+    Keyword::TAIL,
+    Keyword::TEMP,
+    Keyword::TEMPORARY,
+    Keyword::TEXT,
+    Keyword::TIES,
+    Keyword::TIMESTAMPTZ,
+    Keyword::TIMEZONE,
+    Keyword::TRANSACTION,
+    Keyword::TYPE,
+    Keyword::TYPES,
+    Keyword::UNBOUNDED,
+    Keyword::UNCOMMITTED,
+    Keyword::UNLISTEN,
+    Keyword::UNTIL,
+    Keyword::UUID,
+    Keyword::VALID,
+    Keyword::VERSION,
+    Keyword::VIEW,
+    Keyword::VIEWS,
+    Keyword::WEEK,
+    Keyword::WORK,
+    Keyword::ZONE,
+];
+
+/// The broad role of a keyword, as returned by [Keyword::category]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeywordCategory {
+    /// Can't be used as a table or column alias without quoting, see
+    /// [RESERVED_FOR_TABLE_ALIAS] and [RESERVED_FOR_COLUMN_ALIAS]
+    Reserved,
+    /// Names a data type, see [TYPE_NAME_KEYWORDS]
+    TypeName,
+    /// Is a function with special syntax, see [FUNCTION_KEYWORDS]
+    Function,
+    /// Any other keyword, which can be used as an identifier
+    NonReserved,
+}
+
+impl Keyword {
+    /// Returns the keyword as it is spelled in SQL text
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Keyword::NoKeyword => "",
+            _ => ALL_KEYWORDS[self as usize - 1],
+        }
+    }
+
+    /// Returns the category of the keyword. A keyword that falls into
+    /// several categories is placed in the first one listed in
+    /// [KeywordCategory].
+    pub fn category(self) -> KeywordCategory {
+        if RESERVED_FOR_TABLE_ALIAS.contains(&self) || RESERVED_FOR_COLUMN_ALIAS.contains(&self) {
+            KeywordCategory::Reserved
+        } else if TYPE_NAME_KEYWORDS.contains(&self) {
+            KeywordCategory::TypeName
+        } else if FUNCTION_KEYWORDS.contains(&self) {
+            KeywordCategory::Function
+        } else {
+            KeywordCategory::NonReserved
+        }
+    }
+}
+
+/// Returns the keywords of `category` that `dialect` recognizes, in
+/// alphabetical order
+pub fn dialect_keywords(dialect: &dyn Dialect, category: KeywordCategory) -> Vec<Keyword> {
+    ALL_KEYWORDS_INDEX
+        .iter()
+        .copied()
+        .filter(|kw| kw.category() == category && dialect.is_keyword(*kw))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_keywords_sorted() {
+        for w in ALL_KEYWORDS.windows(2) {
+            assert!(w[0] < w[1], "{} must be listed before {}", w[1], w[0]);
+        }
+        for (i, kw) in ALL_KEYWORDS_INDEX.iter().enumerate() {
+            assert_eq!(kw.as_str(), ALL_KEYWORDS[i]);
+        }
+    }
+
+    #[test]
+    fn keyword_categories() {
+        use crate::dialect::{PostgreSqlDialect, SnowflakeDialect};
+
+        assert_eq!(KeywordCategory::Reserved, Keyword::SELECT.category());
+        assert_eq!(KeywordCategory::Reserved, Keyword::FROM.category());
+        assert_eq!(KeywordCategory::TypeName, Keyword::VARCHAR.category());
+        assert_eq!(KeywordCategory::Function, Keyword::EXTRACT.category());
+        assert_eq!(KeywordCategory::NonReserved, Keyword::TABLE.category());
+
+        let postgres = dialect_keywords(&PostgreSqlDialect {}, KeywordCategory::Reserved);
+        let snowflake = dialect_keywords(&SnowflakeDialect {}, KeywordCategory::Reserved);
+        assert!(!postgres.contains(&Keyword::QUALIFY));
+        assert!(snowflake.contains(&Keyword::QUALIFY));
+        assert_eq!(postgres.len() + 1, snowflake.len());
+        assert_eq!(
+            TYPE_NAME_KEYWORDS,
+            &dialect_keywords(&PostgreSqlDialect {}, KeywordCategory::TypeName)[..]
+        );
+    }
+}
@@ -11,19 +11,41 @@
 // limitations under the License.
 
 mod ansi;
+mod bigquery;
+mod clickhouse;
+mod detect;
 mod generic;
+mod hive;
 pub mod keywords;
 mod mssql;
 mod mysql;
 mod postgresql;
+mod redshift;
+mod snowflake;
+mod sqlite;
+mod tables;
 
-use std::fmt::Debug;
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, string::String};
+use core::fmt::Debug;
+
+use crate::ast::{BinaryOperator, SetOperator};
+
+use self::keywords::{Keyword, DIALECT_SPECIFIC_KEYWORDS};
 
 pub use self::ansi::AnsiDialect;
+pub use self::bigquery::BigQueryDialect;
+pub use self::clickhouse::ClickHouseDialect;
+pub use self::detect::detect;
 pub use self::generic::GenericDialect;
+pub use self::hive::HiveDialect;
 pub use self::mssql::MsSqlDialect;
 pub use self::mysql::MySqlDialect;
 pub use self::postgresql::PostgreSqlDialect;
+pub use self::redshift::RedshiftSqlDialect;
+pub use self::snowflake::SnowflakeDialect;
+pub use self::sqlite::SqliteDialect;
+pub(crate) use self::tables::DialectTables;
 
 pub trait Dialect: Debug {
     /// Determine if a character starts a quoted identifier. The default
@@ -34,8 +56,147 @@ pub trait Dialect: Debug {
     fn is_delimited_identifier_start(&self, ch: char) -> bool {
         ch == '"'
     }
+    /// Determine if the periods in a quoted identifier separate the parts of
+    /// a multi-part name, as in BigQuery's `` `project.dataset.table` ``,
+    /// instead of being part of a single identifier
+    fn is_quoted_identifier_multipart(&self) -> bool {
+        false
+    }
+    /// Determine if a `#` that does not start an identifier begins a
+    /// single-line comment, as in MySQL, rather than a JSON operator
+    fn supports_hash_comments(&self) -> bool {
+        false
+    }
+    /// Return the binding power of a set operator in a query body, where
+    /// higher values bind more tightly. The default follows the SQL
+    /// standard, in which INTERSECT binds more tightly than UNION and EXCEPT.
+    fn set_operator_precedence(&self, op: &SetOperator) -> u8 {
+        op.precedence()
+    }
+    /// Return the binding power of a binary operator in an expression, where
+    /// higher values bind more tightly. Keyword operators such as `IS`, `IN`
+    /// and `LIKE` have fixed binding powers between those of `AND` (10) and
+    /// `+` (30).
+    fn binary_operator_precedence(&self, op: &BinaryOperator) -> u8 {
+        op.precedence()
+    }
+    /// Determine if `||` is the logical OR operator, as in MySQL, rather
+    /// than string concatenation
+    fn supports_pipes_as_or(&self) -> bool {
+        false
+    }
+    /// Determine if `^` is bitwise XOR, as in MySQL, rather than
+    /// exponentiation
+    fn supports_caret_as_xor(&self) -> bool {
+        false
+    }
+    /// Determine if an integer in `GROUP BY` refers to an item of the select
+    /// list by its position, as it does in most dialects, rather than being
+    /// a constant
+    fn supports_group_by_ordinals(&self) -> bool {
+        true
+    }
+    /// Determine if a backslash in a string literal begins an escape
+    /// sequence, as in MySQL's `'it\'s'`, rather than standing for itself
+    fn supports_backslash_escapes(&self) -> bool {
+        false
+    }
+    /// Determine if string literals separated only by whitespace or
+    /// comments, like `'foo' 'bar'`, form a single literal, as in the SQL
+    /// standard. Some dialects instead treat the second literal as an alias.
+    fn supports_string_literal_concatenation(&self) -> bool {
+        false
+    }
+    /// Determine if [concatenated](Dialect::supports_string_literal_concatenation)
+    /// string literals must be separated by at least one line break, as in
+    /// the SQL standard and PostgreSQL, so that `'foo' 'bar'` on a single
+    /// line is not a single literal
+    fn string_literal_concatenation_requires_newline(&self) -> bool {
+        true
+    }
+    /// Determine if `INTERVAL` may be followed by an arbitrary expression
+    /// instead of a string literal, as in MySQL's `INTERVAL 1 DAY`
+    fn supports_interval_expressions(&self) -> bool {
+        false
+    }
+    /// Determine if a colon after an expression accesses a path in a
+    /// semi-structured value, as in Snowflake's `src:customer[0].name`
+    fn supports_variant_access(&self) -> bool {
+        false
+    }
+    /// Determine if `CONVERT` takes the target type before the expression,
+    /// as in `CONVERT(VARCHAR, x)`, rather than after it
+    fn convert_type_before_value(&self) -> bool {
+        false
+    }
+    /// Determine if an unquoted word spelled like `keyword` is tokenized as
+    /// that keyword. Words that are not are plain identifiers, so they can't
+    /// introduce the constructs the keyword would, but can be used as names
+    /// or aliases anywhere. By default, every keyword except the
+    /// [dialect-specific ones](keywords::DIALECT_SPECIFIC_KEYWORDS) is
+    /// recognized.
+    fn is_keyword(&self, keyword: Keyword) -> bool {
+        !DIALECT_SPECIFIC_KEYWORDS.contains(&keyword)
+    }
+    /// Return the name an unquoted identifier spelled `ident` refers to.
+    /// The default follows the SQL standard, which folds unquoted
+    /// identifiers to upper case. Only ASCII letters are folded, as
+    /// Unicode case mappings depend on the locale.
+    fn fold_identifier_case(&self, ident: &str) -> String {
+        ident.to_ascii_uppercase()
+    }
+    /// Return the largest precision a `DECIMAL` or `NUMERIC` type may have
+    /// in this dialect, or `None`, the default, for no limit. The parser
+    /// rejects types with a larger precision.
+    fn max_decimal_precision(&self) -> Option<u64> {
+        None
+    }
+    /// Return the names, in any case, of the kinds of objects that
+    /// `SHOW <objects>` lists in this dialect besides those this crate knows
+    /// about, like `SECRETS`. Such a statement is parsed as a
+    /// [ShowObjects](crate::ast::Statement::ShowObjects) of an
+    /// [ObjectType::Custom](crate::ast::ObjectType::Custom), while `SHOW`
+    /// followed by any other unknown name shows a variable.
+    fn show_object_types(&self) -> &[&str] {
+        &[]
+    }
     /// Determine if a character is a valid start character for an unquoted identifier
     fn is_identifier_start(&self, ch: char) -> bool;
     /// Determine if a character is a valid unquoted identifier character
     fn is_identifier_part(&self, ch: char) -> bool;
 }
+
+/// The names accepted by [dialect_from_str], one per dialect
+pub const DIALECT_NAMES: &[&str] = &[
+    "ansi",
+    "bigquery",
+    "clickhouse",
+    "generic",
+    "hive",
+    "mssql",
+    "mysql",
+    "postgres",
+    "redshift",
+    "snowflake",
+    "sqlite",
+];
+
+/// Return the dialect called `name`, ignoring case, for selecting a dialect
+/// from configuration. Besides the [DIALECT_NAMES], `postgresql` is
+/// accepted for Postgres, and `sparksql` for Hive.
+pub fn dialect_from_str(name: &str) -> Option<Box<dyn Dialect + Send + Sync>> {
+    match name.to_ascii_lowercase().as_str() {
+        "ansi" => Some(Box::new(AnsiDialect {})),
+        "bigquery" => Some(Box::new(BigQueryDialect {})),
+        "clickhouse" => Some(Box::new(ClickHouseDialect {})),
+        "generic" => Some(Box::new(GenericDialect {})),
+        "hive" | "sparksql" => Some(Box::new(HiveDialect {})),
+        "mssql" => Some(Box::new(MsSqlDialect {})),
+        "mysql" => Some(Box::new(MySqlDialect {})),
+        "postgres" | "postgresql" => Some(Box::new(PostgreSqlDialect {})),
+        "redshift" => Some(Box::new(RedshiftSqlDialect {})),
+        "snowflake" => Some(Box::new(SnowflakeDialect {})),
+        "sqlite" => Some(Box::new(SqliteDialect {})),
+        _ => None,
+    }
+}
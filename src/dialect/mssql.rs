@@ -10,6 +10,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+use crate::ast::BinaryOperator;
+use crate::dialect::keywords::{Keyword, DIALECT_SPECIFIC_KEYWORDS};
 use crate::dialect::Dialect;
 
 #[derive(Debug)]
@@ -20,6 +25,19 @@ impl Dialect for MsSqlDialect {
         ch == '"' || ch == '['
     }
 
+    // MS SQL only accepts ordinals in ORDER BY
+    fn supports_group_by_ordinals(&self) -> bool {
+        false
+    }
+
+    fn convert_type_before_value(&self) -> bool {
+        true
+    }
+
+    fn is_keyword(&self, keyword: Keyword) -> bool {
+        keyword == Keyword::OUTPUT || !DIALECT_SPECIFIC_KEYWORDS.contains(&keyword)
+    }
+
     fn is_identifier_start(&self, ch: char) -> bool {
         // See https://docs.microsoft.com/en-us/sql/relational-databases/databases/database-identifiers?view=sql-server-2017#rules-for-regular-identifiers
         // We don't support non-latin "letters" currently.
@@ -35,4 +53,27 @@ impl Dialect for MsSqlDialect {
             || ch == '#'
             || ch == '_'
     }
+
+    fn max_decimal_precision(&self) -> Option<u64> {
+        Some(38)
+    }
+
+    fn supports_caret_as_xor(&self) -> bool {
+        true
+    }
+
+    fn binary_operator_precedence(&self, op: &BinaryOperator) -> u8 {
+        match op {
+            // See https://docs.microsoft.com/en-us/sql/t-sql/language-elements/operator-precedence-transact-sql:
+            // `^` shares the precedence of `+` and `-`
+            BinaryOperator::BitwiseXor => BinaryOperator::Plus.precedence(),
+            _ => op.precedence(),
+        }
+    }
+
+    // Unquoted identifiers keep their case, and are compared according to the
+    // database collation
+    fn fold_identifier_case(&self, ident: &str) -> String {
+        String::from(ident)
+    }
 }
@@ -10,6 +10,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
 use crate::dialect::Dialect;
 
 #[derive(Debug)]
@@ -30,4 +33,43 @@ impl Dialect for MySqlDialect {
     fn is_identifier_part(&self, ch: char) -> bool {
         self.is_identifier_start(ch) || (ch >= '0' && ch <= '9')
     }
+
+    fn supports_hash_comments(&self) -> bool {
+        true
+    }
+
+    fn supports_backslash_escapes(&self) -> bool {
+        true
+    }
+
+    fn supports_string_literal_concatenation(&self) -> bool {
+        true
+    }
+
+    fn string_literal_concatenation_requires_newline(&self) -> bool {
+        false
+    }
+
+    fn supports_interval_expressions(&self) -> bool {
+        true
+    }
+
+    fn supports_pipes_as_or(&self) -> bool {
+        // Without the PIPES_AS_CONCAT SQL mode, `||` is a synonym for OR
+        true
+    }
+
+    fn supports_caret_as_xor(&self) -> bool {
+        true
+    }
+
+    fn max_decimal_precision(&self) -> Option<u64> {
+        Some(65)
+    }
+
+    // Unquoted identifiers keep their case; whether it matters depends on the
+    // object type and the server's `lower_case_table_names` setting
+    fn fold_identifier_case(&self, ident: &str) -> String {
+        String::from(ident)
+    }
 }
@@ -10,6 +10,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+use crate::ast::BinaryOperator;
 use crate::dialect::Dialect;
 
 #[derive(Debug)]
@@ -30,4 +34,39 @@ impl Dialect for PostgreSqlDialect {
             || ch == '$'
             || ch == '_'
     }
+
+    fn supports_string_literal_concatenation(&self) -> bool {
+        true
+    }
+
+    fn binary_operator_precedence(&self, op: &BinaryOperator) -> u8 {
+        match op {
+            // See https://www.postgresql.org/docs/current/sql-syntax-lexical.html#SQL-PRECEDENCE:
+            // operators without a precedence of their own, which includes
+            // `||` and the JSON operators, bind more tightly than comparisons,
+            // `LIKE`, `BETWEEN` and `IN`, but less tightly than `+` and `-`
+            BinaryOperator::JsonGet
+            | BinaryOperator::JsonGetAsText
+            | BinaryOperator::JsonGetPath
+            | BinaryOperator::JsonGetPathAsText
+            | BinaryOperator::JsonContainsJson
+            | BinaryOperator::JsonContainedInJson
+            | BinaryOperator::JsonContainsField
+            | BinaryOperator::JsonContainsAnyFields
+            | BinaryOperator::JsonContainsAllFields
+            | BinaryOperator::JsonConcat
+            | BinaryOperator::JsonDeletePath
+            | BinaryOperator::JsonContainsPath
+            | BinaryOperator::JsonApplyPathPredicate => 25,
+            _ => op.precedence(),
+        }
+    }
+
+    fn max_decimal_precision(&self) -> Option<u64> {
+        Some(1000)
+    }
+
+    fn fold_identifier_case(&self, ident: &str) -> String {
+        ident.to_ascii_lowercase()
+    }
 }
@@ -0,0 +1,50 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+use crate::dialect::Dialect;
+
+#[derive(Debug)]
+pub struct RedshiftSqlDialect {}
+
+impl Dialect for RedshiftSqlDialect {
+    fn is_identifier_start(&self, ch: char) -> bool {
+        // See https://docs.aws.amazon.com/redshift/latest/dg/r_names.html.
+        // Temporary table names may start with `#`, so here `#` begins an
+        // identifier instead of a JSON operator.
+        ch.is_ascii_alphabetic()
+            || ch == '_'
+            || ch == '#'
+            || ('\u{0080}'..='\u{ffff}').contains(&ch)
+    }
+
+    fn is_identifier_part(&self, ch: char) -> bool {
+        ch.is_ascii_alphanumeric()
+            || ch == '_'
+            || ch == '$'
+            || ('\u{0080}'..='\u{ffff}').contains(&ch)
+    }
+
+    fn supports_string_literal_concatenation(&self) -> bool {
+        true
+    }
+
+    fn max_decimal_precision(&self) -> Option<u64> {
+        Some(38)
+    }
+
+    fn fold_identifier_case(&self, ident: &str) -> String {
+        ident.to_ascii_lowercase()
+    }
+}
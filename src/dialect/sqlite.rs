@@ -0,0 +1,51 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+use crate::ast::SetOperator;
+use crate::dialect::Dialect;
+
+#[derive(Debug)]
+pub struct SqliteDialect {}
+
+impl Dialect for SqliteDialect {
+    // See https://www.sqlite.org/lang_keywords.html: SQLite accepts "...",
+    // `...` (as MySQL does) and [...] (as MS SQL does) as quoted identifiers.
+    fn is_delimited_identifier_start(&self, ch: char) -> bool {
+        ch == '`' || ch == '"' || ch == '['
+    }
+
+    // See https://www.sqlite.org/lang_select.html#compound_select_statements:
+    // all compound operators have equal precedence.
+    fn set_operator_precedence(&self, _op: &SetOperator) -> u8 {
+        10
+    }
+
+    fn is_identifier_start(&self, ch: char) -> bool {
+        // See https://www.sqlite.org/draft/tokenreq.html
+        ch.is_ascii_alphabetic()
+            || ch == '_'
+            || ch == '$'
+            || ('\u{007f}'..='\u{ffff}').contains(&ch)
+    }
+
+    fn is_identifier_part(&self, ch: char) -> bool {
+        self.is_identifier_start(ch) || ch.is_ascii_digit()
+    }
+
+    // Unquoted identifiers keep their case, but are compared case-insensitively
+    fn fold_identifier_case(&self, ident: &str) -> String {
+        String::from(ident)
+    }
+}
@@ -0,0 +1,152 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::keywords::{Keyword, ALL_KEYWORDS_INDEX};
+use super::Dialect;
+use crate::ast::{BinaryOperator, SetOperator};
+
+/// The answers of a dialect's table-like methods, computed once so that the
+/// tokenizer and parser look them up instead of calling the dialect for
+/// every token
+#[derive(Debug)]
+pub(crate) struct DialectTables {
+    /// Whether each keyword is recognized, indexed by its discriminant
+    keywords: Vec<bool>,
+    /// The binding power of each binary operator, indexed by its
+    /// discriminant
+    binary_operators: Vec<u8>,
+    /// The binding power of each set operator, indexed by its discriminant
+    set_operators: Vec<u8>,
+}
+
+impl DialectTables {
+    pub(crate) fn new(dialect: &dyn Dialect) -> Self {
+        let mut keywords = Vec::with_capacity(ALL_KEYWORDS_INDEX.len() + 1);
+        keywords.push(dialect.is_keyword(Keyword::NoKeyword));
+        keywords.extend(ALL_KEYWORDS_INDEX.iter().map(|kw| dialect.is_keyword(*kw)));
+        DialectTables {
+            keywords,
+            binary_operators: BinaryOperator::ALL
+                .iter()
+                .map(|op| dialect.binary_operator_precedence(op))
+                .collect(),
+            set_operators: SetOperator::ALL
+                .iter()
+                .map(|op| dialect.set_operator_precedence(op))
+                .collect(),
+        }
+    }
+
+    /// See [Dialect::is_keyword]
+    pub(crate) fn is_keyword(&self, keyword: Keyword) -> bool {
+        self.keywords[keyword as usize]
+    }
+
+    /// See [Dialect::binary_operator_precedence]
+    pub(crate) fn binary_operator_precedence(&self, op: &BinaryOperator) -> u8 {
+        self.binary_operators[op.clone() as usize]
+    }
+
+    /// See [Dialect::set_operator_precedence]
+    pub(crate) fn set_operator_precedence(&self, op: &SetOperator) -> u8 {
+        self.set_operators[op.clone() as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialect::{dialect_from_str, DIALECT_NAMES};
+
+    #[test]
+    fn operators_are_listed_in_order() {
+        for (i, op) in BinaryOperator::ALL.iter().enumerate() {
+            assert_eq!(i, op.clone() as usize);
+            // Stops compiling when an operator is added, which must also be
+            // added to `BinaryOperator::ALL`
+            match op {
+                BinaryOperator::Plus
+                | BinaryOperator::Minus
+                | BinaryOperator::Multiply
+                | BinaryOperator::Divide
+                | BinaryOperator::Modulus
+                | BinaryOperator::Gt
+                | BinaryOperator::Lt
+                | BinaryOperator::GtEq
+                | BinaryOperator::LtEq
+                | BinaryOperator::Eq
+                | BinaryOperator::NotEq
+                | BinaryOperator::And
+                | BinaryOperator::Or
+                | BinaryOperator::JsonGet
+                | BinaryOperator::JsonGetAsText
+                | BinaryOperator::JsonGetPath
+                | BinaryOperator::JsonGetPathAsText
+                | BinaryOperator::JsonContainsJson
+                | BinaryOperator::JsonContainedInJson
+                | BinaryOperator::JsonContainsField
+                | BinaryOperator::JsonContainsAnyFields
+                | BinaryOperator::JsonContainsAllFields
+                | BinaryOperator::JsonConcat
+                | BinaryOperator::JsonDeletePath
+                | BinaryOperator::JsonContainsPath
+                | BinaryOperator::JsonApplyPathPredicate
+                | BinaryOperator::Exponent
+                | BinaryOperator::BitwiseXor => (),
+            }
+        }
+        for (i, op) in SetOperator::ALL.iter().enumerate() {
+            assert_eq!(i, op.clone() as usize);
+            match op {
+                SetOperator::Union | SetOperator::Except | SetOperator::Intersect => (),
+            }
+        }
+    }
+
+    #[test]
+    fn tables_match_their_dialect() {
+        for name in DIALECT_NAMES {
+            let dialect = dialect_from_str(name).unwrap();
+            let tables = DialectTables::new(&*dialect);
+            for keyword in ALL_KEYWORDS_INDEX {
+                assert_eq!(
+                    dialect.is_keyword(*keyword),
+                    tables.is_keyword(*keyword),
+                    "{} {:?}",
+                    name,
+                    keyword
+                );
+            }
+            for op in BinaryOperator::ALL {
+                assert_eq!(
+                    dialect.binary_operator_precedence(op),
+                    tables.binary_operator_precedence(op),
+                    "{} {:?}",
+                    name,
+                    op
+                );
+            }
+            for op in SetOperator::ALL {
+                assert_eq!(
+                    dialect.set_operator_precedence(op),
+                    tables.set_operator_precedence(op),
+                    "{} {:?}",
+                    name,
+                    op
+                );
+            }
+        }
+    }
+}
@@ -32,9 +32,15 @@
 //!
 //! println!("AST: {:?}", ast);
 //! ```
+// Unit tests use the standard library even in `no_std` builds
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![warn(clippy::all)]
 #![allow(clippy::unneeded_field_pattern)]
 
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+pub mod analysis;
 pub mod ast;
 pub mod dialect;
 pub mod parser;
@@ -12,14 +12,22 @@
 
 //! SQL Parser
 
+#[cfg(not(feature = "std"))]
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
 use log::debug;
 
 use super::ast::*;
-use super::dialect::keywords;
-use super::dialect::Dialect;
+use super::dialect::keywords::{self, Keyword};
+use super::dialect::{Dialect, DialectTables, GenericDialect};
 use super::tokenizer::*;
-use std::error::Error;
-use std::fmt;
+use core::{fmt, mem, str::FromStr};
 
 use crate::ast::{ParsedDate, ParsedTimestamp};
 
@@ -33,11 +41,21 @@ macro_rules! parser_err {
     };
 }
 
+mod builder;
 mod datetime;
+#[cfg(test)]
+mod round_trip;
+#[cfg(feature = "std")]
+mod stream;
+
+pub use self::builder::ParserBuilder;
+#[cfg(feature = "std")]
+pub use self::stream::ParseFileError;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum ParserError {
-    TokenizerError(String),
+    /// The query could not be tokenized, at the position the error records
+    TokenizerError(TokenizerError),
     ParserError(String),
 }
 
@@ -56,43 +74,309 @@ use IsLateral::*;
 
 impl From<TokenizerError> for ParserError {
     fn from(e: TokenizerError) -> Self {
-        ParserError::TokenizerError(format!("{}", e))
+        ParserError::TokenizerError(e)
     }
 }
 
 impl fmt::Display for ParserError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "sql parser error: {}",
-            match self {
-                ParserError::TokenizerError(s) => s,
-                ParserError::ParserError(s) => s,
+        match self {
+            ParserError::TokenizerError(e) => write!(f, "sql parser error: {}", e),
+            ParserError::ParserError(s) => write!(f, "sql parser error: {}", s),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParserError {}
+
+/// The rows of a COPY payload, parsed a line at a time
+pub(crate) struct CopyRows {
+    format: CopyFormat,
+    rows: Vec<Vec<Option<String>>>,
+    /// The values of the CSV record being parsed, which may span lines
+    values: Vec<Option<String>>,
+    value: String,
+    /// Whether the current value was quoted, so that it isn't NULL
+    quoted: bool,
+    in_quotes: bool,
+}
+
+impl CopyRows {
+    pub fn new(format: CopyFormat) -> Self {
+        CopyRows {
+            format,
+            rows: vec![],
+            values: vec![],
+            value: String::new(),
+            quoted: false,
+            in_quotes: false,
+        }
+    }
+
+    /// Parse the rows of `payload`, the text of a [Token::CopyPayload]
+    pub fn parse(
+        format: CopyFormat,
+        payload: &str,
+    ) -> Result<Vec<Vec<Option<String>>>, ParserError> {
+        let mut rows = CopyRows::new(format);
+        let lines: Vec<&str> = payload
+            .split('\n')
+            .map(|line| line.strip_suffix('\r').unwrap_or(line))
+            .collect();
+        let (last, lines) = lines.split_last().expect("split returns a line");
+        for (i, line) in lines.iter().enumerate() {
+            // The rows start on the line after the `;`
+            if i > 0 || !line.trim().is_empty() {
+                rows.push_line(line);
+            }
+        }
+        // The last line is the `\.` that ends the rows, or is empty after
+        // the last row, unless a quoted CSV value continues on it
+        let ended = *last == "\\." || (last.is_empty() && !rows.in_quotes);
+        let blank = lines.is_empty() && last.trim().is_empty();
+        if !(ended || blank) {
+            rows.push_line(last);
+        }
+        rows.finish()
+    }
+
+    /// Parse a line of the payload. A CSV row continues on the next line
+    /// while a quoted value is open.
+    pub fn push_line(&mut self, line: &str) {
+        let format = &self.format;
+        if format.name != CopyFormatName::Csv {
+            self.rows.push(
+                Parser::split_text_line(line, format.delimiter)
+                    .into_iter()
+                    .map(|value| Parser::parse_tab_value(value, &format.null))
+                    .collect(),
+            );
+            return;
+        }
+        if self.in_quotes {
+            self.value.push('\n');
+        }
+        let mut chars = line.chars().peekable();
+        while let Some(ch) = chars.next() {
+            if self.in_quotes {
+                let next = chars.peek().copied();
+                if ch == self.format.escape
+                    && (next == Some(self.format.quote) || next == Some(self.format.escape))
+                {
+                    self.value.push(chars.next().unwrap());
+                } else if ch == self.format.quote {
+                    self.in_quotes = false;
+                } else {
+                    self.value.push(ch);
+                }
+            } else if ch == self.format.quote {
+                self.in_quotes = true;
+                self.quoted = true;
+            } else if ch == self.format.delimiter {
+                self.end_value();
+            } else {
+                self.value.push(ch);
             }
-        )
+        }
+        if !self.in_quotes {
+            self.end_value();
+            self.rows.push(mem::take(&mut self.values));
+        }
+    }
+
+    fn end_value(&mut self) {
+        let value = mem::take(&mut self.value);
+        if !self.quoted && value == self.format.null {
+            self.values.push(None);
+        } else {
+            self.values.push(Some(value));
+        }
+        self.quoted = false;
+    }
+
+    /// Take the rows parsed so far
+    pub fn take(&mut self) -> Vec<Vec<Option<String>>> {
+        mem::take(&mut self.rows)
     }
+
+    /// Return the rows not yet taken, once every line has been pushed
+    pub fn finish(mut self) -> Result<Vec<Vec<Option<String>>>, ParserError> {
+        if self.in_quotes {
+            return parser_err!("Unterminated quoted CSV value in COPY payload");
+        }
+        Ok(self.take())
+    }
+}
+
+/// What the parser expected at the furthest point it failed at, so that the
+/// error reported after backtracking describes the alternative that got
+/// furthest
+#[derive(Debug, Clone, PartialEq)]
+struct Expectation {
+    /// The index of the unexpected token in `Parser::tokens`
+    index: usize,
+    expected: Vec<String>,
+    found: Option<Token>,
+}
+
+impl Expectation {
+    fn to_error(&self) -> ParserError {
+        let expected = match self.expected.split_last() {
+            // The alternatives may themselves be lists, so always separate
+            // the last one with a comma
+            Some((last, rest)) if !rest.is_empty() => format!("{}, or {}", rest.join(", "), last),
+            _ => self.expected.join(""),
+        };
+        ParserError::ParserError(format!(
+            "Expected {}, found: {}",
+            expected,
+            self.found
+                .as_ref()
+                .map_or_else(|| "EOF".to_string(), |t| format!("{}", t))
+        ))
+    }
+}
+
+/// How [Parser::parse_sql_with_options] treats psql meta-commands, like
+/// `\connect db`, which start with a backslash at the beginning of a line and
+/// run to its end
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MetaCommands {
+    /// Fail to parse them, like any other unexpected input
+    Reject,
+    /// Ignore them, along with a `#!` line at the start of the input
+    Skip,
+    /// Return each as a [Statement::MetaCommand], ignoring a `#!` line at
+    /// the start of the input
+    Capture,
 }
 
-impl Error for ParserError {}
+/// Options for [Parser::parse_sql_with_options]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseOptions {
+    /// Whether to accept the meta-commands of psql scripts
+    pub meta_commands: MetaCommands,
+    /// The limits on the size of the input, none by default
+    pub limits: TokenizerLimits,
+    /// Whether to keep the text of built-in data types that are written
+    /// differently from how they are displayed, like `INTEGER` or
+    /// `CHARACTER VARYING(10)`, in [DataType::Spelled], so that displaying
+    /// the statements reproduces it
+    pub preserve_type_names: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            meta_commands: MetaCommands::Reject,
+            limits: TokenizerLimits::default(),
+            preserve_type_names: false,
+        }
+    }
+}
+
+/// A parser method, as accepted by [Parser::parse_alternatives]
+pub type ParseFn<'a, T> = fn(&mut Parser<'a>) -> Result<T, ParserError>;
 
 /// SQL Parser
-pub struct Parser {
+pub struct Parser<'a> {
     tokens: Vec<Token>,
+    /// The location of each token in `self.tokens`, if known
+    spans: Vec<Span>,
     /// The index of the first unprocessed token in `self.tokens`
     index: usize,
+    dialect: &'a dyn Dialect,
+    /// The dialect's precomputed tables, if any
+    tables: Option<&'a DialectTables>,
+    /// The furthest failed expectation of the alternative being parsed by
+    /// [Parser::parse_alternatives], if any
+    expectation: Option<Expectation>,
+    /// See [ParseOptions::preserve_type_names]
+    preserve_type_names: bool,
 }
 
-impl Parser {
-    /// Parse the specified tokens
-    pub fn new(tokens: Vec<Token>) -> Self {
-        Parser { tokens, index: 0 }
+impl<'a> Parser<'a> {
+    /// Parse the specified tokens, using the syntax of [GenericDialect]
+    #[deprecated(
+        note = "use `Parser::new_with_dialect` with the dialect the tokens were produced with"
+    )]
+    pub fn new(tokens: Vec<Token>) -> Parser<'static> {
+        Parser::new_with_dialect(tokens, &GenericDialect {})
+    }
+
+    /// Parse the specified tokens, using the syntax of `dialect` where
+    /// dialects differ in ways the tokenizer can't express
+    pub fn new_with_dialect(tokens: Vec<Token>, dialect: &'a dyn Dialect) -> Self {
+        Parser {
+            tokens,
+            spans: Vec::new(),
+            index: 0,
+            dialect,
+            tables: None,
+            expectation: None,
+            preserve_type_names: false,
+        }
+    }
+
+    /// Like [Parser::new_with_dialect], but with the location of each token,
+    /// as returned by [Tokenizer::tokenize_with_spans], so that the parser
+    /// can record where some nodes were written
+    pub fn new_with_spans(tokens: Vec<(Token, Span)>, dialect: &'a dyn Dialect) -> Self {
+        let (tokens, spans) = tokens.into_iter().unzip();
+        Parser {
+            tokens,
+            spans,
+            index: 0,
+            dialect,
+            tables: None,
+            expectation: None,
+            preserve_type_names: false,
+        }
+    }
+
+    /// Return the dialect whose syntax this parser follows
+    pub fn dialect(&self) -> &'a dyn Dialect {
+        self.dialect
     }
 
     /// Parse a SQL statement and produce an Abstract Syntax Tree (AST)
     pub fn parse_sql(dialect: &dyn Dialect, sql: String) -> Result<Vec<Statement>, ParserError> {
-        let mut tokenizer = Tokenizer::new(dialect, &sql);
-        let tokens = tokenizer.tokenize()?;
-        let mut parser = Parser::new(tokens);
+        Parser::parse_sql_with_options(dialect, sql, &ParseOptions::default())
+    }
+
+    /// Like [Parser::parse_sql], but with non-default [ParseOptions]
+    pub fn parse_sql_with_options(
+        dialect: &dyn Dialect,
+        sql: String,
+        options: &ParseOptions,
+    ) -> Result<Vec<Statement>, ParserError> {
+        Parser::parse_sql_with_tables(dialect, None, sql, options)
+    }
+
+    /// Like [Parser::parse_sql_with_options], but looking up keywords and
+    /// operator precedences in `tables`, which must have been computed from
+    /// `dialect`, if given
+    pub(crate) fn parse_sql_with_tables(
+        dialect: &dyn Dialect,
+        tables: Option<&DialectTables>,
+        mut sql: String,
+        options: &ParseOptions,
+    ) -> Result<Vec<Statement>, ParserError> {
+        if options.meta_commands != MetaCommands::Reject && sql.starts_with("#!") {
+            // Blank out the line rather than removing it, so that the
+            // locations in tokenizer errors stay accurate
+            let end = sql.find(['\n', '\r']).unwrap_or(sql.len());
+            sql.replace_range(..end, &" ".repeat(end));
+        }
+        let mut tokenizer = Tokenizer::new(dialect, &sql).with_limits(options.limits);
+        if let Some(tables) = tables {
+            tokenizer = tokenizer.with_tables(tables);
+        }
+        let tokens = tokenizer.tokenize_with_spans()?;
+        let mut parser = Parser::new_with_spans(tokens, dialect);
+        parser.tables = tables;
+        parser.preserve_type_names = options.preserve_type_names;
         let mut stmts = Vec::new();
         let mut expecting_statement_delimiter = false;
         debug!("Parsing sql '{}'...", sql);
@@ -108,48 +392,188 @@ impl Parser {
                 return parser.expected("end of statement", parser.peek_token());
             }
 
+            if options.meta_commands != MetaCommands::Reject && parser.at_meta_command() {
+                let command = parser.parse_meta_command();
+                if options.meta_commands == MetaCommands::Capture {
+                    stmts.push(Statement::MetaCommand(command));
+                }
+                continue;
+            }
+
             let statement = parser.parse_statement()?;
+            // The semicolon of a COPY comes before its payload, so the
+            // statement is already delimited, as in pg_dump's output
+            expecting_statement_delimiter = !statement.has_copy_payload();
             stmts.push(statement);
-            expecting_statement_delimiter = true;
         }
         Ok(stmts)
     }
 
+    /// Return the binding power of `op`, as [Dialect::binary_operator_precedence]
+    fn binary_operator_precedence(&self, op: &BinaryOperator) -> u8 {
+        match self.tables {
+            Some(tables) => tables.binary_operator_precedence(op),
+            None => self.dialect.binary_operator_precedence(op),
+        }
+    }
+
+    /// Return the binding power of `op`, as [Dialect::set_operator_precedence]
+    fn set_operator_precedence(&self, op: &SetOperator) -> u8 {
+        match self.tables {
+            Some(tables) => tables.set_operator_precedence(op),
+            None => self.dialect.set_operator_precedence(op),
+        }
+    }
+
+    /// Return the span of the tokens consumed since `self.index` was `start`,
+    /// leaving out surrounding whitespace, or `None` if no token was
+    /// consumed or the tokens' locations aren't known
+    fn span_since(&self, start: usize) -> Option<Span> {
+        if self.spans.is_empty() {
+            return None;
+        }
+        // Reading past the last token still advances the index
+        let end = self.index.min(self.tokens.len());
+        let is_syntax = |i: &usize| !matches!(self.tokens[*i], Token::Whitespace(_));
+        let first = (start..end).find(is_syntax)?;
+        let last = (start..end).rev().find(is_syntax)?;
+        Some(Span {
+            start: self.spans[first].start,
+            end: self.spans[last].end,
+        })
+    }
+
+    /// Return the text of the tokens consumed since `self.index` was `start`,
+    /// leaving out surrounding whitespace and replacing whitespace and
+    /// comments between them with a single space
+    fn text_since(&self, start: usize) -> String {
+        let end = self.index.min(self.tokens.len());
+        let mut text = String::new();
+        let mut space = false;
+        for token in &self.tokens[start..end] {
+            match token {
+                Token::Whitespace(_) => space = !text.is_empty(),
+                token => {
+                    if space {
+                        text.push(' ');
+                        space = false;
+                    }
+                    text.push_str(&token.to_string());
+                }
+            }
+        }
+        text
+    }
+
+    /// Report whether the next token is a backslash at the beginning of a
+    /// line, which starts a psql meta-command
+    fn at_meta_command(&self) -> bool {
+        let mut index = self.index;
+        while let Some(Token::Whitespace(_)) = self.tokens.get(index) {
+            index += 1;
+        }
+        if self.tokens.get(index) != Some(&Token::Backslash) {
+            return false;
+        }
+        // A single-line comment includes the line break that ends it
+        self.tokens[..index]
+            .iter()
+            .rev()
+            .take_while(|t| {
+                !matches!(
+                    t,
+                    Token::Whitespace(Whitespace::Newline)
+                        | Token::Whitespace(Whitespace::SingleLineComment(_))
+                )
+            })
+            .all(|t| {
+                matches!(
+                    t,
+                    Token::Whitespace(Whitespace::Space) | Token::Whitespace(Whitespace::Tab)
+                )
+            })
+    }
+
+    /// Parse a psql meta-command up to the end of its line, returning its
+    /// original text
+    fn parse_meta_command(&mut self) -> String {
+        let mut command = String::new();
+        // Skip the indentation before the backslash
+        while let Some(Token::Whitespace(_)) = self.tokens.get(self.index) {
+            self.index += 1;
+        }
+        while let Some(t) = self.tokens.get(self.index) {
+            match t {
+                Token::Whitespace(Whitespace::Newline) => break,
+                // The comment includes the line break that ends it
+                Token::Whitespace(Whitespace::SingleLineComment(_)) => {
+                    self.index += 1;
+                    break;
+                }
+                _ => {
+                    command.push_str(&t.to_string());
+                    self.index += 1;
+                }
+            }
+        }
+        command.truncate(command.trim_end().len());
+        command
+    }
+
     /// Parse a single top-level statement (such as SELECT, INSERT, CREATE, etc.),
     /// stopping before the statement separator, if any.
     pub fn parse_statement(&mut self) -> Result<Statement, ParserError> {
         match self.next_token() {
             Some(t) => match t {
-                Token::Word(ref w) if w.keyword != "" => match w.keyword.as_ref() {
-                    "SELECT" | "WITH" | "VALUES" => {
+                Token::Word(ref w) if w.keyword != Keyword::NoKeyword => match w.keyword {
+                    Keyword::SELECT | Keyword::WITH | Keyword::VALUES => {
                         self.prev_token();
                         Ok(Statement::Query(Box::new(self.parse_query()?)))
                     }
-                    "CREATE" => Ok(self.parse_create()?),
-                    "DROP" => Ok(self.parse_drop()?),
-                    "DELETE" => Ok(self.parse_delete()?),
-                    "INSERT" => Ok(self.parse_insert()?),
-                    "UPDATE" => Ok(self.parse_update()?),
-                    "ALTER" => Ok(self.parse_alter()?),
-                    "COPY" => Ok(self.parse_copy()?),
-                    "SET" => Ok(self.parse_set()?),
-                    "SHOW" => Ok(self.parse_show()?),
-                    "START" => Ok(self.parse_start_transaction()?),
+                    Keyword::CREATE => Ok(self.parse_create()?),
+                    Keyword::DROP => Ok(self.parse_drop()?),
+                    Keyword::DELETE => Ok(self.parse_delete()?),
+                    Keyword::INSERT => Ok(self.parse_insert()?),
+                    Keyword::REPLACE => Ok(self.parse_replace()?),
+                    Keyword::UPDATE => Ok(self.parse_update()?),
+                    Keyword::ALTER => Ok(self.parse_alter()?),
+                    Keyword::COPY => Ok(self.parse_copy()?),
+                    Keyword::SET => Ok(self.parse_set()?),
+                    Keyword::SHOW => Ok(self.parse_show()?),
+                    Keyword::START => Ok(self.parse_start_transaction()?),
                     // `BEGIN` is a nonstandard but common alias for the
                     // standard `START TRANSACTION` statement. It is supported
                     // by at least PostgreSQL and MySQL.
-                    "BEGIN" => Ok(self.parse_begin()?),
-                    "COMMIT" => Ok(self.parse_commit()?),
-                    "ROLLBACK" => Ok(self.parse_rollback()?),
-                    "PEEK" => Ok(Statement::Peek {
-                        immediate: self.parse_keyword("IMMEDIATE"),
+                    Keyword::BEGIN => Ok(self.parse_begin()?),
+                    Keyword::COMMIT => Ok(self.parse_commit()?),
+                    Keyword::ROLLBACK => Ok(self.parse_rollback()?),
+                    Keyword::PEEK => Ok(Statement::Peek {
+                        immediate: self.parse_keyword(Keyword::IMMEDIATE),
                         name: self.parse_object_name()?,
                     }),
-                    "TAIL" => Ok(Statement::Tail {
+                    Keyword::TAIL => Ok(Statement::Tail {
                         name: self.parse_object_name()?,
                     }),
-                    "EXPLAIN" => Ok(self.parse_explain()?),
-                    "FLUSH" => Ok(self.parse_flush()?),
+                    Keyword::EXPLAIN => Ok(self.parse_explain()?),
+                    Keyword::PREPARE => Ok(self.parse_prepare()?),
+                    Keyword::FLUSH => Ok(self.parse_flush()?),
+                    Keyword::DECLARE => Ok(self.parse_declare()?),
+                    Keyword::FETCH => Ok(self.parse_fetch_cursor()?),
+                    Keyword::CLOSE => Ok(Statement::Close {
+                        name: self.parse_identifier()?,
+                    }),
+                    Keyword::LISTEN => Ok(Statement::Listen {
+                        channel: self.parse_identifier()?,
+                    }),
+                    Keyword::NOTIFY => Ok(self.parse_notify()?),
+                    Keyword::DO => Ok(self.parse_do_block()?),
+                    Keyword::UNLISTEN => Ok(Statement::Unlisten {
+                        channel: if self.consume_token(&Token::Mult) {
+                            None
+                        } else {
+                            Some(self.parse_identifier()?)
+                        },
+                    }),
                     _ => parser_err!(format!(
                         "Unexpected keyword {:?} at the beginning of a statement",
                         w.to_string()
@@ -190,34 +614,96 @@ impl Parser {
         Ok(expr)
     }
 
+    /// Parse a parenthesized expression, assuming the `(` was already consumed
+    fn parse_nested_expr_rest(&mut self) -> Result<Expr, ParserError> {
+        let expr = Expr::Nested(Box::new(self.parse_expr()?));
+        self.expect_token(&Token::RParen)?;
+        Ok(expr)
+    }
+
+    /// Parse a scalar subquery, assuming the `(` was already consumed
+    fn parse_subquery_rest(&mut self) -> Result<Expr, ParserError> {
+        let expr = Expr::Subquery(Box::new(self.parse_query()?));
+        self.expect_token(&Token::RParen)?;
+        Ok(expr)
+    }
+
     /// Parse an expression prefix
     pub fn parse_prefix(&mut self) -> Result<Expr, ParserError> {
         let tok = self
             .next_token()
             .ok_or_else(|| ParserError::ParserError("Unexpected EOF".to_string()))?;
         let expr = match tok {
-            Token::Word(w) => match w.keyword.as_ref() {
-                "TRUE" | "FALSE" | "NULL" => {
+            Token::Word(w) => match w.keyword {
+                Keyword::TRUE | Keyword::FALSE | Keyword::NULL => {
                     self.prev_token();
                     Ok(Expr::Value(self.parse_value()?))
                 }
-                "ARRAY" => {
+                Keyword::ARRAY if self.consume_token(&Token::LParen) => {
+                    let subquery = self.parse_query()?;
+                    self.expect_token(&Token::RParen)?;
+                    Ok(Expr::ArraySubquery(Box::new(subquery)))
+                }
+                Keyword::ARRAY => {
                     self.prev_token();
                     Ok(Expr::Value(self.parse_value()?))
                 }
-                "CASE" => self.parse_case_expr(),
-                "CAST" => self.parse_cast_expr(),
-                "DATE" => Ok(Expr::Value(self.parse_date()?)),
-                "EXISTS" => self.parse_exists_expr(),
-                "EXTRACT" => self.parse_extract_expr(),
-                "INTERVAL" => self.parse_literal_interval(),
-                "NOT" => Ok(Expr::UnaryOp {
-                    op: UnaryOperator::Not,
-                    expr: Box::new(self.parse_subexpr(Self::UNARY_NOT_PREC)?),
-                }),
-                "TIME" => Ok(Expr::Value(Value::Time(self.parse_literal_string()?))),
-                "TIMESTAMP" => self.parse_timestamp(),
-                "TIMESTAMPTZ" => self.parse_timestamptz(),
+                Keyword::CASE => self.parse_case_expr(),
+                Keyword::CAST => self.parse_cast_expr(),
+                Keyword::DATE if self.next_is_string_literal() => {
+                    Ok(Expr::Value(self.parse_date()?))
+                }
+                Keyword::EXISTS => self.parse_exists_expr(),
+                Keyword::EXTRACT => self.parse_extract_expr(),
+                Keyword::TRIM => self.parse_trim_expr(),
+                Keyword::OVERLAY => self.parse_overlay_expr(),
+                Keyword::CONVERT => self.parse_convert_expr(),
+                Keyword::INTERVAL => self.parse_literal_interval(),
+                Keyword::NOT => match self.parse_subexpr(Self::UNARY_NOT_PREC)? {
+                    // `NOT EXISTS (...)`, but not e.g. `NOT EXISTS (...) = x`,
+                    // where NOT applies to the comparison
+                    Expr::Exists {
+                        subquery,
+                        negated: false,
+                    } => Ok(Expr::Exists {
+                        subquery,
+                        negated: true,
+                    }),
+                    expr => Ok(Expr::UnaryOp {
+                        op: UnaryOperator::Not,
+                        expr: Box::new(expr),
+                    }),
+                },
+                Keyword::TIME if self.next_is_string_literal() => {
+                    Ok(Expr::Value(Value::Time(self.parse_literal_string()?)))
+                }
+                Keyword::TIMESTAMP
+                    if self.next_is_string_literal()
+                        || self.peek_one_of_keywords(&[Keyword::WITH, Keyword::WITHOUT]) =>
+                {
+                    self.parse_timestamp()
+                }
+                Keyword::TIMESTAMPTZ if self.next_is_string_literal() => self.parse_timestamptz(),
+                Keyword::CURRENT_CATALOG
+                | Keyword::CURRENT_DATE
+                | Keyword::CURRENT_ROLE
+                | Keyword::CURRENT_SCHEMA
+                | Keyword::CURRENT_TIME
+                | Keyword::CURRENT_TIMESTAMP
+                | Keyword::CURRENT_USER
+                | Keyword::LOCALTIME
+                | Keyword::LOCALTIMESTAMP
+                | Keyword::SESSION_USER
+                    if self.peek_token() != Some(Token::LParen) =>
+                {
+                    Ok(Expr::Function(Function {
+                        name: ObjectName(vec![w.to_ident()]),
+                        args: vec![],
+                        over: None,
+                        distinct: false,
+                        special: true,
+                    }))
+                }
                 // Here `w` is a word, check if it's a part of a multi-part
                 // identifier, a function call, or a simple identifier:
                 _ => match self.peek_token() {
@@ -258,13 +744,15 @@ impl Parser {
                 };
                 Ok(Expr::UnaryOp {
                     op,
-                    expr: Box::new(self.parse_subexpr(Self::PLUS_MINUS_PREC)?),
+                    expr: Box::new(self.parse_subexpr(Self::UNARY_PLUS_MINUS_PREC)?),
                 })
             }
             Token::Number(_)
             | Token::SingleQuotedString(_)
             | Token::NationalStringLiteral(_)
-            | Token::HexStringLiteral(_) => {
+            | Token::UnicodeStringLiteral(_)
+            | Token::HexStringLiteral(_)
+            | Token::DollarQuotedString(_) => {
                 self.prev_token();
                 Ok(Expr::Value(self.parse_value()?))
             }
@@ -273,19 +761,24 @@ impl Parser {
                 Err(err) => return parser_err!("unable to parse parameter: {}", err),
             })),
             Token::LParen => {
-                let expr = if self.parse_keyword("SELECT") || self.parse_keyword("WITH") {
-                    self.prev_token();
-                    Expr::Subquery(Box::new(self.parse_query()?))
+                if self.peek_one_of_keywords(&[Keyword::SELECT, Keyword::WITH]) {
+                    Parser::parse_subquery_rest(self)
+                } else if self.peek_token() == Some(Token::LParen) {
+                    // A second paren starts either an operand, like in
+                    // `((SELECT 1) = x)`, or a query, like in
+                    // `((SELECT 1) UNION (SELECT 2))`, so we try both
+                    self.parse_alternatives(&[
+                        Parser::parse_nested_expr_rest,
+                        Parser::parse_subquery_rest,
+                    ])
                 } else {
-                    Expr::Nested(Box::new(self.parse_expr()?))
-                };
-                self.expect_token(&Token::RParen)?;
-                Ok(expr)
+                    Parser::parse_nested_expr_rest(self)
+                }
             }
             unexpected => self.expected("an expression", Some(unexpected)),
         }?;
 
-        if self.parse_keyword("COLLATE") {
+        if self.parse_keyword(Keyword::COLLATE) {
             Ok(Expr::Collate {
                 expr: Box::new(expr),
                 collation: self.parse_object_name()?,
@@ -297,8 +790,8 @@ impl Parser {
 
     pub fn parse_function(&mut self, name: ObjectName) -> Result<Expr, ParserError> {
         self.expect_token(&Token::LParen)?;
-        let all = self.parse_keyword("ALL");
-        let distinct = self.parse_keyword("DISTINCT");
+        let all = self.parse_keyword(Keyword::ALL);
+        let distinct = self.parse_keyword(Keyword::DISTINCT);
         if all && distinct {
             return parser_err!(format!(
                 "Cannot specify both ALL and DISTINCT in function: {}",
@@ -306,16 +799,16 @@ impl Parser {
             ));
         }
         let args = self.parse_optional_args()?;
-        let over = if self.parse_keyword("OVER") {
+        let over = if self.parse_keyword(Keyword::OVER) {
             // TBD: support window names (`OVER mywin`) in place of inline specification
             self.expect_token(&Token::LParen)?;
-            let partition_by = if self.parse_keywords(vec!["PARTITION", "BY"]) {
+            let partition_by = if self.parse_keywords(&[Keyword::PARTITION, Keyword::BY]) {
                 // a list of possibly-qualified column names
                 self.parse_comma_separated(Parser::parse_expr)?
             } else {
                 vec![]
             };
-            let order_by = if self.parse_keywords(vec!["ORDER", "BY"]) {
+            let order_by = if self.parse_keywords(&[Keyword::ORDER, Keyword::BY]) {
                 self.parse_comma_separated(Parser::parse_order_by_expr)?
             } else {
                 vec![]
@@ -341,43 +834,68 @@ impl Parser {
             name,
             args,
             over,
+            special: false,
             distinct,
         }))
     }
 
     pub fn parse_window_frame(&mut self) -> Result<WindowFrame, ParserError> {
         let units = match self.next_token() {
-            Some(Token::Word(w)) => w.keyword.parse::<WindowFrameUnits>()?,
+            Some(Token::Word(w)) => w.keyword.as_str().parse::<WindowFrameUnits>()?,
             unexpected => return self.expected("ROWS, RANGE, GROUPS", unexpected),
         };
-        let (start_bound, end_bound) = if self.parse_keyword("BETWEEN") {
+        let (start_bound, end_bound) = if self.parse_keyword(Keyword::BETWEEN) {
             let start_bound = self.parse_window_frame_bound()?;
-            self.expect_keyword("AND")?;
+            self.expect_keyword(Keyword::AND)?;
             let end_bound = Some(self.parse_window_frame_bound()?);
             (start_bound, end_bound)
         } else {
             (self.parse_window_frame_bound()?, None)
         };
+        let exclude = if self.parse_keyword(Keyword::EXCLUDE) {
+            Some(self.parse_window_frame_exclusion()?)
+        } else {
+            None
+        };
         Ok(WindowFrame {
             units,
             start_bound,
             end_bound,
+            exclude,
         })
     }
 
+    /// Parse `CURRENT ROW`, `GROUP`, `TIES` or `NO OTHERS` after `EXCLUDE`
+    fn parse_window_frame_exclusion(&mut self) -> Result<WindowFrameExclusion, ParserError> {
+        if self.parse_keywords(&[Keyword::CURRENT, Keyword::ROW]) {
+            Ok(WindowFrameExclusion::CurrentRow)
+        } else if self.parse_keyword(Keyword::GROUP) {
+            Ok(WindowFrameExclusion::Group)
+        } else if self.parse_keyword(Keyword::TIES) {
+            Ok(WindowFrameExclusion::Ties)
+        } else if self.parse_keywords(&[Keyword::NO, Keyword::OTHERS]) {
+            Ok(WindowFrameExclusion::NoOthers)
+        } else {
+            self.expected(
+                "CURRENT ROW, GROUP, TIES or NO OTHERS after EXCLUDE",
+                self.peek_token(),
+            )
+        }
+    }
+
     /// Parse `CURRENT ROW` or `{ <positive number> | UNBOUNDED } { PRECEDING | FOLLOWING }`
     pub fn parse_window_frame_bound(&mut self) -> Result<WindowFrameBound, ParserError> {
-        if self.parse_keywords(vec!["CURRENT", "ROW"]) {
+        if self.parse_keywords(&[Keyword::CURRENT, Keyword::ROW]) {
             Ok(WindowFrameBound::CurrentRow)
         } else {
-            let rows = if self.parse_keyword("UNBOUNDED") {
+            let rows = if self.parse_keyword(Keyword::UNBOUNDED) {
                 None
             } else {
                 Some(self.parse_literal_uint()?)
             };
-            if self.parse_keyword("PRECEDING") {
+            if self.parse_keyword(Keyword::PRECEDING) {
                 Ok(WindowFrameBound::Preceding(rows))
-            } else if self.parse_keyword("FOLLOWING") {
+            } else if self.parse_keyword(Keyword::FOLLOWING) {
                 Ok(WindowFrameBound::Following(rows))
             } else {
                 self.expected("PRECEDING or FOLLOWING", self.peek_token())
@@ -387,31 +905,39 @@ impl Parser {
 
     pub fn parse_case_expr(&mut self) -> Result<Expr, ParserError> {
         let mut operand = None;
-        if !self.parse_keyword("WHEN") {
+        if !self.peek_one_of_keywords(&[Keyword::WHEN]) {
             operand = Some(Box::new(self.parse_expr()?));
-            self.expect_keyword("WHEN")?;
         }
-        let mut conditions = vec![];
-        let mut results = vec![];
+        let mut when_clauses = vec![];
         loop {
-            conditions.push(self.parse_expr()?);
-            self.expect_keyword("THEN")?;
-            results.push(self.parse_expr()?);
-            if !self.parse_keyword("WHEN") {
+            let start = self.index;
+            self.expect_keyword(Keyword::WHEN)?;
+            let condition = self.parse_expr()?;
+            self.expect_keyword(Keyword::THEN)?;
+            let result = self.parse_expr()?;
+            when_clauses.push(WhenClause {
+                condition,
+                result,
+                span: self.span_since(start).unwrap_or_default(),
+            });
+            if !self.peek_one_of_keywords(&[Keyword::WHEN]) {
                 break;
             }
         }
-        let else_result = if self.parse_keyword("ELSE") {
-            Some(Box::new(self.parse_expr()?))
+        let start = self.index;
+        let (else_result, else_span) = if self.parse_keyword(Keyword::ELSE) {
+            let else_result = self.parse_expr()?;
+            let else_span = self.span_since(start).unwrap_or_default();
+            (Some(Box::new(else_result)), else_span)
         } else {
-            None
+            (None, Span::default())
         };
-        self.expect_keyword("END")?;
+        self.expect_keyword(Keyword::END)?;
         Ok(Expr::Case {
             operand,
-            conditions,
-            results,
+            when_clauses,
             else_result,
+            else_span,
         })
     }
 
@@ -419,7 +945,7 @@ impl Parser {
     pub fn parse_cast_expr(&mut self) -> Result<Expr, ParserError> {
         self.expect_token(&Token::LParen)?;
         let expr = self.parse_expr()?;
-        self.expect_keyword("AS")?;
+        self.expect_keyword(Keyword::AS)?;
         let data_type = self.parse_data_type()?;
         self.expect_token(&Token::RParen)?;
         Ok(Expr::Cast {
@@ -431,7 +957,10 @@ impl Parser {
     /// Parse a SQL EXISTS expression e.g. `WHERE EXISTS(SELECT ...)`.
     pub fn parse_exists_expr(&mut self) -> Result<Expr, ParserError> {
         self.expect_token(&Token::LParen)?;
-        let exists_node = Expr::Exists(Box::new(self.parse_query()?));
+        let exists_node = Expr::Exists {
+            subquery: Box::new(self.parse_query()?),
+            negated: false,
+        };
         self.expect_token(&Token::RParen)?;
         Ok(exists_node)
     }
@@ -439,7 +968,7 @@ impl Parser {
     pub fn parse_extract_expr(&mut self) -> Result<Expr, ParserError> {
         self.expect_token(&Token::LParen)?;
         let field = self.parse_extract_field()?;
-        self.expect_keyword("FROM")?;
+        self.expect_keyword(Keyword::FROM)?;
         let expr = self.parse_expr()?;
         self.expect_token(&Token::RParen)?;
         Ok(Expr::Extract {
@@ -448,6 +977,102 @@ impl Parser {
         })
     }
 
+    /// Parse a SQL TRIM expression, e.g. `TRIM(LEADING '0' FROM x)`
+    pub fn parse_trim_expr(&mut self) -> Result<Expr, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let trim_where =
+            match self.parse_one_of_keywords(&[Keyword::BOTH, Keyword::LEADING, Keyword::TRAILING])
+            {
+                Some(Keyword::BOTH) => Some(TrimWhereField::Both),
+                Some(Keyword::LEADING) => Some(TrimWhereField::Leading),
+                Some(Keyword::TRAILING) => Some(TrimWhereField::Trailing),
+                _ => None,
+            };
+        let (expr, trim_what) = if trim_where.is_some() && self.parse_keyword(Keyword::FROM) {
+            // `TRIM(LEADING FROM x)`
+            (self.parse_expr()?, None)
+        } else {
+            let first = self.parse_expr()?;
+            if self.parse_keyword(Keyword::FROM) {
+                // `TRIM([LEADING] 'chars' FROM x)`
+                (self.parse_expr()?, Some(Box::new(first)))
+            } else if trim_where.is_some() {
+                return self.expected("FROM", self.peek_token());
+            } else {
+                // `TRIM(x)`
+                (first, None)
+            }
+        };
+        self.expect_token(&Token::RParen)?;
+        Ok(Expr::Trim {
+            expr: Box::new(expr),
+            trim_where,
+            trim_what,
+        })
+    }
+
+    /// Parse a SQL OVERLAY expression, e.g. `OVERLAY(x PLACING 'y' FROM 2 FOR 3)`
+    pub fn parse_overlay_expr(&mut self) -> Result<Expr, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let expr = self.parse_expr()?;
+        self.expect_keyword(Keyword::PLACING)?;
+        let overlay_what = self.parse_expr()?;
+        self.expect_keyword(Keyword::FROM)?;
+        let overlay_from = self.parse_expr()?;
+        let overlay_for = if self.parse_keyword(Keyword::FOR) {
+            Some(Box::new(self.parse_expr()?))
+        } else {
+            None
+        };
+        self.expect_token(&Token::RParen)?;
+        Ok(Expr::Overlay {
+            expr: Box::new(expr),
+            overlay_what: Box::new(overlay_what),
+            overlay_from: Box::new(overlay_from),
+            overlay_for,
+        })
+    }
+
+    /// Parse a CONVERT expression, e.g. `CONVERT(x, CHAR)` or
+    /// `CONVERT(x USING utf8mb4)`, or `CONVERT(VARCHAR, x, 1)` in dialects
+    /// that put the type first
+    pub fn parse_convert_expr(&mut self) -> Result<Expr, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        if self.dialect.convert_type_before_value() {
+            let data_type = self.parse_data_type()?;
+            self.expect_token(&Token::Comma)?;
+            let expr = self.parse_expr()?;
+            let styles = if self.consume_token(&Token::Comma) {
+                self.parse_comma_separated(Parser::parse_expr)?
+            } else {
+                vec![]
+            };
+            self.expect_token(&Token::RParen)?;
+            return Ok(Expr::Convert {
+                expr: Box::new(expr),
+                data_type: Some(data_type),
+                charset: None,
+                target_before_value: true,
+                styles,
+            });
+        }
+        let expr = self.parse_expr()?;
+        let (data_type, charset) = if self.parse_keyword(Keyword::USING) {
+            (None, Some(self.parse_object_name()?))
+        } else {
+            self.expect_token(&Token::Comma)?;
+            (Some(self.parse_data_type()?), None)
+        };
+        self.expect_token(&Token::RParen)?;
+        Ok(Expr::Convert {
+            expr: Box::new(expr),
+            data_type,
+            charset,
+            target_before_value: false,
+            styles: vec![],
+        })
+    }
+
     // This function parses date/time fields for both the EXTRACT function-like
     // operator and interval qualifiers. EXTRACT supports a wider set of
     // date/time fields than interval qualifiers, so this function may need to
@@ -455,13 +1080,13 @@ impl Parser {
     pub fn parse_date_time_field(&mut self) -> Result<DateTimeField, ParserError> {
         let tok = self.next_token();
         if let Some(Token::Word(ref k)) = tok {
-            match k.keyword.as_ref() {
-                "YEAR" => Ok(DateTimeField::Year),
-                "MONTH" => Ok(DateTimeField::Month),
-                "DAY" => Ok(DateTimeField::Day),
-                "HOUR" => Ok(DateTimeField::Hour),
-                "MINUTE" => Ok(DateTimeField::Minute),
-                "SECOND" => Ok(DateTimeField::Second),
+            match k.keyword {
+                Keyword::YEAR => Ok(DateTimeField::Year),
+                Keyword::MONTH => Ok(DateTimeField::Month),
+                Keyword::DAY => Ok(DateTimeField::Day),
+                Keyword::HOUR => Ok(DateTimeField::Hour),
+                Keyword::MINUTE => Ok(DateTimeField::Minute),
+                Keyword::SECOND => Ok(DateTimeField::Second),
                 _ => self.expected("date/time field", tok)?,
             }
         } else {
@@ -489,7 +1114,7 @@ impl Parser {
     pub fn parse_extract_field(&mut self) -> Result<ExtractField, ParserError> {
         let tok = self.next_token();
         let field: Result<ExtractField, _> = match tok {
-            Some(Token::Word(ref k)) => k.keyword.parse(),
+            Some(Token::Word(ref k)) => k.keyword.as_str().parse(),
             Some(Token::SingleQuotedString(ref s)) => s.parse(),
             _ => return self.expected("extract field token", tok),
         };
@@ -500,7 +1125,7 @@ impl Parser {
     }
 
     pub fn contains_date_time_str(&mut self, interval: &str) -> Result<bool, ParserError> {
-        let upper_case_interval = interval.to_uppercase();
+        let upper_case_interval = interval.to_ascii_uppercase();
         let date_time_strs = ["YEAR", "MONTH", "DAY", "HOUR", "MINUTE", "SECOND"];
         for dts in &date_time_strs {
             if upper_case_interval.contains(dts) {
@@ -511,14 +1136,14 @@ impl Parser {
     }
 
     fn parse_date(&mut self) -> Result<Value, ParserError> {
-        use std::convert::TryInto;
+        use core::convert::TryInto;
 
         let value = self.parse_literal_string()?;
         let pdt = Self::parse_interval_string(&value, &DateTimeField::Year)?;
 
         match (pdt.year, pdt.month, pdt.day, pdt.hour) {
             (Some(year), Some(month), Some(day), None) => {
-                let p_err = |e: std::num::TryFromIntError, field: &str| {
+                let p_err = |e: core::num::TryFromIntError, field: &str| {
                     ParserError::ParserError(format!(
                         "{} in date '{}' is invalid: {}",
                         field, value, e
@@ -555,11 +1180,11 @@ impl Parser {
     }
 
     fn parse_timestamp(&mut self) -> Result<Expr, ParserError> {
-        if self.parse_keyword("WITH") {
-            self.expect_keywords(&["TIME", "ZONE"])?;
+        if self.parse_keyword(Keyword::WITH) {
+            self.expect_keywords(&[Keyword::TIME, Keyword::ZONE])?;
             return Ok(Expr::Value(self.parse_timestamp_inner(true)?));
-        } else if self.parse_keyword("WITHOUT") {
-            self.expect_keywords(&["TIME", "ZONE"])?;
+        } else if self.parse_keyword(Keyword::WITHOUT) {
+            self.expect_keywords(&[Keyword::TIME, Keyword::ZONE])?;
         }
         Ok(Expr::Value(self.parse_timestamp_inner(false)?))
     }
@@ -569,7 +1194,7 @@ impl Parser {
     }
 
     fn parse_timestamp_inner(&mut self, parse_timezone: bool) -> Result<Value, ParserError> {
-        use std::convert::TryInto;
+        use core::convert::TryInto;
 
         let value = self.parse_literal_string()?;
         let pdt = Self::parse_timestamp_string(&value, parse_timezone)?;
@@ -594,7 +1219,7 @@ impl Parser {
                 nano,
                 timezone_offset_second,
             ) => {
-                let p_err = |e: std::num::TryFromIntError, field: &str| {
+                let p_err = |e: core::num::TryFromIntError, field: &str| {
                     ParserError::ParserError(format!(
                         "{} in date '{}' is invalid: {}",
                         field, value, e
@@ -679,6 +1304,8 @@ impl Parser {
     ///   4. `INTERVAL '1:1:1.1' HOUR (5) TO SECOND (5)`
     ///   5. `INTERVAL '1.1' SECOND (2, 2)`
     ///   6. `INTERVAL '1:1' HOUR (5) TO MINUTE (5)`
+    ///   7. `INTERVAL 1 DAY`, in dialects that
+    ///      [support interval expressions](Dialect::supports_interval_expressions)
     ///
     /// Note that we do not currently attempt to parse the quoted value.
     pub fn parse_literal_interval(&mut self) -> Result<Expr, ParserError> {
@@ -687,6 +1314,15 @@ impl Parser {
         // don't currently try to parse it. (The sign can instead be included
         // inside the raw_value string.)
 
+        if self.dialect.supports_interval_expressions() && !self.next_is_string_literal() {
+            let value = self.parse_expr()?;
+            let field = self.parse_date_time_field()?;
+            return Ok(Expr::Interval {
+                value: Box::new(value),
+                field,
+            });
+        }
+
         // The first token in an interval is a string literal which specifies
         // the duration of the interval.
         let mut raw_value = self.parse_literal_string()?;
@@ -698,7 +1334,7 @@ impl Parser {
                 if split.len() == 2 {
                     (
                         String::from(split[0]),
-                        self.parse_date_time_field_given_str(&split[1].to_uppercase())?,
+                        self.parse_date_time_field_given_str(&split[1].to_ascii_uppercase())?,
                     )
                 } else {
                     return parser_err!("Invalid INTERVAL: {:#?}", raw_value);
@@ -727,7 +1363,7 @@ impl Parser {
                 (leading_precision, last_field, fsec_precision)
             } else {
                 let leading_precision = self.parse_optional_precision()?;
-                if self.parse_keyword("TO") {
+                if self.parse_keyword(Keyword::TO) {
                     let last_field = Some(self.parse_date_time_field()?);
                     let fsec_precision = if last_field == Some(DateTimeField::Second) {
                         self.parse_optional_precision()?
@@ -757,51 +1393,12 @@ impl Parser {
         debug!("parsing infix");
         let tok = self.next_token().unwrap(); // safe as EOF's precedence is the lowest
 
-        let regular_binary_operator = match tok {
-            Token::Eq => Some(BinaryOperator::Eq),
-            Token::Neq => Some(BinaryOperator::NotEq),
-            Token::Gt => Some(BinaryOperator::Gt),
-            Token::GtEq => Some(BinaryOperator::GtEq),
-            Token::Lt => Some(BinaryOperator::Lt),
-            Token::LtEq => Some(BinaryOperator::LtEq),
-            Token::Plus => Some(BinaryOperator::Plus),
-            Token::Minus => Some(BinaryOperator::Minus),
-            Token::Mult => Some(BinaryOperator::Multiply),
-            Token::Mod => Some(BinaryOperator::Modulus),
-            Token::Div => Some(BinaryOperator::Divide),
-            Token::JsonGet => Some(BinaryOperator::JsonGet),
-            Token::JsonGetAsText => Some(BinaryOperator::JsonGetAsText),
-            Token::JsonGetPath => Some(BinaryOperator::JsonGetPath),
-            Token::JsonGetPathAsText => Some(BinaryOperator::JsonGetPathAsText),
-            Token::JsonContainsJson => Some(BinaryOperator::JsonContainsJson),
-            Token::JsonContainedInJson => Some(BinaryOperator::JsonContainedInJson),
-            Token::JsonContainsField => Some(BinaryOperator::JsonContainsField),
-            Token::JsonContainsAnyFields => Some(BinaryOperator::JsonContainsAnyFields),
-            Token::JsonContainsAllFields => Some(BinaryOperator::JsonContainsAllFields),
-            Token::JsonConcat => Some(BinaryOperator::JsonConcat),
-            Token::JsonDeletePath => Some(BinaryOperator::JsonDeletePath),
-            Token::JsonContainsPath => Some(BinaryOperator::JsonContainsPath),
-            Token::JsonApplyPathPredicate => Some(BinaryOperator::JsonApplyPathPredicate),
-            Token::Word(ref k) => match k.keyword.as_ref() {
-                "AND" => Some(BinaryOperator::And),
-                "OR" => Some(BinaryOperator::Or),
-                "LIKE" => Some(BinaryOperator::Like),
-                "NOT" => {
-                    if self.parse_keyword("LIKE") {
-                        Some(BinaryOperator::NotLike)
-                    } else {
-                        None
-                    }
-                }
-                _ => None,
-            },
-            _ => None,
-        };
+        let regular_binary_operator = self.binary_operator(&tok);
 
         if let Some(op) = regular_binary_operator {
-            let any = self.parse_keyword("ANY");
-            let some = !any && self.parse_keyword("SOME");
-            let all = !any && !some && self.parse_keyword("ALL");
+            let any = self.parse_keyword(Keyword::ANY);
+            let some = !any && self.parse_keyword(Keyword::SOME);
+            let all = !any && !some && self.parse_keyword(Keyword::ALL);
             if any || some || all {
                 use BinaryOperator::*;
                 match op {
@@ -833,25 +1430,54 @@ impl Parser {
                 })
             }
         } else if let Token::Word(ref k) = tok {
-            match k.keyword.as_ref() {
-                "IS" => {
-                    if self.parse_keyword("NULL") {
+            match k.keyword {
+                Keyword::IS => {
+                    if self.parse_keyword(Keyword::NULL) {
                         Ok(Expr::IsNull(Box::new(expr)))
-                    } else if self.parse_keywords(vec!["NOT", "NULL"]) {
+                    } else if self.parse_keywords(&[Keyword::NOT, Keyword::NULL]) {
                         Ok(Expr::IsNotNull(Box::new(expr)))
                     } else {
                         self.expected("NULL or NOT NULL after IS", self.peek_token())
                     }
                 }
-                "NOT" | "IN" | "BETWEEN" => {
+                Keyword::NOT
+                | Keyword::IN
+                | Keyword::BETWEEN
+                | Keyword::LIKE
+                | Keyword::ILIKE
+                | Keyword::SIMILAR => {
                     self.prev_token();
-                    let negated = self.parse_keyword("NOT");
-                    if self.parse_keyword("IN") {
+                    let negated = self.parse_keyword(Keyword::NOT);
+                    if self.parse_keyword(Keyword::IN) {
                         self.parse_in(expr, negated)
-                    } else if self.parse_keyword("BETWEEN") {
+                    } else if self.parse_keyword(Keyword::BETWEEN) {
                         self.parse_between(expr, negated)
+                    } else if self.parse_keyword(Keyword::LIKE) {
+                        Ok(Expr::Like {
+                            expr: Box::new(expr),
+                            negated,
+                            pattern: Box::new(self.parse_subexpr(Self::BETWEEN_PREC)?),
+                            escape_char: self.parse_escape_char()?,
+                        })
+                    } else if self.parse_keyword(Keyword::ILIKE) {
+                        Ok(Expr::ILike {
+                            expr: Box::new(expr),
+                            negated,
+                            pattern: Box::new(self.parse_subexpr(Self::BETWEEN_PREC)?),
+                            escape_char: self.parse_escape_char()?,
+                        })
+                    } else if self.parse_keywords(&[Keyword::SIMILAR, Keyword::TO]) {
+                        Ok(Expr::SimilarTo {
+                            expr: Box::new(expr),
+                            negated,
+                            pattern: Box::new(self.parse_subexpr(Self::BETWEEN_PREC)?),
+                            escape_char: self.parse_escape_char()?,
+                        })
                     } else {
-                        self.expected("IN or BETWEEN after NOT", self.peek_token())
+                        self.expected(
+                            "IN, BETWEEN, LIKE, ILIKE, or SIMILAR TO after NOT",
+                            self.peek_token(),
+                        )
                     }
                 }
                 // Can only happen if `get_next_precedence` got out of sync with this function
@@ -859,12 +1485,34 @@ impl Parser {
             }
         } else if Token::DoubleColon == tok {
             self.parse_pg_cast(expr)
+        } else if Token::Colon == tok {
+            self.parse_variant_access(expr)
         } else {
             // Can only happen if `get_next_precedence` got out of sync with this function
             panic!("No infix parser for token {:?}", tok)
         }
     }
 
+    /// Parse the path of a Snowflake semi-structured access like `src:a.b[0]`,
+    /// assuming the `src` expression and the colon have already been consumed
+    pub fn parse_variant_access(&mut self, expr: Expr) -> Result<Expr, ParserError> {
+        let mut path = vec![VariantPathElement::Field(self.parse_identifier()?)];
+        loop {
+            if self.consume_token(&Token::Period) {
+                path.push(VariantPathElement::Field(self.parse_identifier()?));
+            } else if self.consume_token(&Token::LBracket) {
+                path.push(VariantPathElement::Index(self.parse_expr()?));
+                self.expect_token(&Token::RBracket)?;
+            } else {
+                break;
+            }
+        }
+        Ok(Expr::VariantAccess {
+            expr: Box::new(expr),
+            path,
+        })
+    }
+
     /// parse
     ///
     /// ```text
@@ -921,7 +1569,7 @@ impl Parser {
     /// Parses the parens following the `[ NOT ] IN` operator
     pub fn parse_in(&mut self, expr: Expr, negated: bool) -> Result<Expr, ParserError> {
         self.expect_token(&Token::LParen)?;
-        let in_op = if self.parse_keyword("SELECT") || self.parse_keyword("WITH") {
+        let in_op = if self.parse_keyword(Keyword::SELECT) || self.parse_keyword(Keyword::WITH) {
             self.prev_token();
             Expr::InSubquery {
                 expr: Box::new(expr),
@@ -944,7 +1592,7 @@ impl Parser {
         // Stop parsing subexpressions for <low> and <high> on tokens with
         // precedence lower than that of `BETWEEN`, such as `AND`, `IS`, etc.
         let low = self.parse_subexpr(Self::BETWEEN_PREC)?;
-        self.expect_keyword("AND")?;
+        self.expect_keyword(Keyword::AND)?;
         let high = self.parse_subexpr(Self::BETWEEN_PREC)?;
         Ok(Expr::Between {
             expr: Box::new(expr),
@@ -954,6 +1602,22 @@ impl Parser {
         })
     }
 
+    /// Parse the optional `ESCAPE '<char>'` clause of a pattern match
+    fn parse_escape_char(&mut self) -> Result<Option<char>, ParserError> {
+        if !self.parse_keyword(Keyword::ESCAPE) {
+            return Ok(None);
+        }
+        let s = self.parse_literal_string()?;
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(Some(c)),
+            _ => parser_err!(format!(
+                "Expected a single character after ESCAPE, found: '{}'",
+                s
+            )),
+        }
+    }
+
     /// Parse a postgresql casting style which is in the form of `expr::datatype`
     pub fn parse_pg_cast(&mut self, expr: Expr) -> Result<Expr, ParserError> {
         Ok(Expr::Cast {
@@ -964,64 +1628,124 @@ impl Parser {
 
     const UNARY_NOT_PREC: u8 = 15;
     const BETWEEN_PREC: u8 = 20;
-    const PLUS_MINUS_PREC: u8 = 30;
-
-    /// Get the precedence of the next token
-    pub fn get_next_precedence(&self) -> Result<u8, ParserError> {
-        if let Some(token) = self.peek_token() {
-            debug!("get_next_precedence() {:?}", token);
+    // Above `^` (45), but below `::` (50)
+    const UNARY_PLUS_MINUS_PREC: u8 = 47;
 
-            match &token {
-                Token::Word(k) if k.keyword == "OR" => Ok(5),
-                Token::Word(k) if k.keyword == "AND" => Ok(10),
-                Token::Word(k) if k.keyword == "NOT" => match &self.peek_nth_token(1) {
-                    // The precedence of NOT varies depending on keyword that
-                    // follows it. If it is followed by IN, BETWEEN, or LIKE,
-                    // it takes on the precedence of those tokens. Otherwise it
-                    // is not an infix operator, and therefore has zero
-                    // precedence.
-                    Some(Token::Word(k)) if k.keyword == "IN" => Ok(Self::BETWEEN_PREC),
-                    Some(Token::Word(k)) if k.keyword == "BETWEEN" => Ok(Self::BETWEEN_PREC),
-                    Some(Token::Word(k)) if k.keyword == "LIKE" => Ok(Self::BETWEEN_PREC),
-                    _ => Ok(0),
-                },
-                Token::Word(k) if k.keyword == "IS" => Ok(17),
-                Token::Word(k) if k.keyword == "IN" => Ok(Self::BETWEEN_PREC),
-                Token::Word(k) if k.keyword == "BETWEEN" => Ok(Self::BETWEEN_PREC),
-                Token::Word(k) if k.keyword == "LIKE" => Ok(Self::BETWEEN_PREC),
-                Token::Eq | Token::Lt | Token::LtEq | Token::Neq | Token::Gt | Token::GtEq => {
-                    Ok(20)
-                }
-                Token::Plus | Token::Minus => Ok(Self::PLUS_MINUS_PREC),
-                Token::Mult | Token::Div | Token::Mod => Ok(40),
-                Token::DoubleColon => Ok(50),
-                // TODO(jamii) it's not clear what precedence postgres gives to json operators
-                Token::JsonGet
-                | Token::JsonGetAsText
-                | Token::JsonGetPath
-                | Token::JsonGetPathAsText
-                | Token::JsonContainsJson
-                | Token::JsonContainedInJson
-                | Token::JsonContainsField
-                | Token::JsonContainsAnyFields
-                | Token::JsonContainsAllFields
-                | Token::JsonConcat
-                | Token::JsonDeletePath
-                | Token::JsonContainsPath
-                | Token::JsonApplyPathPredicate => Ok(1),
-                _ => Ok(0),
-            }
-        } else {
-            Ok(0)
-        }
-    }
-
-    /// Return the first non-whitespace token that has not yet been processed
-    /// (or None if reached end-of-file)
+    /// Return the regular binary operator that `tok` denotes, if any
+    fn binary_operator(&self, tok: &Token) -> Option<BinaryOperator> {
+        match tok {
+            Token::Eq => Some(BinaryOperator::Eq),
+            Token::Neq => Some(BinaryOperator::NotEq),
+            Token::Gt => Some(BinaryOperator::Gt),
+            Token::GtEq => Some(BinaryOperator::GtEq),
+            Token::Lt => Some(BinaryOperator::Lt),
+            Token::LtEq => Some(BinaryOperator::LtEq),
+            Token::Plus => Some(BinaryOperator::Plus),
+            Token::Minus => Some(BinaryOperator::Minus),
+            Token::Mult => Some(BinaryOperator::Multiply),
+            Token::Mod => Some(BinaryOperator::Modulus),
+            Token::Div => Some(BinaryOperator::Divide),
+            Token::Caret if self.dialect.supports_caret_as_xor() => {
+                Some(BinaryOperator::BitwiseXor)
+            }
+            Token::Caret => Some(BinaryOperator::Exponent),
+            Token::JsonGet => Some(BinaryOperator::JsonGet),
+            Token::JsonGetAsText => Some(BinaryOperator::JsonGetAsText),
+            Token::JsonGetPath => Some(BinaryOperator::JsonGetPath),
+            Token::JsonGetPathAsText => Some(BinaryOperator::JsonGetPathAsText),
+            Token::JsonContainsJson => Some(BinaryOperator::JsonContainsJson),
+            Token::JsonContainedInJson => Some(BinaryOperator::JsonContainedInJson),
+            Token::JsonContainsField => Some(BinaryOperator::JsonContainsField),
+            Token::JsonContainsAnyFields => Some(BinaryOperator::JsonContainsAnyFields),
+            Token::JsonContainsAllFields => Some(BinaryOperator::JsonContainsAllFields),
+            Token::JsonConcat if self.dialect.supports_pipes_as_or() => Some(BinaryOperator::Or),
+            Token::JsonConcat => Some(BinaryOperator::JsonConcat),
+            Token::JsonDeletePath => Some(BinaryOperator::JsonDeletePath),
+            Token::JsonContainsPath => Some(BinaryOperator::JsonContainsPath),
+            Token::JsonApplyPathPredicate => Some(BinaryOperator::JsonApplyPathPredicate),
+            Token::Word(ref k) => match k.keyword {
+                Keyword::AND => Some(BinaryOperator::And),
+                Keyword::OR => Some(BinaryOperator::Or),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Get the precedence of the next token
+    pub fn get_next_precedence(&self) -> Result<u8, ParserError> {
+        if let Some(token) = self.peek_token() {
+            debug!("get_next_precedence() {:?}", token);
+
+            match &token {
+                Token::Word(k) if k.keyword == Keyword::NOT => match &self.peek_nth_token(1) {
+                    // The precedence of NOT varies depending on keyword that
+                    // follows it. If it is followed by IN, BETWEEN, or a
+                    // pattern match like LIKE,
+                    // it takes on the precedence of those tokens. Otherwise it
+                    // is not an infix operator, and therefore has zero
+                    // precedence.
+                    Some(Token::Word(k)) if k.keyword == Keyword::IN => Ok(Self::BETWEEN_PREC),
+                    Some(Token::Word(k)) if k.keyword == Keyword::BETWEEN => Ok(Self::BETWEEN_PREC),
+                    Some(Token::Word(k))
+                        if k.keyword == Keyword::LIKE
+                            || k.keyword == Keyword::ILIKE
+                            || k.keyword == Keyword::SIMILAR =>
+                    {
+                        Ok(Self::BETWEEN_PREC)
+                    }
+                    _ => Ok(0),
+                },
+                Token::Word(k) if k.keyword == Keyword::IS => Ok(17),
+                Token::Word(k) if k.keyword == Keyword::IN => Ok(Self::BETWEEN_PREC),
+                Token::Word(k) if k.keyword == Keyword::BETWEEN => Ok(Self::BETWEEN_PREC),
+                Token::Word(k) if k.keyword == Keyword::LIKE || k.keyword == Keyword::ILIKE => {
+                    Ok(Self::BETWEEN_PREC)
+                }
+                // SIMILAR is not reserved, so might be an alias unless it's
+                // followed by TO
+                Token::Word(k)
+                    if k.keyword == Keyword::SIMILAR
+                        && matches!(
+                            self.peek_nth_token(1),
+                            Some(Token::Word(ref w)) if w.keyword == Keyword::TO
+                        ) =>
+                {
+                    Ok(Self::BETWEEN_PREC)
+                }
+                Token::DoubleColon => Ok(50),
+                Token::Colon if self.dialect.supports_variant_access() => Ok(50),
+                _ => match self.binary_operator(&token) {
+                    Some(op) => Ok(self.binary_operator_precedence(&op)),
+                    None => Ok(0),
+                },
+            }
+        } else {
+            Ok(0)
+        }
+    }
+
+    /// Return the first non-whitespace token that has not yet been processed
+    /// (or None if reached end-of-file)
     pub fn peek_token(&self) -> Option<Token> {
         self.peek_nth_token(0)
     }
 
+    /// Report whether the next token is a string literal, which is what
+    /// distinguishes e.g. `DATE '2020-01-01'` from a column named `date`
+    fn next_is_string_literal(&self) -> bool {
+        matches!(self.peek_token(), Some(Token::SingleQuotedString(_)))
+    }
+
+    /// Report whether the next token is one of the given keywords, without
+    /// consuming it
+    fn peek_one_of_keywords(&self, keywords: &[Keyword]) -> bool {
+        match self.peek_token() {
+            Some(Token::Word(w)) => keywords.contains(&w.keyword),
+            _ => false,
+        }
+    }
+
     /// Return nth non-whitespace token that has not yet been processed
     pub fn peek_nth_token(&self, mut n: usize) -> Option<Token> {
         let mut index = self.index;
@@ -1073,24 +1797,82 @@ impl Parser {
     }
 
     /// Report unexpected token
-    fn expected<T>(&self, expected: &str, found: Option<Token>) -> Result<T, ParserError> {
-        parser_err!(format!(
-            "Expected {}, found: {}",
-            expected,
-            found.map_or_else(|| "EOF".to_string(), |t| format!("{}", t))
-        ))
+    fn expected<T>(&mut self, expected: &str, found: Option<Token>) -> Result<T, ParserError> {
+        // `found` is either the token just consumed or the next one. Note
+        // that consuming EOF moves `self.index` past the end.
+        let mut index = self.index.min(self.tokens.len());
+        if index > 0 && found.is_some() && self.tokens.get(index - 1) == found.as_ref() {
+            index -= 1;
+        }
+        while let Some(Token::Whitespace(_)) = self.tokens.get(index) {
+            index += 1;
+        }
+        let expectation = Expectation {
+            index,
+            expected: vec![expected.to_string()],
+            found,
+        };
+        let err = expectation.to_error();
+        self.expectation = Some(match self.expectation.take() {
+            Some(furthest) if furthest.index > index => furthest,
+            Some(mut furthest) if furthest.index == index => {
+                if !furthest.expected.contains(&expectation.expected[0]) {
+                    furthest.expected.extend(expectation.expected);
+                }
+                furthest
+            }
+            _ => expectation,
+        });
+        Err(err)
+    }
+
+    /// Parse the first of `alternatives` that succeeds from the current
+    /// position. If they all fail, return the error of the alternative that
+    /// got furthest, listing everything that was expected there if several
+    /// alternatives got equally far.
+    pub fn parse_alternatives<T>(
+        &mut self,
+        alternatives: &[ParseFn<'a, T>],
+    ) -> Result<T, ParserError> {
+        let start = self.index;
+        let outer = self.expectation.take();
+        let mut furthest: Option<(usize, Option<Expectation>, ParserError)> = None;
+        for alternative in alternatives {
+            self.index = start;
+            let err = match alternative(self) {
+                Ok(result) => {
+                    self.expectation = outer;
+                    return Ok(result);
+                }
+                Err(err) => err,
+            };
+            let expectation = self.expectation.take();
+            let index = expectation.as_ref().map_or(self.index, |e| e.index);
+            furthest = match furthest {
+                Some(prev) if prev.0 > index => Some(prev),
+                Some((_, Some(mut prev), _)) if prev.index == index && expectation.is_some() => {
+                    for expected in expectation.unwrap().expected {
+                        if !prev.expected.contains(&expected) {
+                            prev.expected.push(expected);
+                        }
+                    }
+                    let err = prev.to_error();
+                    Some((index, Some(prev), err))
+                }
+                _ => Some((index, expectation, err)),
+            };
+        }
+        let (_, expectation, err) = furthest.expect("no alternatives to parse");
+        // Let any enclosing `parse_alternatives` compare against this failure
+        self.expectation = expectation;
+        Err(err)
     }
 
     /// Look for an expected keyword and consume it if it exists
     #[must_use]
-    pub fn parse_keyword(&mut self, expected: &'static str) -> bool {
-        // Ideally, we'd accept a enum variant, not a string, but since
-        // it's not trivial to maintain the enum without duplicating all
-        // the keywords three times, we'll settle for a run-time check that
-        // the string actually represents a known keyword...
-        assert!(keywords::ALL_KEYWORDS.contains(&expected));
+    pub fn parse_keyword(&mut self, expected: Keyword) -> bool {
         match self.peek_token() {
-            Some(Token::Word(ref k)) if expected.eq_ignore_ascii_case(&k.keyword) => {
+            Some(Token::Word(ref k)) if expected == k.keyword => {
                 self.next_token();
                 true
             }
@@ -1100,11 +1882,11 @@ impl Parser {
 
     /// Look for an expected sequence of keywords and consume them if they exist
     #[must_use]
-    pub fn parse_keywords(&mut self, keywords: Vec<&'static str>) -> bool {
+    pub fn parse_keywords(&mut self, keywords: &[Keyword]) -> bool {
         let index = self.index;
-        for keyword in keywords {
-            if !self.parse_keyword(&keyword) {
-                //println!("parse_keywords aborting .. did not find {}", keyword);
+        for &keyword in keywords {
+            if !self.parse_keyword(keyword) {
+                //println!("parse_keywords aborting .. did not find {:?}", keyword);
                 // reset index and return immediately
                 self.index = index;
                 return false;
@@ -1115,35 +1897,27 @@ impl Parser {
 
     /// Look for one of the given keywords and return the one that matches.
     #[must_use]
-    pub fn parse_one_of_keywords(&mut self, keywords: &[&'static str]) -> Option<&'static str> {
-        for keyword in keywords {
-            assert!(
-                keywords::ALL_KEYWORDS.contains(keyword),
-                "{} is not contained in keyword list",
-                keyword
-            );
-        }
+    pub fn parse_one_of_keywords(&mut self, keywords: &[Keyword]) -> Option<Keyword> {
         match self.peek_token() {
-            Some(Token::Word(ref k)) => keywords
-                .iter()
-                .find(|keyword| keyword.eq_ignore_ascii_case(&k.keyword))
-                .map(|keyword| {
-                    self.next_token();
-                    *keyword
-                }),
+            Some(Token::Word(ref k)) => {
+                keywords
+                    .iter()
+                    .find(|&&keyword| keyword == k.keyword)
+                    .map(|&keyword| {
+                        self.next_token();
+                        keyword
+                    })
+            }
             _ => None,
         }
     }
 
     /// Bail out if the current token is not one of the expected keywords, or consume it if it is
-    #[must_use]
-    pub fn expect_one_of_keywords(
-        &mut self,
-        keywords: &[&'static str],
-    ) -> Result<&'static str, ParserError> {
+    pub fn expect_one_of_keywords(&mut self, keywords: &[Keyword]) -> Result<Keyword, ParserError> {
         if let Some(keyword) = self.parse_one_of_keywords(keywords) {
             Ok(keyword)
         } else {
+            let keywords: Vec<&str> = keywords.iter().map(|k| k.as_str()).collect();
             self.expected(
                 &format!("one of {}", keywords.join(" or ")),
                 self.peek_token(),
@@ -1152,18 +1926,18 @@ impl Parser {
     }
 
     /// Bail out if the current token is not an expected keyword, or consume it if it is
-    pub fn expect_keyword(&mut self, expected: &'static str) -> Result<(), ParserError> {
+    pub fn expect_keyword(&mut self, expected: Keyword) -> Result<(), ParserError> {
         if self.parse_keyword(expected) {
             Ok(())
         } else {
-            self.expected(expected, self.peek_token())
+            self.expected(expected.as_str(), self.peek_token())
         }
     }
 
     /// Bail out if the following tokens are not the expected sequence of
     /// keywords, or consume them if they are.
-    pub fn expect_keywords(&mut self, expected: &[&'static str]) -> Result<(), ParserError> {
-        for kw in expected {
+    pub fn expect_keywords(&mut self, expected: &[Keyword]) -> Result<(), ParserError> {
+        for &kw in expected {
             self.expect_keyword(kw)?;
         }
         Ok(())
@@ -1193,7 +1967,7 @@ impl Parser {
     /// Parse a comma-separated list of 1+ items accepted by `F`
     pub fn parse_comma_separated<T, F>(&mut self, mut f: F) -> Result<Vec<T>, ParserError>
     where
-        F: FnMut(&mut Parser) -> Result<T, ParserError>,
+        F: FnMut(&mut Parser<'a>) -> Result<T, ParserError>,
     {
         let mut values = vec![];
         loop {
@@ -1207,24 +1981,36 @@ impl Parser {
 
     /// Parse a SQL CREATE statement
     pub fn parse_create(&mut self) -> Result<Statement, ParserError> {
-        if self.parse_keyword("TABLE") {
+        if self.parse_keyword(Keyword::TABLE) {
             self.parse_create_table()
-        } else if self.parse_keyword("MATERIALIZED") || self.parse_keyword("VIEW") {
-            self.prev_token();
+        } else if self.peek_one_of_keywords(&[
+            Keyword::TEMP,
+            Keyword::TEMPORARY,
+            Keyword::MATERIALIZED,
+            Keyword::VIEW,
+        ]) {
             self.parse_create_view()
-        } else if self.parse_keyword("SOURCE") {
+        } else if self.parse_keyword(Keyword::SOURCE) {
             self.parse_create_source()
-        } else if self.parse_keyword("SOURCES") {
+        } else if self.parse_keyword(Keyword::SOURCES) {
             self.parse_create_sources()
-        } else if self.parse_keyword("SINK") {
+        } else if self.parse_keyword(Keyword::SINK) {
             self.parse_create_sink()
-        } else if self.parse_keyword("EXTERNAL") {
+        } else if self.parse_keyword(Keyword::EXTERNAL) {
             self.parse_create_external_table()
-        } else if self.parse_keyword("INDEX") {
+        } else if self.parse_keyword(Keyword::INDEX) {
             self.parse_create_index()
+        } else if self.parse_keyword(Keyword::SEQUENCE) {
+            self.parse_create_sequence()
+        } else if self.parse_keyword(Keyword::EXTENSION) {
+            self.parse_create_extension()
+        } else if self.parse_keyword(Keyword::ROLE) {
+            self.parse_create_role(false)
+        } else if self.parse_keyword(Keyword::USER) {
+            self.parse_create_role(true)
         } else {
             self.expected(
-                "TABLE, VIEW, SOURCE, SINK, or INDEX after CREATE",
+                "TABLE, VIEW, SOURCE, SINK, INDEX, SEQUENCE, EXTENSION, ROLE, or USER after CREATE",
                 self.peek_token(),
             )
         }
@@ -1232,10 +2018,10 @@ impl Parser {
 
     pub fn parse_create_source(&mut self) -> Result<Statement, ParserError> {
         let name = self.parse_object_name()?;
-        self.expect_keyword("FROM")?;
+        self.expect_keyword(Keyword::FROM)?;
         let url = self.parse_literal_string()?;
-        let schema = if self.parse_keywords(vec!["USING", "SCHEMA"]) {
-            let schema = if self.parse_keyword("REGISTRY") {
+        let schema = if self.parse_keywords(&[Keyword::USING, Keyword::SCHEMA]) {
+            let schema = if self.parse_keyword(Keyword::REGISTRY) {
                 SourceSchema::Registry(self.parse_literal_string()?)
             } else {
                 SourceSchema::RawOrPath(self.parse_literal_string()?)
@@ -1256,9 +2042,9 @@ impl Parser {
     pub fn parse_create_sources(&mut self) -> Result<Statement, ParserError> {
         // Need to get the LIKE if it exists, otherwise keep moving.
         let like = self.parse_like_filter()?;
-        self.expect_keyword("FROM")?;
+        self.expect_keyword(Keyword::FROM)?;
         let url = self.parse_literal_string()?;
-        self.expect_keywords(&["USING", "SCHEMA", "REGISTRY"])?;
+        self.expect_keywords(&[Keyword::USING, Keyword::SCHEMA, Keyword::REGISTRY])?;
         let schema_registry = self.parse_literal_string()?;
         let with_options = self.parse_with_options()?;
         Ok(Statement::CreateSources {
@@ -1270,7 +2056,7 @@ impl Parser {
     }
 
     fn parse_like_filter(&mut self) -> Result<Option<String>, ParserError> {
-        if self.parse_keyword("LIKE") {
+        if self.parse_keyword(Keyword::LIKE) {
             Ok(Some(self.parse_literal_string()?))
         } else {
             Ok(None)
@@ -1279,9 +2065,9 @@ impl Parser {
 
     pub fn parse_create_sink(&mut self) -> Result<Statement, ParserError> {
         let name = self.parse_object_name()?;
-        self.expect_keyword("FROM")?;
+        self.expect_keyword(Keyword::FROM)?;
         let from = self.parse_object_name()?;
-        self.expect_keyword("INTO")?;
+        self.expect_keyword(Keyword::INTO)?;
         let url = self.parse_literal_string()?;
         let with_options = self.parse_with_options()?;
         Ok(Statement::CreateSink {
@@ -1293,13 +2079,13 @@ impl Parser {
     }
 
     pub fn parse_create_external_table(&mut self) -> Result<Statement, ParserError> {
-        self.expect_keyword("TABLE")?;
+        self.expect_keyword(Keyword::TABLE)?;
         let table_name = self.parse_object_name()?;
         let (columns, constraints) = self.parse_columns()?;
-        self.expect_keywords(&["STORED", "AS"])?;
+        self.expect_keywords(&[Keyword::STORED, Keyword::AS])?;
         let file_format = self.parse_identifier()?.value.parse::<FileFormat>()?;
 
-        self.expect_keyword("LOCATION")?;
+        self.expect_keyword(Keyword::LOCATION)?;
         let location = self.parse_literal_string()?;
 
         Ok(Statement::CreateTable {
@@ -1310,32 +2096,61 @@ impl Parser {
             external: true,
             file_format: Some(file_format),
             location: Some(location),
+            without_rowid: false,
+            engine: None,
+            order_by: vec![],
         })
     }
 
     pub fn parse_create_view(&mut self) -> Result<Statement, ParserError> {
-        let materialized = self.parse_keyword("MATERIALIZED");
-        self.expect_keyword("VIEW")?;
+        let temporary = self
+            .parse_one_of_keywords(&[Keyword::TEMP, Keyword::TEMPORARY])
+            .is_some();
+        let materialized = self.parse_keyword(Keyword::MATERIALIZED);
+        self.expect_keyword(Keyword::VIEW)?;
         // Many dialects support `OR REPLACE` | `OR ALTER` right after `CREATE`, but we don't (yet).
         // ANSI SQL and Postgres support RECURSIVE here, but we don't support it either.
         let name = self.parse_object_name()?;
-        let columns = self.parse_parenthesized_column_list(Optional)?;
+        let mut columns = self.parse_view_columns()?;
         let with_options = self.parse_with_options()?;
-        self.expect_keyword("AS")?;
+        // Some dialects put the options before the columns
+        if columns.is_empty() {
+            columns = self.parse_view_columns()?;
+        }
+        self.expect_keyword(Keyword::AS)?;
         let query = Box::new(self.parse_query()?);
         // Optional `WITH [ CASCADED | LOCAL ] CHECK OPTION` is widely supported here.
         Ok(Statement::CreateView {
             name,
             columns,
             query,
+            temporary,
             materialized,
             with_options,
         })
     }
 
+    /// Parse the optional parenthesized column list of a `CREATE VIEW`, in
+    /// which each column may be followed by its type
+    fn parse_view_columns(&mut self) -> Result<Vec<ViewColumnDef>, ParserError> {
+        if !self.consume_token(&Token::LParen) {
+            return Ok(vec![]);
+        }
+        let columns = self.parse_comma_separated(|parser| {
+            let name = parser.parse_identifier()?;
+            let data_type = match parser.peek_token() {
+                Some(Token::Comma) | Some(Token::RParen) => None,
+                _ => Some(parser.parse_data_type()?),
+            };
+            Ok(ViewColumnDef { name, data_type })
+        })?;
+        self.expect_token(&Token::RParen)?;
+        Ok(columns)
+    }
+
     pub fn parse_create_index(&mut self) -> Result<Statement, ParserError> {
         let name = self.parse_identifier()?;
-        self.expect_keyword("ON")?;
+        self.expect_keyword(Keyword::ON)?;
         let on_name = self.parse_object_name()?;
         self.expect_token(&Token::LParen)?;
         let key_parts = self.parse_comma_separated(Parser::parse_expr)?;
@@ -1347,29 +2162,255 @@ impl Parser {
         })
     }
 
+    pub fn parse_create_sequence(&mut self) -> Result<Statement, ParserError> {
+        let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let name = self.parse_object_name()?;
+        let options = self.parse_sequence_options()?;
+        if options
+            .iter()
+            .any(|o| matches!(o, SequenceOption::Restart(_)))
+        {
+            return parser_err!("RESTART is only valid in ALTER SEQUENCE");
+        }
+        Ok(Statement::CreateSequence {
+            name,
+            if_not_exists,
+            options,
+        })
+    }
+
+    /// Parse `CREATE EXTENSION`, assuming `CREATE EXTENSION` was already
+    /// consumed
+    pub fn parse_create_extension(&mut self) -> Result<Statement, ParserError> {
+        let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let name = self.parse_identifier()?;
+        let _ = self.parse_keyword(Keyword::WITH);
+        let schema = if self.parse_keyword(Keyword::SCHEMA) {
+            Some(self.parse_identifier()?)
+        } else {
+            None
+        };
+        let version = if self.parse_keyword(Keyword::VERSION) {
+            match self.peek_token() {
+                Some(Token::SingleQuotedString(_)) => Some(self.parse_literal_string()?),
+                _ => Some(self.parse_identifier()?.value),
+            }
+        } else {
+            None
+        };
+        let cascade = self.parse_keyword(Keyword::CASCADE);
+        Ok(Statement::CreateExtension {
+            name,
+            if_not_exists,
+            schema,
+            version,
+            cascade,
+        })
+    }
+
+    pub fn parse_alter_sequence(&mut self) -> Result<Statement, ParserError> {
+        let if_exists = self.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let name = self.parse_object_name()?;
+        if self.peek_one_of_keywords(&[Keyword::OWNER]) {
+            return self.parse_alter_owner(ObjectType::Sequence, if_exists, name);
+        }
+        let options = self.parse_sequence_options()?;
+        if options.is_empty() {
+            return self.expected("an option in ALTER SEQUENCE", self.peek_token());
+        }
+        Ok(Statement::AlterSequence {
+            name,
+            if_exists,
+            options,
+        })
+    }
+
+    /// Parse the options of `CREATE SEQUENCE` and `ALTER SEQUENCE`, which
+    /// may appear in any order
+    fn parse_sequence_options(&mut self) -> Result<Vec<SequenceOption>, ParserError> {
+        let mut options = vec![];
+        loop {
+            let option = if self.parse_keyword(Keyword::AS) {
+                SequenceOption::As(self.parse_data_type()?)
+            } else if self.parse_keyword(Keyword::INCREMENT) {
+                let _ = self.parse_keyword(Keyword::BY);
+                SequenceOption::IncrementBy(self.parse_expr()?)
+            } else if self.parse_keyword(Keyword::MINVALUE) {
+                SequenceOption::MinValue(Some(self.parse_expr()?))
+            } else if self.parse_keywords(&[Keyword::NO, Keyword::MINVALUE]) {
+                SequenceOption::MinValue(None)
+            } else if self.parse_keyword(Keyword::MAXVALUE) {
+                SequenceOption::MaxValue(Some(self.parse_expr()?))
+            } else if self.parse_keywords(&[Keyword::NO, Keyword::MAXVALUE]) {
+                SequenceOption::MaxValue(None)
+            } else if self.parse_keyword(Keyword::START) {
+                let _ = self.parse_keyword(Keyword::WITH);
+                SequenceOption::StartWith(self.parse_expr()?)
+            } else if self.parse_keyword(Keyword::RESTART) {
+                let value = match self.peek_token() {
+                    Some(Token::Word(ref w)) if w.keyword == Keyword::WITH => {
+                        self.next_token();
+                        Some(self.parse_expr()?)
+                    }
+                    Some(Token::Number(_)) | Some(Token::Minus) | Some(Token::Plus) => {
+                        Some(self.parse_expr()?)
+                    }
+                    _ => None,
+                };
+                SequenceOption::Restart(value)
+            } else if self.parse_keyword(Keyword::CACHE) {
+                SequenceOption::Cache(self.parse_expr()?)
+            } else if self.parse_keyword(Keyword::CYCLE) {
+                SequenceOption::Cycle(true)
+            } else if self.parse_keywords(&[Keyword::NO, Keyword::CYCLE]) {
+                SequenceOption::Cycle(false)
+            } else if self.parse_keywords(&[Keyword::OWNED, Keyword::BY]) {
+                if self.parse_keyword(Keyword::NONE) {
+                    SequenceOption::OwnedBy(None)
+                } else {
+                    SequenceOption::OwnedBy(Some(self.parse_object_name()?))
+                }
+            } else {
+                break;
+            };
+            options.push(option);
+        }
+        Ok(options)
+    }
+
+    pub fn parse_create_role(&mut self, is_user: bool) -> Result<Statement, ParserError> {
+        let name = self.parse_identifier()?;
+        let options = self.parse_role_options()?;
+        Ok(Statement::CreateRole {
+            name,
+            is_user,
+            options,
+        })
+    }
+
+    pub fn parse_alter_role(&mut self, is_user: bool) -> Result<Statement, ParserError> {
+        let name = self.parse_identifier()?;
+        let options = self.parse_role_options()?;
+        if options.is_empty() {
+            return self.expected("an option in ALTER ROLE", self.peek_token());
+        }
+        Ok(Statement::AlterRole {
+            name,
+            is_user,
+            options,
+        })
+    }
+
+    /// Parse the `[ WITH ] <option> [ ... ]` options of `CREATE ROLE` and
+    /// `ALTER ROLE`, which may appear in any order
+    fn parse_role_options(&mut self) -> Result<Vec<RoleOption>, ParserError> {
+        let _ = self.parse_keyword(Keyword::WITH);
+        let mut options = vec![];
+        loop {
+            let option = if let Some(keyword) = self.parse_one_of_keywords(&[
+                Keyword::SUPERUSER,
+                Keyword::NOSUPERUSER,
+                Keyword::CREATEDB,
+                Keyword::NOCREATEDB,
+                Keyword::CREATEROLE,
+                Keyword::NOCREATEROLE,
+                Keyword::INHERIT,
+                Keyword::NOINHERIT,
+                Keyword::LOGIN,
+                Keyword::NOLOGIN,
+                Keyword::REPLICATION,
+                Keyword::NOREPLICATION,
+                Keyword::BYPASSRLS,
+                Keyword::NOBYPASSRLS,
+            ]) {
+                match keyword {
+                    Keyword::SUPERUSER => RoleOption::SuperUser(true),
+                    Keyword::NOSUPERUSER => RoleOption::SuperUser(false),
+                    Keyword::CREATEDB => RoleOption::CreateDb(true),
+                    Keyword::NOCREATEDB => RoleOption::CreateDb(false),
+                    Keyword::CREATEROLE => RoleOption::CreateRole(true),
+                    Keyword::NOCREATEROLE => RoleOption::CreateRole(false),
+                    Keyword::INHERIT => RoleOption::Inherit(true),
+                    Keyword::NOINHERIT => RoleOption::Inherit(false),
+                    Keyword::LOGIN => RoleOption::Login(true),
+                    Keyword::NOLOGIN => RoleOption::Login(false),
+                    Keyword::REPLICATION => RoleOption::Replication(true),
+                    Keyword::NOREPLICATION => RoleOption::Replication(false),
+                    Keyword::BYPASSRLS => RoleOption::BypassRls(true),
+                    Keyword::NOBYPASSRLS => RoleOption::BypassRls(false),
+                    val => panic!(
+                        "`parse_one_of_keywords` returned an impossible value: {:?}",
+                        val
+                    ),
+                }
+            } else if self.parse_keywords(&[Keyword::CONNECTION, Keyword::LIMIT]) {
+                RoleOption::ConnectionLimit(self.parse_expr()?)
+            } else if self.parse_keywords(&[Keyword::ENCRYPTED, Keyword::PASSWORD]) {
+                RoleOption::Password {
+                    encrypted: true,
+                    password: Some(self.parse_literal_string()?),
+                }
+            } else if self.parse_keyword(Keyword::PASSWORD) {
+                let password = if self.parse_keyword(Keyword::NULL) {
+                    None
+                } else {
+                    Some(self.parse_literal_string()?)
+                };
+                RoleOption::Password {
+                    encrypted: false,
+                    password,
+                }
+            } else if self.parse_keywords(&[Keyword::VALID, Keyword::UNTIL]) {
+                RoleOption::ValidUntil(self.parse_literal_string()?)
+            } else if self.parse_keywords(&[Keyword::IN, Keyword::ROLE]) {
+                RoleOption::InRole(self.parse_comma_separated(Parser::parse_identifier)?)
+            } else if self.parse_keyword(Keyword::ROLE) {
+                RoleOption::Role(self.parse_comma_separated(Parser::parse_identifier)?)
+            } else if self.parse_keyword(Keyword::ADMIN) {
+                RoleOption::Admin(self.parse_comma_separated(Parser::parse_identifier)?)
+            } else {
+                break;
+            };
+            options.push(option);
+        }
+        Ok(options)
+    }
+
     pub fn parse_drop(&mut self) -> Result<Statement, ParserError> {
-        let object_type = if self.parse_keyword("TABLE") {
+        let object_type = if self.parse_keyword(Keyword::TABLE) {
             ObjectType::Table
-        } else if self.parse_keyword("VIEW") {
+        } else if self.parse_keyword(Keyword::VIEW) {
             ObjectType::View
-        } else if self.parse_keywords(vec!["SOURCE"]) {
+        } else if self.parse_keywords(&[Keyword::SOURCE]) {
             ObjectType::Source
-        } else if self.parse_keywords(vec!["SINK"]) {
+        } else if self.parse_keywords(&[Keyword::SINK]) {
             ObjectType::Sink
-        } else if self.parse_keyword("INDEX") {
+        } else if self.parse_keyword(Keyword::INDEX) {
             ObjectType::Index
+        } else if self.parse_keyword(Keyword::SCHEMA) {
+            ObjectType::Schema
+        } else if self.parse_keyword(Keyword::DATABASE) {
+            ObjectType::Database
+        } else if self.parse_keyword(Keyword::TYPE) {
+            ObjectType::Type
+        } else if self.parse_keyword(Keyword::ROLE) {
+            ObjectType::Role
+        } else if self.parse_keyword(Keyword::SEQUENCE) {
+            ObjectType::Sequence
+        } else if self.parse_keyword(Keyword::EXTENSION) {
+            ObjectType::Extension
         } else {
             return self.expected(
-                "TABLE, VIEW, SOURCE, SINK, or INDEX after DROP",
+                "TABLE, VIEW, SOURCE, SINK, INDEX, SCHEMA, DATABASE, TYPE, ROLE, SEQUENCE, or EXTENSION after DROP",
                 self.peek_token(),
             );
         };
         // Many dialects support the non standard `IF EXISTS` clause and allow
         // specifying multiple objects to delete in a single statement
-        let if_exists = self.parse_keywords(vec!["IF", "EXISTS"]);
+        let if_exists = self.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
         let names = self.parse_comma_separated(Parser::parse_object_name)?;
-        let cascade = self.parse_keyword("CASCADE");
-        let restrict = self.parse_keyword("RESTRICT");
+        let cascade = self.parse_keyword(Keyword::CASCADE);
+        let restrict = self.parse_keyword(Keyword::RESTRICT);
         if cascade && restrict {
             return parser_err!("Cannot specify both CASCADE and RESTRICT in DROP");
         }
@@ -1385,6 +2426,39 @@ impl Parser {
         let table_name = self.parse_object_name()?;
         // parse optional column list (schema)
         let (columns, constraints) = self.parse_columns()?;
+        let without_rowid = self.parse_keywords(&[Keyword::WITHOUT, Keyword::ROWID]);
+        let engine = if self.parse_keyword(Keyword::ENGINE) {
+            self.expect_token(&Token::Eq)?;
+            let name = self.parse_identifier()?;
+            let parameters = if self.consume_token(&Token::LParen) {
+                let parameters = if self.consume_token(&Token::RParen) {
+                    vec![]
+                } else {
+                    let parameters = self.parse_comma_separated(Parser::parse_expr)?;
+                    self.expect_token(&Token::RParen)?;
+                    parameters
+                };
+                Some(parameters)
+            } else {
+                None
+            };
+            Some(TableEngine { name, parameters })
+        } else {
+            None
+        };
+        // ClickHouse's sorting key is either a single expression or a
+        // parenthesized list of them, and belongs to the table engine
+        let order_by = if engine.is_some() && self.parse_keywords(&[Keyword::ORDER, Keyword::BY]) {
+            if self.consume_token(&Token::LParen) {
+                let exprs = self.parse_comma_separated(Parser::parse_expr)?;
+                self.expect_token(&Token::RParen)?;
+                exprs
+            } else {
+                vec![self.parse_expr()?]
+            }
+        } else {
+            vec![]
+        };
         let with_options = self.parse_with_options()?;
 
         Ok(Statement::CreateTable {
@@ -1395,6 +2469,9 @@ impl Parser {
             external: false,
             file_format: None,
             location: None,
+            without_rowid,
+            engine,
+            order_by,
         })
     }
 
@@ -1411,7 +2488,7 @@ impl Parser {
             } else if let Some(Token::Word(column_name)) = self.peek_token() {
                 self.next_token();
                 let data_type = self.parse_data_type()?;
-                let collation = if self.parse_keyword("COLLATE") {
+                let collation = if self.parse_keyword(Keyword::COLLATE) {
                     Some(self.parse_object_name()?)
                 } else {
                     None
@@ -1446,34 +2523,57 @@ impl Parser {
     }
 
     pub fn parse_column_option_def(&mut self) -> Result<ColumnOptionDef, ParserError> {
-        let name = if self.parse_keyword("CONSTRAINT") {
+        let name = if self.parse_keyword(Keyword::CONSTRAINT) {
             Some(self.parse_identifier()?)
         } else {
             None
         };
 
-        let option = if self.parse_keywords(vec!["NOT", "NULL"]) {
+        let option = if self.parse_keywords(&[Keyword::NOT, Keyword::NULL]) {
             ColumnOption::NotNull
-        } else if self.parse_keyword("NULL") {
+        } else if self.parse_keyword(Keyword::NULL) {
             ColumnOption::Null
-        } else if self.parse_keyword("DEFAULT") {
+        } else if self.parse_keyword(Keyword::DEFAULT) {
             ColumnOption::Default(self.parse_expr()?)
-        } else if self.parse_keywords(vec!["PRIMARY", "KEY"]) {
-            ColumnOption::Unique { is_primary: true }
-        } else if self.parse_keyword("UNIQUE") {
-            ColumnOption::Unique { is_primary: false }
-        } else if self.parse_keyword("REFERENCES") {
+        } else if self.parse_keywords(&[Keyword::PRIMARY, Keyword::KEY]) {
+            ColumnOption::Unique {
+                is_primary: true,
+                characteristics: self.parse_constraint_characteristics()?,
+            }
+        } else if self.parse_keyword(Keyword::UNIQUE) {
+            ColumnOption::Unique {
+                is_primary: false,
+                characteristics: self.parse_constraint_characteristics()?,
+            }
+        } else if self.parse_keyword(Keyword::REFERENCES) {
             let foreign_table = self.parse_object_name()?;
             let referred_columns = self.parse_parenthesized_column_list(Mandatory)?;
+            let (on_delete, on_update) = self.parse_referential_actions()?;
+            let characteristics = self.parse_constraint_characteristics()?;
             ColumnOption::ForeignKey {
                 foreign_table,
                 referred_columns,
+                on_delete,
+                on_update,
+                characteristics,
             }
-        } else if self.parse_keyword("CHECK") {
+        } else if self.parse_keyword(Keyword::CHECK) {
             self.expect_token(&Token::LParen)?;
             let expr = self.parse_expr()?;
             self.expect_token(&Token::RParen)?;
-            ColumnOption::Check(expr)
+            ColumnOption::Check {
+                expr,
+                characteristics: self.parse_constraint_characteristics()?,
+            }
+        } else if self.parse_keyword(Keyword::AUTOINCREMENT) {
+            ColumnOption::Autoincrement
+        } else if self.parse_keyword(Keyword::IDENTITY) {
+            self.expect_token(&Token::LParen)?;
+            let seed = self.parse_literal_uint()?;
+            self.expect_token(&Token::Comma)?;
+            let increment = self.parse_literal_uint()?;
+            self.expect_token(&Token::RParen)?;
+            ColumnOption::Identity { seed, increment }
         } else {
             return self.expected("column option", self.peek_token());
         };
@@ -1481,45 +2581,186 @@ impl Parser {
         Ok(ColumnOptionDef { name, option })
     }
 
+    /// Parse the optional `ON DELETE <action>` and `ON UPDATE <action>`
+    /// clauses of a foreign key, which may appear in either order
+    fn parse_referential_actions(
+        &mut self,
+    ) -> Result<(Option<ReferentialAction>, Option<ReferentialAction>), ParserError> {
+        let mut on_delete = None;
+        let mut on_update = None;
+        loop {
+            if on_delete.is_none() && self.parse_keywords(&[Keyword::ON, Keyword::DELETE]) {
+                on_delete = Some(self.parse_referential_action()?);
+            } else if on_update.is_none() && self.parse_keywords(&[Keyword::ON, Keyword::UPDATE]) {
+                on_update = Some(self.parse_referential_action()?);
+            } else {
+                break;
+            }
+        }
+        Ok((on_delete, on_update))
+    }
+
+    pub fn parse_referential_action(&mut self) -> Result<ReferentialAction, ParserError> {
+        if self.parse_keyword(Keyword::RESTRICT) {
+            Ok(ReferentialAction::Restrict)
+        } else if self.parse_keyword(Keyword::CASCADE) {
+            Ok(ReferentialAction::Cascade)
+        } else if self.parse_keywords(&[Keyword::SET, Keyword::NULL]) {
+            Ok(ReferentialAction::SetNull)
+        } else if self.parse_keywords(&[Keyword::NO, Keyword::ACTION]) {
+            Ok(ReferentialAction::NoAction)
+        } else if self.parse_keywords(&[Keyword::SET, Keyword::DEFAULT]) {
+            Ok(ReferentialAction::SetDefault)
+        } else {
+            self.expected(
+                "one of RESTRICT, CASCADE, SET NULL, NO ACTION or SET DEFAULT",
+                self.peek_token(),
+            )
+        }
+    }
+
+    /// Parse the optional `[ NOT ] DEFERRABLE`, `INITIALLY { DEFERRED |
+    /// IMMEDIATE }` and `[ NOT ] ENFORCED` attributes of a constraint, which
+    /// may appear in any order
+    pub fn parse_constraint_characteristics(
+        &mut self,
+    ) -> Result<Option<ConstraintCharacteristics>, ParserError> {
+        /// Record `value` in `slot`, rejecting a characteristic that repeats
+        /// or contradicts an earlier one, where `text` spells a value
+        fn set<T: PartialEq>(
+            slot: &mut Option<T>,
+            value: T,
+            text: impl Fn(&T) -> String,
+        ) -> Result<(), ParserError> {
+            match slot {
+                None => {
+                    *slot = Some(value);
+                    Ok(())
+                }
+                Some(earlier) if *earlier == value => {
+                    parser_err!(format!("Cannot specify {} more than once", text(&value)))
+                }
+                Some(earlier) => parser_err!(format!(
+                    "Cannot specify both {} and {}",
+                    text(earlier),
+                    text(&value)
+                )),
+            }
+        }
+        let deferrable = |deferrable: &bool| {
+            if *deferrable {
+                "DEFERRABLE"
+            } else {
+                "NOT DEFERRABLE"
+            }
+            .to_string()
+        };
+        let initially = |initially: &DeferrableInitial| format!("INITIALLY {}", initially);
+        let enforced = |enforced: &bool| {
+            if *enforced {
+                "ENFORCED"
+            } else {
+                "NOT ENFORCED"
+            }
+            .to_string()
+        };
+
+        let mut characteristics = ConstraintCharacteristics {
+            deferrable: None,
+            initially: None,
+            enforced: None,
+        };
+        loop {
+            if self.parse_keywords(&[Keyword::NOT, Keyword::DEFERRABLE]) {
+                set(&mut characteristics.deferrable, false, deferrable)?;
+            } else if self.parse_keyword(Keyword::DEFERRABLE) {
+                set(&mut characteristics.deferrable, true, deferrable)?;
+            } else if self.parse_keyword(Keyword::INITIALLY) {
+                let value =
+                    match self.expect_one_of_keywords(&[Keyword::DEFERRED, Keyword::IMMEDIATE])? {
+                        Keyword::DEFERRED => DeferrableInitial::Deferred,
+                        Keyword::IMMEDIATE => DeferrableInitial::Immediate,
+                        _ => unreachable!(),
+                    };
+                set(&mut characteristics.initially, value, initially)?;
+            } else if self.parse_keywords(&[Keyword::NOT, Keyword::ENFORCED]) {
+                set(&mut characteristics.enforced, false, enforced)?;
+            } else if self.parse_keyword(Keyword::ENFORCED) {
+                set(&mut characteristics.enforced, true, enforced)?;
+            } else {
+                break;
+            }
+        }
+        // Only a deferrable constraint can be deferred
+        if characteristics.deferrable == Some(false)
+            && characteristics.initially == Some(DeferrableInitial::Deferred)
+        {
+            return parser_err!("Cannot specify both NOT DEFERRABLE and INITIALLY DEFERRED");
+        }
+        if characteristics.deferrable.is_none()
+            && characteristics.initially.is_none()
+            && characteristics.enforced.is_none()
+        {
+            Ok(None)
+        } else {
+            Ok(Some(characteristics))
+        }
+    }
+
     pub fn parse_optional_table_constraint(
         &mut self,
     ) -> Result<Option<TableConstraint>, ParserError> {
-        let name = if self.parse_keyword("CONSTRAINT") {
+        let name = if self.parse_keyword(Keyword::CONSTRAINT) {
             Some(self.parse_identifier()?)
         } else {
             None
         };
         match self.next_token() {
-            Some(Token::Word(ref k)) if k.keyword == "PRIMARY" || k.keyword == "UNIQUE" => {
-                let is_primary = k.keyword == "PRIMARY";
+            Some(Token::Word(ref k))
+                if k.keyword == Keyword::PRIMARY || k.keyword == Keyword::UNIQUE =>
+            {
+                let is_primary = k.keyword == Keyword::PRIMARY;
                 if is_primary {
-                    self.expect_keyword("KEY")?;
+                    self.expect_keyword(Keyword::KEY)?;
                 }
                 let columns = self.parse_parenthesized_column_list(Mandatory)?;
+                let characteristics = self.parse_constraint_characteristics()?;
                 Ok(Some(TableConstraint::Unique {
                     name,
                     columns,
                     is_primary,
+                    characteristics,
                 }))
             }
-            Some(Token::Word(ref k)) if k.keyword == "FOREIGN" => {
-                self.expect_keyword("KEY")?;
+            Some(Token::Word(ref k)) if k.keyword == Keyword::FOREIGN => {
+                self.expect_keyword(Keyword::KEY)?;
                 let columns = self.parse_parenthesized_column_list(Mandatory)?;
-                self.expect_keyword("REFERENCES")?;
+                self.expect_keyword(Keyword::REFERENCES)?;
                 let foreign_table = self.parse_object_name()?;
                 let referred_columns = self.parse_parenthesized_column_list(Mandatory)?;
+                let (on_delete, on_update) = self.parse_referential_actions()?;
+                let characteristics = self.parse_constraint_characteristics()?;
                 Ok(Some(TableConstraint::ForeignKey {
                     name,
                     columns,
                     foreign_table,
                     referred_columns,
+                    on_delete,
+                    on_update,
+                    characteristics,
                 }))
             }
-            Some(Token::Word(ref k)) if k.keyword == "CHECK" => {
+            Some(Token::Word(ref k)) if k.keyword == Keyword::CHECK => {
                 self.expect_token(&Token::LParen)?;
                 let expr = Box::new(self.parse_expr()?);
                 self.expect_token(&Token::RParen)?;
-                Ok(Some(TableConstraint::Check { name, expr }))
+                let no_inherit = self.parse_keywords(&[Keyword::NO, Keyword::INHERIT]);
+                Ok(Some(TableConstraint::Check {
+                    name,
+                    expr,
+                    no_inherit,
+                    characteristics: self.parse_constraint_characteristics()?,
+                }))
             }
             unexpected => {
                 if name.is_some() {
@@ -1533,7 +2774,7 @@ impl Parser {
     }
 
     pub fn parse_with_options(&mut self) -> Result<Vec<SqlOption>, ParserError> {
-        if self.parse_keyword("WITH") {
+        if self.parse_keyword(Keyword::WITH) {
             self.expect_token(&Token::LParen)?;
             let options = self.parse_comma_separated(Parser::parse_sql_option)?;
             self.expect_token(&Token::RParen)?;
@@ -1545,98 +2786,344 @@ impl Parser {
 
     pub fn parse_sql_option(&mut self) -> Result<SqlOption, ParserError> {
         let name = self.parse_identifier()?;
-        self.expect_token(&Token::Eq)?;
-        let value = self.parse_value()?;
-        Ok(SqlOption { name, value })
+        if !self.consume_token(&Token::Eq) {
+            // Postgres treats a bare option name as a boolean flag
+            return match self.peek_token() {
+                Some(Token::Comma) | Some(Token::RParen) => Ok(SqlOption::Flag { name }),
+                t => self.expected("=", t),
+            };
+        }
+        match self.peek_token() {
+            Some(Token::Word(w))
+                if !matches!(
+                    w.keyword,
+                    Keyword::TRUE | Keyword::FALSE | Keyword::NULL | Keyword::ARRAY
+                ) =>
+            {
+                Ok(SqlOption::ObjectName {
+                    name,
+                    object_name: self.parse_object_name()?,
+                })
+            }
+            _ => Ok(SqlOption::Value {
+                name,
+                value: self.parse_value()?,
+            }),
+        }
     }
 
     pub fn parse_alter(&mut self) -> Result<Statement, ParserError> {
-        self.expect_keyword("TABLE")?;
-        let _ = self.parse_keyword("ONLY");
+        if self.parse_keyword(Keyword::SEQUENCE) {
+            return self.parse_alter_sequence();
+        } else if self.parse_keyword(Keyword::ROLE) {
+            return self.parse_alter_role(false);
+        } else if self.parse_keyword(Keyword::USER) {
+            return self.parse_alter_role(true);
+        }
+        let object_type = match self.parse_one_of_keywords(&[
+            Keyword::VIEW,
+            Keyword::SOURCE,
+            Keyword::SINK,
+            Keyword::INDEX,
+            Keyword::SCHEMA,
+            Keyword::DATABASE,
+            Keyword::TYPE,
+        ]) {
+            Some(Keyword::VIEW) => Some(ObjectType::View),
+            Some(Keyword::SOURCE) => Some(ObjectType::Source),
+            Some(Keyword::SINK) => Some(ObjectType::Sink),
+            Some(Keyword::INDEX) => Some(ObjectType::Index),
+            Some(Keyword::SCHEMA) => Some(ObjectType::Schema),
+            Some(Keyword::DATABASE) => Some(ObjectType::Database),
+            Some(Keyword::TYPE) => Some(ObjectType::Type),
+            _ => None,
+        };
+        if let Some(object_type) = object_type {
+            let if_exists = self.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+            let name = self.parse_object_name()?;
+            if object_type == ObjectType::View {
+                if let Some(operation) = self.parse_alter_options_operation()? {
+                    return Ok(Statement::AlterViewOptions {
+                        if_exists,
+                        name,
+                        operation,
+                    });
+                }
+            }
+            return self.parse_alter_owner(object_type, if_exists, name);
+        }
+        self.expect_keyword(Keyword::TABLE)?;
+        let only = self.parse_keyword(Keyword::ONLY);
         let table_name = self.parse_object_name()?;
-        let operation = if self.parse_keyword("ADD") {
+        let operation = if self.parse_keyword(Keyword::ADD) {
             if let Some(constraint) = self.parse_optional_table_constraint()? {
                 AlterTableOperation::AddConstraint(constraint)
             } else {
                 return self.expected("a constraint in ALTER TABLE .. ADD", self.peek_token());
             }
+        } else if self.parse_keyword(Keyword::ALTER) {
+            let _ = self.parse_keyword(Keyword::COLUMN);
+            let column_name = self.parse_identifier()?;
+            let op = if self.parse_keywords(&[Keyword::SET, Keyword::DEFAULT]) {
+                AlterColumnOperation::SetDefault(self.parse_expr()?)
+            } else if self.parse_keywords(&[Keyword::DROP, Keyword::DEFAULT]) {
+                AlterColumnOperation::DropDefault
+            } else {
+                return self.expected(
+                    "SET DEFAULT or DROP DEFAULT after ALTER COLUMN",
+                    self.peek_token(),
+                );
+            };
+            AlterTableOperation::AlterColumn { column_name, op }
+        } else if self.parse_keywords(&[Keyword::OWNER, Keyword::TO]) {
+            AlterTableOperation::OwnerTo {
+                new_owner: self.parse_identifier()?,
+            }
         } else {
-            return self.expected("ADD after ALTER TABLE", self.peek_token());
-        };
-        Ok(Statement::AlterTable {
+            return self.expected(
+                "ADD, ALTER or OWNER TO after ALTER TABLE",
+                self.peek_token(),
+            );
+        };
+        Ok(Statement::AlterTable {
+            only,
             name: table_name,
             operation,
         })
     }
 
+    /// Parse `SET ( <option> [, ...] )` or `RESET ( <option_name> [, ...] )`,
+    /// if present
+    fn parse_alter_options_operation(
+        &mut self,
+    ) -> Result<Option<AlterOptionsOperation>, ParserError> {
+        let operation = if self.parse_keyword(Keyword::SET) {
+            self.expect_token(&Token::LParen)?;
+            AlterOptionsOperation::Set(self.parse_comma_separated(Parser::parse_sql_option)?)
+        } else if self.parse_keyword(Keyword::RESET) {
+            self.expect_token(&Token::LParen)?;
+            AlterOptionsOperation::Reset(self.parse_comma_separated(Parser::parse_identifier)?)
+        } else {
+            return Ok(None);
+        };
+        self.expect_token(&Token::RParen)?;
+        Ok(Some(operation))
+    }
+
+    /// Parse the `OWNER TO <new_owner>` of an `ALTER` statement, assuming
+    /// everything up to and including the object's name was already consumed
+    fn parse_alter_owner(
+        &mut self,
+        object_type: ObjectType,
+        if_exists: bool,
+        name: ObjectName,
+    ) -> Result<Statement, ParserError> {
+        self.expect_keywords(&[Keyword::OWNER, Keyword::TO])?;
+        Ok(Statement::AlterOwner {
+            object_type,
+            if_exists,
+            name,
+            new_owner: self.parse_identifier()?,
+        })
+    }
+
     /// Parse a copy statement
     pub fn parse_copy(&mut self) -> Result<Statement, ParserError> {
-        let table_name = self.parse_object_name()?;
-        let columns = self.parse_parenthesized_column_list(Optional)?;
-        self.expect_keywords(&["FROM", "STDIN"])?;
-        self.expect_token(&Token::SemiColon)?;
-        let values = self.parse_tsv()?;
-        Ok(Statement::Copy {
-            table_name,
-            columns,
-            values,
-        })
+        let relation = if self.consume_token(&Token::LParen) {
+            let query = self.parse_query()?;
+            self.expect_token(&Token::RParen)?;
+            CopyRelation::Query(Box::new(query))
+        } else {
+            CopyRelation::Table {
+                name: self.parse_object_name()?,
+                columns: self.parse_parenthesized_column_list(Optional)?,
+            }
+        };
+        let target = match relation {
+            // Query results can't be copied into
+            CopyRelation::Query(_) => {
+                self.expect_keywords(&[Keyword::TO, Keyword::STDOUT])?;
+                CopyTarget::Stdout
+            }
+            CopyRelation::Table { .. } => {
+                match self.expect_one_of_keywords(&[Keyword::FROM, Keyword::TO])? {
+                    Keyword::FROM => {
+                        self.expect_keyword(Keyword::STDIN)?;
+                        CopyTarget::Stdin
+                    }
+                    _ => {
+                        self.expect_keyword(Keyword::STDOUT)?;
+                        CopyTarget::Stdout
+                    }
+                }
+            }
+        };
+        let with = self.parse_keyword(Keyword::WITH);
+        let options = if with || self.peek_token() == Some(Token::LParen) {
+            self.expect_token(&Token::LParen)?;
+            let options = self.parse_comma_separated(Parser::parse_copy_option)?;
+            self.expect_token(&Token::RParen)?;
+            options
+        } else {
+            vec![]
+        };
+        let mut statement = Statement::Copy {
+            relation,
+            target,
+            options,
+            values: vec![],
+        };
+        if statement.has_copy_payload() {
+            self.expect_token(&Token::SemiColon)?;
+            if let (
+                Statement::Copy {
+                    options, values, ..
+                },
+                Some(Token::CopyPayload(payload)),
+            ) = (&mut statement, self.tokens.get(self.index))
+            {
+                *values = CopyRows::parse(CopyFormat::of(options), payload)?;
+                self.index += 1;
+            }
+        }
+        Ok(statement)
     }
 
-    /// Parse a tab separated values in
-    /// COPY payload
-    fn parse_tsv(&mut self) -> Result<Vec<Option<String>>, ParserError> {
-        let values = self.parse_tab_value()?;
-        Ok(values)
+    /// Parse a single option in the `WITH (...)` list of a COPY statement
+    fn parse_copy_option(&mut self) -> Result<CopyOption, ParserError> {
+        // Not all of the option names are keywords in every dialect
+        let name = match self.next_token() {
+            Some(Token::Word(w)) if w.quote_style.is_none() => w.value.to_ascii_uppercase(),
+            other => return self.expected("COPY option", other),
+        };
+        match name.as_str() {
+            "FORMAT" => match self.next_token() {
+                Some(Token::Word(w)) => match w.value.to_ascii_lowercase().as_str() {
+                    "text" => Ok(CopyOption::Format(CopyFormatName::Text)),
+                    "csv" => Ok(CopyOption::Format(CopyFormatName::Csv)),
+                    "binary" => Ok(CopyOption::Format(CopyFormatName::Binary)),
+                    _ => self.expected("text, csv or binary", Some(Token::Word(w))),
+                },
+                other => self.expected("text, csv or binary", other),
+            },
+            "DELIMITER" => Ok(CopyOption::Delimiter(self.parse_copy_option_char(&name)?)),
+            "NULL" => Ok(CopyOption::Null(self.parse_literal_string()?)),
+            "HEADER" => match self.parse_one_of_keywords(&[Keyword::TRUE, Keyword::FALSE]) {
+                Some(Keyword::FALSE) => Ok(CopyOption::Header(false)),
+                _ => Ok(CopyOption::Header(true)),
+            },
+            "QUOTE" => Ok(CopyOption::Quote(self.parse_copy_option_char(&name)?)),
+            "ESCAPE" => Ok(CopyOption::Escape(self.parse_copy_option_char(&name)?)),
+            _ => parser_err!(format!("Unknown COPY option: {}", name)),
+        }
     }
 
-    fn parse_tab_value(&mut self) -> Result<Vec<Option<String>>, ParserError> {
+    /// Parse the single character string that is the value of the COPY
+    /// option `name`
+    fn parse_copy_option_char(&mut self, name: &str) -> Result<char, ParserError> {
+        let value = self.parse_literal_string()?;
+        let mut chars = value.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) if c != '\n' && c != '\r' => Ok(c),
+            _ => parser_err!(format!(
+                "COPY {} must be a single character, found: '{}'",
+                name, value
+            )),
+        }
+    }
+
+    /// Split a line of a COPY payload in the text format at the delimiters
+    /// that aren't escaped with a backslash
+    fn split_text_line(line: &str, delimiter: char) -> Vec<&str> {
         let mut values = vec![];
-        let mut content = String::from("");
-        while let Some(t) = self.next_token_no_skip() {
-            match t {
-                Token::Whitespace(Whitespace::Tab) => {
-                    values.push(Some(content.to_string()));
-                    content.clear();
-                }
-                Token::Whitespace(Whitespace::Newline) => {
-                    values.push(Some(content.to_string()));
-                    content.clear();
-                }
-                Token::Backslash => {
-                    if self.consume_token(&Token::Period) {
-                        return Ok(values);
+        let mut start = 0;
+        let mut chars = line.char_indices();
+        while let Some((i, ch)) = chars.next() {
+            if ch == '\\' {
+                chars.next();
+            } else if ch == delimiter {
+                values.push(&line[start..i]);
+                start = i + ch.len_utf8();
+            }
+        }
+        values.push(&line[start..]);
+        values
+    }
+
+    /// Decode a single value of a COPY payload in the PostgreSQL text
+    /// format, where `null` (`\N` by default) represents NULL and backslash
+    /// escape sequences stand for special characters
+    fn parse_tab_value(value: &str, null: &str) -> Option<String> {
+        if value == null {
+            return None;
+        }
+        let mut bytes = Vec::with_capacity(value.len());
+        let mut chars = value.chars().peekable();
+        while let Some(ch) = chars.next() {
+            if ch != '\\' {
+                let mut buf = [0; 4];
+                bytes.extend_from_slice(ch.encode_utf8(&mut buf).as_bytes());
+                continue;
+            }
+            match chars.next() {
+                Some('b') => bytes.push(b'\x08'),
+                Some('f') => bytes.push(b'\x0c'),
+                Some('n') => bytes.push(b'\n'),
+                Some('r') => bytes.push(b'\r'),
+                Some('t') => bytes.push(b'\t'),
+                Some('v') => bytes.push(b'\x0b'),
+                // `\digits`: a byte given by up to three octal digits
+                Some(d @ '0'..='7') => {
+                    let mut byte = d.to_digit(8).unwrap();
+                    for _ in 0..2 {
+                        match chars.peek().and_then(|c| c.to_digit(8)) {
+                            Some(digit) => {
+                                byte = byte * 8 + digit;
+                                chars.next();
+                            }
+                            None => break,
+                        }
                     }
-                    if let Some(token) = self.next_token() {
-                        if let Token::Word(Word { value: v, .. }) = token {
-                            if v == "N" {
-                                values.push(None);
+                    bytes.push(byte as u8);
+                }
+                // `\xdigits`: a byte given by one or two hex digits
+                Some('x') if chars.peek().and_then(|c| c.to_digit(16)).is_some() => {
+                    let mut byte = 0;
+                    for _ in 0..2 {
+                        match chars.peek().and_then(|c| c.to_digit(16)) {
+                            Some(digit) => {
+                                byte = byte * 16 + digit;
+                                chars.next();
                             }
+                            None => break,
                         }
-                    } else {
-                        continue;
                     }
+                    bytes.push(byte as u8);
                 }
-                _ => {
-                    content.push_str(&t.to_string());
+                // Any other escaped character, including `\\`, stands for itself
+                Some(ch) => {
+                    let mut buf = [0; 4];
+                    bytes.extend_from_slice(ch.encode_utf8(&mut buf).as_bytes());
                 }
+                None => bytes.push(b'\\'),
             }
         }
-        Ok(values)
+        Some(String::from_utf8_lossy(&bytes).into_owned())
     }
 
     /// Parse a literal value (numbers, strings, date/time, booleans)
     fn parse_value(&mut self) -> Result<Value, ParserError> {
         match self.next_token() {
             Some(t) => match t {
-                Token::Word(k) => match k.keyword.as_ref() {
-                    "TRUE" => Ok(Value::Boolean(true)),
-                    "FALSE" => Ok(Value::Boolean(false)),
-                    "NULL" => Ok(Value::Null),
-                    "ARRAY" => self.parse_array(),
-                    _ => {
-                        return parser_err!(format!("No value parser for keyword {}", k.keyword));
-                    }
+                Token::Word(k) => match k.keyword {
+                    Keyword::TRUE => Ok(Value::Boolean(true)),
+                    Keyword::FALSE => Ok(Value::Boolean(false)),
+                    Keyword::NULL => Ok(Value::Null),
+                    Keyword::ARRAY => self.parse_array(),
+                    _ => parser_err!(format!(
+                        "No value parser for keyword {}",
+                        k.keyword.as_str()
+                    )),
                 },
                 // The call to n.parse() returns a bigdecimal when the
                 // bigdecimal feature is enabled, and is otherwise a no-op
@@ -1645,17 +3132,52 @@ impl Parser {
                     Ok(n) => Ok(Value::Number(n)),
                     Err(e) => parser_err!(format!("Could not parse '{}' as number: {}", n, e)),
                 },
-                Token::SingleQuotedString(ref s) => Ok(Value::SingleQuotedString(s.to_string())),
+                Token::SingleQuotedString(s) => {
+                    let s = self.parse_string_continuation(s);
+                    // Displayed as a standard literal, its backslashes would
+                    // start escape sequences when parsed again
+                    if self.dialect.supports_backslash_escapes() && s.contains('\\') {
+                        Ok(Value::BackslashEscapedString(s))
+                    } else {
+                        Ok(Value::SingleQuotedString(s))
+                    }
+                }
                 Token::NationalStringLiteral(ref s) => {
                     Ok(Value::NationalStringLiteral(s.to_string()))
                 }
+                Token::UnicodeStringLiteral(ref s) => {
+                    let escape = if self.parse_keyword(Keyword::UESCAPE) {
+                        self.parse_unicode_escape_character()?
+                    } else {
+                        '\\'
+                    };
+                    match unescape_unicode_string_literal(s, escape) {
+                        Ok(s) => Ok(Value::UnicodeStringLiteral(s)),
+                        Err(e) => parser_err!(e.to_string()),
+                    }
+                }
                 Token::HexStringLiteral(ref s) => Ok(Value::HexStringLiteral(s.to_string())),
+                Token::DollarQuotedString(s) => Ok(Value::DollarQuotedString(s)),
                 _ => parser_err!(format!("Unsupported value: {:?}", t)),
             },
             None => parser_err!("Expecting a value, but found EOF"),
         }
     }
 
+    /// Parse the `'c'` of `UESCAPE 'c'`
+    fn parse_unicode_escape_character(&mut self) -> Result<char, ParserError> {
+        let s = self.parse_literal_string()?;
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None)
+                if !c.is_ascii_hexdigit() && !c.is_whitespace() && !"+'\"".contains(c) =>
+            {
+                Ok(c)
+            }
+            _ => parser_err!(format!("Invalid Unicode escape character '{}'", s)),
+        }
+    }
+
     fn parse_array(&mut self) -> Result<Value, ParserError> {
         self.expect_token(&Token::LBracket)?;
         let mut values = vec![];
@@ -1695,111 +3217,209 @@ impl Parser {
     /// Parse a literal string
     pub fn parse_literal_string(&mut self) -> Result<String, ParserError> {
         match self.next_token() {
-            Some(Token::SingleQuotedString(ref s)) => Ok(s.clone()),
+            Some(Token::SingleQuotedString(s)) => Ok(self.parse_string_continuation(s)),
             other => self.expected("literal string", other),
         }
     }
 
+    /// Append any string literals that follow the just-consumed string
+    /// literal `s` to it, if the dialect concatenates adjacent literals
+    fn parse_string_continuation(&mut self, mut s: String) -> String {
+        if self.dialect.supports_string_literal_concatenation() {
+            let requires_newline = self.dialect.string_literal_concatenation_requires_newline();
+            while let Some(Token::SingleQuotedString(continuation)) = self.peek_token() {
+                if requires_newline && !self.next_token_follows_newline() {
+                    break;
+                }
+                self.next_token();
+                s.push_str(&continuation);
+            }
+        }
+        s
+    }
+
+    /// Report whether the whitespace and comments before the next
+    /// non-whitespace token include a line break
+    fn next_token_follows_newline(&self) -> bool {
+        self.tokens[self.index..]
+            .iter()
+            .take_while(|token| matches!(token, Token::Whitespace(_)))
+            .any(|token| match token {
+                Token::Whitespace(Whitespace::Newline) => true,
+                Token::Whitespace(Whitespace::SingleLineComment(comment))
+                | Token::Whitespace(Whitespace::MultiLineComment(comment)) => {
+                    comment.contains('\n')
+                }
+                _ => false,
+            })
+    }
+
     /// Parse a SQL datatype (in the context of a CREATE TABLE statement for example)
     pub fn parse_data_type(&mut self) -> Result<DataType, ParserError> {
+        let start = self.index;
         let mut data_type = match self.next_token() {
-            Some(Token::Word(k)) => match k.keyword.as_ref() {
-                "BOOLEAN" => DataType::Boolean,
-                "FLOAT" => DataType::Float(self.parse_optional_precision()?),
-                "REAL" => DataType::Real,
-                "DOUBLE" => {
-                    let _ = self.parse_keyword("PRECISION");
+            Some(Token::Word(k)) => match k.keyword {
+                Keyword::BOOLEAN => DataType::Boolean,
+                Keyword::FLOAT => DataType::Float(self.parse_optional_precision()?),
+                Keyword::REAL => DataType::Real,
+                Keyword::DOUBLE => {
+                    let _ = self.parse_keyword(Keyword::PRECISION);
                     DataType::Double
                 }
-                "SMALLINT" => DataType::SmallInt,
-                "INT" | "INTEGER" => DataType::Int,
-                "BIGINT" => DataType::BigInt,
-                "VARCHAR" => DataType::Varchar(self.parse_optional_precision()?),
-                "CHAR" | "CHARACTER" => {
-                    if self.parse_keyword("VARYING") {
-                        DataType::Varchar(self.parse_optional_precision()?)
+                Keyword::SMALLINT => DataType::SmallInt,
+                Keyword::INT | Keyword::INTEGER => DataType::Int,
+                Keyword::BIGINT => DataType::BigInt,
+                Keyword::VARCHAR => DataType::Varchar(self.parse_optional_character_length()?),
+                Keyword::CHAR | Keyword::CHARACTER => {
+                    if self.parse_keyword(Keyword::VARYING) {
+                        DataType::Varchar(self.parse_optional_character_length()?)
                     } else {
-                        DataType::Char(self.parse_optional_precision()?)
+                        DataType::Char(self.parse_optional_character_length()?)
                     }
                 }
-                "UUID" => DataType::Uuid,
-                "DATE" => DataType::Date,
-                "TIMESTAMP" => {
-                    if self.parse_keyword("WITH") {
-                        self.expect_keywords(&["TIME", "ZONE"])?;
-                        DataType::TimestampTz
+                Keyword::NVARCHAR => DataType::Nvarchar(self.parse_optional_character_length()?),
+                Keyword::NCHAR => {
+                    if self.parse_keyword(Keyword::VARYING) {
+                        DataType::Nvarchar(self.parse_optional_character_length()?)
                     } else {
-                        if self.parse_keyword("WITHOUT") {
-                            self.expect_keywords(&["TIME", "ZONE"])?;
-                        }
-                        DataType::Timestamp
+                        DataType::Nchar(self.parse_optional_character_length()?)
                     }
                 }
-                "TIMESTAMPTZ" => DataType::TimestampTz,
-                "TIME" => {
-                    if self.parse_keyword("WITH") {
-                        self.expect_keywords(&["TIME", "ZONE"])?;
-                        DataType::TimeTz
+                Keyword::NATIONAL => {
+                    self.expect_one_of_keywords(&[Keyword::CHAR, Keyword::CHARACTER])?;
+                    if self.parse_keyword(Keyword::VARYING) {
+                        DataType::Nvarchar(self.parse_optional_character_length()?)
                     } else {
-                        if self.parse_keyword("WITHOUT") {
-                            self.expect_keywords(&["TIME", "ZONE"])?;
-                        }
-                        DataType::Time
+                        DataType::Nchar(self.parse_optional_character_length()?)
                     }
                 }
+                Keyword::UUID => DataType::Uuid,
+                Keyword::DATE => DataType::Date,
+                Keyword::TIMESTAMP => DataType::Timestamp {
+                    precision: self.parse_optional_precision()?,
+                    with_time_zone: self.parse_optional_time_zone()?,
+                },
+                Keyword::TIMESTAMPTZ => DataType::Timestamp {
+                    precision: self.parse_optional_precision()?,
+                    with_time_zone: true,
+                },
+                Keyword::TIME => DataType::Time {
+                    precision: self.parse_optional_precision()?,
+                    with_time_zone: self.parse_optional_time_zone()?,
+                },
                 // Interval types can be followed by a complicated interval
                 // qualifier that we don't currently support. See
                 // parse_interval_literal for a taste.
-                "INTERVAL" => DataType::Interval,
-                "REGCLASS" => DataType::Regclass,
-                "TEXT" => DataType::Text,
-                "BYTEA" => DataType::Bytea,
-                "NUMERIC" | "DECIMAL" | "DEC" => {
+                Keyword::INTERVAL => DataType::Interval,
+                Keyword::REGCLASS => DataType::Regclass,
+                Keyword::TEXT => DataType::Text,
+                Keyword::BYTEA => DataType::Bytea,
+                Keyword::CLOB => DataType::Clob(self.parse_precision()?),
+                Keyword::BINARY => DataType::Binary(self.parse_precision()?),
+                Keyword::VARBINARY => DataType::Varbinary(self.parse_precision()?),
+                Keyword::BLOB => DataType::Blob(self.parse_precision()?),
+                Keyword::NUMERIC | Keyword::DECIMAL | Keyword::DEC => {
+                    let name = match k.keyword {
+                        Keyword::NUMERIC => DecimalName::Numeric,
+                        Keyword::DECIMAL => DecimalName::Decimal,
+                        _ => DecimalName::Dec,
+                    };
                     let (precision, scale) = self.parse_optional_precision_scale()?;
-                    DataType::Decimal(precision, scale)
+                    match (precision, self.dialect.max_decimal_precision()) {
+                        (Some(precision), Some(max)) if precision > max => {
+                            return parser_err!(format!(
+                                "{} precision {} exceeds the maximum of {}",
+                                name, precision, max
+                            ));
+                        }
+                        _ => (),
+                    }
+                    DataType::Decimal {
+                        name,
+                        precision,
+                        scale,
+                    }
                 }
                 _ => {
                     self.prev_token();
                     let type_name = self.parse_object_name()?;
-                    DataType::Custom(type_name)
+                    let modifiers = if self.consume_token(&Token::LParen) {
+                        let modifiers = self.parse_comma_separated(Parser::parse_type_modifier)?;
+                        self.expect_token(&Token::RParen)?;
+                        modifiers
+                    } else {
+                        vec![]
+                    };
+                    DataType::Custom(type_name, modifiers)
                 }
             },
             other => self.expected("a data type name", other)?,
         };
+        if self.preserve_type_names && !matches!(data_type, DataType::Custom(..)) {
+            let spelling = self.text_since(start);
+            if spelling != data_type.to_string() {
+                data_type = DataType::Spelled(Box::new(data_type), spelling);
+            }
+        }
         match &self.peek_token() {
             Some(Token::LBracket) => {
                 while self.consume_token(&Token::LBracket) {
                     // Note: this is postgresql-specific
-                    self.expect_token(&Token::RBracket)?;
-                    data_type = DataType::Array(Box::new(data_type));
+                    let size = self.parse_array_type_size()?;
+                    data_type = DataType::Array(Box::new(data_type), size);
                 }
             }
-            Some(Token::Word(k)) if &k.keyword == "ARRAY" => {
+            Some(Token::Word(k)) if k.keyword == Keyword::ARRAY => {
                 self.next_token();
-                data_type = DataType::Array(Box::new(data_type));
+                let size = if self.consume_token(&Token::LBracket) {
+                    self.parse_array_type_size()?
+                } else {
+                    None
+                };
+                data_type = DataType::Array(Box::new(data_type), size);
             }
             _ => (),
         }
         Ok(data_type)
     }
 
+    /// Parse the optional size of an array type and the closing `]`,
+    /// assuming the opening `[` was already consumed
+    fn parse_array_type_size(&mut self) -> Result<Option<u64>, ParserError> {
+        if self.consume_token(&Token::RBracket) {
+            return Ok(None);
+        }
+        let size = self.parse_literal_uint()?;
+        self.expect_token(&Token::RBracket)?;
+        Ok(Some(size))
+    }
+
+    /// Parse a modifier of a custom type, like the `point` or `4326` of
+    /// `geometry(point, 4326)`, returning it as written
+    fn parse_type_modifier(&mut self) -> Result<String, ParserError> {
+        match self.next_token() {
+            Some(Token::Word(w)) => Ok(w.to_string()),
+            Some(Token::Number(n)) => Ok(n),
+            Some(Token::SingleQuotedString(s)) => Ok(Value::SingleQuotedString(s).to_string()),
+            other => self.expected("a type modifier", other),
+        }
+    }
+
     /// Parse `AS identifier` (or simply `identifier` if it's not a reserved keyword)
     /// Some examples with aliases: `SELECT 1 foo`, `SELECT COUNT(*) AS cnt`,
     /// `SELECT ... FROM t1 foo, t2 bar`, `SELECT ... FROM (...) AS bar`
     pub fn parse_optional_alias(
         &mut self,
-        reserved_kwds: &[&str],
+        reserved_kwds: &[Keyword],
     ) -> Result<Option<Ident>, ParserError> {
-        let after_as = self.parse_keyword("AS");
+        let after_as = self.parse_keyword(Keyword::AS);
         match self.next_token() {
             // Accept any identifier after `AS` (though many dialects have restrictions on
             // keywords that may appear here). If there's no `AS`: don't parse keywords,
             // which may start a construct allowed in this position, to be parsed as aliases.
             // (For example, in `FROM t1 JOIN` the `JOIN` will always be parsed as a keyword,
             // not an alias.)
-            Some(Token::Word(ref w))
-                if after_as || !reserved_kwds.contains(&w.keyword.as_str()) =>
-            {
+            Some(Token::Word(ref w)) if after_as || !reserved_kwds.contains(&w.keyword) => {
                 Ok(Some(w.to_ident()))
             }
             // MSSQL supports single-quoted strings as aliases for columns
@@ -1821,17 +3441,33 @@ impl Parser {
     /// addition to the table itself.
     pub fn parse_optional_table_alias(
         &mut self,
-        reserved_kwds: &[&str],
+        reserved_kwds: &[Keyword],
     ) -> Result<Option<TableAlias>, ParserError> {
         match self.parse_optional_alias(reserved_kwds)? {
             Some(name) => {
-                let columns = self.parse_parenthesized_column_list(Optional)?;
+                let columns = if self.consume_token(&Token::LParen) {
+                    let columns = self.parse_comma_separated(Parser::parse_table_alias_column)?;
+                    self.expect_token(&Token::RParen)?;
+                    columns
+                } else {
+                    vec![]
+                };
                 Ok(Some(TableAlias { name, columns }))
             }
             None => Ok(None),
         }
     }
 
+    /// Parse a column of a table alias, with an optional type
+    fn parse_table_alias_column(&mut self) -> Result<TableAliasColumn, ParserError> {
+        let name = self.parse_identifier()?;
+        let data_type = match self.peek_token() {
+            Some(Token::Comma) | Some(Token::RParen) => None,
+            _ => Some(self.parse_data_type()?),
+        };
+        Ok(TableAliasColumn { name, data_type })
+    }
+
     /// Parse a possibly qualified, possibly quoted identifier, e.g.
     /// `foo` or `myschema."table"`
     pub fn parse_object_name(&mut self) -> Result<ObjectName, ParserError> {
@@ -1869,6 +3505,14 @@ impl Parser {
         }
     }
 
+    /// Parse a mandatory parenthesized length, like the `(10)` in `BINARY(10)`
+    pub fn parse_precision(&mut self) -> Result<u64, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let n = self.parse_literal_uint()?;
+        self.expect_token(&Token::RParen)?;
+        Ok(n)
+    }
+
     pub fn parse_optional_precision(&mut self) -> Result<Option<u64>, ParserError> {
         if self.consume_token(&Token::LParen) {
             let n = self.parse_literal_uint()?;
@@ -1879,6 +3523,43 @@ impl Parser {
         }
     }
 
+    /// Parse an optional `WITH TIME ZONE` or `WITHOUT TIME ZONE`, returning
+    /// whether the type is with time zone
+    fn parse_optional_time_zone(&mut self) -> Result<bool, ParserError> {
+        if self.parse_keyword(Keyword::WITH) {
+            self.expect_keywords(&[Keyword::TIME, Keyword::ZONE])?;
+            Ok(true)
+        } else {
+            if self.parse_keyword(Keyword::WITHOUT) {
+                self.expect_keywords(&[Keyword::TIME, Keyword::ZONE])?;
+            }
+            Ok(false)
+        }
+    }
+
+    /// Parse the optional length of a character string type, e.g. `(10)`,
+    /// `(10 CHARACTERS)` or `(MAX)`
+    pub fn parse_optional_character_length(
+        &mut self,
+    ) -> Result<Option<CharacterLength>, ParserError> {
+        if !self.consume_token(&Token::LParen) {
+            return Ok(None);
+        }
+        let length = if self.parse_keyword(Keyword::MAX) {
+            CharacterLength::Max
+        } else {
+            let length = self.parse_literal_uint()?;
+            let unit = match self.parse_one_of_keywords(&[Keyword::CHARACTERS, Keyword::OCTETS]) {
+                Some(Keyword::CHARACTERS) => Some(CharLengthUnits::Characters),
+                Some(Keyword::OCTETS) => Some(CharLengthUnits::Octets),
+                _ => None,
+            };
+            CharacterLength::IntegerLength { length, unit }
+        };
+        self.expect_token(&Token::RParen)?;
+        Ok(Some(length))
+    }
+
     pub fn parse_optional_precision_scale(
         &mut self,
     ) -> Result<(Option<u64>, Option<u64>), ParserError> {
@@ -1897,16 +3578,16 @@ impl Parser {
     }
 
     pub fn parse_delete(&mut self) -> Result<Statement, ParserError> {
-        self.expect_keyword("FROM")?;
+        self.expect_keyword(Keyword::FROM)?;
         let table_name = self.parse_object_name()?;
-        let selection = if self.parse_keyword("WHERE") {
-            Some(self.parse_expr()?)
-        } else {
-            None
-        };
+        let alias = self.parse_dml_table_alias(Keyword::OUTPUT)?;
+        let output = self.parse_output_clause()?;
+        let selection = self.parse_dml_selection()?;
 
         Ok(Statement::Delete {
             table_name,
+            alias,
+            output,
             selection,
         })
     }
@@ -1916,56 +3597,138 @@ impl Parser {
     /// by `ORDER BY`. Unlike some other parse_... methods, this one doesn't
     /// expect the initial keyword to be already consumed
     pub fn parse_query(&mut self) -> Result<Query, ParserError> {
-        let ctes = if self.parse_keyword("WITH") {
-            // TODO: optional RECURSIVE
-            self.parse_comma_separated(Parser::parse_cte)?
+        self.parse_query_allowing_default(false)
+    }
+
+    /// Parse a query, accepting `DEFAULT` in place of an expression in the
+    /// rows of its body if `allow_default` and the body is a VALUES list, as
+    /// in the source of an INSERT
+    fn parse_query_allowing_default(&mut self, allow_default: bool) -> Result<Query, ParserError> {
+        let start = self.index;
+        let with = if self.parse_keyword(Keyword::WITH) {
+            let recursive = self.parse_keyword(Keyword::RECURSIVE);
+            let ctes = self.parse_comma_separated(Parser::parse_cte)?;
+            Some(With {
+                recursive,
+                ctes,
+                span: self.span_since(start).unwrap_or_default(),
+            })
         } else {
-            vec![]
+            None
         };
 
-        let body = self.parse_query_body(0)?;
+        let body = self.parse_query_body(0, allow_default)?;
 
-        let order_by = if self.parse_keywords(vec!["ORDER", "BY"]) {
-            self.parse_comma_separated(Parser::parse_order_by_expr)?
+        let order_by = if self.parse_keywords(&[Keyword::ORDER, Keyword::BY]) {
+            let mut order_by = self.parse_comma_separated(Parser::parse_order_by_expr)?;
+            for order_by_expr in &mut order_by {
+                let expr = mem::replace(&mut order_by_expr.expr, Expr::Wildcard);
+                order_by_expr.expr = into_ordinal(expr);
+            }
+            order_by
         } else {
             vec![]
         };
 
-        let limit = if self.parse_keyword("LIMIT") {
+        // Like PostgreSQL, accept the locking clauses either before or after
+        // LIMIT/OFFSET/FETCH, but not in both places
+        let mut locks = self.parse_lock_clauses()?;
+
+        let limit = if self.parse_keyword(Keyword::LIMIT) {
             self.parse_limit()?
         } else {
             None
         };
 
-        let offset = if self.parse_keyword("OFFSET") {
+        let offset = if self.parse_keyword(Keyword::OFFSET) {
             Some(self.parse_offset()?)
         } else {
             None
         };
 
-        let fetch = if self.parse_keyword("FETCH") {
+        let fetch = if self.parse_keyword(Keyword::FETCH) {
             Some(self.parse_fetch()?)
         } else {
             None
         };
 
+        if locks.is_empty() {
+            locks = self.parse_lock_clauses()?;
+        }
+
+        let format = if self.parse_keyword(Keyword::FORMAT) {
+            Some(self.parse_identifier()?)
+        } else {
+            None
+        };
+
         Ok(Query {
-            ctes,
+            with,
             body,
             limit,
             order_by,
             offset,
             fetch,
+            locks,
+            format,
         })
     }
 
+    /// Parse any number of `FOR { UPDATE | NO KEY UPDATE | SHARE | KEY SHARE }
+    /// [ OF <table>, ... ] [ NOWAIT | SKIP LOCKED ]` row-locking clauses
+    pub fn parse_lock_clauses(&mut self) -> Result<Vec<LockClause>, ParserError> {
+        let mut locks = vec![];
+        while self.parse_keyword(Keyword::FOR) {
+            let lock_type = match self.expect_one_of_keywords(&[
+                Keyword::UPDATE,
+                Keyword::NO,
+                Keyword::SHARE,
+                Keyword::KEY,
+            ])? {
+                Keyword::UPDATE => LockType::Update,
+                Keyword::NO => {
+                    self.expect_keywords(&[Keyword::KEY, Keyword::UPDATE])?;
+                    LockType::NoKeyUpdate
+                }
+                Keyword::SHARE => LockType::Share,
+                Keyword::KEY => {
+                    self.expect_keyword(Keyword::SHARE)?;
+                    LockType::KeyShare
+                }
+                _ => unreachable!(),
+            };
+            let of = if self.parse_keyword(Keyword::OF) {
+                self.parse_comma_separated(Parser::parse_object_name)?
+            } else {
+                vec![]
+            };
+            let nonblock = if self.parse_keyword(Keyword::NOWAIT) {
+                Some(NonBlock::Nowait)
+            } else if self.parse_keywords(&[Keyword::SKIP, Keyword::LOCKED]) {
+                Some(NonBlock::SkipLocked)
+            } else {
+                None
+            };
+            locks.push(LockClause {
+                lock_type,
+                of,
+                nonblock,
+            });
+        }
+        Ok(locks)
+    }
+
     /// Parse a CTE (`alias [( col1, col2, ... )] AS (subquery)`)
     fn parse_cte(&mut self) -> Result<Cte, ParserError> {
         let alias = TableAlias {
             name: self.parse_identifier()?,
-            columns: self.parse_parenthesized_column_list(Optional)?,
+            columns: self
+                .parse_parenthesized_column_list(Optional)?
+                .into_iter()
+                .map(TableAliasColumn::from)
+                .collect(),
         };
-        self.expect_keyword("AS")?;
+        self.expect_keyword(Keyword::AS)?;
         self.expect_token(&Token::LParen)?;
         let query = self.parse_query()?;
         self.expect_token(&Token::RParen)?;
@@ -1980,18 +3743,22 @@ impl Parser {
     ///   subquery ::= query_body [ order_by_limit ]
     ///   set_operation ::= query_body { 'UNION' | 'EXCEPT' | 'INTERSECT' } [ 'ALL' ] query_body
     /// ```
-    fn parse_query_body(&mut self, precedence: u8) -> Result<SetExpr, ParserError> {
+    fn parse_query_body(
+        &mut self,
+        precedence: u8,
+        allow_default: bool,
+    ) -> Result<SetExpr, ParserError> {
         // We parse the expression using a Pratt parser, as in `parse_expr()`.
         // Start by parsing a restricted SELECT or a `(subquery)`:
-        let mut expr = if self.parse_keyword("SELECT") {
+        let mut expr = if self.parse_keyword(Keyword::SELECT) {
             SetExpr::Select(Box::new(self.parse_select()?))
         } else if self.consume_token(&Token::LParen) {
             // CTEs are not allowed here, but the parser currently accepts them
             let subquery = self.parse_query()?;
             self.expect_token(&Token::RParen)?;
             SetExpr::Query(Box::new(subquery))
-        } else if self.parse_keyword("VALUES") {
-            SetExpr::Values(self.parse_values()?)
+        } else if self.parse_keyword(Keyword::VALUES) {
+            SetExpr::Values(self.parse_values(allow_default)?)
         } else {
             return self.expected(
                 "SELECT, VALUES, or a subquery in the query body",
@@ -2002,24 +3769,28 @@ impl Parser {
         loop {
             // The query can be optionally followed by a set operator:
             let next_token = self.peek_token();
-            let op = self.parse_set_operator(&next_token);
-            let next_precedence = match op {
-                // UNION and EXCEPT have the same binding power and evaluate left-to-right
-                Some(SetOperator::Union) | Some(SetOperator::Except) => 10,
-                // INTERSECT has higher precedence than UNION/EXCEPT
-                Some(SetOperator::Intersect) => 20,
+            let op = match self.parse_set_operator(&next_token) {
+                Some(op) => op,
                 // Unexpected token or EOF => stop parsing the query body
                 None => break,
             };
+            // Operators of equal precedence evaluate left-to-right
+            let next_precedence = self.set_operator_precedence(&op);
             if precedence >= next_precedence {
                 break;
             }
             self.next_token(); // skip past the set operator
+            let all = self.parse_keyword(Keyword::ALL);
+            let distinct = self.parse_keyword(Keyword::DISTINCT);
+            if all && distinct {
+                return parser_err!(format!("Cannot specify both ALL and DISTINCT in {}", op));
+            }
             expr = SetExpr::SetOperation {
                 left: Box::new(expr),
-                op: op.unwrap(),
-                all: self.parse_keyword("ALL"),
-                right: Box::new(self.parse_query_body(next_precedence)?),
+                op,
+                all,
+                distinct,
+                right: Box::new(self.parse_query_body(next_precedence, false)?),
             };
         }
 
@@ -2028,9 +3799,9 @@ impl Parser {
 
     fn parse_set_operator(&mut self, token: &Option<Token>) -> Option<SetOperator> {
         match token {
-            Some(Token::Word(w)) if w.keyword == "UNION" => Some(SetOperator::Union),
-            Some(Token::Word(w)) if w.keyword == "EXCEPT" => Some(SetOperator::Except),
-            Some(Token::Word(w)) if w.keyword == "INTERSECT" => Some(SetOperator::Intersect),
+            Some(Token::Word(w)) if w.keyword == Keyword::UNION => Some(SetOperator::Union),
+            Some(Token::Word(w)) if w.keyword == Keyword::EXCEPT => Some(SetOperator::Except),
+            Some(Token::Word(w)) if w.keyword == Keyword::INTERSECT => Some(SetOperator::Intersect),
             _ => None,
         }
     }
@@ -2038,56 +3809,99 @@ impl Parser {
     /// Parse a restricted `SELECT` statement (no CTEs / `UNION` / `ORDER BY`),
     /// assuming the initial `SELECT` was already consumed
     pub fn parse_select(&mut self) -> Result<Select, ParserError> {
-        let all = self.parse_keyword("ALL");
-        let distinct = self.parse_keyword("DISTINCT");
+        let all = self.parse_keyword(Keyword::ALL);
+        let distinct = self.parse_keyword(Keyword::DISTINCT);
         if all && distinct {
             return parser_err!("Cannot specify both ALL and DISTINCT in SELECT");
         }
-        let projection = self.parse_comma_separated(Parser::parse_select_item)?;
+        let projection = self.parse_comma_separated(|parser| {
+            let start = parser.index;
+            Ok((parser.parse_select_item()?, parser.span_since(start)))
+        })?;
+        let (projection, projection_spans): (Vec<_>, Vec<_>) = projection.into_iter().unzip();
+        let projection_spans = projection_spans.into_iter().flatten().collect();
 
         // Note that for keywords to be properly handled here, they need to be
         // added to `RESERVED_FOR_COLUMN_ALIAS` / `RESERVED_FOR_TABLE_ALIAS`,
         // otherwise they may be parsed as an alias as part of the `projection`
         // or `from`.
 
-        let from = if self.parse_keyword("FROM") {
+        let from = if self.parse_keyword(Keyword::FROM) {
             self.parse_comma_separated(Parser::parse_table_and_joins)?
         } else {
             vec![]
         };
 
-        let selection = if self.parse_keyword("WHERE") {
+        let selection = if self.parse_keyword(Keyword::WHERE) {
             Some(self.parse_expr()?)
         } else {
             None
         };
 
-        let group_by = if self.parse_keywords(vec!["GROUP", "BY"]) {
-            self.parse_comma_separated(Parser::parse_expr)?
+        let group_by = if self.parse_keywords(&[Keyword::GROUP, Keyword::BY]) {
+            let exprs = self.parse_comma_separated(Parser::parse_expr)?;
+            if self.dialect.supports_group_by_ordinals() {
+                exprs.into_iter().map(into_ordinal).collect()
+            } else {
+                exprs
+            }
         } else {
             vec![]
         };
 
-        let having = if self.parse_keyword("HAVING") {
+        let having = if self.parse_keyword(Keyword::HAVING) {
             Some(self.parse_expr()?)
         } else {
             None
         };
 
+        let qualify = if self.parse_keyword(Keyword::QUALIFY) {
+            Some(self.parse_expr()?)
+        } else {
+            None
+        };
+
+        // CLUSTER BY can't be combined with DISTRIBUTE BY or SORT BY
+        let cluster_by = if self.parse_keywords(&[Keyword::CLUSTER, Keyword::BY]) {
+            self.parse_comma_separated(Parser::parse_expr)?
+        } else {
+            vec![]
+        };
+        let (distribute_by, sort_by) = if cluster_by.is_empty() {
+            let distribute_by = if self.parse_keywords(&[Keyword::DISTRIBUTE, Keyword::BY]) {
+                self.parse_comma_separated(Parser::parse_expr)?
+            } else {
+                vec![]
+            };
+            let sort_by = if self.parse_keywords(&[Keyword::SORT, Keyword::BY]) {
+                self.parse_comma_separated(Parser::parse_order_by_expr)?
+            } else {
+                vec![]
+            };
+            (distribute_by, sort_by)
+        } else {
+            (vec![], vec![])
+        };
+
         Ok(Select {
             distinct,
             projection,
+            projection_spans,
             from,
             selection,
             group_by,
             having,
+            qualify,
+            cluster_by,
+            distribute_by,
+            sort_by,
         })
     }
 
     pub fn parse_set(&mut self) -> Result<Statement, ParserError> {
-        let modifier = self.parse_one_of_keywords(&["SESSION", "LOCAL"]);
+        let modifier = self.parse_one_of_keywords(&[Keyword::SESSION, Keyword::LOCAL]);
         let variable = self.parse_identifier()?;
-        if self.consume_token(&Token::Eq) || self.parse_keyword("TO") {
+        if self.consume_token(&Token::Eq) || self.parse_keyword(Keyword::TO) {
             let token = self.peek_token();
             let value = match (self.parse_value(), token) {
                 (Ok(value), _) => SetVariableValue::Literal(value),
@@ -2095,11 +3909,14 @@ impl Parser {
                 (Err(_), other) => self.expected("variable value", other)?,
             };
             Ok(Statement::SetVariable {
-                local: modifier == Some("LOCAL"),
+                local: modifier == Some(Keyword::LOCAL),
                 variable,
                 value,
             })
-        } else if variable.value == "TRANSACTION" && modifier.is_none() {
+        } else if variable.quote_style.is_none()
+            && variable.value.eq_ignore_ascii_case("TRANSACTION")
+            && modifier.is_none()
+        {
             Ok(Statement::SetTransaction {
                 modes: self.parse_transaction_modes()?,
             })
@@ -2110,50 +3927,48 @@ impl Parser {
 
     pub fn parse_show(&mut self) -> Result<Statement, ParserError> {
         if self
-            .parse_one_of_keywords(&["EXTENDED", "FULL", "COLUMNS", "FIELDS"])
+            .parse_one_of_keywords(&[
+                Keyword::EXTENDED,
+                Keyword::FULL,
+                Keyword::COLUMNS,
+                Keyword::FIELDS,
+            ])
             .is_some()
         {
             self.prev_token();
             self.parse_show_columns()
-        } else if let Some(object_type) =
-            self.parse_one_of_keywords(&["SOURCES", "VIEWS", "SINKS", "TABLES"])
-        {
-            // TODO(benesch): support LIKE/WHERE filters, like we do for SHOW
-            // COLUMNS, for parity with MySQL.
+        } else if let Some(object_type) = self.parse_show_object_type() {
             Ok(Statement::ShowObjects {
-                object_type: match object_type {
-                    "SOURCES" => ObjectType::Source,
-                    "VIEWS" => ObjectType::View,
-                    "SINKS" => ObjectType::Sink,
-                    "TABLES" => ObjectType::Table,
-                    val => panic!(
-                        "`parse_one_of_keywords` returned an impossible value: {}",
-                        val
-                    ),
-                },
+                object_type,
                 filter: self.parse_show_statement_filter()?,
             })
-        } else if self
-            .parse_one_of_keywords(&["INDEX", "INDEXES", "KEYS"])
-            .is_some()
+        } else if let Some(keyword) =
+            self.parse_one_of_keywords(&[Keyword::INDEX, Keyword::INDEXES, Keyword::KEYS])
         {
-            match self.parse_one_of_keywords(&["FROM", "IN"]) {
+            match self.parse_one_of_keywords(&[Keyword::FROM, Keyword::IN]) {
                 Some(_) => {
                     let table_name = self.parse_object_name()?;
-                    let filter = if self.parse_keyword("WHERE") {
-                        Some(ShowStatementFilter::Where(self.parse_expr()?))
-                    } else {
-                        None
-                    };
+                    let filter = self.parse_show_statement_filter()?;
                     Ok(Statement::ShowIndexes { table_name, filter })
                 }
+                // A bare `SHOW INDEXES` lists every index in the catalog.
+                None if keyword == Keyword::INDEXES => Ok(Statement::ShowObjects {
+                    object_type: ObjectType::Index,
+                    filter: self.parse_show_statement_filter()?,
+                }),
                 None => self.expected("FROM or IN after SHOW INDEXES", self.peek_token()),
             }
-        } else if self.parse_keywords(vec!["CREATE", "VIEW"]) {
+        } else if self.parse_keyword(Keyword::CONSTRAINTS) {
+            self.expect_one_of_keywords(&[Keyword::FROM, Keyword::IN])?;
+            Ok(Statement::ShowConstraints {
+                table_name: self.parse_object_name()?,
+                filter: self.parse_show_statement_filter()?,
+            })
+        } else if self.parse_keywords(&[Keyword::CREATE, Keyword::VIEW]) {
             Ok(Statement::ShowCreateView {
                 view_name: self.parse_object_name()?,
             })
-        } else if self.parse_keywords(vec!["CREATE", "SOURCE"]) {
+        } else if self.parse_keywords(&[Keyword::CREATE, Keyword::SOURCE]) {
             Ok(Statement::ShowCreateSource {
                 source_name: self.parse_object_name()?,
             })
@@ -2164,11 +3979,58 @@ impl Parser {
         }
     }
 
+    /// Parse the kind of objects that `SHOW <objects>` lists, either one this
+    /// crate knows about or one of the dialect's
+    /// [show_object_types](Dialect::show_object_types)
+    fn parse_show_object_type(&mut self) -> Option<ObjectType> {
+        if let Some(object_type) = self.parse_one_of_keywords(&[
+            Keyword::SOURCES,
+            Keyword::VIEWS,
+            Keyword::SINKS,
+            Keyword::TABLES,
+            Keyword::SCHEMAS,
+            Keyword::DATABASES,
+            Keyword::TYPES,
+            Keyword::ROLES,
+            Keyword::SEQUENCES,
+        ]) {
+            return Some(match object_type {
+                Keyword::SOURCES => ObjectType::Source,
+                Keyword::VIEWS => ObjectType::View,
+                Keyword::SINKS => ObjectType::Sink,
+                Keyword::TABLES => ObjectType::Table,
+                Keyword::SCHEMAS => ObjectType::Schema,
+                Keyword::DATABASES => ObjectType::Database,
+                Keyword::TYPES => ObjectType::Type,
+                Keyword::ROLES => ObjectType::Role,
+                Keyword::SEQUENCES => ObjectType::Sequence,
+                val => panic!(
+                    "`parse_one_of_keywords` returned an impossible value: {:?}",
+                    val
+                ),
+            });
+        }
+        match self.peek_token() {
+            Some(Token::Word(w))
+                if w.quote_style.is_none()
+                    && self
+                        .dialect
+                        .show_object_types()
+                        .iter()
+                        .any(|t| t.eq_ignore_ascii_case(&w.value)) =>
+            {
+                self.next_token();
+                Some(ObjectType::Custom(w.to_ident()))
+            }
+            _ => None,
+        }
+    }
+
     fn parse_show_columns(&mut self) -> Result<Statement, ParserError> {
-        let extended = self.parse_keyword("EXTENDED");
-        let full = self.parse_keyword("FULL");
-        self.expect_one_of_keywords(&["COLUMNS", "FIELDS"])?;
-        self.expect_one_of_keywords(&["FROM", "IN"])?;
+        let extended = self.parse_keyword(Keyword::EXTENDED);
+        let full = self.parse_keyword(Keyword::FULL);
+        self.expect_one_of_keywords(&[Keyword::COLUMNS, Keyword::FIELDS])?;
+        self.expect_one_of_keywords(&[Keyword::FROM, Keyword::IN])?;
         let table_name = self.parse_object_name()?;
         // MySQL also supports FROM <database> here. In other words, MySQL
         // allows both FROM <table> FROM <database> and FROM <database>.<table>,
@@ -2183,11 +4045,11 @@ impl Parser {
     }
 
     fn parse_show_statement_filter(&mut self) -> Result<Option<ShowStatementFilter>, ParserError> {
-        if self.parse_keyword("LIKE") {
+        if self.parse_keyword(Keyword::LIKE) {
             Ok(Some(ShowStatementFilter::Like(
                 self.parse_literal_string()?,
             )))
-        } else if self.parse_keyword("WHERE") {
+        } else if self.parse_keyword(Keyword::WHERE) {
             Ok(Some(ShowStatementFilter::Where(self.parse_expr()?)))
         } else {
             Ok(None)
@@ -2202,10 +4064,10 @@ impl Parser {
         // a table alias.
         let mut joins = vec![];
         loop {
-            let join = if self.parse_keyword("CROSS") {
-                let join_operator = if self.parse_keyword("JOIN") {
+            let join = if self.parse_keyword(Keyword::CROSS) {
+                let join_operator = if self.parse_keyword(Keyword::JOIN) {
                     JoinOperator::CrossJoin
-                } else if self.parse_keyword("APPLY") {
+                } else if self.parse_keyword(Keyword::APPLY) {
                     // MSSQL extension, similar to CROSS JOIN LATERAL
                     JoinOperator::CrossApply
                 } else {
@@ -2215,39 +4077,41 @@ impl Parser {
                     relation: self.parse_table_factor()?,
                     join_operator,
                 }
-            } else if self.parse_keyword("OUTER") {
+            } else if self.parse_keyword(Keyword::OUTER) {
                 // MSSQL extension, similar to LEFT JOIN LATERAL .. ON 1=1
-                self.expect_keyword("APPLY")?;
+                self.expect_keyword(Keyword::APPLY)?;
                 Join {
                     relation: self.parse_table_factor()?,
                     join_operator: JoinOperator::OuterApply,
                 }
             } else {
-                let natural = self.parse_keyword("NATURAL");
+                let natural = self.parse_keyword(Keyword::NATURAL);
                 let peek_keyword = if let Some(Token::Word(kw)) = self.peek_token() {
                     kw.keyword
                 } else {
-                    String::default()
+                    Keyword::NoKeyword
                 };
 
-                let join_operator_type = match peek_keyword.as_ref() {
-                    "INNER" | "JOIN" => {
-                        let _ = self.parse_keyword("INNER");
-                        self.expect_keyword("JOIN")?;
+                let join_operator_type = match peek_keyword {
+                    Keyword::INNER | Keyword::JOIN => {
+                        let _ = self.parse_keyword(Keyword::INNER);
+                        self.expect_keyword(Keyword::JOIN)?;
                         JoinOperator::Inner
                     }
-                    kw @ "LEFT" | kw @ "RIGHT" | kw @ "FULL" => {
+                    kw @ Keyword::LEFT | kw @ Keyword::RIGHT | kw @ Keyword::FULL => {
                         let _ = self.next_token();
-                        let _ = self.parse_keyword("OUTER");
-                        self.expect_keyword("JOIN")?;
+                        let _ = self.parse_keyword(Keyword::OUTER);
+                        self.expect_keyword(Keyword::JOIN)?;
                         match kw {
-                            "LEFT" => JoinOperator::LeftOuter,
-                            "RIGHT" => JoinOperator::RightOuter,
-                            "FULL" => JoinOperator::FullOuter,
+                            Keyword::LEFT => JoinOperator::LeftOuter,
+                            Keyword::RIGHT => JoinOperator::RightOuter,
+                            Keyword::FULL => JoinOperator::FullOuter,
                             _ => unreachable!(),
                         }
                     }
-                    "OUTER" => return self.expected("LEFT, RIGHT, or FULL", self.peek_token()),
+                    Keyword::OUTER => {
+                        return self.expected("LEFT, RIGHT, or FULL", self.peek_token())
+                    }
                     _ if natural => {
                         return self.expected("a join type after NATURAL", self.peek_token());
                     }
@@ -2267,7 +4131,7 @@ impl Parser {
 
     /// A table name or a parenthesized subquery, followed by optional `[AS] alias`
     pub fn parse_table_factor(&mut self) -> Result<TableFactor, ParserError> {
-        if self.parse_keyword("LATERAL") {
+        if self.parse_keyword(Keyword::LATERAL) {
             // LATERAL must always be followed by a subquery.
             if !self.consume_token(&Token::LParen) {
                 self.expected("subquery after LATERAL", self.peek_token())?;
@@ -2276,7 +4140,6 @@ impl Parser {
         }
 
         if self.consume_token(&Token::LParen) {
-            let index = self.index;
             // A left paren introduces either a derived table (i.e., a subquery)
             // or a nested join. It's nearly impossible to determine ahead of
             // time which it is... so we just try to parse both.
@@ -2293,68 +4156,291 @@ impl Parser {
             //                   | (2) starts a nested join
             //                   (1) an additional set of parens around a nested join
             //
-            match self.parse_derived_table_factor(NotLateral) {
-                // The recently consumed '(' started a derived table, and we've
-                // parsed the subquery, followed by the closing ')', and the
-                // alias of the derived table. In the example above this is
-                // case (3), and the next token would be `NATURAL`.
-                Ok(table_factor) => Ok(table_factor),
-                Err(_) => {
-                    // The '(' we've recently consumed does not start a derived
-                    // table. For valid input this can happen either when the
-                    // token following the paren can't start a query (e.g. `foo`
-                    // in `FROM (foo NATURAL JOIN bar)`, or when the '(' we've
-                    // consumed is followed by another '(' that starts a
-                    // derived table, like (3), or another nested join (2).
-                    //
-                    // Ignore the error and back up to where we were before.
-                    // Either we'll be able to parse a valid nested join, or
-                    // we won't, and we'll return that error instead.
-                    self.index = index;
-                    let table_and_joins = self.parse_table_and_joins()?;
-                    match table_and_joins.relation {
-                        TableFactor::NestedJoin { .. } => (),
-                        _ => {
-                            if table_and_joins.joins.is_empty() {
-                                // The SQL spec prohibits derived tables and bare
-                                // tables from appearing alone in parentheses.
-                                self.expected("joined table", self.peek_token())?
-                            }
-                        }
-                    }
-                    self.expect_token(&Token::RParen)?;
-                    Ok(TableFactor::NestedJoin(Box::new(table_and_joins)))
-                }
-            }
+            // If the '(' we've consumed starts a derived table, we'll parse the
+            // subquery, followed by the closing ')', and the alias of the
+            // derived table. In the example above this is case (3), and the
+            // next token would be `NATURAL`.
+            //
+            // Otherwise, for valid input, either the token following the paren
+            // can't start a query (e.g. `foo` in `FROM (foo NATURAL JOIN bar)`),
+            // or the '(' we've consumed is followed by another '(' that starts
+            // a derived table, like (3), or another nested join (2). Either way
+            // we back up and try to parse a nested join instead. If that fails
+            // too, we report the error of whichever attempt got further.
+            self.parse_alternatives(&[
+                |parser| parser.parse_derived_table_factor(NotLateral),
+                Parser::parse_nested_join,
+            ])
+        } else if self.parse_keyword(Keyword::VALUES) {
+            // Unparenthesized `VALUES` lists, as accepted by Snowflake and
+            // Spark, are equivalent to the standard parenthesized form
+            let values = self.parse_values(false)?;
+            let alias = self.parse_optional_table_alias(keywords::RESERVED_FOR_TABLE_ALIAS)?;
+            Ok(TableFactor::Derived {
+                lateral: false,
+                subquery: Box::new(Query {
+                    with: None,
+                    body: SetExpr::Values(values),
+                    order_by: vec![],
+                    limit: None,
+                    offset: None,
+                    fetch: None,
+                    locks: vec![],
+                    format: None,
+                }),
+                alias,
+            })
+        } else if matches!(self.peek_token(), Some(Token::Word(w)) if w.keyword == Keyword::JSON_TABLE)
+            && self.peek_nth_token(1) == Some(Token::LParen)
+        {
+            self.parse_json_table_factor()
+        } else if matches!(self.peek_token(), Some(Token::Word(w)) if w.keyword == Keyword::XMLTABLE)
+            && self.peek_nth_token(1) == Some(Token::LParen)
+        {
+            self.parse_xml_table_factor()
         } else {
+            let only = self.parse_keyword(Keyword::ONLY);
             let name = self.parse_object_name()?;
+            let descendants = self.consume_token(&Token::Mult);
             // Postgres, MSSQL: table-valued functions:
             let args = if self.consume_token(&Token::LParen) {
                 self.parse_optional_args()?
             } else {
                 vec![]
             };
+            let with_ordinality = self.parse_keywords(&[Keyword::WITH, Keyword::ORDINALITY]);
+            let version = self.parse_table_version()?;
             let alias = self.parse_optional_table_alias(keywords::RESERVED_FOR_TABLE_ALIAS)?;
-            // MSSQL-specific table hints:
-            let mut with_hints = vec![];
-            if self.parse_keyword("WITH") {
-                if self.consume_token(&Token::LParen) {
-                    with_hints = self.parse_comma_separated(Parser::parse_expr)?;
-                    self.expect_token(&Token::RParen)?;
-                } else {
-                    // rewind, as WITH may belong to the next statement's CTE
-                    self.prev_token();
-                }
-            };
+            let with_hints = self.parse_table_hints()?;
             Ok(TableFactor::Table {
+                only,
                 name,
+                descendants,
+                version,
                 alias,
                 args,
                 with_hints,
+                with_ordinality,
             })
         }
     }
 
+    /// Parse an optional `FOR SYSTEM_TIME` clause, leaving a `FOR UPDATE`
+    /// or other locking clause that may follow the table for later
+    fn parse_table_version(&mut self) -> Result<Option<TableVersion>, ParserError> {
+        if !self.parse_keywords(&[Keyword::FOR, Keyword::SYSTEM_TIME]) {
+            return Ok(None);
+        }
+        if self.parse_keywords(&[Keyword::AS, Keyword::OF]) {
+            Ok(Some(TableVersion::AsOf(Box::new(self.parse_expr()?))))
+        } else if self.parse_keyword(Keyword::FROM) {
+            let start = Box::new(self.parse_expr()?);
+            self.expect_keyword(Keyword::TO)?;
+            Ok(Some(TableVersion::FromTo(
+                start,
+                Box::new(self.parse_expr()?),
+            )))
+        } else if self.parse_keyword(Keyword::BETWEEN) {
+            // Like in a `BETWEEN` expression, `AND` separates the bounds
+            let start = Box::new(self.parse_subexpr(Self::BETWEEN_PREC)?);
+            self.expect_keyword(Keyword::AND)?;
+            let end = Box::new(self.parse_subexpr(Self::BETWEEN_PREC)?);
+            Ok(Some(TableVersion::Between(start, end)))
+        } else {
+            self.expected("AS OF, FROM or BETWEEN", self.peek_token())
+        }
+    }
+
+    /// Parse `JSON_TABLE(<json>, <path> COLUMNS (...)) [AS] <alias>`
+    fn parse_json_table_factor(&mut self) -> Result<TableFactor, ParserError> {
+        self.expect_keyword(Keyword::JSON_TABLE)?;
+        self.expect_token(&Token::LParen)?;
+        let json_expr = self.parse_expr()?;
+        self.expect_token(&Token::Comma)?;
+        let json_path = self.parse_value()?;
+        let columns = self.parse_json_table_columns()?;
+        self.expect_token(&Token::RParen)?;
+        let alias = self.parse_optional_table_alias(keywords::RESERVED_FOR_TABLE_ALIAS)?;
+        Ok(TableFactor::JsonTable {
+            json_expr: Box::new(json_expr),
+            json_path,
+            columns,
+            alias,
+        })
+    }
+
+    /// Parse `COLUMNS (...)` of `JSON_TABLE`
+    fn parse_json_table_columns(&mut self) -> Result<Vec<JsonTableColumn>, ParserError> {
+        self.expect_keyword(Keyword::COLUMNS)?;
+        self.expect_token(&Token::LParen)?;
+        let columns = self.parse_comma_separated(Parser::parse_json_table_column)?;
+        self.expect_token(&Token::RParen)?;
+        Ok(columns)
+    }
+
+    fn parse_json_table_column(&mut self) -> Result<JsonTableColumn, ParserError> {
+        if self.parse_keyword(Keyword::NESTED) {
+            let _ = self.parse_keyword(Keyword::PATH);
+            let path = self.parse_value()?;
+            let columns = self.parse_json_table_columns()?;
+            return Ok(JsonTableColumn::Nested { path, columns });
+        }
+        let name = self.parse_identifier()?;
+        if self.parse_keywords(&[Keyword::FOR, Keyword::ORDINALITY]) {
+            return Ok(JsonTableColumn::ForOrdinality(name));
+        }
+        let data_type = self.parse_data_type()?;
+        let exists = self.parse_keyword(Keyword::EXISTS);
+        self.expect_keyword(Keyword::PATH)?;
+        let path = self.parse_value()?;
+        let mut on_empty = None;
+        let mut on_error = None;
+        while let Some(handling) = self.parse_json_table_column_error_handling()? {
+            if on_empty.is_none() && self.parse_keywords(&[Keyword::ON, Keyword::EMPTY]) {
+                on_empty = Some(handling);
+            } else if on_error.is_none() && self.parse_keywords(&[Keyword::ON, Keyword::ERROR]) {
+                on_error = Some(handling);
+            } else {
+                return self.expected("ON EMPTY or ON ERROR", self.peek_token());
+            }
+        }
+        Ok(JsonTableColumn::Named(Box::new(JsonTableNamedColumn {
+            name,
+            data_type,
+            path,
+            exists,
+            on_empty,
+            on_error,
+        })))
+    }
+
+    fn parse_json_table_column_error_handling(
+        &mut self,
+    ) -> Result<Option<JsonTableColumnErrorHandling>, ParserError> {
+        let handling = if self.parse_keyword(Keyword::NULL) {
+            JsonTableColumnErrorHandling::Null
+        } else if self.parse_keyword(Keyword::ERROR) {
+            JsonTableColumnErrorHandling::Error
+        } else if self.parse_keyword(Keyword::DEFAULT) {
+            JsonTableColumnErrorHandling::Default(self.parse_value()?)
+        } else {
+            return Ok(None);
+        };
+        Ok(Some(handling))
+    }
+
+    /// Parse `XMLTABLE([XMLNAMESPACES(...), ] <row> PASSING <xml> COLUMNS ...) [AS] <alias>`
+    fn parse_xml_table_factor(&mut self) -> Result<TableFactor, ParserError> {
+        self.expect_keyword(Keyword::XMLTABLE)?;
+        self.expect_token(&Token::LParen)?;
+        let namespaces = if self.parse_keyword(Keyword::XMLNAMESPACES) {
+            self.expect_token(&Token::LParen)?;
+            let namespaces = self.parse_comma_separated(Parser::parse_xml_namespace)?;
+            self.expect_token(&Token::RParen)?;
+            self.expect_token(&Token::Comma)?;
+            namespaces
+        } else {
+            vec![]
+        };
+        let row_expr = self.parse_expr()?;
+        self.expect_keyword(Keyword::PASSING)?;
+        let passing = self.parse_expr()?;
+        self.expect_keyword(Keyword::COLUMNS)?;
+        let columns = self.parse_comma_separated(Parser::parse_xml_table_column)?;
+        self.expect_token(&Token::RParen)?;
+        let alias = self.parse_optional_table_alias(keywords::RESERVED_FOR_TABLE_ALIAS)?;
+        Ok(TableFactor::XmlTable {
+            namespaces,
+            row_expr: Box::new(row_expr),
+            passing: Box::new(passing),
+            columns,
+            alias,
+        })
+    }
+
+    fn parse_xml_namespace(&mut self) -> Result<XmlNamespace, ParserError> {
+        if self.parse_keyword(Keyword::DEFAULT) {
+            return Ok(XmlNamespace {
+                uri: self.parse_expr()?,
+                name: None,
+            });
+        }
+        let uri = self.parse_expr()?;
+        self.expect_keyword(Keyword::AS)?;
+        Ok(XmlNamespace {
+            uri,
+            name: Some(self.parse_identifier()?),
+        })
+    }
+
+    fn parse_xml_table_column(&mut self) -> Result<XmlTableColumn, ParserError> {
+        let name = self.parse_identifier()?;
+        if self.parse_keywords(&[Keyword::FOR, Keyword::ORDINALITY]) {
+            return Ok(XmlTableColumn::ForOrdinality(name));
+        }
+        let data_type = self.parse_data_type()?;
+        let mut path = None;
+        let mut default = None;
+        let mut nullable = None;
+        // The options may be written in any order
+        loop {
+            if path.is_none() && self.parse_keyword(Keyword::PATH) {
+                path = Some(self.parse_expr()?);
+            } else if default.is_none() && self.parse_keyword(Keyword::DEFAULT) {
+                default = Some(self.parse_expr()?);
+            } else if nullable.is_none() && self.parse_keywords(&[Keyword::NOT, Keyword::NULL]) {
+                nullable = Some(false);
+            } else if nullable.is_none() && self.parse_keyword(Keyword::NULL) {
+                nullable = Some(true);
+            } else {
+                break;
+            }
+        }
+        Ok(XmlTableColumn::Named(Box::new(XmlTableNamedColumn {
+            name,
+            data_type,
+            path,
+            default,
+            nullable,
+        })))
+    }
+
+    /// Parse a parenthesized join, assuming the opening '(' was already
+    /// consumed
+    fn parse_nested_join(&mut self) -> Result<TableFactor, ParserError> {
+        let table_and_joins = self.parse_table_and_joins()?;
+        match table_and_joins.relation {
+            TableFactor::NestedJoin { .. } => (),
+            _ => {
+                if table_and_joins.joins.is_empty() {
+                    // The SQL spec prohibits derived tables and bare
+                    // tables from appearing alone in parentheses.
+                    self.expected("joined table", self.peek_token())?
+                }
+            }
+        }
+        self.expect_token(&Token::RParen)?;
+        Ok(TableFactor::NestedJoin(Box::new(table_and_joins)))
+    }
+
+    /// Parse MSSQL-specific table hints, e.g. `WITH (NOLOCK)`
+    ///
+    /// Only `WITH (` introduces hints. A `WITH` followed by anything else,
+    /// like the name of a common table expression or `ORDINALITY`, is left
+    /// for whatever follows the table, as `(` can't start any of those.
+    pub fn parse_table_hints(&mut self) -> Result<Vec<Expr>, ParserError> {
+        if !self.peek_one_of_keywords(&[Keyword::WITH])
+            || self.peek_nth_token(1) != Some(Token::LParen)
+        {
+            return Ok(vec![]);
+        }
+        self.expect_keyword(Keyword::WITH)?;
+        self.expect_token(&Token::LParen)?;
+        let with_hints = self.parse_comma_separated(Parser::parse_expr)?;
+        self.expect_token(&Token::RParen)?;
+        Ok(with_hints)
+    }
+
     pub fn parse_derived_table_factor(
         &mut self,
         lateral: IsLateral,
@@ -2375,11 +4461,22 @@ impl Parser {
     fn parse_join_constraint(&mut self, natural: bool) -> Result<JoinConstraint, ParserError> {
         if natural {
             Ok(JoinConstraint::Natural)
-        } else if self.parse_keyword("ON") {
+        } else if self.parse_keyword(Keyword::ON) {
             let constraint = self.parse_expr()?;
             Ok(JoinConstraint::On(constraint))
-        } else if self.parse_keyword("USING") {
-            let columns = self.parse_parenthesized_column_list(Mandatory)?;
+        } else if self.parse_keyword(Keyword::USING) {
+            self.expect_token(&Token::LParen)?;
+            let columns = self.parse_comma_separated(|parser| {
+                let mut name = parser.parse_object_name()?;
+                if name.0.len() > 1 {
+                    return parser_err!(
+                        "Expected an unqualified column name in USING, found: {}",
+                        name
+                    );
+                }
+                Ok(name.0.remove(0))
+            })?;
+            self.expect_token(&Token::RParen)?;
             Ok(JoinConstraint::Using(columns))
         } else {
             self.expected("ON, or USING after JOIN", self.peek_token())
@@ -2388,39 +4485,163 @@ impl Parser {
 
     /// Parse an INSERT statement
     pub fn parse_insert(&mut self) -> Result<Statement, ParserError> {
-        self.expect_keyword("INTO")?;
+        let conflict = if self.parse_keyword(Keyword::IGNORE) {
+            Some(InsertConflict::Ignore)
+        } else {
+            None
+        };
+        self.parse_insert_into(conflict)
+    }
+
+    /// Parse a MySQL `REPLACE` statement, assuming the `REPLACE` keyword was
+    /// already consumed
+    pub fn parse_replace(&mut self) -> Result<Statement, ParserError> {
+        self.parse_insert_into(Some(InsertConflict::Replace))
+    }
+
+    fn parse_insert_into(
+        &mut self,
+        conflict: Option<InsertConflict>,
+    ) -> Result<Statement, ParserError> {
+        self.expect_keyword(Keyword::INTO)?;
         let table_name = self.parse_object_name()?;
-        let columns = self.parse_parenthesized_column_list(Optional)?;
-        let source = Box::new(self.parse_query()?);
+        let alias = if self.parse_keyword(Keyword::AS) {
+            Some(self.parse_identifier()?)
+        } else {
+            None
+        };
+        let with_hints = self.parse_table_hints()?;
+        // A parenthesized source query, e.g. `INSERT INTO t (SELECT ...)`,
+        // must not be mistaken for a column list
+        let columns = match (self.peek_token(), self.peek_nth_token(1)) {
+            (Some(Token::LParen), Some(Token::LParen)) => vec![],
+            (Some(Token::LParen), Some(Token::Word(w)))
+                if [Keyword::SELECT, Keyword::WITH, Keyword::VALUES].contains(&w.keyword) =>
+            {
+                vec![]
+            }
+            _ => self.parse_parenthesized_column_list(Optional)?,
+        };
+        let overriding = if self.parse_keyword(Keyword::OVERRIDING) {
+            let overriding = match self.expect_one_of_keywords(&[Keyword::SYSTEM, Keyword::USER])? {
+                Keyword::SYSTEM => InsertOverriding::System,
+                Keyword::USER => InsertOverriding::User,
+                _ => unreachable!(),
+            };
+            self.expect_keyword(Keyword::VALUE)?;
+            Some(overriding)
+        } else {
+            None
+        };
+        let output = self.parse_output_clause()?;
+        let source = if columns.is_empty()
+            && overriding.is_none()
+            && self.parse_keywords(&[Keyword::DEFAULT, Keyword::VALUES])
+        {
+            InsertSource::DefaultValues
+        } else {
+            InsertSource::Query(Box::new(self.parse_query_allowing_default(true)?))
+        };
         Ok(Statement::Insert {
+            conflict,
             table_name,
+            alias,
+            with_hints,
             columns,
+            overriding,
+            output,
             source,
         })
     }
 
     pub fn parse_update(&mut self) -> Result<Statement, ParserError> {
         let table_name = self.parse_object_name()?;
-        self.expect_keyword("SET")?;
+        let alias = self.parse_dml_table_alias(Keyword::SET)?;
+        self.expect_keyword(Keyword::SET)?;
         let assignments = self.parse_comma_separated(Parser::parse_assignment)?;
-        let selection = if self.parse_keyword("WHERE") {
-            Some(self.parse_expr()?)
-        } else {
-            None
-        };
+        let output = self.parse_output_clause()?;
+        let selection = self.parse_dml_selection()?;
         Ok(Statement::Update {
             table_name,
+            alias,
             assignments,
+            output,
             selection,
         })
     }
 
-    /// Parse a `var = expr` assignment, used in an UPDATE statement
+    /// Parse the optional `WHERE` clause of an UPDATE or DELETE statement
+    fn parse_dml_selection(&mut self) -> Result<Option<DmlSelection>, ParserError> {
+        if !self.parse_keyword(Keyword::WHERE) {
+            Ok(None)
+        } else if self.parse_keywords(&[Keyword::CURRENT, Keyword::OF]) {
+            Ok(Some(DmlSelection::CurrentOf(self.parse_identifier()?)))
+        } else {
+            Ok(Some(DmlSelection::Expr(self.parse_expr()?)))
+        }
+    }
+
+    /// Parse the optional alias of the table an UPDATE or DELETE statement
+    /// modifies, which is never `next_clause`, the keyword of the clause
+    /// that may follow the table name
+    fn parse_dml_table_alias(
+        &mut self,
+        next_clause: Keyword,
+    ) -> Result<Option<Ident>, ParserError> {
+        if self.peek_one_of_keywords(&[next_clause]) {
+            return Ok(None);
+        }
+        self.parse_optional_alias(keywords::RESERVED_FOR_TABLE_ALIAS)
+    }
+
+    /// Parse an optional MSSQL-specific `OUTPUT` clause of an INSERT, UPDATE
+    /// or DELETE statement. Other dialects don't recognize the keyword, so
+    /// `output` remains an ordinary identifier there.
+    pub fn parse_output_clause(&mut self) -> Result<Vec<SelectItem>, ParserError> {
+        if self.parse_keyword(Keyword::OUTPUT) {
+            self.parse_comma_separated(Parser::parse_select_item)
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    /// Parse a `var = expr` or `(var, ...) = (expr, ...)` assignment, used in
+    /// an UPDATE statement
     pub fn parse_assignment(&mut self) -> Result<Assignment, ParserError> {
-        let id = self.parse_identifier()?;
+        let target = if self.consume_token(&Token::LParen) {
+            let columns = self.parse_comma_separated(Parser::parse_object_name)?;
+            self.expect_token(&Token::RParen)?;
+            AssignmentTarget::Tuple(columns)
+        } else {
+            AssignmentTarget::ColumnName(self.parse_object_name()?)
+        };
         self.expect_token(&Token::Eq)?;
-        let value = self.parse_expr()?;
-        Ok(Assignment { id, value })
+        let value = match (&target, self.peek_token(), self.peek_nth_token(1)) {
+            // `(SELECT ...)` is parsed as a subquery below
+            (AssignmentTarget::Tuple(_), Some(Token::LParen), Some(Token::Word(w)))
+                if [Keyword::SELECT, Keyword::WITH].contains(&w.keyword) =>
+            {
+                self.parse_expr()?
+            }
+            (AssignmentTarget::Tuple(_), Some(Token::LParen), _) => {
+                self.expect_token(&Token::LParen)?;
+                let exprs = self.parse_comma_separated(Parser::parse_expr_or_default)?;
+                self.expect_token(&Token::RParen)?;
+                Expr::Row(exprs)
+            }
+            _ => self.parse_expr_or_default()?,
+        };
+        Ok(Assignment { target, value })
+    }
+
+    /// Parse an expression, or the `DEFAULT` keyword that may stand in for
+    /// one in the VALUES rows of an INSERT and in UPDATE assignments
+    pub fn parse_expr_or_default(&mut self) -> Result<Expr, ParserError> {
+        if self.parse_keyword(Keyword::DEFAULT) {
+            Ok(Expr::Default)
+        } else {
+            self.parse_expr()
+        }
     }
 
     pub fn parse_optional_args(&mut self) -> Result<Vec<Expr>, ParserError> {
@@ -2437,9 +4658,13 @@ impl Parser {
     pub fn parse_select_item(&mut self) -> Result<SelectItem, ParserError> {
         let expr = self.parse_expr()?;
         if let Expr::Wildcard = expr {
-            Ok(SelectItem::Wildcard)
+            let options = self.parse_wildcard_options()?;
+            self.reject_wildcard_alias()?;
+            Ok(SelectItem::Wildcard(options))
         } else if let Expr::QualifiedWildcard(prefix) = expr {
-            Ok(SelectItem::QualifiedWildcard(ObjectName(prefix)))
+            let options = self.parse_wildcard_options()?;
+            self.reject_wildcard_alias()?;
+            Ok(SelectItem::QualifiedWildcard(ObjectName(prefix), options))
         } else {
             // `expr` is a regular SQL expression and can be followed by an alias
             if let Some(alias) = self.parse_optional_alias(keywords::RESERVED_FOR_COLUMN_ALIAS)? {
@@ -2450,13 +4675,54 @@ impl Parser {
         }
     }
 
+    /// Report `SELECT * AS alias`, which would otherwise fail further on
+    /// with a less helpful "Expected end of statement"
+    fn reject_wildcard_alias(&mut self) -> Result<(), ParserError> {
+        if self.parse_keyword(Keyword::AS) {
+            parser_err!("A wildcard can't have an alias")
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Parse the BigQuery `EXCEPT (col, ...)` and `REPLACE (expr AS col, ...)`
+    /// modifiers that may follow a `SELECT *`
+    pub fn parse_wildcard_options(&mut self) -> Result<WildcardOptions, ParserError> {
+        let mut options = WildcardOptions::default();
+        // Don't mistake the set operation in `SELECT * EXCEPT (SELECT ...)`
+        // for a list of columns
+        let is_column_list = match (self.peek_nth_token(1), self.peek_nth_token(2)) {
+            (Some(Token::LParen), Some(Token::LParen)) => false,
+            (Some(Token::LParen), Some(Token::Word(w))) => {
+                ![Keyword::SELECT, Keyword::WITH, Keyword::VALUES].contains(&w.keyword)
+            }
+            _ => false,
+        };
+        if is_column_list && self.parse_keyword(Keyword::EXCEPT) {
+            self.expect_token(&Token::LParen)?;
+            options.except = self.parse_comma_separated(Parser::parse_identifier)?;
+            self.expect_token(&Token::RParen)?;
+        }
+        if self.parse_keyword(Keyword::REPLACE) {
+            self.expect_token(&Token::LParen)?;
+            options.replace = self.parse_comma_separated(|parser| {
+                let expr = parser.parse_expr()?;
+                parser.expect_keyword(Keyword::AS)?;
+                let column_name = parser.parse_identifier()?;
+                Ok(WildcardReplacement { expr, column_name })
+            })?;
+            self.expect_token(&Token::RParen)?;
+        }
+        Ok(options)
+    }
+
     /// Parse an expression, optionally followed by ASC or DESC (used in ORDER BY)
     pub fn parse_order_by_expr(&mut self) -> Result<OrderByExpr, ParserError> {
         let expr = self.parse_expr()?;
 
-        let asc = if self.parse_keyword("ASC") {
+        let asc = if self.parse_keyword(Keyword::ASC) {
             Some(true)
-        } else if self.parse_keyword("DESC") {
+        } else if self.parse_keyword(Keyword::DESC) {
             Some(false)
         } else {
             None
@@ -2466,7 +4732,7 @@ impl Parser {
 
     /// Parse a LIMIT clause
     pub fn parse_limit(&mut self) -> Result<Option<Expr>, ParserError> {
-        if self.parse_keyword("ALL") {
+        if self.parse_keyword(Keyword::ALL) {
             Ok(None)
         } else {
             Ok(Some(Expr::Value(self.parse_number_value()?)))
@@ -2476,24 +4742,27 @@ impl Parser {
     /// Parse an OFFSET clause
     pub fn parse_offset(&mut self) -> Result<Expr, ParserError> {
         let value = Expr::Value(self.parse_number_value()?);
-        self.expect_one_of_keywords(&["ROW", "ROWS"])?;
+        self.expect_one_of_keywords(&[Keyword::ROW, Keyword::ROWS])?;
         Ok(value)
     }
 
     /// Parse a FETCH clause
     pub fn parse_fetch(&mut self) -> Result<Fetch, ParserError> {
-        self.expect_one_of_keywords(&["FIRST", "NEXT"])?;
-        let (quantity, percent) = if self.parse_one_of_keywords(&["ROW", "ROWS"]).is_some() {
+        self.expect_one_of_keywords(&[Keyword::FIRST, Keyword::NEXT])?;
+        let (quantity, percent) = if self
+            .parse_one_of_keywords(&[Keyword::ROW, Keyword::ROWS])
+            .is_some()
+        {
             (None, false)
         } else {
             let quantity = Expr::Value(self.parse_value()?);
-            let percent = self.parse_keyword("PERCENT");
-            self.expect_one_of_keywords(&["ROW", "ROWS"])?;
+            let percent = self.parse_keyword(Keyword::PERCENT);
+            self.expect_one_of_keywords(&[Keyword::ROW, Keyword::ROWS])?;
             (Some(quantity), percent)
         };
-        let with_ties = if self.parse_keyword("ONLY") {
+        let with_ties = if self.parse_keyword(Keyword::ONLY) {
             false
-        } else if self.parse_keywords(vec!["WITH", "TIES"]) {
+        } else if self.parse_keywords(&[Keyword::WITH, Keyword::TIES]) {
             true
         } else {
             return self.expected("one of ONLY or WITH TIES", self.peek_token());
@@ -2505,10 +4774,18 @@ impl Parser {
         })
     }
 
-    pub fn parse_values(&mut self) -> Result<Values, ParserError> {
+    /// Parse the rows of a VALUES list, which may contain `DEFAULT` in place
+    /// of an expression if `allow_default`
+    pub fn parse_values(&mut self, allow_default: bool) -> Result<Values, ParserError> {
         let values = self.parse_comma_separated(|parser| {
             parser.expect_token(&Token::LParen)?;
-            let exprs = parser.parse_comma_separated(Parser::parse_expr)?;
+            let exprs =
+                parser.parse_comma_separated(|parser| match parser.parse_expr_or_default()? {
+                    Expr::Default if !allow_default => {
+                        parser_err!("DEFAULT is only allowed in the VALUES of an INSERT")
+                    }
+                    expr => Ok(expr),
+                })?;
             parser.expect_token(&Token::RParen)?;
             Ok(exprs)
         })?;
@@ -2516,14 +4793,14 @@ impl Parser {
     }
 
     pub fn parse_start_transaction(&mut self) -> Result<Statement, ParserError> {
-        self.expect_keyword("TRANSACTION")?;
+        self.expect_keyword(Keyword::TRANSACTION)?;
         Ok(Statement::StartTransaction {
             modes: self.parse_transaction_modes()?,
         })
     }
 
     pub fn parse_begin(&mut self) -> Result<Statement, ParserError> {
-        let _ = self.parse_one_of_keywords(&["TRANSACTION", "WORK"]);
+        let _ = self.parse_one_of_keywords(&[Keyword::TRANSACTION, Keyword::WORK]);
         Ok(Statement::StartTransaction {
             modes: self.parse_transaction_modes()?,
         })
@@ -2533,24 +4810,24 @@ impl Parser {
         let mut modes = vec![];
         let mut required = false;
         loop {
-            let mode = if self.parse_keywords(vec!["ISOLATION", "LEVEL"]) {
-                let iso_level = if self.parse_keywords(vec!["READ", "UNCOMMITTED"]) {
+            let mode = if self.parse_keywords(&[Keyword::ISOLATION, Keyword::LEVEL]) {
+                let iso_level = if self.parse_keywords(&[Keyword::READ, Keyword::UNCOMMITTED]) {
                     TransactionIsolationLevel::ReadUncommitted
-                } else if self.parse_keywords(vec!["READ", "COMMITTED"]) {
+                } else if self.parse_keywords(&[Keyword::READ, Keyword::COMMITTED]) {
                     TransactionIsolationLevel::ReadCommitted
-                } else if self.parse_keywords(vec!["REPEATABLE", "READ"]) {
+                } else if self.parse_keywords(&[Keyword::REPEATABLE, Keyword::READ]) {
                     TransactionIsolationLevel::RepeatableRead
-                } else if self.parse_keyword("SERIALIZABLE") {
+                } else if self.parse_keyword(Keyword::SERIALIZABLE) {
                     TransactionIsolationLevel::Serializable
                 } else {
                     self.expected("isolation level", self.peek_token())?
                 };
                 TransactionMode::IsolationLevel(iso_level)
-            } else if self.parse_keywords(vec!["READ", "ONLY"]) {
+            } else if self.parse_keywords(&[Keyword::READ, Keyword::ONLY]) {
                 TransactionMode::AccessMode(TransactionAccessMode::ReadOnly)
-            } else if self.parse_keywords(vec!["READ", "WRITE"]) {
+            } else if self.parse_keywords(&[Keyword::READ, Keyword::WRITE]) {
                 TransactionMode::AccessMode(TransactionAccessMode::ReadWrite)
-            } else if required || self.peek_token().is_some() {
+            } else if required || !matches!(self.peek_token(), None | Some(Token::SemiColon)) {
                 self.expected("transaction mode", self.peek_token())?
             } else {
                 break;
@@ -2578,10 +4855,10 @@ impl Parser {
     }
 
     pub fn parse_commit_rollback_chain(&mut self) -> Result<bool, ParserError> {
-        let _ = self.parse_one_of_keywords(&["TRANSACTION", "WORK"]);
-        if self.parse_keyword("AND") {
-            let chain = !self.parse_keyword("NO");
-            self.expect_keyword("CHAIN")?;
+        let _ = self.parse_one_of_keywords(&[Keyword::TRANSACTION, Keyword::WORK]);
+        if self.parse_keyword(Keyword::AND) {
+            let chain = !self.parse_keyword(Keyword::NO);
+            self.expect_keyword(Keyword::CHAIN)?;
             Ok(chain)
         } else {
             Ok(false)
@@ -2591,30 +4868,150 @@ impl Parser {
     /// Parse an `EXPLAIN [DATAFLOW | PLAN] FOR` statement, assuming that the `EXPLAIN` token
     /// has already been consumed.
     pub fn parse_explain(&mut self) -> Result<Statement, ParserError> {
-        let stage = if self.parse_keyword("DATAFLOW") {
+        let stage = if self.parse_keyword(Keyword::DATAFLOW) {
             Stage::Dataflow
-        } else if self.parse_keyword("PLAN") {
+        } else if self.parse_keyword(Keyword::PLAN) {
             Stage::Plan
         } else {
             self.expected("DATAFLOW or PLAN", self.peek_token())?
         };
-        self.expect_keyword("FOR")?;
+        self.expect_keyword(Keyword::FOR)?;
 
         Ok(Statement::Explain {
             stage,
-            query: Box::new(self.parse_query()?),
+            statement: self.parse_nested_statement(
+                "a query, INSERT, UPDATE or DELETE",
+                Statement::is_plannable,
+            )?,
         })
     }
 
+    /// Parse a `PREPARE` statement, assuming the `PREPARE` keyword was
+    /// already consumed
+    pub fn parse_prepare(&mut self) -> Result<Statement, ParserError> {
+        let name = self.parse_identifier()?;
+        let mut data_types = vec![];
+        if self.consume_token(&Token::LParen) {
+            data_types = self.parse_comma_separated(Parser::parse_data_type)?;
+            self.expect_token(&Token::RParen)?;
+        }
+        self.expect_keyword(Keyword::AS)?;
+        Ok(Statement::Prepare {
+            name,
+            data_types,
+            statement: self.parse_nested_statement(
+                "a query, INSERT, UPDATE or DELETE",
+                Statement::is_plannable,
+            )?,
+        })
+    }
+
+    /// Parse a statement embedded in another one, like the body of `EXPLAIN`
+    /// or `DECLARE`, from the current position in the token stream
+    ///
+    /// Fails with "Expected `expected`" if the embedded statement is not one
+    /// that `allowed` accepts.
+    pub fn parse_nested_statement(
+        &mut self,
+        expected: &str,
+        allowed: impl Fn(&Statement) -> bool,
+    ) -> Result<Box<Statement>, ParserError> {
+        let start = self.index;
+        let statement = self.parse_statement()?;
+        if !allowed(&statement) {
+            self.index = start;
+            return self.expected(expected, self.peek_token());
+        }
+        Ok(Box::new(statement))
+    }
+
+    /// Parse a `DECLARE` statement, assuming the `DECLARE` keyword was
+    /// already consumed
+    pub fn parse_declare(&mut self) -> Result<Statement, ParserError> {
+        let name = self.parse_identifier()?;
+        self.expect_keyword(Keyword::CURSOR)?;
+        let hold = if self.parse_keyword(Keyword::WITH) {
+            self.expect_keyword(Keyword::HOLD)?;
+            true
+        } else {
+            if self.parse_keyword(Keyword::WITHOUT) {
+                self.expect_keyword(Keyword::HOLD)?;
+            }
+            false
+        };
+        self.expect_keyword(Keyword::FOR)?;
+        let stmt = self.parse_nested_statement("a query or TAIL", |stmt| {
+            stmt.classification() == StatementKind::Query
+        })?;
+        Ok(Statement::Declare { name, hold, stmt })
+    }
+
+    /// Parse a `FETCH` statement, assuming the `FETCH` keyword was already
+    /// consumed
+    pub fn parse_fetch_cursor(&mut self) -> Result<Statement, ParserError> {
+        let direction = if self.parse_keyword(Keyword::NEXT) {
+            Some(FetchDirection::Next)
+        } else {
+            let forward = self.parse_keyword(Keyword::FORWARD);
+            if self.parse_keyword(Keyword::ALL) {
+                Some(FetchDirection::All)
+            } else if let Some(Token::Number(_)) = self.peek_token() {
+                Some(FetchDirection::Count(self.parse_literal_uint()?))
+            } else if forward {
+                Some(FetchDirection::Next)
+            } else {
+                None
+            }
+        };
+        let _ = self.parse_one_of_keywords(&[Keyword::FROM, Keyword::IN]);
+        Ok(Statement::Fetch {
+            name: self.parse_identifier()?,
+            direction,
+        })
+    }
+
+    /// Parse a `NOTIFY` statement, assuming the `NOTIFY` keyword was already
+    /// consumed
+    pub fn parse_notify(&mut self) -> Result<Statement, ParserError> {
+        let channel = self.parse_identifier()?;
+        let payload = if self.consume_token(&Token::Comma) {
+            Some(self.parse_literal_string()?)
+        } else {
+            None
+        };
+        Ok(Statement::Notify { channel, payload })
+    }
+
+    /// Parse a `DO` statement, assuming the `DO` keyword was already
+    /// consumed. `LANGUAGE` may come before or after the body.
+    pub fn parse_do_block(&mut self) -> Result<Statement, ParserError> {
+        let mut language = if self.parse_keyword(Keyword::LANGUAGE) {
+            Some(self.parse_identifier()?)
+        } else {
+            None
+        };
+        let body = match self.next_token() {
+            Some(Token::SingleQuotedString(s)) => {
+                Value::SingleQuotedString(self.parse_string_continuation(s))
+            }
+            Some(Token::DollarQuotedString(s)) => Value::DollarQuotedString(s),
+            other => return self.expected("string literal", other),
+        };
+        if language.is_none() && self.parse_keyword(Keyword::LANGUAGE) {
+            language = Some(self.parse_identifier()?);
+        }
+        Ok(Statement::DoBlock { language, body })
+    }
+
     /// Parse a statement like `FLUSH SOURCE foo` or `FLUSH ALL SOURCES`,
     /// assuming that the `FLUSH` token has already been consumed.
     ///
     /// This causes the source (or sources) to downgrade their capability(-ies),
     /// promising not to send any new data for the current timestamp
     pub fn parse_flush(&mut self) -> Result<Statement, ParserError> {
-        if self.parse_keywords(vec!["ALL", "SOURCES"]) {
+        if self.parse_keywords(&[Keyword::ALL, Keyword::SOURCES]) {
             Ok(Statement::FlushAllSources)
-        } else if self.parse_keyword("SOURCE") {
+        } else if self.parse_keyword(Keyword::SOURCE) {
             Ok(Statement::FlushSource {
                 name: self.parse_object_name()?,
             })
@@ -2624,6 +5021,78 @@ impl Parser {
     }
 }
 
+/// Convert a lone integer in `ORDER BY` or `GROUP BY` to an ordinal
+fn into_ordinal(expr: Expr) -> Expr {
+    match expr {
+        Expr::Value(Value::Number(ref n)) => match n.to_string().parse() {
+            Ok(n) => Expr::Ordinal(n),
+            Err(_) => expr,
+        },
+        _ => expr,
+    }
+}
+
+/// Parse all of `s` with `parse`, using the syntax of [GenericDialect]
+fn parse_fragment<T>(
+    s: &str,
+    parse: impl FnOnce(&mut Parser) -> Result<T, ParserError>,
+) -> Result<T, ParserError> {
+    parse_fragment_with_dialect(s, &GenericDialect {}, parse)
+}
+
+/// Parse all of `s` with `parse`, using the syntax of `dialect`
+fn parse_fragment_with_dialect<T>(
+    s: &str,
+    dialect: &dyn Dialect,
+    parse: impl FnOnce(&mut Parser) -> Result<T, ParserError>,
+) -> Result<T, ParserError> {
+    let tokens = Tokenizer::new(dialect, s).tokenize_with_spans()?;
+    let mut parser = Parser::new_with_spans(tokens, dialect);
+    let fragment = parse(&mut parser)?;
+    match parser.peek_token() {
+        None => Ok(fragment),
+        unexpected => parser.expected("end of input", unexpected),
+    }
+}
+
+impl FromStr for Expr {
+    type Err = ParserError;
+
+    /// Parse an expression like `a + b`, using the syntax of [GenericDialect]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_fragment(s, |parser| parser.parse_expr())
+    }
+}
+
+impl FromStr for Query {
+    type Err = ParserError;
+
+    /// Parse a query like `SELECT a FROM t`, using the syntax of
+    /// [GenericDialect]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_fragment(s, |parser| parser.parse_query())
+    }
+}
+
+impl FromStr for ObjectName {
+    type Err = ParserError;
+
+    /// Parse a possibly qualified name like `db.schema."Table"`, using the
+    /// syntax of [GenericDialect]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_fragment(s, |parser| parser.parse_object_name())
+    }
+}
+
+impl ObjectName {
+    /// Split a possibly qualified name into its parts, using the quoting
+    /// rules of `dialect`, so that periods inside quoted parts, like the one
+    /// in `` db.`my.table` `` in MySQL, don't separate parts
+    pub fn parse_with_dialect(s: &str, dialect: &dyn Dialect) -> Result<Self, ParserError> {
+        parse_fragment_with_dialect(s, dialect, |parser| parser.parse_object_name())
+    }
+}
+
 impl Word {
     pub fn to_ident(&self) -> Ident {
         Ident {
@@ -2658,4 +5127,13 @@ mod tests {
             parser.prev_token();
         });
     }
+
+    #[test]
+    fn test_parser_dialect() {
+        let dialect = crate::dialect::MsSqlDialect {};
+        let tokens = Tokenizer::new(&dialect, "SELECT 1").tokenize().unwrap();
+        let parser = Parser::new_with_dialect(tokens, &dialect);
+        assert!(parser.dialect().is_delimited_identifier_start('['));
+        assert_eq!("MsSqlDialect", format!("{:?}", parser.dialect()));
+    }
 }
@@ -0,0 +1,136 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, string::ToString, vec::Vec};
+
+use crate::ast::Statement;
+use crate::dialect::{dialect_from_str, Dialect, DialectTables};
+use crate::parser::{ParseOptions, Parser, ParserError};
+
+/// A dialect and [ParseOptions], set up once and then used to parse any
+/// number of queries
+///
+/// The tables a dialect derives, like the set of words it recognizes as
+/// keywords and the binding powers of operators, are computed when the
+/// builder is created rather than looked up for every token. A builder is
+/// `Send` and `Sync`, so it can be shared between threads.
+///
+/// ```
+/// use sqlparser::parser::ParserBuilder;
+///
+/// let parser = ParserBuilder::from_dialect_name("postgres").unwrap();
+/// let statements = parser.parse("SELECT 1; SELECT 2").unwrap();
+/// assert_eq!(2, statements.len());
+/// ```
+#[derive(Debug)]
+pub struct ParserBuilder {
+    dialect: Box<dyn Dialect + Send + Sync>,
+    tables: DialectTables,
+    options: ParseOptions,
+}
+
+impl ParserBuilder {
+    /// Create a builder that parses queries written in `dialect`
+    pub fn new(dialect: impl Dialect + Send + Sync + 'static) -> Self {
+        ParserBuilder::from_boxed_dialect(Box::new(dialect))
+    }
+
+    /// Create a builder for the dialect called `name`, as understood by
+    /// [dialect_from_str]
+    pub fn from_dialect_name(name: &str) -> Option<Self> {
+        dialect_from_str(name).map(ParserBuilder::from_boxed_dialect)
+    }
+
+    fn from_boxed_dialect(dialect: Box<dyn Dialect + Send + Sync>) -> Self {
+        ParserBuilder {
+            tables: DialectTables::new(&*dialect),
+            dialect,
+            options: ParseOptions::default(),
+        }
+    }
+
+    /// Parse queries with `options` instead of the defaults
+    pub fn with_options(mut self, options: ParseOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Return the dialect queries are parsed with
+    pub fn dialect(&self) -> &dyn Dialect {
+        &*self.dialect
+    }
+
+    /// Parse the statements of `sql`, like [Parser::parse_sql_with_options]
+    pub fn parse(&self, sql: &str) -> Result<Vec<Statement>, ParserError> {
+        Parser::parse_sql_with_tables(
+            &*self.dialect,
+            Some(&self.tables),
+            sql.to_string(),
+            &self.options,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialect::{MySqlDialect, DIALECT_NAMES};
+
+    #[test]
+    fn builder_parses_like_its_dialect() {
+        let sqls = &[
+            "SELECT a || b, a ^ b * c FROM t",
+            "SELECT src:a.b, 'x' 'y' FROM t QUALIFY 1",
+            "SELECT a FROM t UNION SELECT b FROM u INTERSECT SELECT c FROM v",
+            "SELECT CAST(a AS decimal(50, 2)) FROM t",
+        ];
+        for name in DIALECT_NAMES {
+            let builder = ParserBuilder::from_dialect_name(name).unwrap();
+            let dialect = dialect_from_str(name).unwrap();
+            assert_eq!(format!("{:?}", dialect), format!("{:?}", builder.dialect()));
+            for sql in sqls {
+                assert_eq!(
+                    Parser::parse_sql(&*dialect, sql.to_string()),
+                    builder.parse(sql),
+                    "{}: {}",
+                    name,
+                    sql
+                );
+            }
+        }
+        assert!(ParserBuilder::from_dialect_name("PostgreSQL").is_some());
+        assert!(ParserBuilder::from_dialect_name("oracle").is_none());
+    }
+
+    #[test]
+    fn builder_is_shareable() {
+        use std::sync::Arc;
+
+        let builder = Arc::new(ParserBuilder::new(MySqlDialect {}));
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let builder = Arc::clone(&builder);
+                std::thread::spawn(move || {
+                    builder
+                        .parse(&format!("SELECT a{} # comment", i))
+                        .unwrap()
+                        .remove(0)
+                        .to_string()
+                })
+            })
+            .collect();
+        for (i, handle) in handles.into_iter().enumerate() {
+            assert_eq!(format!("SELECT a{}", i), handle.join().unwrap());
+        }
+    }
+}
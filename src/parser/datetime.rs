@@ -1,3 +1,11 @@
+#[cfg(not(feature = "std"))]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
 use crate::ast::ParsedDateTime;
 use crate::parser::{DateTimeField, ParserError};
 
@@ -41,7 +49,7 @@ pub(crate) fn tokenize_interval(value: &str) -> Result<Vec<IntervalToken>, Parse
             }
             chr if chr.is_digit(10) => num_buf.push(chr),
             chr => {
-                return Err(ParserError::TokenizerError(format!(
+                return Err(ParserError::ParserError(format!(
                     "Invalid character at offset {} in {}: {:?}",
                     i, value, chr
                 )))
@@ -147,7 +155,7 @@ fn tokenize_timezone(value: &str) -> Result<Vec<IntervalToken>, ParserError> {
                 return Ok(toks);
             }
             chr => {
-                return Err(ParserError::TokenizerError(format!(
+                return Err(ParserError::ParserError(format!(
                     "Error tokenizing timezone string ({}): invalid character {:?} at offset {}",
                     value, chr, i
                 )))
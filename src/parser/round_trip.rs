@@ -0,0 +1,255 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Checks that every variant of [Statement], [Expr], [DataType] and [Token]
+//! survives being displayed and parsed again.
+//!
+//! The enums are `#[non_exhaustive]`, so only this crate can match on them
+//! exhaustively. The `variant_names!` lists below do exactly that: a new
+//! variant fails to compile until it is listed, and then fails the tests
+//! until one of the cases produces it.
+
+use std::collections::BTreeSet;
+
+use crate::ast::simplify::{simplify_statement, SimplifyOptions};
+use crate::ast::visit::{self, Visit};
+use crate::ast::*;
+use crate::dialect::{GenericDialect, PostgreSqlDialect};
+use crate::parser::{MetaCommands, ParseOptions, Parser};
+use crate::tokenizer::{Token, Tokenizer};
+
+/// Expand to a function naming the variant of a value of `$enum`, along with
+/// the names of all the listed variants
+macro_rules! variant_names {
+    ($enum:ident: $($variant:ident),* $(,)?) => {
+        (
+            (|value: &$enum| match value {
+                $($enum::$variant { .. } => stringify!($variant),)*
+            }) as fn(&$enum) -> &'static str,
+            &[$(stringify!($variant)),*] as &[&'static str],
+        )
+    };
+}
+
+const STATEMENTS: &[&str] = &[
+    "SELECT DISTINCT a.*, * FROM t AS u (a int) WHERE a IS NULL AND b IS NOT NULL GROUP BY 1 ORDER BY 2",
+    "SELECT a FROM t WHERE a IN (1, 2) OR b NOT IN (SELECT c FROM u) OR c BETWEEN 1 AND 2",
+    "SELECT a LIKE 'x%' ESCAPE '\\', a NOT ILIKE 'y', a SIMILAR TO 'z', a.b + 1 ^ 2, NOT a, - $1",
+    "SELECT CAST(a AS numeric(10,2)), EXTRACT(YEAR FROM a), INTERVAL a + 1 DAY, TRIM(BOTH 'x' FROM a), OVERLAY(a PLACING 'b' FROM 1 FOR 2)",
+    "SELECT CONVERT(a, char), a COLLATE de_DE, a:b.c[0], (a), count(*), count(t.*), CURRENT_TIMESTAMP",
+    "SELECT CASE WHEN a THEN 1 ELSE 2 END, EXISTS (SELECT 1), NOT EXISTS (SELECT 1), (SELECT 1), ARRAY(SELECT 1)",
+    "SELECT a = ANY (SELECT 1), a > ALL (SELECT 1)",
+    "INSERT INTO t (a) VALUES (1, DEFAULT)",
+    "COPY t FROM stdin;\n\\.",
+    "COPY (SELECT 1) TO STDOUT WITH (FORMAT csv, DELIMITER ';', NULL '-', HEADER false, QUOTE '''', ESCAPE '\\')",
+    "UPDATE t SET a = 1, (b, c) = (2, DEFAULT) WHERE d",
+    "DELETE FROM t WHERE a",
+    "DELETE FROM t WHERE CURRENT OF c",
+    "SELECT * FROM ONLY t, u * AS v",
+    "SELECT * FROM t FOR SYSTEM_TIME AS OF now() AS u",
+    "SELECT x.a FROM XMLTABLE('/r' PASSING d COLUMNS a int PATH '@a' NOT NULL) AS x",
+    "SELECT j.a FROM JSON_TABLE('[1]', '$[*]' COLUMNS (a int PATH '$' NULL ON ERROR)) AS j",
+    "SELECT t.* EXCEPT (a) REPLACE (b AS c) FROM t",
+    "CREATE TEMPORARY VIEW v (a, b int) AS SELECT 1, 2",
+    "CREATE SOURCE foo FROM 'bar' USING SCHEMA 'baz' WITH (name = 'val')",
+    "CREATE SOURCES FROM 'kafka://whatever' USING SCHEMA REGISTRY 'http://foo.bar:8081'",
+    "CREATE SINK foo FROM bar INTO 'baz' WITH (name = 'val')",
+    "FLUSH SOURCE foo",
+    "FLUSH ALL SOURCES",
+    "CREATE VIEW v AS SELECT 1",
+    "CREATE TABLE t (a char(10), b character varying(10), c nchar, d nvarchar(20), e uuid, f clob(10), g binary(10), h varbinary(10), i blob(10), j numeric(10,2), k float, l smallint, m int, n bigint, o real, p double, q boolean, r date, s time, t timestamp, u interval, v regclass, w text, x bytea, y mytype, y2 geometry(point, 4326), z int[], z2 int[3])",
+    "CREATE INDEX foo ON bar (a, b)",
+    "ALTER TABLE ONLY t ALTER COLUMN id DROP DEFAULT",
+    "ALTER VIEW v OWNER TO r",
+    "ALTER VIEW v SET (check_option = local)",
+    "CREATE SEQUENCE s",
+    "CREATE EXTENSION IF NOT EXISTS \"uuid-ossp\" WITH SCHEMA public VERSION '1.1' CASCADE",
+    "ALTER SEQUENCE s RESTART NO MAXVALUE",
+    "CREATE ROLE r",
+    "ALTER ROLE r NOINHERIT",
+    "DROP TABLE foo",
+    "SET a = b",
+    "SHOW a",
+    "SHOW TABLES",
+    "SHOW INDEXES FROM t",
+    "SHOW CONSTRAINTS FROM foo",
+    "SHOW COLUMNS FROM t",
+    "SHOW CREATE VIEW foo",
+    "SHOW CREATE SOURCE foo",
+    "START TRANSACTION",
+    "SET TRANSACTION READ ONLY",
+    "COMMIT",
+    "ROLLBACK",
+    "PEEK v",
+    "TAIL v",
+    "EXPLAIN PLAN FOR SELECT 1",
+    "PREPARE p (int, text) AS INSERT INTO t VALUES ($1, $2)",
+    "DECLARE c CURSOR FOR SELECT 1",
+    "FETCH 10 FROM c",
+    "CLOSE c",
+    "LISTEN c",
+    "NOTIFY c, 'payload'",
+    "UNLISTEN *",
+    "DO $body$BEGIN RAISE NOTICE 'x'; END$body$ LANGUAGE plpgsql",
+    "\\connect db",
+];
+
+fn parse_statement(sql: &str) -> Statement {
+    let options = ParseOptions {
+        meta_commands: MetaCommands::Capture,
+        ..ParseOptions::default()
+    };
+    let mut statements =
+        Parser::parse_sql_with_options(&GenericDialect {}, sql.to_string(), &options)
+            .unwrap_or_else(|e| panic!("{}: {}", sql, e));
+    assert_eq!(1, statements.len(), "{}", sql);
+    statements.pop().unwrap()
+}
+
+fn assert_covered(kind: &str, all: &[&'static str], seen: &BTreeSet<&'static str>) {
+    let missing: Vec<_> = all.iter().filter(|name| !seen.contains(*name)).collect();
+    assert!(
+        missing.is_empty(),
+        "no {} round trip case for {:?}",
+        kind,
+        missing
+    );
+}
+
+/// Collects every expression and data type in a statement
+#[derive(Default)]
+struct Collector<'ast> {
+    exprs: Vec<&'ast Expr>,
+    data_types: Vec<&'ast DataType>,
+}
+
+impl<'ast> Visit<'ast> for Collector<'ast> {
+    fn visit_expr(&mut self, expr: &'ast Expr) {
+        self.exprs.push(expr);
+        visit::visit_expr(self, expr);
+    }
+
+    fn visit_type(&mut self, data_type: &'ast DataType) {
+        self.data_types.push(data_type);
+    }
+}
+
+#[test]
+fn round_trip_statements() {
+    let (name, all) = variant_names!(Statement:
+        Query, Insert, Copy, Update, Delete, CreateSource, CreateSources, CreateSink, FlushSource,
+        FlushAllSources, CreateView, CreateTable, CreateIndex, AlterTable, AlterOwner,
+        AlterViewOptions, CreateSequence, CreateExtension, AlterSequence, CreateRole, AlterRole,
+        Drop, SetVariable, ShowVariable, ShowObjects, ShowIndexes, ShowConstraints, ShowColumns,
+        ShowCreateView, ShowCreateSource, StartTransaction, SetTransaction, Commit, Rollback, Peek,
+        Tail, Explain, Declare, Fetch, Close, Listen, Notify, Unlisten, Prepare, DoBlock,
+        MetaCommand,
+    );
+    let mut seen = BTreeSet::new();
+    for sql in STATEMENTS {
+        let statement = parse_statement(sql);
+        assert_eq!(*sql, statement.to_string());
+        assert_eq!(statement, parse_statement(&statement.to_string()));
+        seen.insert(name(&statement));
+    }
+    assert_covered("Statement", all, &seen);
+}
+
+#[test]
+fn round_trip_exprs_and_data_types() {
+    let (expr_name, all_exprs) = variant_names!(Expr:
+        Identifier, Wildcard, QualifiedWildcard, CompoundIdentifier, Parameter, Ordinal, IsNull,
+        IsNotNull, InList, InSubquery, Between, Like, ILike, SimilarTo, BinaryOp, Conjunction,
+        UnaryOp, Cast, Extract, Interval, Trim, Overlay, Convert, Collate, Default, VariantAccess, Nested,
+        Row, Value, Function, Case, Exists, Subquery, ArraySubquery, Any, All,
+    );
+    let (data_type_name, all_data_types) = variant_names!(DataType:
+        Char, Varchar, Nchar, Nvarchar, Uuid, Clob, Binary, Varbinary, Blob, Decimal, Float,
+        SmallInt, Int, BigInt, Real, Double, Boolean, Date, Time, Timestamp, Interval, Regclass,
+        Text, Bytea, Custom, Array, Spelled,
+    );
+    let statements: Vec<_> = STATEMENTS.iter().map(|sql| parse_statement(sql)).collect();
+    let mut collector = Collector::default();
+    for statement in &statements {
+        collector.visit_statement(statement);
+    }
+    let mut seen_exprs: BTreeSet<_> = collector.exprs.iter().map(|e| expr_name(e)).collect();
+    let mut seen_data_types: BTreeSet<_> = collector
+        .data_types
+        .iter()
+        .map(|t| data_type_name(t))
+        .collect();
+
+    // Only types parsed with their spelling preserved are spelled
+    let options = ParseOptions {
+        preserve_type_names: true,
+        ..ParseOptions::default()
+    };
+    let parse = |sql: &str| {
+        Parser::parse_sql_with_options(&GenericDialect {}, sql.to_string(), &options)
+            .unwrap()
+            .pop()
+            .unwrap()
+    };
+    let sql = "CREATE TABLE t (a INTEGER, b int, c character varying(10)[])";
+    let statement = parse(sql);
+    assert_eq!(sql, statement.to_string());
+    assert_eq!(statement, parse(&statement.to_string()));
+    let mut collector = Collector::default();
+    collector.visit_statement(&statement);
+    seen_data_types.extend(collector.data_types.iter().map(|t| data_type_name(t)));
+
+    // The parser never produces conjunctions, so they can only round trip
+    // through their flattened form.
+    let options = SimplifyOptions {
+        flatten_conjunctions: true,
+        ..SimplifyOptions::default()
+    };
+    let mut statement = parse_statement("SELECT a AND b AND c");
+    simplify_statement(&mut statement, &options);
+    let mut reparsed = parse_statement(&statement.to_string());
+    simplify_statement(&mut reparsed, &options);
+    assert_eq!(statement, reparsed);
+    let mut collector = Collector::default();
+    collector.visit_statement(&statement);
+    seen_exprs.extend(collector.exprs.iter().map(|e| expr_name(e)));
+
+    assert_covered("Expr", all_exprs, &seen_exprs);
+    assert_covered("DataType", all_data_types, &seen_data_types);
+}
+
+#[test]
+fn round_trip_tokens() {
+    let (name, all) = variant_names!(Token:
+        Word, Number, Char, SingleQuotedString, NationalStringLiteral, UnicodeStringLiteral,
+        HexStringLiteral, DollarQuotedString, Parameter, Comma, Whitespace, Eq, Neq, Lt, Gt, LtEq, GtEq, Plus, Minus,
+        Mult, Div, Mod, Caret, JsonGet, JsonGetAsText, JsonGetPath, JsonGetPathAsText, JsonContainsJson,
+        JsonContainedInJson, JsonContainsField, JsonContainsAnyFields, JsonContainsAllFields,
+        JsonConcat, JsonDeletePath, JsonContainsPath, JsonApplyPathPredicate, LParen, RParen,
+        Period, Colon, DoubleColon, SemiColon, Backslash, CopyPayload, LBracket, RBracket,
+        Ampersand, LBrace, RBrace,
+    );
+    let sql =
+        "SELECT 1, 'a' N'b' U&'\\0063' X'0F' $$c$$ $1 = <> < > <= >= + - * / % -> ->> #> #>> \
+               @> <@ ? ?| ?& || #- @? @@ ( ) . : :: ; \\ [ ] & { } ^ ~; \
+               COPY t FROM stdin;\n1\t'\n\\.";
+    let dialect = PostgreSqlDialect {};
+    let tokens = Tokenizer::new(&dialect, sql).tokenize().unwrap();
+    let displayed: String = tokens.iter().map(|t| t.to_string()).collect();
+    assert_eq!(sql, displayed);
+    assert_eq!(
+        tokens,
+        Tokenizer::new(&dialect, &displayed).tokenize().unwrap()
+    );
+    let seen = tokens.iter().map(name).collect();
+    assert_covered("Token", all, &seen);
+}
@@ -0,0 +1,433 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Parsing of SQL read incrementally, for inputs like database dumps that
+//! are too large to hold in memory as a single string
+
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
+use std::str;
+
+use super::{Parser, ParserError};
+use crate::ast::{Span, Statement};
+use crate::dialect::Dialect;
+use crate::tokenizer::{Checkpoint, Token, Tokenizer, TokenizerError, TokenizerErrorKind};
+
+/// How many bytes to read from the input at a time
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// An error returned by [Parser::parse_reader] and [Parser::parse_file]
+#[derive(Debug)]
+pub enum ParseFileError {
+    /// Reading the input failed, or it is not valid UTF-8
+    Io(io::Error),
+    /// The statement that starts at byte `offset` of the input failed to
+    /// parse
+    Parse { offset: usize, error: ParserError },
+}
+
+impl fmt::Display for ParseFileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseFileError::Io(e) => write!(f, "failed to read SQL: {}", e),
+            ParseFileError::Parse { offset, error } => {
+                write!(f, "{} (statement at byte {})", error, offset)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseFileError {}
+
+impl From<io::Error> for ParseFileError {
+    fn from(e: io::Error) -> Self {
+        ParseFileError::Io(e)
+    }
+}
+
+impl<'a> Parser<'a> {
+    /// Parse the SQL statements in the file at `path`, calling `callback`
+    /// with each statement and its span in the file as soon as it is parsed
+    ///
+    /// See [Parser::parse_reader].
+    pub fn parse_file<P, F>(
+        dialect: &dyn Dialect,
+        path: P,
+        callback: F,
+    ) -> Result<(), ParseFileError>
+    where
+        P: AsRef<Path>,
+        F: FnMut(Statement, Span),
+    {
+        Parser::parse_reader(dialect, File::open(path)?, callback)
+    }
+
+    /// Parse the SQL statements read from `reader`, calling `callback` with
+    /// each statement and its span in the input as soon as it is parsed
+    ///
+    /// The input is read in chunks and only the text of the statement
+    /// being parsed is held in memory, so the size of the input is not
+    /// limited by the available memory. The span's offsets are in bytes
+    /// from the start of the input, which also makes them suitable for
+    /// reporting progress. Parsing stops at the first error, after
+    /// `callback` has been called for all the statements before it.
+    pub fn parse_reader<R, F>(
+        dialect: &dyn Dialect,
+        mut reader: R,
+        mut callback: F,
+    ) -> Result<(), ParseFileError>
+    where
+        R: Read,
+        F: FnMut(Statement, Span),
+    {
+        let mut input = Input::default();
+        let mut chunk = vec![0; CHUNK_SIZE];
+        loop {
+            let n = match reader.read(&mut chunk) {
+                Ok(n) => n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e.into()),
+            };
+            let eof = n == 0;
+            input.push(&chunk[..n], eof)?;
+            // The statements before a token that fails to tokenize are
+            // still parsed
+            let tokenized = input.tokenize(dialect, eof);
+            input.parse_statements(dialect, eof && tokenized.is_ok(), &mut callback)?;
+            if let Err(e) = tokenized {
+                return Err(input.error(e.into()));
+            }
+            if eof {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// The input that has been read but not yet parsed
+struct Input {
+    /// The text that has not been tokenized yet, which starts at
+    /// `checkpoint`
+    text: String,
+    /// Bytes at the end of the last chunk that don't form a whole UTF-8
+    /// character yet
+    partial_char: Vec<u8>,
+    /// Where the tokenizer stopped in the input
+    checkpoint: Checkpoint,
+    /// The tokens of the statements not parsed yet, up to `checkpoint`,
+    /// with their spans in the input
+    tokens: Vec<(Token, Span)>,
+    /// How many of `tokens` are known not to end a statement
+    searched: usize,
+    /// The position in the input after the last whitespace read so far
+    cut: usize,
+    /// The position in the input up to which `text` has been searched for
+    /// whitespace
+    scanned: usize,
+    /// The position that `cut` must reach before tokenizing again
+    retry_at: usize,
+}
+
+impl Default for Input {
+    fn default() -> Self {
+        Input {
+            text: String::new(),
+            partial_char: vec![],
+            checkpoint: Checkpoint::new(),
+            tokens: vec![],
+            searched: 0,
+            cut: 0,
+            scanned: 0,
+            retry_at: 0,
+        }
+    }
+}
+
+impl Input {
+    fn push(&mut self, bytes: &[u8], eof: bool) -> Result<(), io::Error> {
+        self.partial_char.extend_from_slice(bytes);
+        let valid = match str::from_utf8(&self.partial_char) {
+            Ok(s) => s.len(),
+            Err(e) if e.error_len().is_none() && !eof => e.valid_up_to(),
+            Err(e) => return Err(io::Error::new(io::ErrorKind::InvalidData, e)),
+        };
+        let rest = self.partial_char.split_off(valid);
+        // Validated above
+        self.text
+            .push_str(str::from_utf8(&self.partial_char).expect("valid UTF-8"));
+        self.partial_char = rest;
+        Ok(())
+    }
+
+    /// Tokenize the part of `text` that doesn't depend on the text still to
+    /// be read, or all of it at the end of the input, and return how many
+    /// bytes were tokenized
+    fn tokenize(&mut self, dialect: &dyn Dialect, eof: bool) -> Result<usize, TokenizerError> {
+        let start = self.checkpoint.offset();
+        let end = start + self.text.len();
+        // Outside of strings and comments, whitespace ends a token, so the
+        // text up to the last whitespace tokenizes the same whatever
+        // follows, except for the token that includes that whitespace
+        if let Some(i) = self.text[self.scanned - start..].rfind([' ', '\t', '\n', '\r']) {
+            self.cut = self.scanned + i + 1;
+        }
+        self.scanned = end;
+        let cut = if eof { end } else { self.cut };
+        if !eof && (cut <= start || cut < self.retry_at) {
+            return Ok(0);
+        }
+
+        let tokenizer = Tokenizer::new(dialect, &self.text[..cut - start]);
+        let len = self.tokens.len();
+        match tokenizer.tokenize_from(&mut self.checkpoint, &mut self.tokens, !eof) {
+            Ok(()) => (),
+            // The string or comment may end in the text still to be read
+            Err(TokenizerError {
+                kind:
+                    TokenizerErrorKind::UnterminatedString | TokenizerErrorKind::UnterminatedComment,
+                ..
+            }) if !eof => self.tokens.truncate(len),
+            Err(e) => return Err(e),
+        }
+        self.text.drain(..self.checkpoint.offset() - start);
+        // The text after `checkpoint` is tokenized again next time, so wait
+        // until at least as much text has been read, which keeps the total
+        // cost linear even when a single token spans many chunks
+        self.retry_at = cut + (cut - self.checkpoint.offset());
+        Ok(cut - start)
+    }
+
+    /// Parse the complete statements tokenized so far, which are those up
+    /// to the last `;`, or all of them at the end of the input
+    fn parse_statements<F>(
+        &mut self,
+        dialect: &dyn Dialect,
+        eof: bool,
+        callback: &mut F,
+    ) -> Result<(), ParseFileError>
+    where
+        F: FnMut(Statement, Span),
+    {
+        // A `;` token is final: none of the tokens before it depend on the
+        // text after it. Neither does a COPY payload that ends with its `\.`
+        // line, but the `;` before any other payload doesn't end a statement.
+        let end = if eof {
+            self.tokens.len()
+        } else {
+            let tokens = &self.tokens;
+            let is_final = |i: usize| match &tokens[i].0 {
+                Token::SemiColon => matches!(
+                    tokens.get(i + 1),
+                    Some((token, _)) if !matches!(token, Token::CopyPayload(_))
+                ),
+                Token::CopyPayload(payload) => matches!(
+                    payload.strip_suffix("\\."),
+                    Some(rest) if rest.ends_with(['\n', '\r'])
+                ),
+                _ => false,
+            };
+            let end = (self.searched..tokens.len())
+                .rev()
+                .find(|i| is_final(*i))
+                .map(|i| i + 1);
+            // Whether the last token is final depends on the one after it
+            self.searched = tokens.len().saturating_sub(1);
+            match end {
+                Some(end) => end,
+                None => return Ok(()),
+            }
+        };
+        let tokens: Vec<_> = self.tokens.drain(..end).collect();
+        self.searched = self.tokens.len().saturating_sub(1);
+
+        let mut parser = Parser::new_with_spans(tokens, dialect);
+        let mut expecting_statement_delimiter = false;
+        loop {
+            while parser.consume_token(&Token::SemiColon) {
+                expecting_statement_delimiter = false;
+            }
+            let start = parser.index;
+            if parser.peek_token().is_none() {
+                break;
+            } else if expecting_statement_delimiter {
+                let error = parser
+                    .expected::<()>("end of statement", parser.peek_token())
+                    .unwrap_err();
+                return Err(ParseFileError::Parse {
+                    offset: parser.spans[start].start,
+                    error,
+                });
+            }
+            let statement = match parser.parse_statement() {
+                Ok(statement) => statement,
+                Err(error) => {
+                    let offset = parser.spans[start..]
+                        .iter()
+                        .zip(&parser.tokens[start..])
+                        .find(|(_, t)| !matches!(t, Token::Whitespace(_)))
+                        .map_or(parser.spans[start].start, |(span, _)| span.start);
+                    return Err(ParseFileError::Parse { offset, error });
+                }
+            };
+            let span = parser.span_since(start).expect("statement has tokens");
+            expecting_statement_delimiter = !statement.has_copy_payload();
+            callback(statement, span);
+        }
+        Ok(())
+    }
+
+    /// Return `error` as the error of the statement not parsed yet
+    fn error(&self, error: ParserError) -> ParseFileError {
+        let offset = match self
+            .tokens
+            .iter()
+            .find(|(token, _)| !matches!(token, Token::Whitespace(_)))
+        {
+            Some((_, span)) => span.start,
+            None => self.checkpoint.offset() + self.text.len() - self.text.trim_start().len(),
+        };
+        ParseFileError::Parse { offset, error }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialect::PostgreSqlDialect;
+
+    /// Parse `sql` from a reader that returns `chunk_size` bytes at a time,
+    /// and return each statement with the text of its span
+    fn parse_chunked(sql: &str, chunk_size: usize) -> Result<Vec<(String, &str)>, ParseFileError> {
+        struct Chunked<'a>(&'a [u8], usize);
+        impl<'a> Read for Chunked<'a> {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                let n = self.0.len().min(self.1).min(buf.len());
+                buf[..n].copy_from_slice(&self.0[..n]);
+                self.0 = &self.0[n..];
+                Ok(n)
+            }
+        }
+        let mut statements = vec![];
+        Parser::parse_reader(
+            &PostgreSqlDialect {},
+            Chunked(sql.as_bytes(), chunk_size),
+            |statement, span| statements.push((statement.to_string(), &sql[span.start..span.end])),
+        )?;
+        Ok(statements)
+    }
+
+    #[test]
+    fn parse_reader_in_chunks() {
+        let sql = "CREATE TABLE \"é\" (a int);\n\
+                   INSERT INTO \"é\" VALUES ('it''s; ok'), ($$;$$);\n\
+                   /* ; */ SELECT 1 -- ;\n\
+                   ; COPY t FROM stdin;\nit's; /* $$\n\\.\n\
+                   SELECT 2";
+        let expected = vec![
+            ("CREATE TABLE \"é\" (a int)", "CREATE TABLE \"é\" (a int)"),
+            (
+                "INSERT INTO \"é\" VALUES ('it''s; ok'), ($$;$$)",
+                "INSERT INTO \"é\" VALUES ('it''s; ok'), ($$;$$)",
+            ),
+            ("SELECT 1", "SELECT 1"),
+            (
+                "COPY t FROM stdin;\nit's; /* $$\n\\.",
+                "COPY t FROM stdin;\nit's; /* $$\n\\.",
+            ),
+            ("SELECT 2", "SELECT 2"),
+        ];
+        for chunk_size in &[1, 2, 3, 7, 1000] {
+            let statements = parse_chunked(sql, *chunk_size).unwrap();
+            let statements: Vec<_> = statements.iter().map(|(s, t)| (s.as_str(), *t)).collect();
+            assert_eq!(expected, statements, "chunk size {}", chunk_size);
+        }
+
+        match parse_chunked("SELECT 1;\nSELECT 'x;\nSELECT 3", 4) {
+            Err(ParseFileError::Parse { offset, error }) => {
+                assert_eq!(10, offset);
+                assert_eq!(
+                    "sql parser error: Unterminated string literal at line 2, column 8",
+                    error.to_string()
+                );
+                assert!(matches!(
+                    error,
+                    ParserError::TokenizerError(TokenizerError {
+                        kind: TokenizerErrorKind::UnterminatedString,
+                        ..
+                    })
+                ));
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+        match parse_chunked("SELECT 1;\nSELECT 1 +;", 5) {
+            Err(ParseFileError::Parse { offset, .. }) => assert_eq!(10, offset),
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        // Errors other than an unterminated string or comment don't depend
+        // on the rest of the input, so they are reported without reading it
+        struct Failing;
+        impl Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "read past the error",
+                ))
+            }
+        }
+        let reader = "SELECT 1;\nSELECT a ! b ".as_bytes().chain(Failing);
+        match Parser::parse_reader(&PostgreSqlDialect {}, reader, |_, _| ()) {
+            Err(ParseFileError::Parse { offset, error }) => {
+                assert_eq!(10, offset);
+                assert_eq!(
+                    "sql parser error: Unexpected character '!' at line 2, column 10",
+                    error.to_string()
+                );
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parse_reader_in_linear_time() {
+        // Statements that span many chunks are not tokenized from their
+        // start again for every chunk
+        let dialect = PostgreSqlDialect {};
+        let sqls = &[
+            format!("SELECT '{}';", "x".repeat(100_000)),
+            format!("COPY t FROM stdin;\n{}\\.\n", "1\n".repeat(50_000)),
+            format!("INSERT INTO t VALUES {};", vec!["(1)"; 25_000].join(", ")),
+        ];
+        for sql in sqls {
+            let mut input = Input::default();
+            let mut tokenized = 0;
+            let mut statements = 0;
+            for chunk in sql.as_bytes().chunks(16).chain(Some(&[][..])) {
+                let eof = chunk.is_empty();
+                input.push(chunk, eof).unwrap();
+                tokenized += input.tokenize(&dialect, eof).unwrap();
+                input
+                    .parse_statements(&dialect, eof, &mut |_, _| statements += 1)
+                    .unwrap();
+            }
+            assert_eq!(1, statements);
+            assert!(
+                tokenized <= 3 * sql.len(),
+                "tokenized {} bytes of {}",
+                tokenized,
+                sql.len()
+            );
+        }
+    }
+}
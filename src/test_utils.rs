@@ -10,10 +10,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::fmt::Debug;
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, format, string::ToString, vec, vec::Vec};
+use core::fmt::Debug;
 
 use super::ast::*;
 use super::dialect::*;
+#[cfg(feature = "std")]
+use super::parser::ParseFileError;
 use super::parser::{Parser, ParserError};
 use super::tokenizer::Tokenizer;
 
@@ -24,11 +28,36 @@ pub struct TestedDialects {
 }
 
 impl TestedDialects {
+    /// Test with the dialects called `names`, as accepted by
+    /// [dialect_from_str]
+    pub fn named(names: &[&str]) -> Self {
+        TestedDialects {
+            dialects: names
+                .iter()
+                .map(|name| -> Box<dyn Dialect> {
+                    dialect_from_str(name).unwrap_or_else(|| panic!("Unknown dialect: {}", name))
+                })
+                .collect(),
+        }
+    }
+
+    /// Return the dialects listed in [DIALECT_NAMES] that are not tested by
+    /// `self`, for checking that SQL specific to some dialects is rejected
+    /// by all the others
+    pub fn others(&self) -> TestedDialects {
+        let tested: Vec<_> = self.dialects.iter().map(|d| format!("{:?}", d)).collect();
+        let mut others = TestedDialects::named(DIALECT_NAMES);
+        others
+            .dialects
+            .retain(|d| !tested.contains(&format!("{:?}", d)));
+        others
+    }
+
     /// Run the given function for all of `self.dialects`, assert that they
     /// return the same result, and return that result.
-    pub fn one_of_identical_results<F, T: Debug + PartialEq>(&self, f: F) -> T
+    pub fn one_of_identical_results<'a, F, T: Debug + PartialEq>(&'a self, f: F) -> T
     where
-        F: Fn(&dyn Dialect) -> T,
+        F: Fn(&'a dyn Dialect) -> T,
     {
         let parse_results = self.dialects.iter().map(|dialect| (dialect, f(&**dialect)));
         parse_results
@@ -46,14 +75,14 @@ impl TestedDialects {
             .1
     }
 
-    pub fn run_parser_method<F, T: Debug + PartialEq>(&self, sql: &str, f: F) -> T
+    pub fn run_parser_method<'a, F, T: Debug + PartialEq>(&'a self, sql: &str, f: F) -> T
     where
-        F: Fn(&mut Parser) -> T,
+        F: Fn(&mut Parser<'a>) -> T,
     {
         self.one_of_identical_results(|dialect| {
             let mut tokenizer = Tokenizer::new(dialect, sql);
-            let tokens = tokenizer.tokenize().unwrap();
-            f(&mut Parser::new(tokens))
+            let tokens = tokenizer.tokenize_with_spans().unwrap();
+            f(&mut Parser::new_with_spans(tokens, dialect))
         })
     }
 
@@ -63,6 +92,44 @@ impl TestedDialects {
         // Parser::parse_sql(&**self.dialects.first().unwrap(), sql.to_string())
     }
 
+    /// Ensures that `sql` fails to parse with each of the dialects, with the
+    /// same error, and returns it
+    pub fn parse_error(&self, sql: &str) -> ParserError {
+        self.one_of_identical_results(
+            |dialect| match Parser::parse_sql(dialect, sql.to_string()) {
+                Ok(statements) => panic!(
+                    "Expected {:?} to fail with {:?}, but it parses as: {:?}",
+                    sql, dialect, statements
+                ),
+                Err(e) => e,
+            },
+        )
+    }
+
+    /// Ensures that `sql` fails to parse with each of the dialects with the
+    /// error `message`
+    pub fn assert_parse_error(&self, sql: &str, message: &str) {
+        match self.parse_error(sql) {
+            ParserError::ParserError(m) => assert_eq!(message, m, "parsing {:?}", sql),
+            ParserError::TokenizerError(e) => {
+                assert_eq!(message, e.to_string(), "parsing {:?}", sql)
+            }
+        }
+    }
+
+    /// Ensures that `sql` is a [verified statement](Self::verified_stmt)
+    /// with each of the dialects, and fails to parse with the error
+    /// `message` with each of the `rejecting` dialects
+    pub fn verified_stmt_rejected_by(
+        &self,
+        sql: &str,
+        rejecting: &TestedDialects,
+        message: &str,
+    ) -> Statement {
+        rejecting.assert_parse_error(sql, message);
+        self.verified_stmt(sql)
+    }
+
     /// Ensures that `sql` parses as a single statement, optionally checking
     /// that converting AST back to string equals to `canonical` (unless an
     /// empty canonical string is provided).
@@ -127,10 +194,67 @@ impl TestedDialects {
     /// after a serialization round-trip.
     pub fn verified_expr(&self, sql: &str) -> Expr {
         let ast = self.run_parser_method(sql, Parser::parse_expr).unwrap();
+        #[cfg(feature = "std")]
         dbg!(&ast);
         assert_eq!(sql, &ast.to_string(), "round-tripping without changes");
         ast
     }
+
+    /// Ensures that the `;`-separated statements in `corpus` parse with each
+    /// of the dialects, and that the display of each parses back to the same
+    /// [Statement] with the same dialect.
+    ///
+    /// Unlike [verified_stmt](Self::verified_stmt), this doesn't require the
+    /// statements to be written in their canonical form, and all the
+    /// statements that fail are reported together.
+    #[cfg(feature = "std")]
+    pub fn verified_corpus(&self, corpus: &str) {
+        let mut failures = vec![];
+        for dialect in &self.dialects {
+            let mut statements = vec![];
+            let parsed = Parser::parse_reader(&**dialect, corpus.as_bytes(), |statement, _| {
+                statements.push(statement)
+            });
+            if let Err(e) = parsed {
+                let at = match &e {
+                    ParseFileError::Parse { offset, .. } => corpus[*offset..].lines().next(),
+                    ParseFileError::Io(_) => None,
+                };
+                panic!(
+                    "Corpus fails to parse with {:?}: {}\n    at: {}",
+                    dialect,
+                    e,
+                    at.unwrap_or_default()
+                );
+            }
+            for statement in statements {
+                let sql = statement.to_string();
+                match Parser::parse_sql(&**dialect, sql.clone()) {
+                    Ok(reparsed) if reparsed == [statement] => (),
+                    Ok(reparsed) => failures.push(format!(
+                        "{:?}: {}\n    parses back as: {}",
+                        dialect,
+                        sql,
+                        reparsed
+                            .iter()
+                            .map(|s| s.to_string())
+                            .collect::<Vec<_>>()
+                            .join("; ")
+                    )),
+                    Err(e) => failures.push(format!(
+                        "{:?}: {}\n    fails to parse back: {}",
+                        dialect, sql, e
+                    )),
+                }
+            }
+        }
+        assert!(
+            failures.is_empty(),
+            "{} statements don't survive a round trip:\n{}",
+            failures.len(),
+            failures.join("\n")
+        );
+    }
 }
 
 pub fn all_dialects() -> TestedDialects {
@@ -144,6 +268,14 @@ pub fn all_dialects() -> TestedDialects {
     }
 }
 
+/// Test with only the [GenericDialect], for SQL that other dialects parse
+/// differently
+pub fn generic() -> TestedDialects {
+    TestedDialects {
+        dialects: vec![Box::new(GenericDialect {})],
+    }
+}
+
 pub fn only<T>(v: impl IntoIterator<Item = T>) -> T {
     let mut iter = v.into_iter();
     if let (Some(item), None) = (iter.next(), iter.next()) {
@@ -16,16 +16,25 @@
 //!
 //! The tokens then form the input for the parser, which outputs an Abstract Syntax Tree (AST).
 
-use std::iter::Peekable;
-use std::str::Chars;
-
-use super::dialect::keywords::ALL_KEYWORDS;
-use super::dialect::Dialect;
-use std::error::Error;
-use std::fmt;
+#[cfg(not(feature = "std"))]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use core::iter::Peekable;
+use core::str::Chars;
+
+use super::ast::{unescape_string_literal, DollarQuotedString, Span};
+use super::dialect::keywords::{Keyword, ALL_KEYWORDS, ALL_KEYWORDS_INDEX};
+use super::dialect::{Dialect, DialectTables};
+use core::fmt;
 
 /// SQL Token enumeration
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum Token {
     /// A keyword (like SELECT) or an optionally quoted SQL identifier
     Word(Word),
@@ -34,11 +43,23 @@ pub enum Token {
     /// A character that could not be tokenized
     Char(char),
     /// Single quoted string: i.e: 'string'
+    ///
+    /// Like the other string literals, it holds the string without its
+    /// quotes and with its escape sequences decoded, unless the tokenizer
+    /// was built [without unescaping](Tokenizer::with_unescape).
     SingleQuotedString(String),
     /// "National" string literal: i.e: N'string'
     NationalStringLiteral(String),
+    /// Unicode string literal: i.e: U&'d\0061ta'
+    ///
+    /// Unlike the other string literals, it holds the text between the
+    /// quotes exactly as written, as its escapes can only be decoded once
+    /// the `UESCAPE` clause that may follow it is known.
+    UnicodeStringLiteral(String),
     /// Hexadecimal string literal: i.e.: X'deadbeef'
     HexStringLiteral(String),
+    /// Dollar-quoted string literal: i.e.: $$string$$ or $tag$string$tag$
+    DollarQuotedString(DollarQuotedString),
     /// An unsigned numeric literal representing positional
     /// parameters like $1, $2, etc. in prepared statements and
     /// function definitions
@@ -69,6 +90,8 @@ pub enum Token {
     Div,
     /// Modulo Operator `%`
     Mod,
+    /// Caret `^`, exponentiation in PostgreSQL and bitwise XOR in MySQL
+    Caret,
     // Json functions are documented at https://www.postgresql.org/docs/current/functions-json.html
     /// Get json field operator '->'
     JsonGet,
@@ -108,8 +131,12 @@ pub enum Token {
     DoubleColon,
     /// SemiColon `;` used as separator for COPY and payload
     SemiColon,
-    /// Backslash `\` used in terminating the COPY payload with `\.`
+    /// Backslash `\`, which starts a psql meta-command
     Backslash,
+    /// The rows that follow the `;` of a `COPY ... FROM STDIN`, exactly as
+    /// written, up to and including the `\.` line that ends them. They are
+    /// data rather than SQL, so they are not tokenized.
+    CopyPayload(String),
     /// Left bracket `[`
     LBracket,
     /// Right bracket `]`
@@ -130,7 +157,9 @@ impl fmt::Display for Token {
             Token::Char(ref c) => write!(f, "{}", c),
             Token::SingleQuotedString(ref s) => write!(f, "'{}'", s),
             Token::NationalStringLiteral(ref s) => write!(f, "N'{}'", s),
+            Token::UnicodeStringLiteral(ref s) => write!(f, "U&'{}'", s),
             Token::HexStringLiteral(ref s) => write!(f, "X'{}'", s),
+            Token::DollarQuotedString(ref s) => write!(f, "{}", s),
             Token::Parameter(n) => write!(f, "${}", n),
             Token::Comma => f.write_str(","),
             Token::Whitespace(ws) => write!(f, "{}", ws),
@@ -145,6 +174,7 @@ impl fmt::Display for Token {
             Token::Mult => f.write_str("*"),
             Token::Div => f.write_str("/"),
             Token::Mod => f.write_str("%"),
+            Token::Caret => f.write_str("^"),
             Token::JsonGet => f.write_str("->"),
             Token::JsonGetAsText => f.write_str("->>"),
             Token::JsonGetPath => f.write_str("#>"),
@@ -165,6 +195,7 @@ impl fmt::Display for Token {
             Token::DoubleColon => f.write_str("::"),
             Token::SemiColon => f.write_str(";"),
             Token::Backslash => f.write_str("\\"),
+            Token::CopyPayload(ref s) => f.write_str(s),
             Token::LBracket => f.write_str("["),
             Token::RBracket => f.write_str("]"),
             Token::Ampersand => f.write_str("&"),
@@ -179,19 +210,22 @@ impl Token {
         Token::make_word(keyword, None)
     }
     pub fn make_word(word: &str, quote_style: Option<char>) -> Self {
-        let word_uppercase = word.to_uppercase();
-        //TODO: need to reintroduce FnvHashSet at some point .. iterating over keywords is
-        // not fast but I want the simplicity for now while I experiment with pluggable
-        // dialects
-        let is_keyword = quote_style == None && ALL_KEYWORDS.contains(&word_uppercase.as_str());
+        // Keywords are ASCII, so only ASCII letters are folded: otherwise
+        // e.g. the Turkish dotless `ı` in `lımıt` would uppercase to `I`
+        // and match `LIMIT`
+        let word_uppercase = word.to_ascii_uppercase();
+        // Quoted words are always identifiers, never keywords
+        let keyword = if quote_style.is_none() {
+            ALL_KEYWORDS
+                .binary_search(&word_uppercase.as_str())
+                .map_or(Keyword::NoKeyword, |i| ALL_KEYWORDS_INDEX[i])
+        } else {
+            Keyword::NoKeyword
+        };
         Token::Word(Word {
             value: word.to_string(),
             quote_style,
-            keyword: if is_keyword {
-                word_uppercase
-            } else {
-                "".to_string()
-            },
+            keyword,
         })
     }
 }
@@ -207,16 +241,15 @@ pub struct Word {
     /// but some implementations support other quoting styles as well (e.g. \[MS SQL])
     pub quote_style: Option<char>,
     /// If the word was not quoted and it matched one of the known keywords,
-    /// this will have one of the values from dialect::keywords, otherwise empty
-    pub keyword: String,
+    /// this will have one of the values from dialect::keywords, otherwise
+    /// `Keyword::NoKeyword`
+    pub keyword: Keyword,
 }
 
 impl fmt::Display for Word {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self.quote_style {
-            Some(s) if s == '"' || s == '[' || s == '`' => {
-                write!(f, "{}{}{}", s, self.value, Word::matching_end_quote(s))
-            }
+            Some(s) if s == '"' || s == '[' || s == '`' => write!(f, "{}", self.to_ident()),
             None => f.write_str(&self.value),
             _ => panic!("Unexpected quote_style!"),
         }
@@ -254,24 +287,242 @@ impl fmt::Display for Whitespace {
     }
 }
 
-/// Tokenizer error
-#[derive(Debug, PartialEq)]
-pub struct TokenizerError(String);
+/// The kind of problem a [TokenizerError] reports
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenizerErrorKind {
+    /// A string literal or quoted identifier is missing its closing quote
+    UnterminatedString,
+    /// A `/* ... */` comment is missing its closing `*/`
+    UnterminatedComment,
+    /// A character that cannot start (or continue) a token
+    UnexpectedChar,
+    /// An identifier exceeds [TokenizerLimits::max_identifier_length]
+    IdentifierTooLong,
+    /// A string literal exceeds [TokenizerLimits::max_string_literal_length]
+    StringLiteralTooLong,
+    /// A statement exceeds [TokenizerLimits::max_statement_length]
+    StatementTooLong,
+}
+
+/// Limits on the size of the syntax a [Tokenizer] accepts, in bytes, so
+/// that untrusted input fails early with a [TokenizerError] rather than
+/// being tokenized in full. There are no limits by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct TokenizerLimits {
+    /// The longest identifier, quoted or not, not counting its quotes
+    pub max_identifier_length: Option<usize>,
+    /// The longest string literal as written, not counting its quotes, so
+    /// that an escape sequence counts with its escape characters
+    pub max_string_literal_length: Option<usize>,
+    /// The longest statement, counted from the end of the previous one
+    pub max_statement_length: Option<usize>,
+}
+
+/// Tokenizer error, located at the start of the offending token
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenizerError {
+    pub kind: TokenizerErrorKind,
+    pub message: String,
+    /// The line of the offending token, starting at 1
+    pub line: u64,
+    /// The column of the offending token, in characters, starting at 1
+    pub col: u64,
+    /// The byte offset of the offending token in the query
+    pub offset: usize,
+}
 
 impl fmt::Display for TokenizerError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.write_str(&self.0)
+        write!(
+            f,
+            "{} at line {}, column {}",
+            self.message, self.line, self.col
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TokenizerError {}
+
+/// The position of a character in the query
+#[derive(Debug, Clone, Copy)]
+struct Location {
+    line: u64,
+    col: u64,
+    offset: usize,
+}
+
+impl Location {
+    fn error(self, kind: TokenizerErrorKind, message: impl Into<String>) -> TokenizerError {
+        TokenizerError {
+            kind,
+            message: message.into(),
+            line: self.line,
+            col: self.col,
+            offset: self.offset,
+        }
     }
 }
 
-impl Error for TokenizerError {}
+/// The characters of the query still to be tokenized, along with the
+/// location of the next one
+struct State<'a> {
+    peekable: Peekable<Chars<'a>>,
+    location: Location,
+    after_cr: bool,
+}
+
+impl<'a> State<'a> {
+    fn next(&mut self) -> Option<char> {
+        let ch = self.peekable.next()?;
+        match ch {
+            // `\r\n` is a single line break
+            '\n' if self.after_cr => {}
+            '\n' | '\r' => {
+                self.location.line += 1;
+                self.location.col = 1;
+            }
+            _ => self.location.col += 1,
+        }
+        self.location.offset += ch.len_utf8();
+        self.after_cr = ch == '\r';
+        Some(ch)
+    }
+
+    fn peek(&mut self) -> Option<&char> {
+        self.peekable.peek()
+    }
+
+    /// Return the `n`th character after the next one, without consuming
+    /// anything
+    fn peek_nth(&self, n: usize) -> Option<char> {
+        self.peekable.clone().nth(n)
+    }
+
+    /// Whether the next characters are the opening `$tag$` of a
+    /// dollar-quoted string, where the tag may be empty but can't start
+    /// with a digit
+    fn starts_dollar_quote(&self) -> bool {
+        let mut chars = self.peekable.clone().skip(1);
+        match chars.next() {
+            Some('$') => return true,
+            Some(ch) if ch.is_alphabetic() || ch == '_' => (),
+            _ => return false,
+        }
+        for ch in chars {
+            match ch {
+                '$' => return true,
+                _ if ch.is_alphanumeric() || ch == '_' => (),
+                _ => return false,
+            }
+        }
+        false
+    }
+}
+
+/// Recognizes the `;` of a `COPY ... FROM STDIN` statement, which the rows
+/// of its payload follow, as [Parser::parse_copy](crate::parser::Parser)
+/// would parse the statement
+#[derive(Debug, Clone, Copy, Default)]
+struct CopyStatement {
+    started: bool,
+    is_copy: bool,
+    /// The depth of the parentheses around the current token
+    depth: usize,
+    after_from: bool,
+    after_format: bool,
+    from_stdin: bool,
+    binary: bool,
+}
+
+impl CopyStatement {
+    /// Take the next token of the input into account, returning whether it
+    /// is the `;` that a COPY payload follows
+    fn push(&mut self, token: &Token) -> bool {
+        match token {
+            Token::Whitespace(_) => return false,
+            Token::SemiColon => {
+                let payload = self.is_copy && self.from_stdin && !self.binary;
+                *self = CopyStatement::default();
+                return payload;
+            }
+            _ => (),
+        }
+        if !self.started {
+            self.started = true;
+            self.is_copy = matches!(token, Token::Word(w) if w.keyword == Keyword::COPY);
+        }
+        if !self.is_copy {
+            return false;
+        }
+        let (after_from, after_format) = (self.after_from, self.after_format);
+        self.after_from = false;
+        self.after_format = false;
+        match token {
+            Token::LParen => self.depth += 1,
+            Token::RParen => self.depth = self.depth.saturating_sub(1),
+            Token::Word(w) => {
+                // A query copied to STDOUT may select FROM a table named
+                // stdin, but only within its parentheses
+                if after_from && w.keyword == Keyword::STDIN && self.depth == 0 {
+                    self.from_stdin = true;
+                }
+                if after_format && w.value.eq_ignore_ascii_case("binary") {
+                    self.binary = true;
+                }
+                self.after_from = w.keyword == Keyword::FROM;
+                self.after_format =
+                    w.quote_style.is_none() && w.value.eq_ignore_ascii_case("format");
+            }
+            _ => (),
+        }
+        false
+    }
+}
+
+/// The state of a [Tokenizer] between two tokens, from which it can go on
+/// tokenizing a query that is read in pieces
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Checkpoint {
+    location: Location,
+    after_cr: bool,
+    /// Where the statement being tokenized starts
+    statement_start: usize,
+    copy: CopyStatement,
+}
+
+impl Checkpoint {
+    /// The checkpoint at the start of a query
+    pub(crate) fn new() -> Self {
+        Checkpoint {
+            location: Location {
+                line: 1,
+                col: 1,
+                offset: 0,
+            },
+            after_cr: false,
+            statement_start: 0,
+            copy: CopyStatement::default(),
+        }
+    }
+
+    /// The byte offset in the query of the next token
+    #[cfg(feature = "std")]
+    pub(crate) fn offset(&self) -> usize {
+        self.location.offset
+    }
+}
 
 /// SQL Tokenizer
 pub struct Tokenizer<'a> {
     dialect: &'a dyn Dialect,
+    /// The dialect's precomputed tables, if any
+    tables: Option<&'a DialectTables>,
     pub query: String,
     pub line: u64,
     pub col: u64,
+    unescape: bool,
+    limits: TokenizerLimits,
 }
 
 impl<'a> Tokenizer<'a> {
@@ -279,42 +530,209 @@ impl<'a> Tokenizer<'a> {
     pub fn new(dialect: &'a dyn Dialect, query: &str) -> Self {
         Self {
             dialect,
+            tables: None,
             query: query.to_string(),
             line: 1,
             col: 1,
+            unescape: true,
+            limits: TokenizerLimits::default(),
         }
     }
 
+    /// Look up the dialect's keywords in `tables`, which must have been
+    /// computed from the tokenizer's dialect
+    pub(crate) fn with_tables(mut self, tables: &'a DialectTables) -> Self {
+        self.tables = Some(tables);
+        self
+    }
+
+    /// Reject tokens and statements longer than `limits` allow
+    pub fn with_limits(mut self, limits: TokenizerLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Set whether to decode the escape sequences of string literals, as
+    /// the tokenizer does by default. Without decoding, string literal
+    /// tokens hold their text exactly as written between the quotes, so
+    /// that the raw literal can be preserved or passed on to a dialect with
+    /// the same escaping rules. The parser expects decoded strings, though.
+    pub fn with_unescape(mut self, unescape: bool) -> Self {
+        self.unescape = unescape;
+        self
+    }
+
     /// Tokenize the statement and produce a vector of tokens
     pub fn tokenize(&mut self) -> Result<Vec<Token>, TokenizerError> {
-        let mut peekable = self.query.chars().peekable();
+        let tokens = self.tokenize_with_spans()?;
+        Ok(tokens.into_iter().map(|(token, _)| token).collect())
+    }
 
-        let mut tokens: Vec<Token> = vec![];
+    /// Like [Tokenizer::tokenize], but also return where each token was
+    /// written. The parts of a quoted identifier that a dialect splits on
+    /// `.` each get the span of the whole identifier.
+    pub fn tokenize_with_spans(&mut self) -> Result<Vec<(Token, Span)>, TokenizerError> {
+        let mut checkpoint = Checkpoint::new();
+        checkpoint.location.line = self.line;
+        checkpoint.location.col = self.col;
+        let mut tokens = vec![];
+        self.tokenize_from(&mut checkpoint, &mut tokens, false)?;
+        self.line = checkpoint.location.line;
+        self.col = checkpoint.location.col;
+        Ok(tokens)
+    }
 
-        while let Some(token) = self.next_token(&mut peekable)? {
-            match &token {
-                Token::Whitespace(Whitespace::Newline) => {
-                    self.line += 1;
-                    self.col = 1;
+    /// Tokenize `self.query` as the continuation of the query at
+    /// `checkpoint`, appending the tokens to `tokens` with spans that count
+    /// from the start of the whole query, and move `checkpoint` past them
+    ///
+    /// If `partial`, the query goes on after `self.query`, so its last token
+    /// may be incomplete: that token is left out, and `checkpoint` is moved
+    /// to its start instead.
+    pub(crate) fn tokenize_from(
+        &self,
+        checkpoint: &mut Checkpoint,
+        tokens: &mut Vec<(Token, Span)>,
+        partial: bool,
+    ) -> Result<(), TokenizerError> {
+        let mut state = State {
+            peekable: self.query.chars().peekable(),
+            location: checkpoint.location,
+            after_cr: checkpoint.after_cr,
+        };
+
+        let mut location = state.location;
+        let mut statement_start = checkpoint.statement_start;
+        let mut copy = checkpoint.copy;
+        // Where the last token starts, and how many tokens came before it
+        let mut last_token = None;
+        let end = loop {
+            let before = Checkpoint {
+                location,
+                after_cr: state.after_cr,
+                statement_start,
+                copy,
+            };
+            let token = match self.next_token(&mut state)? {
+                Some(token) => token,
+                None => break before,
+            };
+            last_token = Some((before, tokens.len()));
+            let span = Span {
+                start: location.offset,
+                end: state.location.offset,
+            };
+            self.check_limits(&token, location, span.end - statement_start)?;
+            if token == Token::SemiColon {
+                statement_start = span.end;
+            }
+            if copy.push(&token) {
+                tokens.push((token, span));
+                let payload = self.tokenize_copy_payload(&mut state);
+                let span = Span {
+                    start: span.end,
+                    end: state.location.offset,
+                };
+                self.check_limits(&payload, location, span.end - statement_start)?;
+                tokens.push((payload, span));
+                location = state.location;
+                continue;
+            }
+            location = state.location;
+            match token {
+                Token::Word(w)
+                    if w.quote_style.is_some()
+                        && w.value.contains('.')
+                        && self.dialect.is_quoted_identifier_multipart() =>
+                {
+                    for (i, part) in w.value.split('.').enumerate() {
+                        if i > 0 {
+                            tokens.push((Token::Period, span));
+                        }
+                        tokens.push((self.make_word(part, w.quote_style), span));
+                    }
                 }
+                token => tokens.push((token, span)),
+            }
+        };
+        *checkpoint = match last_token {
+            Some((start, len)) if partial => {
+                tokens.truncate(len);
+                start
+            }
+            _ => end,
+        };
+        Ok(())
+    }
 
-                Token::Whitespace(Whitespace::Tab) => self.col += 4,
-                Token::Word(w) if w.quote_style == None => self.col += w.value.len() as u64,
-                Token::Word(w) if w.quote_style != None => self.col += w.value.len() as u64 + 2,
-                Token::Number(s) => self.col += s.len() as u64,
-                Token::SingleQuotedString(s) => self.col += s.len() as u64,
-                Token::Parameter(s) => self.col += s.len() as u64,
-                _ => self.col += 1,
+    /// Check `token`, which starts at `start`, and the `statement_length`
+    /// bytes of the statement up to its end against `self.limits`
+    ///
+    /// String literals and quoted identifiers are checked as they are read,
+    /// so that an overlong one fails without being read in full.
+    fn check_limits(
+        &self,
+        token: &Token,
+        start: Location,
+        statement_length: usize,
+    ) -> Result<(), TokenizerError> {
+        let limits = &self.limits;
+        let kind = match token {
+            // Keywords are never too long, and may be longer than the limit
+            Token::Word(w)
+                if w.keyword == Keyword::NoKeyword
+                    && too_long(w.value.len(), limits.max_identifier_length) =>
+            {
+                TokenizerErrorKind::IdentifierTooLong
+            }
+            _ if too_long(statement_length, limits.max_statement_length) => {
+                TokenizerErrorKind::StatementTooLong
             }
+            _ => return Ok(()),
+        };
+        Err(self.limit_error(kind, start))
+    }
 
-            tokens.push(token);
+    /// The error for a token starting at `start` that exceeds the limit
+    /// `kind` refers to
+    fn limit_error(&self, kind: TokenizerErrorKind, start: Location) -> TokenizerError {
+        let limits = &self.limits;
+        let (what, limit) = match kind {
+            TokenizerErrorKind::IdentifierTooLong => ("Identifier", limits.max_identifier_length),
+            TokenizerErrorKind::StringLiteralTooLong => {
+                ("String literal", limits.max_string_literal_length)
+            }
+            TokenizerErrorKind::StatementTooLong => ("Statement", limits.max_statement_length),
+            _ => unreachable!("{:?} is not a limit", kind),
+        };
+        start.error(
+            kind,
+            format!("{} is longer than {} bytes", what, limit.unwrap()),
+        )
+    }
+
+    /// Like [Token::make_word], but only recognizes the keywords of the
+    /// tokenizer's dialect
+    fn make_word(&self, word: &str, quote_style: Option<char>) -> Token {
+        match Token::make_word(word, quote_style) {
+            Token::Word(mut w) => {
+                let is_keyword = match self.tables {
+                    Some(tables) => tables.is_keyword(w.keyword),
+                    None => self.dialect.is_keyword(w.keyword),
+                };
+                if !is_keyword {
+                    w.keyword = Keyword::NoKeyword;
+                }
+                Token::Word(w)
+            }
+            token => token,
         }
-        Ok(tokens)
     }
 
     /// Get the next token or return None
-    fn next_token(&self, chars: &mut Peekable<Chars<'_>>) -> Result<Option<Token>, TokenizerError> {
+    fn next_token(&self, chars: &mut State) -> Result<Option<Token>, TokenizerError> {
         //println!("next_token: {:?}", chars.peek());
+        let start = chars.location;
         match chars.peek() {
             Some(&ch) => match ch {
                 ' ' => self.consume_and_return(chars, Token::Whitespace(Whitespace::Space)),
@@ -328,21 +746,28 @@ impl<'a> Tokenizer<'a> {
                     }
                     Ok(Some(Token::Whitespace(Whitespace::Newline)))
                 }
-                'N' => {
+                n @ 'N' | n @ 'n' => {
                     chars.next(); // consume, to check the next char
                     match chars.peek() {
                         Some('\'') => {
                             // N'...' - a <national character string literal>
-                            let s = self.tokenize_single_quoted_string(chars);
+                            let s = self.tokenize_single_quoted_string(chars, start)?;
                             Ok(Some(Token::NationalStringLiteral(s)))
                         }
                         _ => {
                             // regular identifier starting with an "N"
-                            let s = self.tokenize_word('N', chars);
-                            Ok(Some(Token::make_word(&s, None)))
+                            let s = self.tokenize_word(n, chars, start)?;
+                            Ok(Some(self.make_word(&s, None)))
                         }
                     }
                 }
+                // U&'...' - a <Unicode character string literal>
+                'U' | 'u' if chars.peek_nth(1) == Some('&') && chars.peek_nth(2) == Some('\'') => {
+                    chars.next(); // consume the U
+                    chars.next(); // consume the &
+                    let s = self.tokenize_raw_single_quoted_string(chars, start, false)?;
+                    Ok(Some(Token::UnicodeStringLiteral(s)))
+                }
                 // The spec only allows an uppercase 'X' to introduce a hex
                 // string, but PostgreSQL, at least, allows a lowercase 'x' too.
                 x @ 'x' | x @ 'X' => {
@@ -350,40 +775,55 @@ impl<'a> Tokenizer<'a> {
                     match chars.peek() {
                         Some('\'') => {
                             // X'...' - a <binary string literal>
-                            let s = self.tokenize_single_quoted_string(chars);
+                            let s = self.tokenize_single_quoted_string(chars, start)?;
                             Ok(Some(Token::HexStringLiteral(s)))
                         }
                         _ => {
                             // regular identifier starting with an "X"
-                            let s = self.tokenize_word(x, chars);
-                            Ok(Some(Token::make_word(&s, None)))
+                            let s = self.tokenize_word(x, chars, start)?;
+                            Ok(Some(self.make_word(&s, None)))
                         }
                     }
                 }
                 // identifier or keyword
                 ch if self.dialect.is_identifier_start(ch) => {
                     chars.next(); // consume the first char
-                    let s = self.tokenize_word(ch, chars);
-                    Ok(Some(Token::make_word(&s, None)))
+                    let s = self.tokenize_word(ch, chars, start)?;
+                    Ok(Some(self.make_word(&s, None)))
                 }
                 // string
                 '\'' => {
-                    let s = self.tokenize_single_quoted_string(chars);
+                    let s = self.tokenize_single_quoted_string(chars, start)?;
                     Ok(Some(Token::SingleQuotedString(s)))
                 }
                 // delimited (quoted) identifier
                 quote_start if self.dialect.is_delimited_identifier_start(quote_start) => {
                     chars.next(); // consume the opening quote
                     let quote_end = Word::matching_end_quote(quote_start);
-                    let s = peeking_take_while(chars, |ch| ch != quote_end);
-                    if chars.next() == Some(quote_end) {
-                        Ok(Some(Token::make_word(&s, Some(quote_start))))
-                    } else {
-                        Err(TokenizerError(format!(
-                            "Expected close delimiter '{}' before EOF.",
-                            quote_end
-                        )))
+                    let mut s = String::new();
+                    loop {
+                        match chars.next() {
+                            // A doubled closing delimiter stands for itself
+                            Some(ch) if ch == quote_end && chars.peek() == Some(&quote_end) => {
+                                s.push(ch);
+                                chars.next();
+                            }
+                            Some(ch) if ch == quote_end => break,
+                            Some(ch) => s.push(ch),
+                            None => {
+                                return Err(start.error(
+                                    TokenizerErrorKind::UnterminatedString,
+                                    format!("Expected close delimiter '{}' before EOF.", quote_end),
+                                ))
+                            }
+                        }
+                        if too_long(s.len(), self.limits.max_identifier_length) {
+                            return Err(
+                                self.limit_error(TokenizerErrorKind::IdentifierTooLong, start)
+                            );
+                        }
                     }
+                    Ok(Some(self.make_word(&s, Some(quote_start))))
                 }
                 // numbers
                 '0'..='9' => {
@@ -428,12 +868,7 @@ impl<'a> Tokenizer<'a> {
                     match chars.peek() {
                         Some('-') => {
                             chars.next(); // consume the second '-', starting a single-line comment
-                            let mut s = peeking_take_while(chars, |ch| ch != '\n');
-                            if let Some(ch) = chars.next() {
-                                assert_eq!(ch, '\n');
-                                s.push(ch);
-                            }
-                            Ok(Some(Token::Whitespace(Whitespace::SingleLineComment(s))))
+                            self.tokenize_single_line_comment(chars)
                         }
                         Some('>') => {
                             chars.next(); // consume the '>'
@@ -451,7 +886,7 @@ impl<'a> Tokenizer<'a> {
                     match chars.peek() {
                         Some('*') => {
                             chars.next(); // consume the '*', starting a multi-line comment
-                            self.tokenize_multiline_comment(chars)
+                            self.tokenize_multiline_comment(chars, start)
                         }
                         // a regular '/' operator
                         _ => Ok(Some(Token::Div)),
@@ -460,6 +895,11 @@ impl<'a> Tokenizer<'a> {
                 '+' => self.consume_and_return(chars, Token::Plus),
                 '*' => self.consume_and_return(chars, Token::Mult),
                 '%' => self.consume_and_return(chars, Token::Mod),
+                '^' => self.consume_and_return(chars, Token::Caret),
+                '#' if self.dialect.supports_hash_comments() => {
+                    chars.next(); // consume the '#', starting a single-line comment
+                    self.tokenize_single_line_comment(chars)
+                }
                 '#' => {
                     chars.next(); // consume '#'
                     match chars.peek() {
@@ -473,10 +913,10 @@ impl<'a> Tokenizer<'a> {
                             }
                         }
                         Some('-') => self.consume_and_return(chars, Token::JsonDeletePath),
-                        _ => Err(TokenizerError(format!(
-                            "Tokenizer Error at Line: {}, Col: {}",
-                            self.line, self.col
-                        ))),
+                        _ => Err(start.error(
+                            TokenizerErrorKind::UnexpectedChar,
+                            "Unexpected character '#'",
+                        )),
                     }
                 }
                 '@' => {
@@ -485,10 +925,10 @@ impl<'a> Tokenizer<'a> {
                         Some('>') => self.consume_and_return(chars, Token::JsonContainsJson),
                         Some('?') => self.consume_and_return(chars, Token::JsonContainsPath),
                         Some('@') => self.consume_and_return(chars, Token::JsonApplyPathPredicate),
-                        _ => Err(TokenizerError(format!(
-                            "Tokenizer Error at Line: {}, Col: {}",
-                            self.line, self.col
-                        ))),
+                        _ => Err(start.error(
+                            TokenizerErrorKind::UnexpectedChar,
+                            "Unexpected character '@'",
+                        )),
                     }
                 }
                 '?' => {
@@ -503,10 +943,10 @@ impl<'a> Tokenizer<'a> {
                     chars.next(); // consume '|'
                     match chars.peek() {
                         Some('|') => self.consume_and_return(chars, Token::JsonConcat),
-                        _ => Err(TokenizerError(format!(
-                            "Tokenizer Error at Line: {}, Col: {}",
-                            self.line, self.col
-                        ))),
+                        _ => Err(start.error(
+                            TokenizerErrorKind::UnexpectedChar,
+                            "Unexpected character '|'",
+                        )),
                     }
                 }
                 '=' => self.consume_and_return(chars, Token::Eq),
@@ -515,10 +955,10 @@ impl<'a> Tokenizer<'a> {
                     chars.next(); // consume
                     match chars.peek() {
                         Some('=') => self.consume_and_return(chars, Token::Neq),
-                        _ => Err(TokenizerError(format!(
-                            "Tokenizer Error at Line: {}, Col: {}",
-                            self.line, self.col
-                        ))),
+                        _ => Err(start.error(
+                            TokenizerErrorKind::UnexpectedChar,
+                            "Unexpected character '!'",
+                        )),
                     }
                 }
                 '<' => {
@@ -551,6 +991,7 @@ impl<'a> Tokenizer<'a> {
                 '&' => self.consume_and_return(chars, Token::Ampersand),
                 '{' => self.consume_and_return(chars, Token::LBrace),
                 '}' => self.consume_and_return(chars, Token::RBrace),
+                '$' if chars.starts_dollar_quote() => self.tokenize_dollar_quoted_string(chars),
                 '$' => self.tokenize_parameter(chars),
                 other => self.consume_and_return(chars, Token::Char(other)),
             },
@@ -558,47 +999,105 @@ impl<'a> Tokenizer<'a> {
         }
     }
 
-    /// Tokenize an identifier or keyword, after the first char is already consumed.
-    fn tokenize_word(&self, first_char: char, chars: &mut Peekable<Chars<'_>>) -> String {
+    /// Tokenize an identifier or keyword, after the first char is already
+    /// consumed. `start` is the location of the first char.
+    fn tokenize_word(
+        &self,
+        first_char: char,
+        chars: &mut State,
+        start: Location,
+    ) -> Result<String, TokenizerError> {
+        // Keywords are exempt from the identifier limit, so a word is only
+        // known to be too long while it is read once it is longer than any
+        // keyword; `check_limits` catches the rest
+        let limit = self
+            .limits
+            .max_identifier_length
+            .map(|limit| limit.max(MAX_KEYWORD_LENGTH));
         let mut s = first_char.to_string();
-        s.push_str(&peeking_take_while(chars, |ch| {
-            self.dialect.is_identifier_part(ch)
-        }));
-        s
+        while let Some(&ch) = chars.peek() {
+            if !self.dialect.is_identifier_part(ch) {
+                break;
+            }
+            chars.next();
+            s.push(ch);
+            if too_long(s.len(), limit) {
+                return Err(self.limit_error(TokenizerErrorKind::IdentifierTooLong, start));
+            }
+        }
+        Ok(s)
     }
 
-    /// Read a single quoted string, starting with the opening quote.
-    fn tokenize_single_quoted_string(&self, chars: &mut Peekable<Chars<'_>>) -> String {
-        //TODO: handle escaped quotes in string
-        //TODO: handle newlines in string
-        //TODO: handle EOF before terminating quote
+    /// Read a single quoted string, starting with the opening quote. `start`
+    /// is the location of the token, which may begin with a prefix like `N`.
+    fn tokenize_single_quoted_string(
+        &self,
+        chars: &mut State,
+        start: Location,
+    ) -> Result<String, TokenizerError> {
         //TODO: handle 'string' <white space> 'string continuation'
+        let backslash_escapes = self.dialect.supports_backslash_escapes();
+        let s = self.tokenize_raw_single_quoted_string(chars, start, backslash_escapes)?;
+        if self.unescape {
+            Ok(unescape_string_literal(&s, self.dialect))
+        } else {
+            Ok(s)
+        }
+    }
+
+    /// Read a single-quoted string, returning the text between the quotes
+    /// as written
+    fn tokenize_raw_single_quoted_string(
+        &self,
+        chars: &mut State,
+        start: Location,
+        backslash_escapes: bool,
+    ) -> Result<String, TokenizerError> {
         let mut s = String::new();
         chars.next(); // consume the opening quote
-        while let Some(&ch) = chars.peek() {
+        while let Some(ch) = chars.next() {
             match ch {
-                '\'' => {
-                    chars.next(); // consume
-                    let escaped_quote = chars.peek().map(|c| *c == '\'').unwrap_or(false);
-                    if escaped_quote {
-                        s.push('\'');
-                        chars.next();
-                    } else {
-                        break;
-                    }
+                '\'' if chars.peek() == Some(&'\'') => {
+                    s.push(ch);
+                    s.push(ch);
+                    chars.next();
                 }
-                _ => {
-                    chars.next(); // consume
+                '\'' => return Ok(s),
+                '\\' if backslash_escapes => {
+                    // The escaped character can't end the string
                     s.push(ch);
+                    if let Some(ch) = chars.next() {
+                        s.push(ch);
+                    }
                 }
+                _ => s.push(ch),
+            }
+            if too_long(s.len(), self.limits.max_string_literal_length) {
+                return Err(self.limit_error(TokenizerErrorKind::StringLiteralTooLong, start));
             }
         }
-        s
+        Err(start.error(
+            TokenizerErrorKind::UnterminatedString,
+            "Unterminated string literal",
+        ))
+    }
+
+    fn tokenize_single_line_comment(
+        &self,
+        chars: &mut State,
+    ) -> Result<Option<Token>, TokenizerError> {
+        let mut s = peeking_take_while(chars, |ch| ch != '\n');
+        if let Some(ch) = chars.next() {
+            assert_eq!(ch, '\n');
+            s.push(ch);
+        }
+        Ok(Some(Token::Whitespace(Whitespace::SingleLineComment(s))))
     }
 
     fn tokenize_multiline_comment(
         &self,
-        chars: &mut Peekable<Chars<'_>>,
+        chars: &mut State,
+        start: Location,
     ) -> Result<Option<Token>, TokenizerError> {
         let mut s = String::new();
         let mut maybe_closing_comment = false;
@@ -619,21 +1118,48 @@ impl<'a> Tokenizer<'a> {
                     }
                 }
                 None => {
-                    break Err(TokenizerError(
-                        "Unexpected EOF while in a multi-line comment".to_string(),
+                    break Err(start.error(
+                        TokenizerErrorKind::UnterminatedComment,
+                        "Unexpected EOF while in a multi-line comment",
                     ));
                 }
             }
         }
     }
 
+    /// Read the rows of a COPY payload, following the `;` of the statement,
+    /// up to and including the `\.` line that ends them, or to EOF
+    fn tokenize_copy_payload(&self, chars: &mut State) -> Token {
+        let mut s = String::new();
+        // The rows start on the line after the `;`
+        let mut line_start = false;
+        loop {
+            if line_start
+                && chars.peek_nth(0) == Some('\\')
+                && chars.peek_nth(1) == Some('.')
+                && matches!(chars.peek_nth(2), None | Some('\n') | Some('\r'))
+            {
+                s.push_str("\\.");
+                chars.next();
+                chars.next();
+                break;
+            }
+            match chars.next() {
+                Some(ch) => {
+                    s.push(ch);
+                    line_start = ch == '\n' || ch == '\r';
+                }
+                None => break,
+            }
+        }
+        Token::CopyPayload(s)
+    }
+
     /// PostgreSQL supports positional parameters (like $1, $2, etc.) for
     /// prepared statements and function definitions.
     /// Grab the positional argument following a $ to parse it.
-    fn tokenize_parameter(
-        &self,
-        chars: &mut Peekable<Chars<'_>>,
-    ) -> Result<Option<Token>, TokenizerError> {
+    fn tokenize_parameter(&self, chars: &mut State) -> Result<Option<Token>, TokenizerError> {
+        let start = chars.location;
         assert_eq!(Some('$'), chars.next());
 
         let n = peeking_take_while(chars, |ch| match ch {
@@ -642,19 +1168,57 @@ impl<'a> Tokenizer<'a> {
         });
 
         if n.is_empty() {
-            return Err(TokenizerError(
-                "parameter marker ($) was not followed by \
-                 at least one digit"
-                    .into(),
+            return Err(start.error(
+                TokenizerErrorKind::UnexpectedChar,
+                "parameter marker ($) was not followed by at least one digit",
             ));
         }
 
         Ok(Some(Token::Parameter(n)))
     }
 
+    /// Read a dollar-quoted string, like `$$text$$` or `$tag$text$tag$`,
+    /// assuming [State::starts_dollar_quote]
+    fn tokenize_dollar_quoted_string(
+        &self,
+        chars: &mut State,
+    ) -> Result<Option<Token>, TokenizerError> {
+        let start = chars.location;
+        assert_eq!(Some('$'), chars.next());
+
+        let tag = peeking_take_while(chars, |ch| ch.is_alphanumeric() || ch == '_');
+        assert_eq!(Some('$'), chars.next());
+
+        let delimiter = format!("${}$", tag);
+        // Unless it ends the string, `value` may end with all but the last
+        // char of the closing delimiter
+        let limit = self
+            .limits
+            .max_string_literal_length
+            .map(|limit| limit + delimiter.len() - 1);
+        let mut value = String::new();
+        while let Some(ch) = chars.next() {
+            value.push(ch);
+            if ch == '$' && value.ends_with(&delimiter) {
+                value.truncate(value.len() - delimiter.len());
+                return Ok(Some(Token::DollarQuotedString(DollarQuotedString {
+                    value,
+                    tag: if tag.is_empty() { None } else { Some(tag) },
+                })));
+            }
+            if too_long(value.len(), limit) {
+                return Err(self.limit_error(TokenizerErrorKind::StringLiteralTooLong, start));
+            }
+        }
+        Err(start.error(
+            TokenizerErrorKind::UnterminatedString,
+            "Unterminated dollar-quoted string",
+        ))
+    }
+
     fn consume_and_return(
         &self,
-        chars: &mut Peekable<Chars<'_>>,
+        chars: &mut State,
         t: Token,
     ) -> Result<Option<Token>, TokenizerError> {
         chars.next();
@@ -662,13 +1226,28 @@ impl<'a> Tokenizer<'a> {
     }
 }
 
+/// The length of the longest keyword
+const MAX_KEYWORD_LENGTH: usize = {
+    let mut max = 0;
+    let mut i = 0;
+    while i < ALL_KEYWORDS.len() {
+        if ALL_KEYWORDS[i].len() > max {
+            max = ALL_KEYWORDS[i].len();
+        }
+        i += 1;
+    }
+    max
+};
+
+/// Whether `length` exceeds `limit`, if there is one
+fn too_long(length: usize, limit: Option<usize>) -> bool {
+    matches!(limit, Some(limit) if length > limit)
+}
+
 /// Read from `chars` until `predicate` returns `false` or EOF is hit.
 /// Return the characters read as String, and keep the first non-matching
 /// char available as `chars.next()`.
-fn peeking_take_while(
-    chars: &mut Peekable<Chars<'_>>,
-    mut predicate: impl FnMut(char) -> bool,
-) -> String {
+fn peeking_take_while(chars: &mut State, mut predicate: impl FnMut(char) -> bool) -> String {
     let mut s = String::new();
     while let Some(&ch) = chars.peek() {
         if predicate(ch) {
@@ -683,7 +1262,7 @@ fn peeking_take_while(
 
 #[cfg(test)]
 mod tests {
-    use super::super::dialect::GenericDialect;
+    use super::super::dialect::{GenericDialect, MySqlDialect};
     use super::*;
 
     #[test]
@@ -878,6 +1457,24 @@ mod tests {
         compare(expected, tokens);
     }
 
+    #[test]
+    fn tokenize_hash_comment() {
+        let sql = String::from("0 #this is a comment\n1");
+
+        let dialect = MySqlDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, &sql);
+        let tokens = tokenizer.tokenize().unwrap();
+        let expected = vec![
+            Token::Number("0".to_string()),
+            Token::Whitespace(Whitespace::Space),
+            Token::Whitespace(Whitespace::SingleLineComment(
+                "this is a comment\n".to_string(),
+            )),
+            Token::Number("1".to_string()),
+        ];
+        compare(expected, tokens);
+    }
+
     #[test]
     fn tokenize_multiline_comment() {
         let sql = String::from("0/*multi-line\n* /comment*/1");
@@ -918,9 +1515,105 @@ mod tests {
         let mut tokenizer = Tokenizer::new(&dialect, &sql);
         assert_eq!(
             tokenizer.tokenize(),
-            Err(TokenizerError(
-                "Expected close delimiter '\"' before EOF.".to_string(),
-            ))
+            Err(TokenizerError {
+                kind: TokenizerErrorKind::UnterminatedString,
+                message: "Expected close delimiter '\"' before EOF.".to_string(),
+                line: 1,
+                col: 1,
+                offset: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn tokenize_with_limits() {
+        let dialect = GenericDialect {};
+        let limits = TokenizerLimits {
+            max_identifier_length: Some(5),
+            max_string_literal_length: Some(3),
+            max_statement_length: Some(25),
+        };
+        let tokenize = |sql: &str| Tokenizer::new(&dialect, sql).with_limits(limits).tokenize();
+
+        assert!(tokenize("SELECT 'abc', \"abcde\"; SELECT 1").is_ok());
+
+        let e = tokenize("SELECT abcdef").unwrap_err();
+        assert_eq!(TokenizerErrorKind::IdentifierTooLong, e.kind);
+        assert_eq!(7, e.offset);
+        assert_eq!(
+            "Identifier is longer than 5 bytes at line 1, column 8",
+            e.to_string()
+        );
+
+        let e = tokenize("SELECT N'abcd'").unwrap_err();
+        assert_eq!(TokenizerErrorKind::StringLiteralTooLong, e.kind);
+        assert_eq!(7, e.offset);
+
+        let e = tokenize("SELECT 1; SELECT 1 + 2 + 3 + 4 + 5 + 6").unwrap_err();
+        assert_eq!(TokenizerErrorKind::StatementTooLong, e.kind);
+        assert_eq!(34, e.offset);
+
+        // Escapes count as written
+        let e = tokenize("SELECT 'a''b'").unwrap_err();
+        assert_eq!(TokenizerErrorKind::StringLiteralTooLong, e.kind);
+    }
+
+    #[test]
+    fn tokenize_with_limits_stops_partway() {
+        let dialect = GenericDialect {};
+        let limits = TokenizerLimits {
+            max_identifier_length: Some(5),
+            max_string_literal_length: Some(3),
+            max_statement_length: None,
+        };
+        let tokenize = |sql: &str| Tokenizer::new(&dialect, sql).with_limits(limits).tokenize();
+
+        // Unterminated tokens that are already too long report the limit,
+        // not the missing end, as they are not read in full
+        for (sql, kind) in &[
+            ("SELECT 'abcd", TokenizerErrorKind::StringLiteralTooLong),
+            ("SELECT X'abcd", TokenizerErrorKind::StringLiteralTooLong),
+            ("SELECT $t$abcdef", TokenizerErrorKind::StringLiteralTooLong),
+            ("SELECT \"abcdef", TokenizerErrorKind::IdentifierTooLong),
+        ] {
+            let e = tokenize(sql).unwrap_err();
+            assert_eq!(*kind, e.kind, "{}", sql);
+            assert_eq!(7, e.offset, "{}", sql);
+        }
+        let e = tokenize("SELECT 'abc").unwrap_err();
+        assert_eq!(TokenizerErrorKind::UnterminatedString, e.kind);
+        assert!(tokenize("SELECT $t$abc$t$").is_ok());
+
+        let long_word = "a".repeat(MAX_KEYWORD_LENGTH + 1);
+        let e = tokenize(&format!("SELECT {}", long_word)).unwrap_err();
+        assert_eq!(TokenizerErrorKind::IdentifierTooLong, e.kind);
+        assert_eq!(7, e.offset);
+        assert!(tokenize("SELECT CURRENT_TIMESTAMP").is_ok());
+    }
+
+    #[test]
+    fn tokenize_error_locations() {
+        let dialect = GenericDialect {};
+        let error = |sql: &str| Tokenizer::new(&dialect, sql).tokenize().unwrap_err();
+
+        let e = error("SELECT 'ñ', N'abc");
+        assert_eq!(TokenizerErrorKind::UnterminatedString, e.kind);
+        assert_eq!((1, 13, 13), (e.line, e.col, e.offset));
+        assert_eq!(
+            "Unterminated string literal at line 1, column 13",
+            e.to_string()
+        );
+
+        let e = error("SELECT 1\r\n\tFROM t /* comment");
+        assert_eq!(TokenizerErrorKind::UnterminatedComment, e.kind);
+        assert_eq!((2, 9, 18), (e.line, e.col, e.offset));
+
+        let e = error("SELECT a\n  !b");
+        assert_eq!(TokenizerErrorKind::UnexpectedChar, e.kind);
+        assert_eq!((2, 3, 11), (e.line, e.col, e.offset));
+        assert_eq!(
+            "Unexpected character '!' at line 2, column 3",
+            e.to_string()
         );
     }
 
@@ -944,6 +1637,157 @@ mod tests {
         compare(expected, tokens);
     }
 
+    #[test]
+    fn tokenize_quoted_keyword() {
+        let sql = String::from(r#"select "select" "Select""#);
+        let dialect = GenericDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, &sql);
+        let tokens = tokenizer.tokenize().unwrap();
+
+        let keywords: Vec<_> = tokens
+            .iter()
+            .filter_map(|t| match t {
+                Token::Word(w) => Some((w.value.as_str(), w.keyword)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            keywords,
+            vec![
+                ("select", Keyword::SELECT),
+                ("select", Keyword::NoKeyword),
+                ("Select", Keyword::NoKeyword),
+            ]
+        );
+    }
+
+    #[test]
+    fn tokenize_dialect_specific_keyword() {
+        let sql = String::from("select qualify format");
+        let words = |dialect: &dyn Dialect| -> Vec<Keyword> {
+            Tokenizer::new(dialect, &sql)
+                .tokenize()
+                .unwrap()
+                .into_iter()
+                .filter_map(|t| match t {
+                    Token::Word(w) => Some(w.keyword),
+                    _ => None,
+                })
+                .collect()
+        };
+        assert_eq!(
+            words(&GenericDialect {}),
+            vec![Keyword::SELECT, Keyword::QUALIFY, Keyword::FORMAT]
+        );
+        assert_eq!(
+            words(&MySqlDialect {}),
+            vec![Keyword::SELECT, Keyword::NoKeyword, Keyword::NoKeyword]
+        );
+    }
+
+    #[test]
+    fn tokenize_string_escapes() {
+        let sql = r"'it''s' 'a\'b\\c'";
+        let strings = |mut tokenizer: Tokenizer| -> Vec<String> {
+            tokenizer
+                .tokenize()
+                .unwrap()
+                .into_iter()
+                .filter_map(|t| match t {
+                    Token::SingleQuotedString(s) => Some(s),
+                    _ => None,
+                })
+                .collect()
+        };
+        // Backslashes stand for themselves, so the second string is `'a\'`
+        // followed by a bare `b\\c'`
+        assert!(Tokenizer::new(&GenericDialect {}, sql).tokenize().is_err());
+        assert_eq!(
+            strings(Tokenizer::new(&MySqlDialect {}, sql)),
+            vec!["it's", r"a'b\c"]
+        );
+        assert_eq!(
+            strings(Tokenizer::new(&MySqlDialect {}, sql).with_unescape(false)),
+            vec!["it''s", r"a\'b\\c"]
+        );
+    }
+
+    #[test]
+    fn tokenize_unicode_string_literal() {
+        let sql = r"U&'a\0062' u &'c'";
+        let dialect = GenericDialect {};
+        let tokens = Tokenizer::new(&dialect, sql).tokenize().unwrap();
+        let expected = vec![
+            Token::UnicodeStringLiteral(r"a\0062".to_string()),
+            Token::Whitespace(Whitespace::Space),
+            Token::make_word("u", None),
+            Token::Whitespace(Whitespace::Space),
+            Token::Ampersand,
+            Token::SingleQuotedString("c".to_string()),
+        ];
+        compare(expected, tokens);
+    }
+
+    #[test]
+    fn tokenize_dollar_quoted_string() {
+        let sql = "$$it's$$ $fn$a $$ $b$ c$fn$$1";
+        let dialect = GenericDialect {};
+        let tokens = Tokenizer::new(&dialect, sql).tokenize().unwrap();
+        let expected = vec![
+            Token::DollarQuotedString(DollarQuotedString {
+                value: "it's".to_string(),
+                tag: None,
+            }),
+            Token::Whitespace(Whitespace::Space),
+            Token::DollarQuotedString(DollarQuotedString {
+                value: "a $$ $b$ c".to_string(),
+                tag: Some("fn".to_string()),
+            }),
+            Token::Parameter("1".to_string()),
+        ];
+        compare(expected, tokens);
+
+        for sql in &["$fn$a$f", "$fn a$fn$"] {
+            assert!(Tokenizer::new(&dialect, sql).tokenize().is_err(), "{}", sql);
+        }
+    }
+
+    #[test]
+    fn tokenize_copy_payload() {
+        let sql = "COPY t FROM stdin;\nit's /*\n\\.\nSELECT";
+        let dialect = GenericDialect {};
+        let tokens = Tokenizer::new(&dialect, sql).tokenize().unwrap();
+        let expected = vec![
+            Token::make_keyword("COPY"),
+            Token::Whitespace(Whitespace::Space),
+            Token::make_word("t", None),
+            Token::Whitespace(Whitespace::Space),
+            Token::make_keyword("FROM"),
+            Token::Whitespace(Whitespace::Space),
+            Token::make_keyword("stdin"),
+            Token::SemiColon,
+            Token::CopyPayload("\nit's /*\n\\.".to_string()),
+            Token::Whitespace(Whitespace::Newline),
+            Token::make_keyword("SELECT"),
+        ];
+        compare(expected, tokens);
+
+        // Without a payload, the statement's `;` is followed by SQL
+        for sql in &[
+            "COPY (SELECT * FROM stdin) TO STDOUT; 'x'",
+            "COPY t FROM stdin WITH (FORMAT binary); 'x'",
+            "SELECT 1 FROM stdin; 'x'",
+        ] {
+            let tokens = Tokenizer::new(&dialect, sql).tokenize().unwrap();
+            assert_eq!(
+                Some(&Token::SingleQuotedString("x".to_string())),
+                tokens.last(),
+                "{}",
+                sql
+            );
+        }
+    }
+
     fn compare(expected: Vec<Token>, actual: Vec<Token>) {
         //println!("------------------------------");
         //println!("tokens   = {:?}", actual);
@@ -0,0 +1,84 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![warn(clippy::all)]
+
+//! Checks that the statements in the corpora under `tests/roundtrip` are
+//! displayed as SQL that parses back to the same AST in their dialect.
+
+use sqlparser::dialect::*;
+use sqlparser::test_utils::*;
+
+fn dialect(dialect: impl Dialect + 'static) -> TestedDialects {
+    TestedDialects {
+        dialects: vec![Box::new(dialect)],
+    }
+}
+
+#[test]
+fn round_trip_common() {
+    // BigQuery only quotes identifiers with backquotes
+    let mut tested = all_dialects();
+    tested.dialects.extend(vec![
+        Box::new(MySqlDialect {}) as Box<dyn Dialect>,
+        Box::new(SnowflakeDialect {}),
+        Box::new(SqliteDialect {}),
+        Box::new(ClickHouseDialect {}),
+        Box::new(RedshiftSqlDialect {}),
+    ]);
+    tested.verified_corpus(include_str!("roundtrip/common.sql"));
+}
+
+#[test]
+fn round_trip_postgres() {
+    dialect(PostgreSqlDialect {}).verified_corpus(include_str!("roundtrip/postgres.sql"));
+}
+
+#[test]
+fn round_trip_mysql() {
+    dialect(MySqlDialect {}).verified_corpus(include_str!("roundtrip/mysql.sql"));
+}
+
+#[test]
+fn round_trip_mssql() {
+    dialect(MsSqlDialect {}).verified_corpus(include_str!("roundtrip/mssql.sql"));
+}
+
+#[test]
+fn round_trip_bigquery() {
+    dialect(BigQueryDialect {}).verified_corpus(include_str!("roundtrip/bigquery.sql"));
+}
+
+#[test]
+fn round_trip_snowflake() {
+    dialect(SnowflakeDialect {}).verified_corpus(include_str!("roundtrip/snowflake.sql"));
+}
+
+#[test]
+fn round_trip_sqlite() {
+    dialect(SqliteDialect {}).verified_corpus(include_str!("roundtrip/sqlite.sql"));
+}
+
+#[test]
+fn round_trip_clickhouse() {
+    dialect(ClickHouseDialect {}).verified_corpus(include_str!("roundtrip/clickhouse.sql"));
+}
+
+#[test]
+fn round_trip_redshift() {
+    dialect(RedshiftSqlDialect {}).verified_corpus(include_str!("roundtrip/redshift.sql"));
+}
+
+#[test]
+fn round_trip_hive() {
+    dialect(HiveDialect {}).verified_corpus(include_str!("roundtrip/hive.sql"));
+}
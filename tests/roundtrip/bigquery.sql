@@ -0,0 +1,58 @@
+-- BigQuery statements, with backquoted project-qualified names
+
+SELECT * FROM `my-project.dataset.table`;
+SELECT * FROM `my-project`.`dataset`.`table`;
+SELECT `my-project`.dataset.`table`.a FROM `t`;
+SELECT `sıze` FROM `ﬁle`;
+SELECT * EXCEPT (a, b) REPLACE (c + 1 AS c, 'x' AS d) FROM t;
+SELECT * EXCEPT (a) FROM t;
+SELECT * REPLACE (1 AS a) FROM t;
+SELECT t.* EXCEPT (a), u.b FROM t, u;
+SELECT * EXCEPT (SELECT 1);
+SELECT user_id, COUNT(*) AS events FROM `analytics.events_2021` WHERE event_name = 'purchase' GROUP BY user_id ORDER BY events DESC LIMIT 100;
+SELECT * FROM `proj.ds.t` AS t JOIN `proj.ds.u` AS u ON t.id = u.id;
+SELECT 1 AS x UNION ALL SELECT 2;
+
+-- Analytics over public datasets
+SELECT name, sum(number) AS total FROM `bigquery-public-data.usa_names.usa_1910_2013` GROUP BY name ORDER BY total DESC LIMIT 10;
+SELECT corpus, count(*) AS words FROM `bigquery-public-data.samples.shakespeare` GROUP BY corpus ORDER BY words DESC;
+SELECT word, word_count FROM `bigquery-public-data`.samples.shakespeare WHERE corpus = 'hamlet' AND word_count > 100;
+SELECT title, score FROM `bigquery-public-data.hacker_news.stories` WHERE score > 500 ORDER BY score DESC LIMIT 20;
+SELECT EXTRACT(YEAR FROM date) AS year, count(*) FROM `proj.ds.events` GROUP BY year ORDER BY year;
+SELECT DATE_TRUNC(created_at, MONTH) AS month, count(*) FROM `proj.ds.users` GROUP BY month;
+SELECT DATE_DIFF(end_date, start_date, DAY) FROM `proj.ds.projects`;
+SELECT TIMESTAMP_SECONDS(ts), FORMAT_TIMESTAMP('%Y-%m-%d', ts) FROM `proj.ds.log`;
+SELECT PARSE_DATE('%Y%m%d', event_date) FROM `proj.analytics_123.events_20210101`;
+SELECT user_pseudo_id, count(*) FROM `proj.analytics_123.events_*` WHERE _TABLE_SUFFIX BETWEEN '20210101' AND '20210131' GROUP BY 1;
+SELECT CAST(x AS STRING), CAST(y AS FLOAT64), CAST(z AS NUMERIC) FROM t;
+SELECT IF(a > 0, 'pos', 'neg'), IFNULL(b, 0), COALESCE(c, d) FROM t;
+SELECT CASE WHEN country = 'US' THEN 'domestic' ELSE 'international' END AS market, count(*) FROM `proj.ds.orders` GROUP BY market;
+SELECT STRING_AGG(name, ', ') FROM t;
+SELECT APPROX_COUNT_DISTINCT(user_id) FROM `proj.ds.events`;
+SELECT SPLIT(tags, ',') FROM posts;
+SELECT * FROM t, UNNEST(t.items) AS item;
+SELECT item.sku FROM `proj.ds.orders` AS o CROSS JOIN UNNEST(o.items) AS item;
+SELECT user_id, ROW_NUMBER() OVER (PARTITION BY user_id ORDER BY ts DESC) AS rn FROM `proj.ds.events`;
+SELECT user_id, SUM(amount) OVER (PARTITION BY user_id ORDER BY ts ROWS BETWEEN 6 PRECEDING AND CURRENT ROW) FROM `proj.ds.payments`;
+SELECT * FROM `proj.ds.events` QUALIFY ROW_NUMBER() OVER (PARTITION BY id) = 1;
+SELECT a.id, b.name FROM `proj.ds.a` AS a LEFT JOIN `proj.ds.b` AS b USING (id);
+SELECT * FROM `proj.ds.a` AS a INNER JOIN `proj.ds.b` AS b ON a.id = b.a_id AND b.active;
+SELECT * FROM `proj.ds.t` WHERE id IN (SELECT id FROM `proj.ds.u`);
+SELECT * FROM `proj.ds.t` WHERE NOT EXISTS (SELECT 1 FROM `proj.ds.u` WHERE u.id = t.id);
+SELECT DISTINCT country FROM `proj.ds.customers`;
+SELECT country, count(*) AS n FROM `proj.ds.customers` GROUP BY country HAVING n > 10 ORDER BY n DESC;
+SELECT LOWER(email), UPPER(name), LENGTH(name), SUBSTR(name, 1, 3) FROM `proj.ds.users`;
+SELECT CONCAT(first_name, ' ', last_name) AS full_name FROM `proj.ds.users`;
+SELECT CURRENT_TIMESTAMP(), CURRENT_DATE(), GENERATE_UUID();
+WITH daily AS (SELECT DATE(ts) AS d, count(*) AS n FROM `proj.ds.events` GROUP BY d) SELECT AVG(n) FROM daily;
+WITH a AS (SELECT 1 AS x), b AS (SELECT 2 AS x) SELECT x FROM a UNION ALL SELECT x FROM b;
+SELECT x FROM a UNION DISTINCT SELECT x FROM b;
+SELECT x FROM a EXCEPT DISTINCT SELECT x FROM b;
+CREATE TABLE `proj.ds.new_table` (id INT64, name STRING, created_at TIMESTAMP);
+CREATE VIEW `proj.ds.v` AS SELECT * FROM `proj.ds.t` WHERE a > 0;
+DROP TABLE IF EXISTS `proj.ds.old_table`;
+DROP VIEW `proj.ds.v`;
+INSERT INTO `proj.ds.t` (id, name) VALUES (1, 'a'), (2, 'b');
+INSERT INTO ds.t SELECT * FROM ds.staging;
+UPDATE `proj.ds.t` SET name = 'c' WHERE id = 2;
+DELETE FROM `proj.ds.t` WHERE id = 1;
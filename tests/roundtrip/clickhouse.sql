@@ -0,0 +1,63 @@
+-- ClickHouse statements, with table engines and output formats
+
+SELECT a, count(*) FROM t GROUP BY a LIMIT 10 FORMAT JSONEachRow;
+SELECT 1 FORMAT TabSeparated;
+CREATE TABLE hits (ts timestamp, url text) ENGINE = MergeTree() ORDER BY (ts, url);
+CREATE TABLE t (a int) ENGINE = Memory;
+CREATE TABLE t (a int, d date) ENGINE = ReplacingMergeTree(d) ORDER BY a;
+CREATE TABLE t (a int) ENGINE = MergeTree() ORDER BY (a);
+SELECT `a b` FROM `t`;
+SELECT url, count(*) AS views FROM hits WHERE ts >= '2021-01-01' GROUP BY url ORDER BY views DESC LIMIT 10;
+
+-- Tables for event analytics
+CREATE TABLE events (event_date date, event_time timestamp, user_id bigint, event_type text, url text) ENGINE = MergeTree() ORDER BY (event_date, user_id);
+CREATE TABLE visits (user_id bigint, visits int, updated_at timestamp) ENGINE = ReplacingMergeTree(updated_at) ORDER BY user_id;
+CREATE TABLE counters (day date, hits bigint) ENGINE = SummingMergeTree() ORDER BY day;
+CREATE TABLE log (ts timestamp, message text) ENGINE = Log;
+CREATE TABLE buffer_t (a int) ENGINE = TinyLog;
+CREATE TABLE t_copy (a int, b text) ENGINE = MergeTree() ORDER BY tuple();
+CREATE VIEW daily_events AS SELECT event_date, count(*) AS c FROM events GROUP BY event_date;
+CREATE MATERIALIZED VIEW hourly AS SELECT toStartOfHour(event_time) AS hour, count(*) AS c FROM events GROUP BY hour;
+DROP TABLE IF EXISTS log;
+DROP VIEW daily_events;
+INSERT INTO events (event_date, user_id, event_type) VALUES ('2021-01-01', 1, 'click'), ('2021-01-01', 2, 'view');
+INSERT INTO counters SELECT event_date, count(*) FROM events GROUP BY event_date;
+
+-- Queries
+SELECT event_type, count() AS c FROM events GROUP BY event_type ORDER BY c DESC;
+SELECT uniq(user_id) FROM events WHERE event_date = today();
+SELECT uniqExact(user_id), uniqHLL12(user_id) FROM events;
+SELECT toDate(event_time) AS d, count(*) FROM events WHERE event_time >= now() - 86400 GROUP BY d ORDER BY d;
+SELECT toStartOfMonth(event_date) AS month, sum(hits) FROM counters GROUP BY month ORDER BY month;
+SELECT toYYYYMM(event_date) AS ym, count(*) FROM events GROUP BY ym;
+SELECT domain(url) AS d, count(*) FROM events GROUP BY d ORDER BY count(*) DESC LIMIT 10;
+SELECT user_id, groupArray(event_type) FROM events GROUP BY user_id;
+SELECT user_id, argMax(event_type, event_time) AS last_event FROM events GROUP BY user_id;
+SELECT avg(duration), max(duration), min(duration) FROM sessions WHERE user_id != 0;
+SELECT countIf(event_type = 'click') AS clicks, countIf(event_type = 'view') AS views FROM events;
+SELECT sumIf(duration, duration > 60) FROM sessions;
+SELECT if(duration > 60, 'long', 'short') AS kind, count(*) FROM sessions GROUP BY kind;
+SELECT multiIf(duration < 10, 'bounce', duration < 60, 'short', 'long') FROM sessions;
+SELECT CASE WHEN duration > 3600 THEN 'hour+' ELSE 'less' END FROM sessions;
+SELECT * FROM events WHERE event_type IN ('click', 'view') AND user_id > 100 LIMIT 100;
+SELECT * FROM events AS e INNER JOIN sessions AS s ON e.user_id = s.user_id WHERE s.duration > 10;
+SELECT * FROM events AS e LEFT JOIN sessions AS s ON e.user_id = s.user_id;
+SELECT e.user_id, count(*) FROM events AS e WHERE e.user_id IN (SELECT user_id FROM sessions WHERE duration > 600) GROUP BY e.user_id;
+SELECT user_id, count(*) AS c FROM events GROUP BY user_id HAVING c > 10 ORDER BY c DESC LIMIT 5;
+SELECT user_id, event_time, row_number() OVER (PARTITION BY user_id ORDER BY event_time) AS n FROM events;
+SELECT lower(event_type), length(url), substring(url, 1, 10) FROM events;
+SELECT concat(event_type, ':', url) FROM events;
+SELECT CAST(user_id AS text), CAST('1' AS int) FROM events;
+SELECT toUInt32(user_id), toString(event_date) FROM events;
+SELECT tuple(1, 'a');
+SELECT now(), today(), yesterday();
+SELECT version();
+SELECT count(*) FROM system.tables WHERE database = 'default';
+SELECT name, type FROM system.columns WHERE table = 'events' FORMAT Pretty;
+SELECT * FROM events LIMIT 10 FORMAT CSVWithNames;
+SELECT * FROM events ORDER BY event_time DESC LIMIT 10 FORMAT JSON;
+SELECT DISTINCT event_type FROM events;
+SELECT a FROM t1 UNION ALL SELECT a FROM t2;
+WITH recent AS (SELECT * FROM events WHERE event_date >= today() - 7) SELECT count(*) FROM recent;
+SHOW TABLES;
+SHOW DATABASES;
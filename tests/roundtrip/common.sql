@@ -0,0 +1,207 @@
+-- Statements that every dialect accepts, mostly as written by applications
+-- and reporting tools rather than in canonical form
+
+-- Simple queries
+SELECT 1;
+SELECT 1 + 2 * 3 - 4 / 5 % 6;
+select * from customers;
+SELECT id, name FROM customers WHERE id = 42;
+SELECT c.id, c.name AS customer_name FROM customers c;
+SELECT DISTINCT country FROM customers ORDER BY country;
+SELECT count(*) FROM orders;
+SELECT count(DISTINCT customer_id) AS buyers FROM orders;
+SELECT sum(amount), avg(amount), min(amount), max(amount) FROM payments;
+SELECT * FROM orders WHERE status = 'shipped' AND total > 100.50;
+SELECT * FROM orders WHERE status <> 'cancelled' OR total >= 1000;
+SELECT * FROM orders WHERE NOT (status = 'open');
+SELECT * FROM orders WHERE shipped_at IS NULL;
+SELECT * FROM orders WHERE shipped_at IS NOT NULL;
+SELECT * FROM products WHERE price BETWEEN 10 AND 20;
+SELECT * FROM products WHERE price NOT BETWEEN 10 AND 20;
+SELECT * FROM products WHERE category IN ('books', 'music', 'film');
+SELECT * FROM products WHERE category NOT IN ('books');
+SELECT * FROM products WHERE name LIKE 'Widget%';
+SELECT * FROM products WHERE name NOT LIKE '%test%';
+SELECT * FROM products WHERE name LIKE 'a!%b' ESCAPE '!';
+SELECT * FROM products WHERE id IN (SELECT product_id FROM order_items);
+SELECT * FROM products WHERE id NOT IN (SELECT product_id FROM discontinued);
+SELECT * FROM customers WHERE EXISTS (SELECT 1 FROM orders WHERE orders.customer_id = customers.id);
+SELECT * FROM customers WHERE NOT EXISTS (SELECT 1 FROM orders o WHERE o.customer_id = customers.id);
+SELECT (SELECT max(total) FROM orders) AS largest;
+SELECT name FROM customers ORDER BY name ASC;
+SELECT name FROM customers ORDER BY name DESC, id;
+SELECT name FROM customers ORDER BY 1;
+SELECT a, b FROM t ORDER BY a DESC, b ASC;
+SELECT -1, +1, - -1;
+SELECT 'it''s', '';
+SELECT 1.5, 0.5, 1e10, 1.5E-3;
+SELECT NULL, TRUE, FALSE;
+SELECT a || b FROM t;
+SELECT (a + b) * c FROM t;
+SELECT ((a)) FROM t;
+SELECT a FROM t WHERE (a = 1 OR b = 2) AND c = 3;
+SELECT a FROM t WHERE a = 1 OR b = 2 AND c = 3;
+SELECT a FROM t WHERE NOT a = 1 AND b = 2;
+SELECT lower(name), upper(name), length(name) FROM t;
+SELECT coalesce(a, b, 0) FROM t;
+SELECT nullif(a, 0) FROM t;
+SELECT abs(-1), round(1.5), floor(1.5), ceil(1.5);
+SELECT substring(name, 1, 3) FROM t;
+
+-- Aggregation
+SELECT country, count(*) FROM customers GROUP BY country;
+SELECT country, count(*) AS n FROM customers GROUP BY country HAVING count(*) > 10;
+SELECT country, city, sum(amount) FROM sales GROUP BY country, city ORDER BY 3 DESC;
+SELECT customer_id, sum(total) FROM orders WHERE placed_at >= '2020-01-01' GROUP BY customer_id HAVING sum(total) > 1000 ORDER BY 2 DESC;
+
+-- Joins
+SELECT * FROM a JOIN b ON a.id = b.a_id;
+SELECT * FROM a INNER JOIN b ON a.id = b.a_id;
+SELECT * FROM a LEFT JOIN b ON a.id = b.a_id;
+SELECT * FROM a LEFT OUTER JOIN b ON a.id = b.a_id;
+SELECT * FROM a RIGHT JOIN b ON a.id = b.a_id;
+SELECT * FROM a RIGHT OUTER JOIN b ON a.id = b.a_id;
+SELECT * FROM a FULL JOIN b ON a.id = b.a_id;
+SELECT * FROM a FULL OUTER JOIN b ON a.id = b.a_id;
+SELECT * FROM a CROSS JOIN b;
+SELECT * FROM a, b WHERE a.id = b.a_id;
+SELECT * FROM a JOIN b USING (id);
+SELECT * FROM a JOIN b USING ("Id", "order");
+SELECT * FROM a JOIN b ON (SELECT max(id) FROM c) = b.id JOIN d USING (id);
+SELECT * FROM a JOIN b ON ((SELECT 1) UNION (SELECT 2)) = b.id;
+SELECT * FROM a JOIN b USING (id, version);
+SELECT * FROM a NATURAL JOIN b;
+SELECT * FROM a NATURAL LEFT JOIN b;
+SELECT * FROM a JOIN b ON a.id = b.a_id JOIN c ON b.id = c.b_id;
+SELECT * FROM a LEFT JOIN (b JOIN c ON b.id = c.b_id) ON a.id = b.a_id;
+SELECT * FROM (a JOIN b ON a.id = b.a_id);
+SELECT o.id, c.name, p.title FROM orders o JOIN customers c ON c.id = o.customer_id LEFT JOIN products p ON p.id = o.product_id WHERE c.country = 'NZ';
+SELECT * FROM a JOIN b ON a.x = b.x AND a.y = b.y;
+SELECT * FROM t1 AS x JOIN t2 AS y ON x.id = y.id;
+
+-- Derived tables and common table expressions
+SELECT * FROM (SELECT 1) AS t;
+SELECT * FROM (SELECT a, b FROM t) AS sub (x, y);
+SELECT t.total FROM (SELECT sum(amount) AS total FROM payments) AS t;
+WITH recent AS (SELECT * FROM orders WHERE placed_at > '2021-01-01') SELECT count(*) FROM recent;
+WITH a AS (SELECT 1 AS x), b AS (SELECT x + 1 AS y FROM a) SELECT * FROM a, b;
+WITH totals (customer_id, total) AS (SELECT customer_id, sum(amount) FROM payments GROUP BY customer_id) SELECT * FROM totals WHERE total > 100;
+WITH RECURSIVE subordinates AS (SELECT id, manager_id FROM employees WHERE id = 1 UNION ALL SELECT e.id, e.manager_id FROM employees AS e JOIN subordinates AS s ON e.manager_id = s.id) SELECT * FROM subordinates;
+
+-- Set operations
+SELECT a FROM t UNION SELECT a FROM u;
+SELECT a FROM t UNION ALL SELECT a FROM u;
+SELECT a FROM t EXCEPT SELECT a FROM u;
+SELECT a FROM t INTERSECT SELECT a FROM u;
+SELECT a FROM t UNION SELECT a FROM u UNION ALL SELECT a FROM v;
+(SELECT a FROM t) UNION (SELECT a FROM u);
+SELECT a FROM t UNION (SELECT a FROM u UNION SELECT a FROM v);
+
+-- Expressions
+SELECT CASE WHEN a > 0 THEN 'positive' WHEN a < 0 THEN 'negative' ELSE 'zero' END FROM t;
+SELECT CASE status WHEN 'o' THEN 'open' WHEN 'c' THEN 'closed' END FROM orders;
+SELECT CASE WHEN a IS NULL THEN 0 ELSE a END AS a FROM t;
+SELECT CAST(a AS int), CAST(b AS varchar(10)), CAST(c AS decimal(10,2)) FROM t;
+SELECT CAST('2020-01-01' AS date), CAST(x AS timestamp), CAST(y AS boolean) FROM t;
+SELECT CAST(price * quantity AS numeric(12,2)) AS line_total FROM order_items;
+SELECT CAST(a AS dec), CAST(b AS DECIMAL(5)), CAST(c AS Numeric(38, 0)) FROM t;
+SELECT EXTRACT(YEAR FROM placed_at), EXTRACT(MONTH FROM placed_at) FROM orders;
+SELECT DATE '2020-01-01', TIME '12:00:00', TIMESTAMP '2020-01-01 12:00:00';
+SELECT INTERVAL '1' DAY, INTERVAL '1-2' YEAR TO MONTH;
+SELECT INTERVAL '10' SECOND, INTERVAL '1:30' HOUR TO MINUTE;
+SELECT TRIM(name), TRIM(LEADING ' ' FROM name), TRIM(TRAILING 'x' FROM name) FROM t;
+SELECT a = ANY (SELECT b FROM u), a < ALL (SELECT b FROM u) FROM t;
+UPDATE t SET (a, b) = (1, 2) WHERE c = 3;
+SELECT row_number() OVER (ORDER BY id) FROM t;
+SELECT rank() OVER (PARTITION BY country ORDER BY total DESC) FROM t;
+SELECT sum(amount) OVER (PARTITION BY customer_id) FROM payments;
+SELECT sum(amount) OVER (ORDER BY paid_at ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW) FROM payments;
+SELECT avg(amount) OVER (ORDER BY paid_at ROWS BETWEEN 3 PRECEDING AND 3 FOLLOWING) FROM payments;
+SELECT sum(amount) OVER (ORDER BY paid_at RANGE UNBOUNDED PRECEDING) FROM payments;
+SELECT lag(amount) OVER (PARTITION BY customer_id ORDER BY paid_at) FROM payments;
+SELECT first_value(amount) OVER () FROM payments;
+SELECT count(*) OVER (PARTITION BY a, b ORDER BY c, d DESC) FROM t;
+SELECT * FROM t WHERE a IN (1);
+SELECT * FROM t WHERE a IN (SELECT b FROM u WHERE u.c = t.c);
+SELECT * FROM t WHERE (SELECT count(*) FROM u) > 0;
+SELECT x.y.z FROM x;
+SELECT "quoted name", "Mixed Case" FROM "some table";
+SELECT "a""b" FROM t;
+SELECT a AS "select" FROM t;
+SELECT count(*) AS "count" FROM t;
+SELECT a FROM t WHERE b = 'x' || 'y';
+SELECT a FROM t WHERE a * -1 > 0;
+SELECT 1 < 2, 1 <= 2, 1 > 2, 1 >= 2, 1 = 2, 1 <> 2, 1 != 2;
+
+-- Limits
+SELECT * FROM t LIMIT 10;
+SELECT * FROM t ORDER BY a LIMIT 10 OFFSET 20 ROWS;
+SELECT * FROM t OFFSET 5 ROWS;
+SELECT * FROM t ORDER BY a OFFSET 10 ROWS FETCH NEXT 10 ROWS ONLY;
+SELECT * FROM t FETCH FIRST 5 ROWS ONLY;
+SELECT * FROM t FETCH FIRST 10 PERCENT ROWS ONLY;
+SELECT * FROM t ORDER BY a FETCH FIRST 3 ROWS WITH TIES;
+
+-- Inserts, updates and deletes
+INSERT INTO customers VALUES (1, 'Alice', 'NZ');
+INSERT INTO customers (id, name, country) VALUES (1, 'Alice', 'NZ');
+INSERT INTO customers (id, name) VALUES (1, 'Alice'), (2, 'Bob'), (3, 'Carol');
+INSERT INTO archive SELECT * FROM orders WHERE placed_at < '2015-01-01';
+INSERT INTO archive (id, total) SELECT id, total FROM orders;
+INSERT INTO t (a) VALUES (DEFAULT);
+INSERT INTO s.t (a, b) VALUES (NULL, 'x');
+UPDATE customers SET name = 'Alice' WHERE id = 1;
+UPDATE customers SET name = 'Alice', country = 'AU' WHERE id = 1;
+UPDATE products SET price = price * 1.1;
+UPDATE products SET price = (SELECT avg(price) FROM products) WHERE price IS NULL;
+UPDATE t SET a = DEFAULT WHERE b = 1;
+DELETE FROM orders WHERE id = 7;
+DELETE FROM orders;
+DELETE FROM orders WHERE customer_id IN (SELECT id FROM customers WHERE country = 'XX');
+
+-- Schema changes
+CREATE TABLE customers (id int NOT NULL PRIMARY KEY, name varchar(255) NOT NULL, country char(2));
+CREATE TABLE orders (id bigint PRIMARY KEY, customer_id int REFERENCES customers (id), total decimal(10,2) DEFAULT 0, placed_at timestamp);
+CREATE TABLE t (a int UNIQUE, b int NULL, c text DEFAULT 'x', d int CHECK (d > 0));
+CREATE TABLE t (a int, b int, PRIMARY KEY (a, b));
+CREATE TABLE t (a int, b int, CONSTRAINT uq UNIQUE (a, b));
+CREATE TABLE t (a int, CONSTRAINT fk FOREIGN KEY (a) REFERENCES u (id));
+CREATE TABLE t (a int, CHECK (a > 0));
+CREATE TABLE t (a int, CONSTRAINT positive CHECK (a > 0));
+CREATE TABLE t (a smallint, b int, c bigint, d real, e double precision, f float, g boolean, h date, i time, j timestamp, k text, l char(1), m varchar(10), n numeric(5,2), o uuid, p interval);
+CREATE TABLE t (a int CONSTRAINT a_pk PRIMARY KEY, b int CONSTRAINT b_nn NOT NULL);
+CREATE TABLE t (a int REFERENCES u (id) ON DELETE CASCADE);
+CREATE TABLE t (a int REFERENCES u (id) ON DELETE SET NULL ON UPDATE CASCADE);
+CREATE TABLE t (a int, FOREIGN KEY (a) REFERENCES u (id) ON DELETE RESTRICT);
+CREATE VIEW active_customers AS SELECT * FROM customers WHERE active;
+CREATE VIEW v (a, b) AS SELECT 1, 2;
+CREATE MATERIALIZED VIEW mv AS SELECT count(*) FROM orders;
+CREATE INDEX idx_orders_customer ON orders (customer_id);
+CREATE INDEX idx ON t (a, b);
+ALTER TABLE customers ADD CONSTRAINT uq_email UNIQUE (email);
+ALTER TABLE orders ADD CONSTRAINT fk_customer FOREIGN KEY (customer_id) REFERENCES customers (id);
+ALTER TABLE t ALTER COLUMN a SET DEFAULT 0;
+ALTER TABLE t ALTER COLUMN a DROP DEFAULT;
+DROP TABLE customers;
+DROP TABLE IF EXISTS customers;
+DROP TABLE a, b, c;
+DROP TABLE t CASCADE;
+DROP VIEW v;
+DROP VIEW IF EXISTS v RESTRICT;
+DROP INDEX idx;
+
+-- Transactions and session state
+START TRANSACTION;
+START TRANSACTION READ ONLY;
+START TRANSACTION ISOLATION LEVEL SERIALIZABLE;
+START TRANSACTION ISOLATION LEVEL READ COMMITTED, READ WRITE;
+SET TRANSACTION ISOLATION LEVEL REPEATABLE READ;
+COMMIT;
+COMMIT WORK;
+ROLLBACK;
+ROLLBACK WORK;
+SET search_path = public;
+SHOW search_path;
+
+-- Explain
+EXPLAIN PLAN FOR SELECT * FROM t;
@@ -0,0 +1,54 @@
+-- Hive and Spark SQL statements, with their clauses for distributing and
+-- sorting rows among reducers
+
+SELECT a, b FROM t CLUSTER BY a, b;
+SELECT a, b FROM t DISTRIBUTE BY a SORT BY b DESC, a;
+SELECT a FROM t DISTRIBUTE BY a;
+SELECT a FROM t SORT BY a ASC LIMIT 10;
+SELECT dept, count(*) AS n FROM `db`.`employees` WHERE salary > 0 GROUP BY dept HAVING count(*) > 1 DISTRIBUTE BY dept SORT BY n DESC;
+SELECT * FROM (SELECT a FROM t DISTRIBUTE BY a) AS s SORT BY a;
+SELECT 'it\'s', `select` FROM t;
+INSERT INTO t SELECT a FROM u CLUSTER BY a;
+
+-- Tables over files
+CREATE TABLE page_views (view_time int, user_id bigint, page_url string, referrer_url string, ip string);
+CREATE EXTERNAL TABLE logs (ts string, level string, message string) STORED AS TEXTFILE LOCATION 's3://bucket/logs/';
+CREATE EXTERNAL TABLE events (id bigint, payload string) STORED AS PARQUET LOCATION '/data/events';
+CREATE VIEW dept_salaries AS SELECT dept, avg(salary) AS avg_salary FROM employees GROUP BY dept;
+DROP TABLE IF EXISTS staging;
+DROP VIEW IF EXISTS dept_salaries;
+INSERT INTO page_views VALUES (1, 2, 'http://a', 'http://b', '127.0.0.1');
+
+-- Queries
+SELECT page_url, count(*) AS views FROM page_views GROUP BY page_url ORDER BY views DESC LIMIT 10;
+SELECT user_id, count(DISTINCT page_url) FROM page_views GROUP BY user_id;
+SELECT dept, max(salary), min(salary), avg(salary) FROM employees GROUP BY dept HAVING avg(salary) > 50000;
+SELECT e.name, d.name FROM employees AS e JOIN departments AS d ON e.dept = d.id;
+SELECT e.name FROM employees AS e LEFT OUTER JOIN managers AS m ON e.name = m.name WHERE m.name IS NULL;
+SELECT * FROM employees AS e FULL OUTER JOIN contractors AS c ON e.name = c.name;
+SELECT * FROM employees CROSS JOIN departments;
+SELECT name, salary, rank() OVER (PARTITION BY dept ORDER BY salary DESC) AS r FROM employees;
+SELECT name, sum(salary) OVER (PARTITION BY dept) AS dept_total FROM employees;
+SELECT name, lead(salary, 1) OVER (ORDER BY salary) FROM employees;
+SELECT from_unixtime(view_time), to_date(from_unixtime(view_time)) FROM page_views;
+SELECT year(dt), month(dt), day(dt) FROM calendar;
+SELECT date_add('2021-01-01', 7), datediff('2021-01-08', '2021-01-01');
+SELECT concat_ws(',', name, dept), lower(name), regexp_replace(name, ' ', '_') FROM employees;
+SELECT get_json_object(payload, '$.id') FROM events;
+SELECT size(split(message, ' ')) FROM logs;
+SELECT collect_set(dept), collect_list(name) FROM employees;
+SELECT if(salary > 100000, 'high', 'normal') FROM employees;
+SELECT nvl(dept, 'unknown') FROM employees;
+SELECT CAST(salary AS int), CAST('1' AS bigint) FROM employees;
+SELECT CASE WHEN level = 'ERROR' THEN 1 ELSE 0 END AS is_error FROM logs;
+SELECT * FROM logs WHERE level IN ('WARN', 'ERROR') AND message LIKE '%timeout%';
+SELECT count(*) FROM (SELECT DISTINCT user_id FROM page_views) AS t;
+SELECT * FROM employees WHERE dept IN (SELECT id FROM departments WHERE active);
+SELECT dept, count(*) FROM employees GROUP BY dept ORDER BY dept;
+SELECT a FROM t1 UNION ALL SELECT a FROM t2;
+SELECT a FROM t1 UNION DISTINCT SELECT a FROM t2;
+WITH top AS (SELECT * FROM employees ORDER BY salary DESC LIMIT 10) SELECT name FROM top;
+SELECT user_id, page_url FROM page_views DISTRIBUTE BY user_id SORT BY user_id, view_time;
+SELECT `user`.`name`, `date` FROM `db`.`user`;
+SHOW TABLES;
+SHOW DATABASES;
@@ -0,0 +1,21 @@
+-- T-SQL statements, with bracketed identifiers and OUTPUT clauses
+
+SELECT @@version, _foo$123 FROM ##temp;
+SELECT foo 'alias', foo AS 'alias' FROM t;
+SELECT foo AS 'it''s', bar AS [a]]b] FROM t;
+SELECT [a.b!] [FROM] FROM foo [WHERE];
+SELECT [sıze] FROM [ﬁle];
+SELECT [Order ID], [Customer].[Name] FROM [dbo].[Orders] JOIN [dbo].[Customer] ON [Customer].[ID] = [Orders].[CustomerID];
+SELECT * FROM t WITH (NOLOCK);
+SELECT * FROM fn(1, 2) AS foo, schema.bar AS bar WITH (NOLOCK);
+SELECT a FROM t GROUP BY 1 ORDER BY 1;
+SELECT 'a' 'b';
+SELECT CONVERT(character varying(10), d, 121), CONVERT(int, '1');
+SELECT CAST(a AS nvarchar(max)), CAST(b AS varchar(max)) FROM t;
+CREATE TABLE t (a VARCHAR(MAX), b character varying(max), c nvarchar(50), d nchar(2));
+INSERT INTO t WITH (TABLOCK) (a, b) OUTPUT inserted.id, inserted.a AS x VALUES (1, 2);
+INSERT INTO t OUTPUT inserted.* SELECT * FROM u;
+INSERT INTO t WITH u AS (SELECT 1) SELECT * FROM u;
+UPDATE t SET a = 1 OUTPUT deleted.a, inserted.a WHERE b = 2;
+DELETE FROM t OUTPUT deleted.* WHERE a = 1;
+SELECT * FROM [dbo].[Orders] ORDER BY [OrderDate] DESC OFFSET 0 ROWS FETCH NEXT 50 ROWS ONLY;
@@ -0,0 +1,35 @@
+-- MySQL statements, including its string escapes and comment styles
+
+SELECT a # the first column
+FROM t;
+SELECT a -- the first column
+FROM t;
+SELECT 'it\'s', 'c\nd', 'e\%', "double quoted";
+SELECT 'a\\', '\\n', 'C:\\Users\\me' FROM t WHERE path LIKE '%\\\\%';
+SELECT CONVERT(a USING utf8mb4), CONVERT(a + 1, char) FROM t;
+SELECT a = 1 || b = 2 FROM t;
+SELECT d + INTERVAL 1 DAY, d - INTERVAL '1' MONTH FROM t;
+SELECT DATE_ADD(d, INTERVAL n * 2 HOUR) FROM t;
+SELECT lımıt, ſelect FROM ﬁle;
+SELECT $a$, àà;
+SELECT * FROM orders WHERE placed_at > NOW() - INTERVAL 7 DAY ORDER BY placed_at DESC LIMIT 20;
+SELECT customer_id, COUNT(*) AS n FROM orders GROUP BY customer_id HAVING n > 5;
+SELECT IFNULL(nickname, name), CONCAT(first_name, ' ', last_name) FROM users;
+SELECT * FROM users WHERE email LIKE '%@example.com' AND deleted_at IS NULL;
+SHOW COLUMNS FROM mytable;
+SHOW COLUMNS FROM mydb.mytable;
+SHOW EXTENDED COLUMNS FROM mytable;
+SHOW FULL COLUMNS FROM mytable;
+SHOW COLUMNS FROM mytable LIKE 'pattern';
+SHOW COLUMNS FROM mytable WHERE 1 = 2;
+SHOW FIELDS FROM mytable;
+SHOW COLUMNS IN mytable;
+SHOW FIELDS IN mytable;
+CREATE TABLE t (a int, CHECK (a > 0) NOT ENFORCED, UNIQUE (a) ENFORCED);
+CREATE TABLE users (id int NOT NULL, email varchar(255) NOT NULL, created_at timestamp, PRIMARY KEY (id), UNIQUE (email));
+INSERT INTO users (id, email) VALUES (1, 'a@example.com'), (2, 'b@example.com');
+INSERT IGNORE INTO users (id, email) VALUES (1, 'a@example.com');
+REPLACE INTO users (id, email) VALUES (2, 'c@example.com');
+REPLACE INTO users_archive SELECT * FROM users WHERE created_at < '2020-01-01';
+UPDATE users SET email = LOWER(email) WHERE id = 1;
+DELETE FROM sessions WHERE expires_at < NOW();
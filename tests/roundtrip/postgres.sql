@@ -0,0 +1,161 @@
+-- PostgreSQL statements, largely as pg_dump and psql users write them
+
+SET statement_timeout = 0;
+SET lock_timeout = 0;
+SET client_encoding = 'UTF8';
+SET standard_conforming_strings = on;
+SET check_function_bodies = false;
+SET search_path TO public;
+SET LOCAL work_mem = '64MB';
+SET SESSION timezone TO 'UTC';
+SET default_tablespace = '';
+SET default_with_oids = false;
+SHOW ALL;
+SHOW server_version;
+
+CREATE EXTENSION IF NOT EXISTS plpgsql WITH SCHEMA pg_catalog;
+CREATE EXTENSION IF NOT EXISTS "uuid-ossp" WITH SCHEMA public;
+CREATE EXTENSION hstore;
+CREATE EXTENSION postgis VERSION '3.1.0' CASCADE;
+DROP EXTENSION IF EXISTS hstore, postgis CASCADE;
+
+CREATE SEQUENCE public.actor_actor_id_seq START WITH 1 INCREMENT BY 1 NO MINVALUE NO MAXVALUE CACHE 1;
+CREATE SEQUENCE IF NOT EXISTS public.t_id_seq AS int START WITH 1 INCREMENT BY 1 NO MINVALUE MAXVALUE 1000 CACHE 1 NO CYCLE OWNED BY public.t.id;
+CREATE SEQUENCE s;
+ALTER SEQUENCE public.actor_actor_id_seq OWNED BY public.actor.actor_id;
+ALTER SEQUENCE IF EXISTS s RESTART WITH 5 OWNED BY NONE;
+ALTER SEQUENCE s RESTART NO MAXVALUE;
+ALTER SEQUENCE IF EXISTS public.s OWNER TO postgres;
+DROP SEQUENCE IF EXISTS s CASCADE;
+
+CREATE TABLE public.actor (
+    actor_id integer DEFAULT nextval('public.actor_actor_id_seq'::regclass) NOT NULL,
+    first_name character varying(45) NOT NULL,
+    last_name character varying(45) NOT NULL,
+    last_update timestamp without time zone DEFAULT now() NOT NULL
+);
+CREATE TABLE public.customer (
+    customer_id integer DEFAULT nextval('public.customer_customer_id_seq'::regclass) NOT NULL,
+    store_id smallint NOT NULL,
+    first_name character varying(45) NOT NULL,
+    last_name character varying(45) COLLATE "es_ES" NOT NULL,
+    email character varying(50),
+    info text[],
+    activebool boolean DEFAULT true NOT NULL,
+    create_date date DEFAULT ('now'::text)::date NOT NULL,
+    last_update timestamp with time zone DEFAULT now(),
+    release_year public.year,
+    active integer
+) WITH (fillfactor = 20, autovacuum_vacuum_threshold = 100);
+CREATE TABLE public.film (
+    film_id serial NOT NULL,
+    title character varying(255) NOT NULL,
+    description text,
+    rental_rate numeric(4,2) DEFAULT 4.99 NOT NULL,
+    length smallint,
+    replacement_cost numeric(5,2) DEFAULT 19.99 NOT NULL,
+    special_features text[],
+    fulltext tsvector NOT NULL
+);
+CREATE TABLE public.payment_p2020_01 (
+    CONSTRAINT payment_p2020_01_payment_date_check CHECK (payment_date >= '2020-01-01 00:00:00+00'::timestamp with time zone)
+);
+CREATE TABLE t (a int CONSTRAINT a_pk PRIMARY KEY DEFERRABLE INITIALLY DEFERRED, b int REFERENCES u (id) NOT DEFERRABLE);
+CREATE TABLE t (id uuid DEFAULT uuid_generate_v4() PRIMARY KEY, doc jsonb, tags varchar(20)[], created timestamp(3), amount money);
+CREATE TABLE t (a int[3], b int[][], c "My Type"[], d geometry(point, 4326));
+CREATE TABLE t (a bytea, b regclass, c interval, d time without time zone);
+
+ALTER TABLE ONLY public.actor ALTER COLUMN actor_id SET DEFAULT nextval('public.actor_actor_id_seq'::regclass);
+ALTER TABLE ONLY public.actor ADD CONSTRAINT actor_pkey PRIMARY KEY (actor_id);
+ALTER TABLE ONLY public.payment ADD CONSTRAINT payment_customer_id_fkey FOREIGN KEY (customer_id) REFERENCES public.customer (customer_id) ON UPDATE CASCADE ON DELETE RESTRICT;
+ALTER TABLE ONLY public.film ADD CONSTRAINT film_title_key UNIQUE (title);
+ALTER TABLE t ADD CONSTRAINT positive CHECK (a > 0) NO INHERIT;
+ALTER TABLE ONLY t ALTER COLUMN id DROP DEFAULT;
+ALTER TABLE public.actor OWNER TO postgres;
+ALTER TABLE ONLY parent OWNER TO postgres;
+ALTER VIEW v OWNER TO r;
+ALTER SCHEMA public OWNER TO r;
+ALTER TYPE t OWNER TO r;
+ALTER VIEW v SET (check_option = local, security_barrier = true);
+ALTER VIEW v RESET (check_option, security_barrier);
+
+CREATE VIEW public.actor_info AS SELECT a.actor_id, a.first_name, a.last_name FROM public.actor a;
+CREATE VIEW v WITH (check_option = local, security_barrier = true) AS SELECT 1;
+CREATE TEMPORARY VIEW v (a, b int) AS SELECT 1, 2;
+CREATE INDEX idx_actor_last_name ON public.actor (last_name);
+
+CREATE ROLE analyst LOGIN NOSUPERUSER NOCREATEDB INHERIT CONNECTION LIMIT 5 PASSWORD 'it''s secret' VALID UNTIL '2030-01-01' IN ROLE readers, writers ADMIN dba;
+CREATE ROLE r;
+CREATE USER u ENCRYPTED PASSWORD 'md5abc' ROLE a, b;
+ALTER USER u NOLOGIN PASSWORD NULL;
+ALTER ROLE r NOINHERIT NOREPLICATION NOBYPASSRLS NOCREATEROLE;
+
+COPY public.actor (actor_id, first_name, last_name, last_update) FROM stdin;
+1	PENELOPE	GUINESS	2006-02-15 09:34:33
+2	NICK	WAHLBERG	2006-02-15 09:34:33
+3	ED	\N	2006-02-15 09:34:33
+\.
+COPY t FROM stdin;
+\.
+COPY public.actor (actor_id, first_name) FROM stdin WITH (FORMAT csv, HEADER);
+actor_id,first_name
+4,"JENNIFER, ""JEN"""
+5,
+6,O'Brien
+\.
+COPY t FROM stdin WITH (FORMAT binary);
+COPY (SELECT actor_id, last_name FROM public.actor WHERE actor_id < 10) TO STDOUT WITH (FORMAT csv, HEADER);
+COPY public.actor TO STDOUT;
+
+INSERT INTO t OVERRIDING USER VALUE VALUES (1);
+INSERT INTO t AS x DEFAULT VALUES;
+INSERT INTO t AS x (a, b) OVERRIDING SYSTEM VALUE SELECT 1, 2;
+INSERT INTO public.actor (first_name, last_name) VALUES ('PENELOPE', 'GUINESS'), ('NICK', 'WAHLBERG');
+UPDATE public.film SET rental_rate = rental_rate * 1.1 WHERE film_id = $1;
+UPDATE t SET (a, b) = (SELECT x, y FROM u WHERE u.id = t.id);
+DELETE FROM public.payment WHERE payment_date < now() - INTERVAL '1 year';
+
+SELECT 'now'::timestamp, '1'::int + 1, x::text::int FROM t;
+SELECT '{{1,2},{3,4}}'::int[][];
+SELECT ARRAY[1, 2, 3], ARRAY[], ARRAY[ARRAY[1], ARRAY[2]];
+SELECT ARRAY(SELECT x FROM t ORDER BY x);
+SELECT cardinality(ARRAY(SELECT c FROM u)) FROM t;
+SELECT doc -> 'a', doc ->> 'b', doc #> '{a,b}', doc #>> '{a,b}' FROM t;
+SELECT * FROM t WHERE doc @> '{"a": 1}' AND doc <@ '{"a": 1, "b": 2}';
+SELECT * FROM t WHERE doc ? 'a' OR doc ?| ARRAY['a', 'b'] OR doc ?& ARRAY['c'];
+SELECT doc || '{"c": 3}', doc #- '{a}', doc @? '$.a', doc @@ '$.a == 1' FROM t;
+SELECT a ->> 'x' = 'y';
+SELECT * FROM t WHERE name ILIKE 'foo%' AND name NOT ILIKE '%bar';
+SELECT * FROM t WHERE name SIMILAR TO '(a|b)%';
+SELECT * FROM unnest(ARRAY[1, 2]) WITH ORDINALITY;
+SELECT * FROM generate_series(1, 3) WITH ORDINALITY AS t (x, n);
+SELECT * FROM jsonb_to_recordset(x) AS t (a int, b text, c);
+SELECT * FROM ONLY parent AS p;
+SELECT * FROM parent * JOIN child * ON true;
+SELECT a AS format FROM t AS qualify;
+SELECT a, b, count(*) FROM t GROUP BY 1, b, 2 + 0;
+SELECT 'foo'
+  'bar';
+SELECT E'line\nbreak', $$dollar 'quoted'$$, $tag$with $$ inside$tag$;
+SELECT CAST(x AS geometry(point, 4326)), CAST(y AS "My Type"[]);
+SELECT current_date, current_timestamp, localtimestamp;
+SELECT date_trunc('month', created) AS month, count(*) FROM orders GROUP BY 1 ORDER BY 1;
+SELECT DISTINCT ON (customer_id) customer_id, placed_at FROM orders ORDER BY customer_id, placed_at DESC;
+SELECT * FROM t FOR UPDATE;
+SELECT * FROM t FOR SHARE;
+
+PREPARE p (int, text) AS SELECT $1, $2;
+PREPARE p AS SELECT 1;
+PREPARE p (int) AS UPDATE t SET a = $1;
+PREPARE p AS DELETE FROM t;
+DECLARE c CURSOR FOR SELECT * FROM t;
+FETCH 10 FROM c;
+CLOSE c;
+LISTEN events;
+NOTIFY events, 'it''s done';
+NOTIFY events;
+UNLISTEN *;
+UNLISTEN events;
+DO $$BEGIN RAISE NOTICE 'hi'; END$$ LANGUAGE plpgsql;
+DO 'BEGIN NULL; END';
+DO LANGUAGE plpgsql $body$BEGIN PERFORM 1; END$body$;
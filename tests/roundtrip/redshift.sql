@@ -0,0 +1,49 @@
+-- Redshift statements, with temporary tables named with #
+
+CREATE TABLE #staging (id bigint IDENTITY(1,1) NOT NULL, name text);
+CREATE TABLE #staging (id bigint IDENTITY(1, 1) NOT NULL, name text);
+SELECT #t.a FROM #t;
+INSERT INTO #staging (name) SELECT name FROM users;
+SELECT count(*) FROM #staging;
+
+-- Data warehouse queries and maintenance
+CREATE TABLE sales (salesid int NOT NULL, listid int NOT NULL, sellerid int NOT NULL, buyerid int NOT NULL, eventid int NOT NULL, dateid smallint NOT NULL, qtysold smallint NOT NULL, pricepaid decimal(8, 2), commission decimal(8, 2), saletime timestamp);
+CREATE TABLE users (userid int NOT NULL, username char(8), firstname varchar(30), lastname varchar(30), city varchar(30), state char(2), email varchar(100), phone char(14), likesports boolean);
+CREATE TABLE event (eventid int NOT NULL, venueid smallint NOT NULL, catid smallint NOT NULL, dateid smallint NOT NULL, eventname varchar(200), starttime timestamp);
+CREATE TABLE #recent_sales (salesid int, saletime timestamp);
+CREATE VIEW top_sellers AS SELECT sellerid, sum(pricepaid) AS total FROM sales GROUP BY sellerid;
+DROP TABLE IF EXISTS #recent_sales;
+DROP VIEW top_sellers;
+INSERT INTO #recent_sales SELECT salesid, saletime FROM sales WHERE saletime > '2008-12-01';
+INSERT INTO category VALUES (12, 'Concerts', 'Comedy', 'All stand-up comedy performances');
+INSERT INTO category (catid, catgroup) VALUES (13, 'Concerts'), (14, 'Shows');
+UPDATE category SET catdesc = 'Broadway Musical' WHERE catid = 7;
+UPDATE sales SET commission = pricepaid * 0.15 WHERE commission IS NULL;
+DELETE FROM category WHERE catid = 12;
+DELETE FROM #recent_sales;
+SELECT sum(qtysold) FROM sales, date WHERE sales.dateid = date.dateid AND caldate = '2008-01-05';
+SELECT firstname, lastname, total_quantity FROM (SELECT buyerid, sum(qtysold) AS total_quantity FROM sales GROUP BY buyerid ORDER BY total_quantity DESC LIMIT 10) AS q, users WHERE q.buyerid = userid ORDER BY q.total_quantity DESC;
+SELECT eventname, total_price FROM (SELECT eventid, total_price, ntile(1000) OVER (ORDER BY total_price DESC) AS percentile FROM (SELECT eventid, sum(pricepaid) AS total_price FROM sales GROUP BY eventid) AS s) AS q, event AS e WHERE q.eventid = e.eventid AND percentile = 1 ORDER BY total_price DESC;
+SELECT catgroup, count(*) FROM category GROUP BY catgroup ORDER BY 2 DESC;
+SELECT username, city FROM users WHERE likesports AND state = 'CA' ORDER BY username LIMIT 20;
+SELECT e.eventname, count(s.salesid) AS sold FROM event AS e LEFT JOIN sales AS s ON e.eventid = s.eventid GROUP BY e.eventname HAVING count(s.salesid) > 100;
+SELECT date_trunc('month', saletime) AS month, sum(pricepaid) FROM sales GROUP BY 1 ORDER BY 1;
+SELECT datediff(day, starttime, getdate()) FROM event;
+SELECT dateadd(month, 1, saletime) FROM sales;
+SELECT nvl(firstname, '') || ' ' || nvl(lastname, '') AS full_name FROM users;
+SELECT CASE WHEN pricepaid > 1000 THEN 'high' WHEN pricepaid > 100 THEN 'medium' ELSE 'low' END AS band, count(*) FROM sales GROUP BY 1;
+SELECT sellerid, pricepaid, rank() OVER (PARTITION BY sellerid ORDER BY pricepaid DESC) FROM sales;
+SELECT sellerid, sum(pricepaid) OVER (PARTITION BY sellerid ORDER BY saletime ROWS UNBOUNDED PRECEDING) FROM sales;
+SELECT count(DISTINCT buyerid) FROM sales WHERE saletime BETWEEN '2008-01-01' AND '2008-12-31';
+SELECT * FROM sales WHERE eventid IN (SELECT eventid FROM event WHERE catid = 9);
+SELECT * FROM users WHERE email LIKE '%@example.com' OR phone IS NULL;
+SELECT CAST(pricepaid AS int), pricepaid::varchar FROM sales;
+SELECT getdate(), sysdate, current_date;
+SELECT split_part(email, '@', 2) FROM users;
+SELECT trunc(saletime), sum(qtysold) FROM sales GROUP BY 1;
+WITH venue_sales AS (SELECT venueid, sum(pricepaid) AS total FROM sales JOIN event USING (eventid) GROUP BY venueid) SELECT * FROM venue_sales ORDER BY total DESC;
+SELECT a FROM t UNION ALL SELECT a FROM u;
+SELECT a FROM t INTERSECT SELECT a FROM u;
+BEGIN;
+COMMIT;
+SHOW search_path;
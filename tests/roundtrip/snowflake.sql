@@ -0,0 +1,79 @@
+-- Snowflake statements, with semi-structured data access and QUALIFY
+
+SELECT src:customer[0].name FROM t;
+SELECT t.src:"Name"::TEXT FROM t;
+SELECT src:salesperson.id, src:vehicle[0].price::number FROM car_sales;
+SELECT a FROM t GROUP BY a HAVING count(*) > 1 QUALIFY rank() OVER (ORDER BY a) < 3;
+SELECT a, row_number() OVER (PARTITION BY a ORDER BY b) AS rn FROM t QUALIFY rn = 1;
+SELECT * FROM db.schema.t WHERE ts > '2021-01-01' ORDER BY ts LIMIT 10;
+
+-- Semi-structured data
+SELECT v:id::int AS id, v:name::string AS name FROM raw_events;
+SELECT payload:user.address.city::text AS city, count(*) FROM events GROUP BY 1 ORDER BY 2 DESC;
+SELECT src:"customer"[0]:"name" FROM t;
+SELECT data:tags[1]::varchar FROM docs WHERE data:type = 'article';
+SELECT parse_json(raw):status AS status FROM api_log;
+SELECT t.v:a.b.c FROM t WHERE t.v:a.b IS NOT NULL;
+SELECT object_construct('id', id, 'name', name) FROM users;
+SELECT array_size(v:items) FROM orders WHERE v:items IS NOT NULL;
+
+-- Analytics queries
+SELECT date_trunc('day', created_at) AS day, count(DISTINCT user_id) AS dau FROM events GROUP BY 1 ORDER BY 1;
+SELECT dateadd(day, -7, current_date());
+SELECT datediff(day, start_date, end_date) AS days FROM projects;
+SELECT iff(amount > 100, 'large', 'small') AS size, sum(amount) FROM payments GROUP BY 1;
+SELECT zeroifnull(sum(amount)) FROM payments WHERE paid_at >= '2021-01-01';
+SELECT nvl(nickname, first_name) AS display_name FROM users;
+SELECT coalesce(a, b, 0), nullif(c, 0) FROM t;
+SELECT user_id, sum(amount) OVER (PARTITION BY user_id ORDER BY paid_at ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW) AS running_total FROM payments;
+SELECT user_id, lag(amount) OVER (PARTITION BY user_id ORDER BY paid_at) AS previous FROM payments;
+SELECT * FROM orders QUALIFY row_number() OVER (PARTITION BY customer_id ORDER BY ordered_at DESC) = 1;
+SELECT customer_id, max(ordered_at) FROM orders WHERE status IN ('shipped', 'delivered') GROUP BY customer_id;
+SELECT a.id, b.total FROM accounts AS a LEFT JOIN (SELECT account_id, sum(amount) AS total FROM txns GROUP BY account_id) AS b ON a.id = b.account_id;
+WITH recent AS (SELECT * FROM orders WHERE ordered_at > dateadd(month, -1, current_timestamp())) SELECT count(*) FROM recent;
+WITH a AS (SELECT 1 AS x), b AS (SELECT x + 1 AS y FROM a) SELECT * FROM b;
+SELECT CASE WHEN score >= 90 THEN 'A' WHEN score >= 80 THEN 'B' ELSE 'C' END AS grade FROM results;
+SELECT CAST(amount AS decimal(18, 2)) FROM payments;
+SELECT amount::number(38, 0) FROM payments;
+SELECT to_varchar(created_at, 'YYYY-MM-DD') FROM events;
+SELECT * FROM analytics.public.sessions WHERE started_at BETWEEN '2021-01-01' AND '2021-02-01';
+SELECT region, product, sum(sales) FROM sales GROUP BY region, product HAVING sum(sales) > 1000 ORDER BY 3 DESC LIMIT 20;
+SELECT * FROM t1 INNER JOIN t2 ON t1.id = t2.id FULL OUTER JOIN t3 ON t2.id = t3.id;
+SELECT * FROM t WHERE name LIKE 'a%' AND name NOT ILIKE '%test%';
+SELECT DISTINCT country FROM customers ORDER BY country;
+SELECT count(*) FROM (SELECT DISTINCT user_id FROM events) AS u;
+SELECT 1 UNION SELECT 2 EXCEPT SELECT 3;
+
+-- DDL and DML
+CREATE TABLE t (id int, payload variant, tags array, attrs object);
+DROP TABLE IF EXISTS staging.tmp_orders;
+DROP VIEW IF EXISTS active_users;
+INSERT INTO dim_users (id, name) VALUES (1, 'alice'), (2, 'bob');
+INSERT INTO raw_events SELECT parse_json(column1), current_timestamp() FROM staging;
+UPDATE orders SET status = 'cancelled' WHERE ordered_at < '2020-01-01';
+DELETE FROM events WHERE loaded_at < dateadd(year, -2, current_date());
+CREATE VIEW active_users AS SELECT * FROM users WHERE active;
+CREATE TABLE staging.orders (id int NOT NULL, customer_id int, amount decimal(12, 2), ordered_at timestamp, PRIMARY KEY (id));
+DROP SCHEMA IF EXISTS staging CASCADE;
+BEGIN;
+COMMIT;
+ROLLBACK;
+SHOW TABLES;
+SELECT current_user(), current_role(), current_warehouse();
+SELECT uuid_string();
+SELECT hash(a, b) FROM t;
+SELECT split_part(email, '@', 2) AS domain, count(*) FROM users GROUP BY domain;
+SELECT regexp_substr(url, 'https?://([^/]+)', 1, 1, 'e') FROM hits;
+SELECT to_date(created_at) AS d, count(*) FROM events GROUP BY d HAVING count(*) > 10;
+SELECT approx_count_distinct(user_id) FROM events;
+SELECT * FROM orders AS o WHERE EXISTS (SELECT 1 FROM refunds AS r WHERE r.order_id = o.id);
+SELECT * FROM orders WHERE customer_id NOT IN (SELECT id FROM blocked_customers);
+SELECT a, b, sum(c) FROM t GROUP BY ROLLUP (a, b);
+SELECT a, b, sum(c) FROM t GROUP BY CUBE (a, b);
+SELECT first_value(name) OVER (PARTITION BY dept ORDER BY salary DESC) FROM employees;
+SELECT ntile(4) OVER (ORDER BY score) AS quartile FROM results;
+SELECT dense_rank() OVER (ORDER BY total DESC) AS r, customer_id FROM totals QUALIFY r <= 10;
+SELECT extract(year FROM created_at) AS y, count(*) FROM users GROUP BY y;
+SELECT created_at::date AS d FROM users;
+SELECT 'a' || 'b' || name FROM users;
+SELECT upper(trim(name)) FROM users WHERE length(name) > 0;
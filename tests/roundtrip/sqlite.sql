@@ -0,0 +1,56 @@
+-- SQLite statements, including its several identifier quoting styles
+
+CREATE TABLE t (a int PRIMARY KEY AUTOINCREMENT, b text) WITHOUT ROWID;
+CREATE TABLE log (key text, value text, date text, time text, timestamp int);
+SELECT date, time, timestamp FROM log WHERE date > '2020-01-01';
+SELECT DATE '2020-01-01';
+SELECT [a b], `c`, "d" FROM [t];
+SELECT (SELECT 1 UNION SELECT 2) INTERSECT SELECT 3;
+INSERT INTO log (key, value) VALUES ('k', 'v');
+SELECT key, value FROM log ORDER BY key LIMIT 5;
+
+-- Schemas as applications create them
+CREATE TABLE users (id integer PRIMARY KEY AUTOINCREMENT, name text NOT NULL, email text UNIQUE, created_at text DEFAULT CURRENT_TIMESTAMP);
+CREATE TABLE posts (id integer PRIMARY KEY, user_id integer NOT NULL REFERENCES users (id), title text, body text, published integer DEFAULT 0);
+CREATE TABLE tags (post_id integer, tag text, PRIMARY KEY (post_id, tag)) WITHOUT ROWID;
+CREATE TABLE comments (id integer PRIMARY KEY, post_id integer, body text, FOREIGN KEY (post_id) REFERENCES posts (id) ON DELETE CASCADE);
+CREATE TABLE items (id integer PRIMARY KEY, price real CHECK (price >= 0));
+CREATE INDEX idx_posts_user ON posts (user_id);
+CREATE VIEW published_posts AS SELECT * FROM posts WHERE published = 1;
+DROP TABLE IF EXISTS scratch;
+DROP INDEX IF EXISTS idx_tags_tag;
+DROP VIEW published_posts;
+
+-- Queries and changes
+INSERT INTO users (name, email) VALUES ('alice', 'alice@example.com');
+INSERT INTO users (name, email) VALUES ('bob', 'bob@example.com'), ('carol', NULL);
+INSERT INTO posts (user_id, title, body) SELECT id, 'hello', 'first post' FROM users;
+REPLACE INTO settings (key, value) VALUES ('lang', 'en');
+UPDATE posts SET published = 1 WHERE id = 1;
+UPDATE users SET name = upper(name), email = lower(email) WHERE email IS NOT NULL;
+DELETE FROM posts WHERE published = 0 AND created_at < date('now', '-30 days');
+DELETE FROM tags;
+SELECT * FROM users WHERE email LIKE '%@example.com' ORDER BY name;
+SELECT u.name, count(p.id) AS posts FROM users AS u LEFT JOIN posts AS p ON p.user_id = u.id GROUP BY u.id ORDER BY posts DESC;
+SELECT p.title, group_concat(t.tag, ', ') FROM posts AS p JOIN tags AS t ON t.post_id = p.id GROUP BY p.id;
+SELECT datetime('now'), date('now', 'start of month'), strftime('%Y-%m', created_at) FROM users;
+SELECT julianday('now') - julianday(created_at) AS age FROM users;
+SELECT ifnull(email, 'none'), coalesce(name, email, 'anonymous') FROM users;
+SELECT typeof(value), length(value) FROM settings;
+SELECT substr(body, 1, 100) AS excerpt FROM posts;
+SELECT abs(-1), round(3.14159, 2), random();
+SELECT count(*), sum(price), avg(price), max(price), min(price) FROM items;
+SELECT * FROM posts WHERE id IN (SELECT post_id FROM tags WHERE tag = 'intro');
+SELECT * FROM posts WHERE EXISTS (SELECT 1 FROM comments WHERE comments.post_id = posts.id);
+SELECT * FROM posts LIMIT 10;
+SELECT CASE published WHEN 1 THEN 'yes' ELSE 'no' END FROM posts;
+SELECT CAST(price AS integer) FROM items;
+SELECT name FROM sqlite_master WHERE type = 'table' ORDER BY name;
+SELECT a FROM t1 UNION SELECT a FROM t2 ORDER BY a;
+SELECT a FROM t1 EXCEPT SELECT a FROM t2;
+WITH RECURSIVE cnt (x) AS (SELECT 1 UNION ALL SELECT x + 1 FROM cnt WHERE x < 10) SELECT x FROM cnt;
+WITH top AS (SELECT user_id, count(*) AS n FROM posts GROUP BY user_id) SELECT * FROM top WHERE n > 1;
+SELECT "name", [email], `id` FROM "users";
+BEGIN TRANSACTION;
+COMMIT;
+ROLLBACK;
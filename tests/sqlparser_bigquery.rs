@@ -0,0 +1,116 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![warn(clippy::all)]
+
+//! Test SQL syntax specific to BigQuery. The parser based on the generic
+//! dialect is also tested (on the inputs it can handle).
+
+use sqlparser::ast::*;
+use sqlparser::dialect::{BigQueryDialect, GenericDialect};
+use sqlparser::test_utils::*;
+
+#[test]
+fn parse_quoted_multipart_table_name() {
+    let query = bigquery().unverified_query("SELECT * FROM `my-project.dataset.table`");
+    assert_eq!(
+        "SELECT * FROM `my-project`.`dataset`.`table`",
+        query.to_string()
+    );
+    let select = match query.body {
+        SetExpr::Select(select) => select,
+        _ => unreachable!(),
+    };
+    match only(select.from).relation {
+        TableFactor::Table { name, .. } => assert_eq!(
+            name,
+            ObjectName(vec![
+                Ident::with_quote('`', "my-project"),
+                Ident::with_quote('`', "dataset"),
+                Ident::with_quote('`', "table"),
+            ])
+        ),
+        _ => unreachable!(),
+    }
+    bigquery().verified_stmt("SELECT `my-project`.dataset.`table`.a FROM `t`");
+
+    let select = bigquery().verified_only_select("SELECT `sıze` FROM `ﬁle`");
+    assert_eq!(
+        &Expr::Identifier(Ident::with_quote('`', "sıze")),
+        expr_from_projection(only(&select.projection))
+    );
+}
+
+#[test]
+fn parse_wildcard_except_replace() {
+    let select = bigquery_and_generic()
+        .verified_only_select("SELECT * EXCEPT (a, b) REPLACE (c + 1 AS c, 'x' AS d) FROM t");
+    assert_eq!(
+        only(&select.projection),
+        &SelectItem::Wildcard(WildcardOptions {
+            except: vec![Ident::new("a"), Ident::new("b")],
+            replace: vec![
+                WildcardReplacement {
+                    expr: Expr::BinaryOp {
+                        left: Box::new(Expr::Identifier(Ident::new("c"))),
+                        op: BinaryOperator::Plus,
+                        right: Box::new(Expr::Value(number("1"))),
+                    },
+                    column_name: Ident::new("c"),
+                },
+                WildcardReplacement {
+                    expr: Expr::Value(Value::SingleQuotedString("x".to_string())),
+                    column_name: Ident::new("d"),
+                },
+            ],
+        })
+    );
+    bigquery_and_generic().verified_stmt("SELECT * EXCEPT (a) FROM t");
+    bigquery_and_generic().verified_stmt("SELECT * REPLACE (1 AS a) FROM t");
+
+    let select =
+        bigquery_and_generic().verified_only_select("SELECT t.* EXCEPT (a), u.b FROM t, u");
+    assert_eq!(
+        select.projection[0],
+        SelectItem::QualifiedWildcard(
+            ObjectName(vec![Ident::new("t")]),
+            WildcardOptions {
+                except: vec![Ident::new("a")],
+                replace: vec![],
+            }
+        )
+    );
+
+    // `EXCEPT` followed by a subquery is still a set operation
+    match bigquery_and_generic().verified_stmt("SELECT * EXCEPT (SELECT 1)") {
+        Statement::Query(query) => match query.body {
+            SetExpr::SetOperation {
+                op: SetOperator::Except,
+                ..
+            } => (),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+}
+
+fn bigquery() -> TestedDialects {
+    TestedDialects {
+        dialects: vec![Box::new(BigQueryDialect {})],
+    }
+}
+
+fn bigquery_and_generic() -> TestedDialects {
+    TestedDialects {
+        dialects: vec![Box::new(BigQueryDialect {}), Box::new(GenericDialect {})],
+    }
+}
@@ -0,0 +1,96 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![warn(clippy::all)]
+
+//! Test SQL syntax specific to ClickHouse. The parser based on the generic
+//! dialect is also tested (on the inputs it can handle).
+
+use sqlparser::ast::*;
+use sqlparser::dialect::{ClickHouseDialect, GenericDialect};
+use sqlparser::test_utils::*;
+
+#[test]
+fn parse_query_format() {
+    let query = clickhouse_and_generic()
+        .verified_query("SELECT a, count(*) FROM t GROUP BY a LIMIT 10 FORMAT JSONEachRow");
+    assert_eq!(Some(Ident::new("JSONEachRow")), query.format);
+
+    let query = clickhouse_and_generic().verified_query("SELECT 1 FORMAT TabSeparated");
+    assert_eq!(Some(Ident::new("TabSeparated")), query.format);
+
+    let query = clickhouse_and_generic().verified_query("SELECT 1");
+    assert_eq!(None, query.format);
+}
+
+#[test]
+fn parse_create_table_engine() {
+    let sql = "CREATE TABLE hits (ts timestamp, url text) ENGINE = MergeTree() ORDER BY (ts, url)";
+    match clickhouse_and_generic().verified_stmt(sql) {
+        Statement::CreateTable {
+            engine, order_by, ..
+        } => {
+            assert_eq!(
+                Some(TableEngine {
+                    name: Ident::new("MergeTree"),
+                    parameters: Some(vec![]),
+                }),
+                engine
+            );
+            assert_eq!(
+                vec![
+                    Expr::Identifier(Ident::new("ts")),
+                    Expr::Identifier(Ident::new("url")),
+                ],
+                order_by
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    clickhouse_and_generic().verified_stmt("CREATE TABLE t (a int) ENGINE = Memory");
+    clickhouse_and_generic()
+        .verified_stmt("CREATE TABLE t (a int, d date) ENGINE = ReplacingMergeTree(d) ORDER BY a");
+    clickhouse_and_generic().one_statement_parses_to(
+        "CREATE TABLE t (a int) ENGINE = MergeTree() ORDER BY (a)",
+        "CREATE TABLE t (a int) ENGINE = MergeTree() ORDER BY a",
+    );
+
+    // Only ClickHouse, and the generic dialect, parse the engine, and the
+    // sorting key must follow it
+    clickhouse_and_generic().verified_stmt_rejected_by(
+        "CREATE TABLE t (a int) ENGINE = MergeTree() ORDER BY a",
+        &clickhouse_and_generic().others(),
+        "Expected end of statement, found: ENGINE",
+    );
+    clickhouse_and_generic().assert_parse_error(
+        "CREATE TABLE t (a int) ORDER BY a",
+        "Expected end of statement, found: ORDER",
+    );
+}
+
+#[test]
+fn parse_backtick_identifiers() {
+    clickhouse().verified_stmt("SELECT `a b` FROM `t`");
+}
+
+fn clickhouse() -> TestedDialects {
+    TestedDialects {
+        dialects: vec![Box::new(ClickHouseDialect {})],
+    }
+}
+
+fn clickhouse_and_generic() -> TestedDialects {
+    TestedDialects {
+        dialects: vec![Box::new(ClickHouseDialect {}), Box::new(GenericDialect {})],
+    }
+}
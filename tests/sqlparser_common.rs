@@ -21,8 +21,9 @@
 use matches::assert_matches;
 
 use sqlparser::ast::*;
+use sqlparser::dialect::GenericDialect;
 use sqlparser::parser::*;
-use sqlparser::test_utils::{all_dialects, expr_from_projection, number, only};
+use sqlparser::test_utils::{all_dialects, expr_from_projection, number, only, TestedDialects};
 
 #[test]
 fn parse_insert_values() {
@@ -72,8 +73,13 @@ fn parse_insert_values() {
                 for (index, column) in columns.iter().enumerate() {
                     assert_eq!(column, &Ident::new(expected_columns[index].clone()));
                 }
-                match &source.body {
-                    SetExpr::Values(Values(values)) => assert_eq!(values.as_slice(), expected_rows),
+                match source {
+                    InsertSource::Query(query) => match &query.body {
+                        SetExpr::Values(Values(values)) => {
+                            assert_eq!(values.as_slice(), expected_rows)
+                        }
+                        _ => unreachable!(),
+                    },
                     _ => unreachable!(),
                 }
             }
@@ -84,6 +90,88 @@ fn parse_insert_values() {
     verified_stmt("INSERT INTO customer WITH foo AS (SELECT 1) SELECT * FROM foo UNION VALUES (1)");
 }
 
+#[test]
+fn parse_insert_parenthesized_source() {
+    match verified_stmt("INSERT INTO t (SELECT 1)") {
+        Statement::Insert {
+            columns, source, ..
+        } => {
+            assert!(columns.is_empty());
+            assert_eq!("(SELECT 1)", source.to_string());
+        }
+        _ => unreachable!(),
+    }
+    verified_stmt("INSERT INTO t (a) (SELECT 1)");
+    verified_stmt("INSERT INTO t ((SELECT 1) UNION (SELECT 2))");
+    verified_stmt("INSERT INTO t (VALUES (1))");
+}
+
+#[test]
+fn parse_insert_default() {
+    match verified_stmt("INSERT INTO t DEFAULT VALUES") {
+        Statement::Insert {
+            columns, source, ..
+        } => {
+            assert!(columns.is_empty());
+            assert_eq!(InsertSource::DefaultValues, source);
+        }
+        _ => unreachable!(),
+    }
+
+    match verified_stmt("INSERT INTO t (a, b) VALUES (DEFAULT, 1), (2, DEFAULT)") {
+        Statement::Insert {
+            source: InsertSource::Query(query),
+            ..
+        } => match query.body {
+            SetExpr::Values(Values(values)) => assert_eq!(
+                values,
+                vec![
+                    vec![Expr::Default, Expr::Value(number("1"))],
+                    vec![Expr::Value(number("2")), Expr::Default],
+                ]
+            ),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+
+    match verified_stmt("UPDATE t SET a = DEFAULT, (b, c) = (1, DEFAULT)") {
+        Statement::Update { assignments, .. } => {
+            assert_eq!(assignments[0].value, Expr::Default);
+            assert_eq!(
+                assignments[1].value,
+                Expr::Row(vec![Expr::Value(number("1")), Expr::Default])
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    // elsewhere `DEFAULT` is just an identifier, like other non-reserved keywords
+    assert_eq!(
+        &Expr::Identifier("DEFAULT".into()),
+        expr_from_projection(&verified_only_select("SELECT DEFAULT").projection[0]),
+    );
+    for sql in &[
+        "VALUES (DEFAULT, 1)",
+        "SELECT * FROM (VALUES (DEFAULT)) AS v",
+        "INSERT INTO t VALUES (1) UNION VALUES (DEFAULT)",
+    ] {
+        assert_eq!(
+            ParserError::ParserError("DEFAULT is only allowed in the VALUES of an INSERT".into()),
+            parse_sql_statements(sql).unwrap_err(),
+            "{}",
+            sql
+        );
+    }
+    let res = parse_sql_statements("INSERT INTO t (a) DEFAULT VALUES");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected SELECT, VALUES, or a subquery in the query body, found: DEFAULT".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
 #[test]
 fn parse_insert_invalid() {
     let sql = "INSERT public.customer (id, name, active) VALUES (1, 2, 3)";
@@ -109,26 +197,67 @@ fn parse_update() {
                 assignments,
                 vec![
                     Assignment {
-                        id: "a".into(),
+                        target: AssignmentTarget::ColumnName(ObjectName(vec!["a".into()])),
                         value: Expr::Value(number("1")),
                     },
                     Assignment {
-                        id: "b".into(),
+                        target: AssignmentTarget::ColumnName(ObjectName(vec!["b".into()])),
                         value: Expr::Value(number("2")),
                     },
                     Assignment {
-                        id: "c".into(),
+                        target: AssignmentTarget::ColumnName(ObjectName(vec!["c".into()])),
                         value: Expr::Value(number("3")),
                     },
                 ]
             );
-            assert_eq!(selection.unwrap(), Expr::Identifier("d".into()));
+            assert_eq!(
+                selection.unwrap(),
+                DmlSelection::Expr(Expr::Identifier("d".into()))
+            );
         }
         _ => unreachable!(),
     }
 
     verified_stmt("UPDATE t SET a = 1, a = 2, a = 3");
 
+    match verified_stmt("UPDATE t SET t.a = 1, (b, t.c) = (2, DEFAULT_C())") {
+        Statement::Update { assignments, .. } => assert_eq!(
+            assignments,
+            vec![
+                Assignment {
+                    target: AssignmentTarget::ColumnName(ObjectName(vec!["t".into(), "a".into()])),
+                    value: Expr::Value(number("1")),
+                },
+                Assignment {
+                    target: AssignmentTarget::Tuple(vec![
+                        ObjectName(vec!["b".into()]),
+                        ObjectName(vec!["t".into(), "c".into()]),
+                    ]),
+                    value: Expr::Row(vec![
+                        Expr::Value(number("2")),
+                        Expr::Function(Function {
+                            name: ObjectName(vec!["DEFAULT_C".into()]),
+                            args: vec![],
+                            over: None,
+                            distinct: false,
+                            special: false,
+                        }),
+                    ]),
+                },
+            ]
+        ),
+        _ => unreachable!(),
+    }
+
+    match verified_stmt("UPDATE t SET (a, b) = (SELECT x, y FROM u)") {
+        Statement::Update { assignments, .. } => match &assignments[0].value {
+            Expr::Subquery(_) => (),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+    verified_stmt("UPDATE t SET (a) = (1)");
+
     let sql = "UPDATE t WHERE 1";
     let res = parse_sql_statements(sql);
     assert_eq!(
@@ -170,6 +299,50 @@ fn parse_delete_statement() {
     }
 }
 
+#[test]
+fn parse_dml_aliases() {
+    match verified_stmt("UPDATE t AS x SET a = 1 WHERE x.b = 2") {
+        Statement::Update { alias, .. } => assert_eq!(Some(Ident::new("x")), alias),
+        _ => unreachable!(),
+    }
+    match verified_stmt("DELETE FROM t AS x WHERE x.a = 1") {
+        Statement::Delete { alias, .. } => assert_eq!(Some(Ident::new("x")), alias),
+        _ => unreachable!(),
+    }
+    one_statement_parses_to("UPDATE t x SET a = 1", "UPDATE t AS x SET a = 1");
+    one_statement_parses_to("DELETE FROM t x", "DELETE FROM t AS x");
+    verified_stmt("INSERT INTO t AS x VALUES (1)");
+    match verified_stmt("DELETE FROM t") {
+        Statement::Delete { alias, .. } => assert_eq!(None, alias),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_update_delete_current_of() {
+    match verified_stmt("UPDATE t SET a = 1 WHERE CURRENT OF c") {
+        Statement::Update { selection, .. } => {
+            assert_eq!(Some(DmlSelection::CurrentOf(Ident::new("c"))), selection);
+        }
+        _ => unreachable!(),
+    }
+    match verified_stmt("DELETE FROM t WHERE CURRENT OF \"my cursor\"") {
+        Statement::Delete { selection, .. } => {
+            assert_eq!(
+                Some(DmlSelection::CurrentOf(Ident::with_quote('"', "my cursor"))),
+                selection
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    let res = parse_sql_statements("DELETE FROM t WHERE CURRENT OF");
+    assert_eq!(
+        ParserError::ParserError("Expected identifier, found: EOF".to_string()),
+        res.unwrap_err()
+    );
+}
+
 #[test]
 fn parse_where_delete_statement() {
     use self::BinaryOperator::*;
@@ -184,11 +357,11 @@ fn parse_where_delete_statement() {
             assert_eq!(ObjectName(vec![Ident::new("foo")]), table_name);
 
             assert_eq!(
-                Expr::BinaryOp {
+                DmlSelection::Expr(Expr::BinaryOp {
                     left: Box::new(Expr::Identifier(Ident::new("name"))),
                     op: Eq,
                     right: Box::new(Expr::Value(number("5"))),
-                },
+                }),
                 selection.unwrap(),
             );
         }
@@ -253,24 +426,69 @@ fn parse_select_all_distinct() {
 fn parse_select_wildcard() {
     let sql = "SELECT * FROM foo";
     let select = verified_only_select(sql);
-    assert_eq!(&SelectItem::Wildcard, only(&select.projection));
+    assert_eq!(
+        &SelectItem::Wildcard(WildcardOptions::default()),
+        only(&select.projection)
+    );
 
     let sql = "SELECT foo.* FROM foo";
     let select = verified_only_select(sql);
     assert_eq!(
-        &SelectItem::QualifiedWildcard(ObjectName(vec![Ident::new("foo")])),
+        &SelectItem::QualifiedWildcard(
+            ObjectName(vec![Ident::new("foo")]),
+            WildcardOptions::default()
+        ),
         only(&select.projection)
     );
 
     let sql = "SELECT myschema.mytable.* FROM myschema.mytable";
     let select = verified_only_select(sql);
     assert_eq!(
-        &SelectItem::QualifiedWildcard(ObjectName(vec![
-            Ident::new("myschema"),
-            Ident::new("mytable"),
-        ])),
+        &SelectItem::QualifiedWildcard(
+            ObjectName(vec![Ident::new("myschema"), Ident::new("mytable")]),
+            WildcardOptions::default()
+        ),
         only(&select.projection)
     );
+
+    for sql in &["SELECT * AS foo FROM foo", "SELECT foo.* AS bar FROM foo"] {
+        assert_eq!(
+            ParserError::ParserError("A wildcard can't have an alias".to_string()),
+            parse_sql_statements(sql).unwrap_err()
+        );
+    }
+}
+
+#[test]
+fn parse_select_item_spans() {
+    let sql = "SELECT a,\n  b + 1 AS c , t.* EXCEPT (d), * FROM t";
+    let select = all_dialects().unverified_only_select(sql);
+    let items: Vec<_> = select
+        .projection_spans
+        .iter()
+        .map(|span| &sql[span.start..span.end])
+        .collect();
+    assert_eq!(vec!["a", "b + 1 AS c", "t.* EXCEPT (d)", "*"], items);
+}
+
+#[test]
+fn parse_case_spans() {
+    let sql = "SELECT CASE x WHEN 1 THEN 'a'\n  WHEN 2 THEN 'b' ELSE  'c' END";
+    let select = all_dialects().unverified_only_select(sql);
+    let (when_clauses, else_span) = match expr_from_projection(only(&select.projection)) {
+        Expr::Case {
+            when_clauses,
+            else_span,
+            ..
+        } => (when_clauses, else_span),
+        e => panic!("expected CASE, got {:?}", e),
+    };
+    let spans: Vec<_> = when_clauses
+        .iter()
+        .map(|w| &sql[w.span.start..w.span.end])
+        .collect();
+    assert_eq!(vec!["WHEN 1 THEN 'a'", "WHEN 2 THEN 'b'"], spans);
+    assert_eq!("ELSE  'c'", &sql[else_span.start..else_span.end]);
 }
 
 #[test]
@@ -327,6 +545,7 @@ fn parse_select_count_wildcard() {
             args: vec![Expr::Wildcard],
             over: None,
             distinct: false,
+            special: false,
         }),
         expr_from_projection(only(&select.projection))
     );
@@ -345,6 +564,7 @@ fn parse_select_count_distinct() {
             }],
             over: None,
             distinct: true,
+            special: false,
         }),
         expr_from_projection(only(&select.projection))
     );
@@ -381,13 +601,16 @@ fn parse_parameters() {
         verified_expr("$91 + $42"),
     );
 
-    let res = parse_sql_statements("SELECT $q");
-    assert_eq!(
-        ParserError::TokenizerError(
-            "parameter marker ($) was not followed by at least one digit".into()
-        ),
-        res.unwrap_err()
-    );
+    match parse_sql_statements("SELECT $q") {
+        Err(ParserError::TokenizerError(e)) => {
+            assert_eq!(
+                "parameter marker ($) was not followed by at least one digit at line 1, column 8",
+                e.to_string()
+            );
+            assert_eq!((1, 8, 7), (e.line, e.col, e.offset));
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
 
     let res = parse_sql_statements("SELECT $1$2");
     assert_eq!(
@@ -615,10 +838,11 @@ fn parse_not_precedence() {
         verified_expr(sql),
         Expr::UnaryOp {
             op: UnaryOperator::Not,
-            expr: Box::new(Expr::BinaryOp {
-                left: Box::new(Expr::Value(Value::SingleQuotedString("a".into()))),
-                op: BinaryOperator::NotLike,
-                right: Box::new(Expr::Value(Value::SingleQuotedString("b".into()))),
+            expr: Box::new(Expr::Like {
+                expr: Box::new(Expr::Value(Value::SingleQuotedString("a".into()))),
+                negated: true,
+                pattern: Box::new(Expr::Value(Value::SingleQuotedString("b".into()))),
+                escape_char: None,
             }),
         },
     );
@@ -647,14 +871,27 @@ fn parse_like() {
         );
         let select = verified_only_select(sql);
         assert_eq!(
-            Expr::BinaryOp {
-                left: Box::new(Expr::Identifier(Ident::new("name"))),
-                op: if negated {
-                    BinaryOperator::NotLike
-                } else {
-                    BinaryOperator::Like
-                },
-                right: Box::new(Expr::Value(Value::SingleQuotedString("%a".to_string()))),
+            Expr::Like {
+                expr: Box::new(Expr::Identifier(Ident::new("name"))),
+                negated,
+                pattern: Box::new(Expr::Value(Value::SingleQuotedString("%a".to_string()))),
+                escape_char: None,
+            },
+            select.selection.unwrap()
+        );
+
+        // Test with escape char
+        let sql = &format!(
+            "SELECT * FROM customers WHERE name {}LIKE '%a' ESCAPE '\\'",
+            if negated { "NOT " } else { "" }
+        );
+        let select = verified_only_select(sql);
+        assert_eq!(
+            Expr::Like {
+                expr: Box::new(Expr::Identifier(Ident::new("name"))),
+                negated,
+                pattern: Box::new(Expr::Value(Value::SingleQuotedString("%a".to_string()))),
+                escape_char: Some('\\'),
             },
             select.selection.unwrap()
         );
@@ -667,20 +904,72 @@ fn parse_like() {
         );
         let select = verified_only_select(sql);
         assert_eq!(
-            Expr::IsNull(Box::new(Expr::BinaryOp {
-                left: Box::new(Expr::Identifier(Ident::new("name"))),
-                op: if negated {
-                    BinaryOperator::NotLike
-                } else {
-                    BinaryOperator::Like
-                },
-                right: Box::new(Expr::Value(Value::SingleQuotedString("%a".to_string()))),
+            Expr::IsNull(Box::new(Expr::Like {
+                expr: Box::new(Expr::Identifier(Ident::new("name"))),
+                negated,
+                pattern: Box::new(Expr::Value(Value::SingleQuotedString("%a".to_string()))),
+                escape_char: None,
             })),
             select.selection.unwrap()
         );
     }
     chk(false);
     chk(true);
+
+    verified_stmt("SELECT * FROM t WHERE a LIKE b ESCAPE '''' AND c");
+
+    let res = parse_sql_statements("SELECT * FROM t WHERE a LIKE 'x' ESCAPE 'ab'");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected a single character after ESCAPE, found: 'ab'".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_ilike() {
+    let select = verified_only_select("SELECT * FROM customers WHERE name NOT ILIKE '%a'");
+    assert_eq!(
+        Expr::ILike {
+            expr: Box::new(Expr::Identifier(Ident::new("name"))),
+            negated: true,
+            pattern: Box::new(Expr::Value(Value::SingleQuotedString("%a".to_string()))),
+            escape_char: None,
+        },
+        select.selection.unwrap()
+    );
+
+    verified_stmt("SELECT * FROM customers WHERE name ILIKE '%a' ESCAPE '^'");
+}
+
+#[test]
+fn parse_similar_to() {
+    let select = verified_only_select("SELECT * FROM customers WHERE name SIMILAR TO '%(b|d)%'");
+    assert_eq!(
+        Expr::SimilarTo {
+            expr: Box::new(Expr::Identifier(Ident::new("name"))),
+            negated: false,
+            pattern: Box::new(Expr::Value(Value::SingleQuotedString(
+                "%(b|d)%".to_string()
+            ))),
+            escape_char: None,
+        },
+        select.selection.unwrap()
+    );
+
+    verified_stmt("SELECT * FROM customers WHERE name NOT SIMILAR TO '%a' ESCAPE '^' IS NULL");
+    // SIMILAR not followed by TO is an alias
+    one_statement_parses_to("SELECT a similar FROM t", "SELECT a AS similar FROM t");
+
+    let res = parse_sql_statements("SELECT * FROM t WHERE a NOT SIMILAR 'x'");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected IN, BETWEEN, LIKE, ILIKE, or SIMILAR TO after NOT, found: SIMILAR"
+                .to_string()
+        ),
+        res.unwrap_err()
+    );
 }
 
 #[test]
@@ -863,7 +1152,8 @@ fn parse_select_having() {
                 name: ObjectName(vec![Ident::new("COUNT")]),
                 args: vec![Expr::Wildcard],
                 over: None,
-                distinct: false
+                distinct: false,
+                special: false
             })),
             op: BinaryOperator::Gt,
             right: Box::new(Expr::Value(number("1")))
@@ -901,15 +1191,105 @@ fn parse_cast() {
     );
 
     verified_stmt("SELECT CAST(id AS numeric) FROM customer");
+    verified_stmt("SELECT CAST(id AS dec) FROM customer");
+    verified_stmt("SELECT CAST(id AS decimal) FROM customer");
+}
+
+#[test]
+fn parse_decimal_datatypes() {
+    for (sql, name) in &[
+        ("numeric", DecimalName::Numeric),
+        ("decimal", DecimalName::Decimal),
+        ("dec", DecimalName::Dec),
+    ] {
+        for (modifiers, precision, scale) in &[
+            ("", None, None),
+            ("(10)", Some(10), None),
+            ("(10,2)", Some(10), Some(2)),
+            ("(1,0)", Some(1), Some(0)),
+        ] {
+            let sql = format!("SELECT CAST(a AS {}{})", sql, modifiers);
+            let select = verified_only_select(&sql);
+            assert_eq!(
+                &Expr::Cast {
+                    expr: Box::new(Expr::Identifier(Ident::new("a"))),
+                    data_type: DataType::Decimal {
+                        name: *name,
+                        precision: *precision,
+                        scale: *scale,
+                    },
+                },
+                expr_from_projection(only(&select.projection))
+            );
+        }
+    }
+    one_statement_parses_to(
+        "SELECT CAST(a AS DECIMAL(10, 2)), CAST(b AS Numeric)",
+        "SELECT CAST(a AS decimal(10,2)), CAST(b AS numeric)",
+    );
+
+    // A scale is only written after a precision
+    let data_type = DataType::Decimal {
+        name: DecimalName::Numeric,
+        precision: None,
+        scale: Some(2),
+    };
+    assert_eq!("numeric", data_type.to_string());
 
+    // The largest precision depends on the dialect
+    let sql = "SELECT CAST(a AS decimal(66,2))";
+    TestedDialects::named(&["postgres", "generic"]).verified_stmt(sql);
+    TestedDialects::named(&["mysql"])
+        .assert_parse_error(sql, "decimal precision 66 exceeds the maximum of 65");
+    TestedDialects::named(&["postgres"]).assert_parse_error(
+        "SELECT CAST(a AS NUMERIC(1001))",
+        "numeric precision 1001 exceeds the maximum of 1000",
+    );
+}
+
+#[test]
+fn parse_time_datatypes() {
+    let select = verified_only_select("SELECT CAST(a AS timestamp(6) with time zone)");
+    assert_eq!(
+        &Expr::Cast {
+            expr: Box::new(Expr::Identifier(Ident::new("a"))),
+            data_type: DataType::Timestamp {
+                precision: Some(6),
+                with_time_zone: true,
+            },
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    verified_stmt("SELECT CAST(a AS time(3)), CAST(b AS time with time zone)");
+    one_statement_parses_to(
+        "SELECT CAST(a AS TIMESTAMP(0) WITHOUT TIME ZONE), CAST(b AS TIMESTAMPTZ(3))",
+        "SELECT CAST(a AS timestamp(0)), CAST(b AS timestamp(3) with time zone)",
+    );
     one_statement_parses_to(
-        "SELECT CAST(id AS dec) FROM customer",
-        "SELECT CAST(id AS numeric) FROM customer",
+        "SELECT CAST(a AS TIME WITHOUT TIME ZONE)",
+        "SELECT CAST(a AS time)",
+    );
+}
+
+#[test]
+fn parse_character_datatypes() {
+    let select = verified_only_select("SELECT CAST(a AS character varying(100 characters))");
+    assert_eq!(
+        &Expr::Cast {
+            expr: Box::new(Expr::Identifier(Ident::new("a"))),
+            data_type: DataType::Varchar(Some(CharacterLength::IntegerLength {
+                length: 100,
+                unit: Some(CharLengthUnits::Characters),
+            })),
+        },
+        expr_from_projection(only(&select.projection))
     );
 
+    verified_stmt("CREATE TABLE t (a char(10 octets), b nchar, c nvarchar(20))");
     one_statement_parses_to(
-        "SELECT CAST(id AS decimal) FROM customer",
-        "SELECT CAST(id AS numeric) FROM customer",
+        "CREATE TABLE t (a NATIONAL CHARACTER(10), b NATIONAL CHAR VARYING(20), c NCHAR VARYING)",
+        "CREATE TABLE t (a nchar(10), b nvarchar(20), c nvarchar)",
     );
 }
 
@@ -922,7 +1302,7 @@ fn parse_array_datatype() {
             expr: Box::new(Expr::Value(Value::SingleQuotedString(
                 "{{1,2},{3,4}}".to_owned()
             ))),
-            data_type: DataType::Array(Box::new(DataType::Int)),
+            data_type: DataType::Array(Box::new(DataType::Int), None),
         },
         expr_from_projection(only(&select.projection))
     );
@@ -984,6 +1364,54 @@ fn parse_extract() {
     );
 }
 
+#[test]
+fn parse_trim() {
+    let select = verified_only_select("SELECT TRIM(LEADING '0' FROM a)");
+    assert_eq!(
+        &Expr::Trim {
+            expr: Box::new(Expr::Identifier(Ident::new("a"))),
+            trim_where: Some(TrimWhereField::Leading),
+            trim_what: Some(Box::new(Expr::Value(Value::SingleQuotedString(
+                "0".to_string()
+            )))),
+        },
+        expr_from_projection(only(&select.projection)),
+    );
+
+    verified_stmt("SELECT TRIM(a)");
+    verified_stmt("SELECT TRIM(' ' FROM a)");
+    verified_stmt("SELECT TRIM(BOTH FROM a)");
+    verified_stmt("SELECT TRIM(TRAILING 'xy' || 'z' FROM a || b)");
+
+    let res = parse_sql_statements("SELECT TRIM(BOTH 'x' a)");
+    assert_eq!(
+        ParserError::ParserError("Expected FROM, found: a".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_overlay() {
+    let select = verified_only_select("SELECT OVERLAY(a PLACING 'xyz' FROM 2 FOR 3)");
+    assert_eq!(
+        &Expr::Overlay {
+            expr: Box::new(Expr::Identifier(Ident::new("a"))),
+            overlay_what: Box::new(Expr::Value(Value::SingleQuotedString("xyz".to_string()))),
+            overlay_from: Box::new(Expr::Value(number("2"))),
+            overlay_for: Some(Box::new(Expr::Value(number("3")))),
+        },
+        expr_from_projection(only(&select.projection)),
+    );
+
+    verified_stmt("SELECT OVERLAY(a PLACING b FROM 1 + 1)");
+
+    let res = parse_sql_statements("SELECT OVERLAY(a FROM 2)");
+    assert_eq!(
+        ParserError::ParserError("Expected PLACING, found: FROM".to_string()),
+        res.unwrap_err()
+    );
+}
+
 #[test]
 fn parse_create_table() {
     let sql = "CREATE TABLE uk_cities (\
@@ -1010,6 +1438,9 @@ fn parse_create_table() {
             external: false,
             file_format: None,
             location: None,
+            without_rowid: false,
+            engine: None,
+            order_by: _,
         } => {
             assert_eq!("uk_cities", name.to_string());
             assert_eq!(
@@ -1017,7 +1448,10 @@ fn parse_create_table() {
                 vec![
                     ColumnDef {
                         name: "name".into(),
-                        data_type: DataType::Varchar(Some(100)),
+                        data_type: DataType::Varchar(Some(CharacterLength::IntegerLength {
+                            length: 100,
+                            unit: None,
+                        })),
                         collation: None,
                         options: vec![ColumnOptionDef {
                             name: None,
@@ -1050,7 +1484,10 @@ fn parse_create_table() {
                             },
                             ColumnOptionDef {
                                 name: Some("pkey".into()),
-                                option: ColumnOption::Unique { is_primary: true }
+                                option: ColumnOption::Unique {
+                                    is_primary: true,
+                                    characteristics: None,
+                                }
                             },
                             ColumnOptionDef {
                                 name: None,
@@ -1058,11 +1495,17 @@ fn parse_create_table() {
                             },
                             ColumnOptionDef {
                                 name: None,
-                                option: ColumnOption::Unique { is_primary: false },
+                                option: ColumnOption::Unique {
+                                    is_primary: false,
+                                    characteristics: None,
+                                },
                             },
                             ColumnOptionDef {
                                 name: None,
-                                option: ColumnOption::Check(verified_expr("constrained > 0")),
+                                option: ColumnOption::Check {
+                                    expr: verified_expr("constrained > 0"),
+                                    characteristics: None,
+                                },
                             }
                         ],
                     },
@@ -1075,6 +1518,9 @@ fn parse_create_table() {
                             option: ColumnOption::ForeignKey {
                                 foreign_table: ObjectName(vec!["othertable".into()]),
                                 referred_columns: vec!["a".into(), "b".into(),],
+                                on_delete: None,
+                                on_update: None,
+                                characteristics: None,
                             }
                         }]
                     }
@@ -1100,11 +1546,11 @@ fn parse_create_table_with_options() {
         Statement::CreateTable { with_options, .. } => {
             assert_eq!(
                 vec![
-                    SqlOption {
+                    SqlOption::Value {
                         name: "foo".into(),
                         value: Value::SingleQuotedString("bar".into())
                     },
-                    SqlOption {
+                    SqlOption::Value {
                         name: "a".into(),
                         value: number("123")
                     },
@@ -1128,7 +1574,7 @@ fn parse_create_external_table() {
                name VARCHAR(100) NOT NULL,\
                lat DOUBLE NULL,\
                lng DOUBLE)\
-               STORED AS TEXTFILE LOCATION '/tmp/example.csv";
+               STORED AS TEXTFILE LOCATION '/tmp/example.csv'";
     let ast = one_statement_parses_to(
         sql,
         "CREATE EXTERNAL TABLE uk_cities (\
@@ -1146,6 +1592,9 @@ fn parse_create_external_table() {
             external,
             file_format,
             location,
+            without_rowid: false,
+            engine: None,
+            order_by: _,
         } => {
             assert_eq!("uk_cities", name.to_string());
             assert_eq!(
@@ -1153,7 +1602,10 @@ fn parse_create_external_table() {
                 vec![
                     ColumnDef {
                         name: "name".into(),
-                        data_type: DataType::Varchar(Some(100)),
+                        data_type: DataType::Varchar(Some(CharacterLength::IntegerLength {
+                            length: 100,
+                            unit: None,
+                        })),
                         collation: None,
                         options: vec![ColumnOptionDef {
                             name: None,
@@ -1189,6 +1641,78 @@ fn parse_create_external_table() {
     }
 }
 
+#[test]
+fn parse_create_table_referential_actions() {
+    let sql = "CREATE TABLE t (\
+               a int REFERENCES other (a) ON DELETE CASCADE ON UPDATE SET DEFAULT, \
+               b int REFERENCES other (b) ON DELETE NO ACTION, \
+               c int REFERENCES other (c) ON UPDATE RESTRICT, \
+               FOREIGN KEY (a, b) REFERENCES other(a, b) ON DELETE SET NULL)";
+    match verified_stmt(sql) {
+        Statement::CreateTable {
+            columns,
+            constraints,
+            ..
+        } => {
+            let actions: Vec<_> = columns
+                .iter()
+                .map(|c| match &c.options[0].option {
+                    ColumnOption::ForeignKey {
+                        on_delete,
+                        on_update,
+                        ..
+                    } => (*on_delete, *on_update),
+                    _ => unreachable!(),
+                })
+                .collect();
+            assert_eq!(
+                vec![
+                    (
+                        Some(ReferentialAction::Cascade),
+                        Some(ReferentialAction::SetDefault)
+                    ),
+                    (Some(ReferentialAction::NoAction), None),
+                    (None, Some(ReferentialAction::Restrict)),
+                ],
+                actions
+            );
+            match &constraints[0] {
+                TableConstraint::ForeignKey {
+                    on_delete,
+                    on_update,
+                    ..
+                } => {
+                    assert_eq!(Some(ReferentialAction::SetNull), *on_delete);
+                    assert_eq!(None, *on_update);
+                }
+                _ => unreachable!(),
+            }
+        }
+        _ => unreachable!(),
+    }
+
+    // the actions may appear in either order, but not twice
+    one_statement_parses_to(
+        "CREATE TABLE t (a int REFERENCES o (a) ON UPDATE CASCADE ON DELETE RESTRICT)",
+        "CREATE TABLE t (a int REFERENCES o (a) ON DELETE RESTRICT ON UPDATE CASCADE)",
+    );
+    let res = parse_sql_statements(
+        "CREATE TABLE t (a int REFERENCES o (a) ON DELETE CASCADE ON DELETE CASCADE)",
+    );
+    assert_eq!(
+        ParserError::ParserError("Expected column option, found: ON".to_string()),
+        res.unwrap_err()
+    );
+    let res = parse_sql_statements("CREATE TABLE t (a int REFERENCES o (a) ON DELETE SET)");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected one of RESTRICT, CASCADE, SET NULL, NO ACTION or SET DEFAULT, found: SET"
+                .to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
 #[test]
 fn parse_create_table_empty() {
     // Zero-column tables are weird, but supported by at least PostgreSQL.
@@ -1208,11 +1732,16 @@ fn parse_alter_table_constraints() {
     check_one("PRIMARY KEY (foo, bar)");
     check_one("UNIQUE (id)");
     check_one("FOREIGN KEY (foo, bar) REFERENCES AnotherTable(foo, bar)");
-    check_one("CHECK (end_date > start_date OR end_date IS NULL)");
+    check_one(
+        "FOREIGN KEY (foo) REFERENCES AnotherTable(foo) \
+         ON DELETE CASCADE ON UPDATE SET NULL",
+    );
+    check_one("CHECK (end_date > start_date OR end_date IS NULL)");
 
     fn check_one(constraint_text: &str) {
         match verified_stmt(&format!("ALTER TABLE tab ADD {}", constraint_text)) {
             Statement::AlterTable {
+                only: false,
                 name,
                 operation: AlterTableOperation::AddConstraint(constraint),
             } => {
@@ -1254,11 +1783,54 @@ fn parse_scalar_function_in_projection() {
             args: vec![Expr::Identifier(Ident::new("id"))],
             over: None,
             distinct: false,
+            special: false,
         }),
         expr_from_projection(only(&select.projection))
     );
 }
 
+#[test]
+fn parse_niladic_functions() {
+    for name in &[
+        "CURRENT_DATE",
+        "CURRENT_TIME",
+        "CURRENT_TIMESTAMP",
+        "LOCALTIME",
+        "LOCALTIMESTAMP",
+        "CURRENT_USER",
+        "SESSION_USER",
+        "CURRENT_ROLE",
+        "CURRENT_CATALOG",
+        "CURRENT_SCHEMA",
+    ] {
+        let select = verified_only_select(&format!("SELECT {}", name));
+        assert_eq!(
+            &Expr::Function(Function {
+                name: ObjectName(vec![Ident::new(*name)]),
+                args: vec![],
+                over: None,
+                distinct: false,
+                special: true,
+            }),
+            expr_from_projection(only(&select.projection))
+        );
+    }
+
+    let select = verified_only_select("SELECT CURRENT_TIMESTAMP(3)");
+    assert_eq!(
+        &Expr::Function(Function {
+            name: ObjectName(vec![Ident::new("CURRENT_TIMESTAMP")]),
+            args: vec![Expr::Value(number("3"))],
+            over: None,
+            distinct: false,
+            special: false,
+        }),
+        expr_from_projection(only(&select.projection))
+    );
+
+    verified_only_select("SELECT * FROM t WHERE created_at < current_timestamp - INTERVAL '1' DAY");
+}
+
 #[test]
 fn parse_window_functions() {
     let sql = "SELECT row_number() OVER (ORDER BY dt DESC), \
@@ -1284,9 +1856,303 @@ fn parse_window_functions() {
                 window_frame: None,
             }),
             distinct: false,
+            special: false,
         }),
         expr_from_projection(&select.projection[0])
     );
+
+    let sql = "SELECT sum(a) OVER (ORDER BY b ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING \
+               EXCLUDE CURRENT ROW), \
+               sum(a) OVER (RANGE UNBOUNDED PRECEDING EXCLUDE GROUP), \
+               sum(a) OVER (GROUPS 1 PRECEDING EXCLUDE TIES), \
+               sum(a) OVER (ROWS 1 PRECEDING EXCLUDE NO OTHERS) \
+               FROM foo";
+    let select = verified_only_select(sql);
+    let exclusions: Vec<_> = select
+        .projection
+        .iter()
+        .map(|item| match expr_from_projection(item) {
+            Expr::Function(Function {
+                over: Some(over), ..
+            }) => over.window_frame.as_ref().unwrap().exclude.unwrap(),
+            _ => unreachable!(),
+        })
+        .collect();
+    assert_eq!(
+        vec![
+            WindowFrameExclusion::CurrentRow,
+            WindowFrameExclusion::Group,
+            WindowFrameExclusion::Ties,
+            WindowFrameExclusion::NoOthers,
+        ],
+        exclusions
+    );
+
+    let res = parse_sql_statements("SELECT sum(a) OVER (ROWS 1 PRECEDING EXCLUDE ALL) FROM foo");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected CURRENT ROW, GROUP, TIES or NO OTHERS after EXCLUDE, found: ALL".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn validate_window_frames() {
+    fn validate(frame: &str) -> Result<(), WindowFrameError> {
+        let sql = format!("SELECT sum(a) OVER (ORDER BY b {}) FROM foo", frame);
+        match expr_from_projection(only(&verified_only_select(&sql).projection)) {
+            Expr::Function(Function {
+                over: Some(over), ..
+            }) => over.window_frame.as_ref().unwrap().validate(),
+            _ => unreachable!(),
+        }
+    }
+
+    assert_eq!(Ok(()), validate("ROWS UNBOUNDED PRECEDING"));
+    assert_eq!(
+        Ok(()),
+        validate("GROUPS BETWEEN 1 FOLLOWING AND 2 FOLLOWING")
+    );
+    assert_eq!(
+        Ok(()),
+        validate("RANGE BETWEEN 1 PRECEDING AND 2 PRECEDING")
+    );
+    assert_eq!(
+        Err(WindowFrameError::StartUnboundedFollowing),
+        validate("ROWS BETWEEN UNBOUNDED FOLLOWING AND UNBOUNDED FOLLOWING")
+    );
+    assert_eq!(
+        Err(WindowFrameError::EndUnboundedPreceding),
+        validate("ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED PRECEDING")
+    );
+    assert_eq!(
+        Err(WindowFrameError::StartAfterEnd {
+            start: WindowFrameBound::CurrentRow,
+            end: WindowFrameBound::Preceding(Some(1)),
+        }),
+        validate("ROWS BETWEEN CURRENT ROW AND 1 PRECEDING")
+    );
+    let err = validate("GROUPS 1 FOLLOWING").unwrap_err();
+    assert_eq!(
+        "frame starting from 1 FOLLOWING cannot end with CURRENT ROW",
+        err.to_string()
+    );
+}
+
+#[test]
+fn parse_table_version() {
+    let select = verified_only_select(
+        "SELECT * FROM t FOR SYSTEM_TIME AS OF '2020-01-01' AS a, u FOR SYSTEM_TIME BETWEEN 1 AND 2",
+    );
+    match &select.from[0].relation {
+        TableFactor::Table { version, alias, .. } => {
+            assert_eq!(
+                &Some(TableVersion::AsOf(Box::new(Expr::Value(
+                    Value::SingleQuotedString("2020-01-01".to_string())
+                )))),
+                version
+            );
+            assert_eq!(Ident::new("a"), alias.as_ref().unwrap().name);
+        }
+        _ => unreachable!(),
+    }
+    match &select.from[1].relation {
+        TableFactor::Table { version, .. } => assert_eq!(
+            &Some(TableVersion::Between(
+                Box::new(Expr::Value(number("1"))),
+                Box::new(Expr::Value(number("2")))
+            )),
+            version
+        ),
+        _ => unreachable!(),
+    }
+    verified_stmt("SELECT * FROM t FOR SYSTEM_TIME FROM a - 1 TO a");
+    verified_stmt("SELECT * FROM t FOR UPDATE");
+
+    let res = parse_sql_statements("SELECT * FROM t FOR SYSTEM_TIME ALL");
+    assert_eq!(
+        ParserError::ParserError("Expected AS OF, FROM or BETWEEN, found: ALL".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_json_table() {
+    let sql = "SELECT * FROM JSON_TABLE(t.doc, '$.items[*]' COLUMNS (\
+               n FOR ORDINALITY, \
+               name text PATH '$.name' DEFAULT 'x' ON EMPTY ERROR ON ERROR, \
+               has_id boolean EXISTS PATH '$.id', \
+               NESTED PATH '$.tags[*]' COLUMNS (tag text PATH '$')\
+               )) AS j";
+    let select = verified_only_select(sql);
+    match &select.from[0].relation {
+        TableFactor::JsonTable {
+            json_expr,
+            json_path,
+            columns,
+            alias,
+        } => {
+            assert_eq!(
+                Expr::CompoundIdentifier(vec![Ident::new("t"), Ident::new("doc")]),
+                **json_expr
+            );
+            assert_eq!(
+                &Value::SingleQuotedString("$.items[*]".to_string()),
+                json_path
+            );
+            assert_eq!(
+                vec![
+                    JsonTableColumn::ForOrdinality(Ident::new("n")),
+                    JsonTableColumn::Named(Box::new(JsonTableNamedColumn {
+                        name: Ident::new("name"),
+                        data_type: DataType::Text,
+                        path: Value::SingleQuotedString("$.name".to_string()),
+                        exists: false,
+                        on_empty: Some(JsonTableColumnErrorHandling::Default(
+                            Value::SingleQuotedString("x".to_string())
+                        )),
+                        on_error: Some(JsonTableColumnErrorHandling::Error),
+                    })),
+                    JsonTableColumn::Named(Box::new(JsonTableNamedColumn {
+                        name: Ident::new("has_id"),
+                        data_type: DataType::Boolean,
+                        path: Value::SingleQuotedString("$.id".to_string()),
+                        exists: true,
+                        on_empty: None,
+                        on_error: None,
+                    })),
+                    JsonTableColumn::Nested {
+                        path: Value::SingleQuotedString("$.tags[*]".to_string()),
+                        columns: vec![JsonTableColumn::Named(Box::new(JsonTableNamedColumn {
+                            name: Ident::new("tag"),
+                            data_type: DataType::Text,
+                            path: Value::SingleQuotedString("$".to_string()),
+                            exists: false,
+                            on_empty: None,
+                            on_error: None,
+                        }))],
+                    },
+                ],
+                *columns
+            );
+            assert_eq!(Ident::new("j"), alias.as_ref().unwrap().name);
+        }
+        _ => unreachable!(),
+    }
+
+    one_statement_parses_to(
+        "SELECT * FROM JSON_TABLE(d, '$[*]' COLUMNS (NESTED '$.a' COLUMNS (a int PATH '$'))) j",
+        "SELECT * FROM JSON_TABLE(d, '$[*]' COLUMNS (NESTED PATH '$.a' COLUMNS (a int PATH '$'))) AS j",
+    );
+    // Without a parenthesized argument list, `json_table` is a table name
+    verified_stmt("SELECT * FROM json_table");
+
+    let res = parse_sql_statements(
+        "SELECT * FROM JSON_TABLE(d, '$' COLUMNS (a int PATH '$' NULL ON EMPTY NULL ON EMPTY))",
+    );
+    assert_eq!(
+        ParserError::ParserError("Expected ON EMPTY or ON ERROR, found: ON".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_xml_table() {
+    let sql = "SELECT * FROM XMLTABLE(\
+               XMLNAMESPACES('http://example.com/a' AS a, DEFAULT 'http://example.com'), \
+               '//row' PASSING t.doc COLUMNS \
+               n FOR ORDINALITY, \
+               id int PATH '@id' NOT NULL, \
+               name text DEFAULT 'x', \
+               note text\
+               ) AS x";
+    let select = verified_only_select(sql);
+    match &select.from[0].relation {
+        TableFactor::XmlTable {
+            namespaces,
+            row_expr,
+            passing,
+            columns,
+            alias,
+        } => {
+            assert_eq!(
+                vec![
+                    XmlNamespace {
+                        uri: Expr::Value(Value::SingleQuotedString(
+                            "http://example.com/a".to_string()
+                        )),
+                        name: Some(Ident::new("a")),
+                    },
+                    XmlNamespace {
+                        uri: Expr::Value(Value::SingleQuotedString(
+                            "http://example.com".to_string()
+                        )),
+                        name: None,
+                    },
+                ],
+                *namespaces
+            );
+            assert_eq!(
+                Expr::Value(Value::SingleQuotedString("//row".to_string())),
+                **row_expr
+            );
+            assert_eq!(
+                Expr::CompoundIdentifier(vec![Ident::new("t"), Ident::new("doc")]),
+                **passing
+            );
+            assert_eq!(
+                vec![
+                    XmlTableColumn::ForOrdinality(Ident::new("n")),
+                    XmlTableColumn::Named(Box::new(XmlTableNamedColumn {
+                        name: Ident::new("id"),
+                        data_type: DataType::Int,
+                        path: Some(Expr::Value(Value::SingleQuotedString("@id".to_string()))),
+                        default: None,
+                        nullable: Some(false),
+                    })),
+                    XmlTableColumn::Named(Box::new(XmlTableNamedColumn {
+                        name: Ident::new("name"),
+                        data_type: DataType::Text,
+                        path: None,
+                        default: Some(Expr::Value(Value::SingleQuotedString("x".to_string()))),
+                        nullable: None,
+                    })),
+                    XmlTableColumn::Named(Box::new(XmlTableNamedColumn {
+                        name: Ident::new("note"),
+                        data_type: DataType::Text,
+                        path: None,
+                        default: None,
+                        nullable: None,
+                    })),
+                ],
+                *columns
+            );
+            assert_eq!(Ident::new("x"), alias.as_ref().unwrap().name);
+        }
+        _ => unreachable!(),
+    }
+
+    // The column options may be written in any order
+    one_statement_parses_to(
+        "SELECT * FROM XMLTABLE('/r' PASSING d COLUMNS a int DEFAULT 0 NOT NULL PATH 'a') x",
+        "SELECT * FROM XMLTABLE('/r' PASSING d COLUMNS a int PATH 'a' DEFAULT 0 NOT NULL) AS x",
+    );
+    // Without a parenthesized argument list, `xmltable` is a table name
+    verified_stmt("SELECT * FROM xmltable");
+
+    let res = parse_sql_statements("SELECT * FROM XMLTABLE('/r' COLUMNS a int)");
+    assert_eq!(
+        ParserError::ParserError("Expected PASSING, found: COLUMNS".to_string()),
+        res.unwrap_err()
+    );
+    let res = parse_sql_statements(
+        "SELECT * FROM XMLTABLE('/r' PASSING d COLUMNS a int PATH 'a' PATH 'b')",
+    );
+    assert_eq!(
+        ParserError::ParserError("Expected ), found: PATH".to_string()),
+        res.unwrap_err()
+    );
 }
 
 #[test]
@@ -1332,6 +2198,38 @@ fn parse_literal_string() {
     );
 
     one_statement_parses_to("SELECT x'deadBEEF'", "SELECT X'deadBEEF'");
+    one_statement_parses_to("SELECT n'national'", "SELECT N'national'");
+}
+
+#[test]
+fn parse_unicode_string_literal() {
+    let sql = r"SELECT U&'d\0061t\+000061 \\ it''s'";
+    let select = match one_statement_parses_to(sql, r"SELECT U&'data \\ it''s'") {
+        Statement::Query(query) => match query.body {
+            SetExpr::Select(select) => select,
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        &Expr::Value(Value::UnicodeStringLiteral(r"data \ it's".to_string())),
+        expr_from_projection(only(&select.projection))
+    );
+    one_statement_parses_to(
+        r"SELECT u&'d!0061t!+000061!!' UESCAPE '!'",
+        "SELECT U&'data!'",
+    );
+
+    let res = parse_sql_statements(r"SELECT U&'\00zz'");
+    assert_eq!(
+        ParserError::ParserError(r"Invalid Unicode escape '\00zz'".to_string()),
+        res.unwrap_err()
+    );
+    let res = parse_sql_statements(r"SELECT U&'a' UESCAPE '+'");
+    assert_eq!(
+        ParserError::ParserError("Invalid Unicode escape character '+'".to_string()),
+        res.unwrap_err()
+    );
 }
 
 #[test]
@@ -1388,7 +2286,7 @@ fn parse_literal_date() {
     );
     assert_eq!(
         ParserError::ParserError("Hours cannot be supplied for DATE, got 2 in '1-1-1 2'".into()),
-        parse_sql_statements("SELECT DATE '1-1-1 2").unwrap_err(),
+        parse_sql_statements("SELECT DATE '1-1-1 2'").unwrap_err(),
     );
 }
 
@@ -1619,8 +2517,6 @@ fn parse_literal_interval_error_messages() {
 
 #[test]
 fn parse_literal_interval_with_character_precision() {
-    use std::time::Duration;
-
     verify_interval(
         "SELECT INTERVAL '01:01.01' MINUTE (5) TO SECOND (5)",
         IntervalValue {
@@ -1636,10 +2532,7 @@ fn parse_literal_interval_with_character_precision() {
             last_field: Some(DateTimeField::Second),
             fractional_seconds_precision: Some(5),
         },
-        Interval::Duration {
-            is_positive: true,
-            duration: Duration::new(61, 10_000_000),
-        },
+        Interval::Duration(SignedDuration::new(61, 10_000_000)),
         Some("61.01s"),
         None,
     );
@@ -1657,10 +2550,7 @@ fn parse_literal_interval_with_character_precision() {
             last_field: None,
             fractional_seconds_precision: Some(4),
         },
-        Interval::Duration {
-            is_positive: true,
-            duration: Duration::from_secs(1),
-        },
+        Interval::Duration(SignedDuration::from_secs(1)),
         Some("1s"),
         None,
     );
@@ -1681,10 +2571,7 @@ fn parse_literal_interval_with_character_precision() {
             last_field: None,
             fractional_seconds_precision: None,
         },
-        Interval::Duration {
-            is_positive: true,
-            duration: Duration::from_secs(10 * 60 * 60),
-        },
+        Interval::Duration(SignedDuration::from_secs(10 * 60 * 60)),
         Some("36000s"),
         None,
     );
@@ -1692,8 +2579,6 @@ fn parse_literal_interval_with_character_precision() {
 
 #[test]
 fn parse_literal_interval_durationlike() {
-    use std::time::Duration;
-
     verify_interval(
         "SELECT INTERVAL '10' HOUR",
         IntervalValue {
@@ -1707,10 +2592,7 @@ fn parse_literal_interval_durationlike() {
             last_field: None,
             fractional_seconds_precision: None,
         },
-        Interval::Duration {
-            is_positive: true,
-            duration: Duration::from_secs(10 * 60 * 60),
-        },
+        Interval::Duration(SignedDuration::from_secs(10 * 60 * 60)),
         Some("36000s"),
         None,
     );
@@ -1820,10 +2702,7 @@ fn parse_literal_interval_durationlike() {
     verify_interval(
         "SELECT INTERVAL '1 1:1:1.1' DAY TO SECOND",
         iv.clone(),
-        Interval::Duration {
-            is_positive: true,
-            duration: Duration::new(3600 * 24 + 3600 + 60 + 1, 100_000_000),
-        },
+        Interval::Duration(SignedDuration::new(3600 * 24 + 3600 + 60 + 1, 100_000_000)),
         Some("90061.1s"),
         None,
     );
@@ -1884,10 +2763,7 @@ fn parse_literal_interval_durationlike() {
     verify_interval(
         "SELECT INTERVAL '-1' HOUR",
         iv,
-        Interval::Duration {
-            is_positive: false,
-            duration: Duration::from_secs(3600),
-        },
+        Interval::Duration(-SignedDuration::from_secs(3600)),
         Some("3600s"),
         None,
     );
@@ -1928,10 +2804,14 @@ fn parse_delimited_identifiers() {
     // check FROM
     match only(select.from).relation {
         TableFactor::Table {
+            only: false,
             name,
+            descendants: false,
+            version: None,
             alias,
             args,
             with_hints,
+            with_ordinality: false,
         } => {
             assert_eq!(vec![Ident::with_quote('"', "a table")], name.0);
             assert_eq!(Ident::with_quote('"', "alias"), alias.unwrap().name);
@@ -1955,6 +2835,7 @@ fn parse_delimited_identifiers() {
             args: vec![],
             over: None,
             distinct: false,
+            special: false,
         }),
         expr_from_projection(&select.projection[1]),
     );
@@ -1966,11 +2847,196 @@ fn parse_delimited_identifiers() {
         _ => panic!("Expected ExprWithAlias"),
     }
 
+    // a doubled quote inside a delimited identifier stands for itself
+    let select = verified_only_select(r#"SELECT a AS "say ""hi""" FROM t"#);
+    match only(&select.projection) {
+        SelectItem::ExprWithAlias { alias, .. } => {
+            assert_eq!(&Ident::with_quote('"', r#"say "hi""#), alias);
+        }
+        _ => panic!("Expected ExprWithAlias"),
+    }
+
+    // non-ASCII letters are kept as written, also when normalized
+    let select = verified_only_select(r#"SELECT "sıze" FROM "ﬁle""#);
+    let ident = Ident::with_quote('"', "sıze");
+    assert_eq!(
+        &Expr::Identifier(ident.clone()),
+        expr_from_projection(only(&select.projection))
+    );
+    assert_eq!(ident, ident.normalized(&GenericDialect {}));
+    assert_eq!(
+        Ident::new("SıZE"),
+        Ident::new("sıze").normalized(&GenericDialect {})
+    );
+
     verified_stmt(r#"CREATE TABLE "foo" ("bar" "int")"#);
     verified_stmt(r#"ALTER TABLE foo ADD CONSTRAINT "bar" PRIMARY KEY (baz)"#);
     //TODO verified_stmt(r#"UPDATE foo SET "bar" = 5"#);
 }
 
+#[test]
+fn ident_quote_if_needed() {
+    use sqlparser::dialect::{dialect_from_str, PostgreSqlDialect, DIALECT_NAMES};
+
+    let pg = PostgreSqlDialect {};
+    assert_eq!(Ident::new("foo"), Ident::quote_if_needed("foo", &pg));
+    assert_eq!(Ident::new("foo_1"), Ident::quote_if_needed("foo_1", &pg));
+    assert_eq!(
+        Ident::with_quote('"', "Foo"),
+        Ident::quote_if_needed("Foo", &pg)
+    );
+    assert_eq!(
+        Ident::with_quote('"', "select"),
+        Ident::quote_if_needed("select", &pg)
+    );
+    assert_eq!(
+        Ident::with_quote('"', "1a"),
+        Ident::quote_if_needed("1a", &pg)
+    );
+    assert_eq!(
+        "\"say \"\"hi\"\"\"",
+        Ident::quote_if_needed("say \"hi\"", &pg).to_string()
+    );
+    // Unquoted, FOO refers to foo in PostgreSQL but stays FOO in the standard
+    assert_eq!(
+        Ident::new("FOO"),
+        Ident::quote_if_needed("FOO", &GenericDialect {})
+    );
+    assert_eq!(
+        Ident::with_quote('"', "foo"),
+        Ident::quote_if_needed("foo", &GenericDialect {})
+    );
+    let hive = dialect_from_str("hive").unwrap();
+    assert_eq!(
+        Ident::with_quote('`', "select"),
+        Ident::quote_if_needed("select", &*hive)
+    );
+
+    assert_eq!(
+        ParserError::ParserError("Identifiers can't be empty".to_string()),
+        Ident::new_checked("", &pg).unwrap_err()
+    );
+    assert!(Ident::new_checked("a\0b", &pg).is_err());
+    assert_eq!(
+        Ident::with_quote('"', "a b"),
+        Ident::new_checked("a b", &pg).unwrap()
+    );
+
+    // Whether quoted or not, the identifier refers to the value in each dialect
+    let values = &[
+        "foo", "FOO", "Foo", "select", "from", "a b", "a\"b", "a`b", "a]b", "1a", "é",
+    ];
+    for name in DIALECT_NAMES {
+        let dialect = dialect_from_str(name).unwrap();
+        for value in values {
+            let ident = Ident::quote_if_needed(*value, &*dialect);
+            let sql = format!("SELECT {} FROM t", ident);
+            let statement = Parser::parse_sql(&*dialect, sql.clone())
+                .unwrap_or_else(|e| panic!("{}: {}: {}", name, sql, e));
+            let projection = match only(statement) {
+                Statement::Query(query) => match query.body {
+                    SetExpr::Select(select) => only(select.projection),
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            };
+            match projection {
+                SelectItem::UnnamedExpr(Expr::Identifier(parsed)) => {
+                    assert_eq!(
+                        *value,
+                        parsed.normalized(&*dialect).value,
+                        "{}: {}",
+                        name,
+                        sql
+                    )
+                }
+                other => panic!("{}: {} parsed as {:?}", name, sql, other),
+            }
+        }
+    }
+}
+
+#[test]
+fn ident_quote_if_needed_keywords() {
+    use sqlparser::dialect::keywords::ALL_KEYWORDS;
+    use sqlparser::dialect::{dialect_from_str, PostgreSqlDialect, DIALECT_NAMES};
+
+    let pg = PostgreSqlDialect {};
+    for value in &["primary", "null", "case", "default", "user"] {
+        assert_eq!(
+            Ident::with_quote('"', *value),
+            Ident::quote_if_needed(*value, &pg)
+        );
+    }
+    assert_eq!(Ident::new("type"), Ident::quote_if_needed("type", &pg));
+
+    // Every keyword, quoted or not, can be read back as a column name, a
+    // table name and the name in a column definition
+    for name in DIALECT_NAMES {
+        let dialect = dialect_from_str(name).unwrap();
+        for keyword in ALL_KEYWORDS {
+            let value = dialect.fold_identifier_case(&keyword.to_ascii_lowercase());
+            let ident = Ident::quote_if_needed(value.clone(), &*dialect);
+            let parse = |sql: String| {
+                let statement = Parser::parse_sql(&*dialect, sql.clone())
+                    .unwrap_or_else(|e| panic!("{}: {}: {}", name, sql, e));
+                (sql, only(statement))
+            };
+
+            let (sql, statement) = parse(format!("SELECT {} FROM t", ident));
+            let parsed = match statement {
+                Statement::Query(query) => match query.body {
+                    SetExpr::Select(select) => match only(select.projection) {
+                        SelectItem::UnnamedExpr(Expr::Identifier(parsed)) => parsed,
+                        other => panic!("{}: {} parsed as {:?}", name, sql, other),
+                    },
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            };
+            assert_eq!(
+                value,
+                parsed.normalized(&*dialect).value,
+                "{}: {}",
+                name,
+                sql
+            );
+
+            let (sql, statement) = parse(format!("SELECT a FROM {}", ident));
+            let parsed = match statement {
+                Statement::Query(query) => match query.body {
+                    SetExpr::Select(mut select) => match only(select.from.drain(..)).relation {
+                        TableFactor::Table { name: table, .. } => only(table.0),
+                        other => panic!("{}: {} parsed as {:?}", name, sql, other),
+                    },
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            };
+            assert_eq!(
+                value,
+                parsed.normalized(&*dialect).value,
+                "{}: {}",
+                name,
+                sql
+            );
+
+            let (sql, statement) = parse(format!("CREATE TABLE t ({} int)", ident));
+            let parsed = match statement {
+                Statement::CreateTable { columns, .. } => only(columns).name,
+                _ => unreachable!(),
+            };
+            assert_eq!(
+                value,
+                parsed.normalized(&*dialect).value,
+                "{}: {}",
+                name,
+                sql
+            );
+        }
+    }
+}
+
 #[test]
 fn parse_parens() {
     use self::BinaryOperator::*;
@@ -2003,27 +3069,35 @@ fn parse_searched_case_expr() {
     assert_eq!(
         &Case {
             operand: None,
-            conditions: vec![
-                IsNull(Box::new(Identifier(Ident::new("bar")))),
-                BinaryOp {
-                    left: Box::new(Identifier(Ident::new("bar"))),
-                    op: Eq,
-                    right: Box::new(Expr::Value(number("0")))
+            when_clauses: vec![
+                WhenClause {
+                    condition: IsNull(Box::new(Identifier(Ident::new("bar")))),
+                    result: Expr::Value(Value::SingleQuotedString("null".to_string())),
+                    span: Span::default(),
+                },
+                WhenClause {
+                    condition: BinaryOp {
+                        left: Box::new(Identifier(Ident::new("bar"))),
+                        op: Eq,
+                        right: Box::new(Expr::Value(number("0")))
+                    },
+                    result: Expr::Value(Value::SingleQuotedString("=0".to_string())),
+                    span: Span::default(),
+                },
+                WhenClause {
+                    condition: BinaryOp {
+                        left: Box::new(Identifier(Ident::new("bar"))),
+                        op: GtEq,
+                        right: Box::new(Expr::Value(number("0")))
+                    },
+                    result: Expr::Value(Value::SingleQuotedString(">=0".to_string())),
+                    span: Span::default(),
                 },
-                BinaryOp {
-                    left: Box::new(Identifier(Ident::new("bar"))),
-                    op: GtEq,
-                    right: Box::new(Expr::Value(number("0")))
-                }
-            ],
-            results: vec![
-                Expr::Value(Value::SingleQuotedString("null".to_string())),
-                Expr::Value(Value::SingleQuotedString("=0".to_string())),
-                Expr::Value(Value::SingleQuotedString(">=0".to_string()))
             ],
             else_result: Some(Box::new(Expr::Value(Value::SingleQuotedString(
                 "<0".to_string()
-            ))))
+            )))),
+            else_span: Span::default(),
         },
         expr_from_projection(only(&select.projection)),
     );
@@ -2036,6 +3110,11 @@ fn parse_show_objects() {
         ("VIEWS", ObjectType::View),
         ("TABLES", ObjectType::Table),
         ("SINKS", ObjectType::Sink),
+        ("INDEXES", ObjectType::Index),
+        ("SCHEMAS", ObjectType::Schema),
+        ("DATABASES", ObjectType::Database),
+        ("TYPES", ObjectType::Type),
+        ("ROLES", ObjectType::Role),
     ];
 
     for (s, ot) in &trials {
@@ -2043,7 +3122,7 @@ fn parse_show_objects() {
         assert_eq!(
             verified_stmt(&sql),
             Statement::ShowObjects {
-                object_type: *ot,
+                object_type: ot.clone(),
                 filter: None
             }
         )
@@ -2065,6 +3144,66 @@ fn parse_show_objects_with_like_regex() {
     }
 }
 
+#[test]
+fn parse_show_custom_objects() {
+    use sqlparser::dialect::Dialect;
+    use sqlparser::test_utils::TestedDialects;
+
+    #[derive(Debug)]
+    struct CatalogDialect {}
+    impl Dialect for CatalogDialect {
+        fn is_identifier_start(&self, ch: char) -> bool {
+            ch.is_ascii_alphabetic() || ch == '_'
+        }
+        fn is_identifier_part(&self, ch: char) -> bool {
+            ch.is_ascii_alphanumeric() || ch == '_'
+        }
+        fn show_object_types(&self) -> &[&str] {
+            &["SECRETS", "CLUSTERS"]
+        }
+    }
+    let catalog = TestedDialects {
+        dialects: vec![Box::new(CatalogDialect {})],
+    };
+
+    assert_eq!(
+        catalog.verified_stmt("SHOW SECRETS"),
+        Statement::ShowObjects {
+            object_type: ObjectType::Custom(Ident::new("SECRETS")),
+            filter: None,
+        }
+    );
+    assert_eq!(
+        catalog.verified_stmt("SHOW clusters LIKE 'prod%'"),
+        Statement::ShowObjects {
+            object_type: ObjectType::Custom(Ident::new("clusters")),
+            filter: Some(ShowStatementFilter::Like("prod%".into())),
+        }
+    );
+    // Known kinds are unaffected, and quoted names are still variables
+    assert_eq!(
+        catalog.verified_stmt("SHOW TABLES"),
+        Statement::ShowObjects {
+            object_type: ObjectType::Table,
+            filter: None,
+        }
+    );
+    assert_eq!(
+        catalog.verified_stmt("SHOW \"secrets\""),
+        Statement::ShowVariable {
+            variable: Ident::with_quote('"', "secrets"),
+        }
+    );
+
+    // Without the dialect's help, an unknown kind is a variable
+    assert_eq!(
+        verified_stmt("SHOW SECRETS"),
+        Statement::ShowVariable {
+            variable: Ident::new("SECRETS"),
+        }
+    );
+}
+
 #[test]
 fn parse_show_indexes() {
     let canonical_sql = "SHOW INDEXES FROM foo";
@@ -2086,6 +3225,34 @@ fn parse_show_indexes() {
     }
 }
 
+#[test]
+fn parse_show_constraints() {
+    let canonical_sql = "SHOW CONSTRAINTS FROM foo";
+    assert_eq!(
+        verified_stmt(canonical_sql),
+        Statement::ShowConstraints {
+            table_name: ObjectName(vec!["foo".into()]),
+            filter: None,
+        }
+    );
+    one_statement_parses_to("SHOW CONSTRAINTS IN foo", canonical_sql);
+
+    match verified_stmt("SHOW CONSTRAINTS FROM foo.bar LIKE 'pk%'") {
+        Statement::ShowConstraints { table_name, filter } => {
+            assert_eq!("foo.bar", table_name.to_string());
+            assert_eq!(filter.unwrap(), ShowStatementFilter::Like("pk%".into()));
+        }
+        _ => panic!("invalid SHOW CONSTRAINTS statement"),
+    }
+    verified_stmt("SHOW CONSTRAINTS FROM foo WHERE name = 'bar'");
+
+    let res = parse_sql_statements("SHOW CONSTRAINTS foo");
+    assert_eq!(
+        ParserError::ParserError("Expected one of FROM or IN, found: foo".to_string()),
+        res.unwrap_err()
+    );
+}
+
 #[test]
 fn parse_show_indexes_with_where_expr() {
     let canonical_sql = "SHOW INDEXES FROM foo WHERE index_name = 'bar'";
@@ -2115,6 +3282,13 @@ fn parse_show_indexes_with_where_expr() {
             one_statement_parses_to(&sql, &canonical_sql);
         }
     }
+
+    match verified_stmt("SHOW INDEXES FROM foo LIKE 'foo_%'") {
+        Statement::ShowIndexes { filter, .. } => {
+            assert_eq!(filter.unwrap(), ShowStatementFilter::Like("foo_%".into()));
+        }
+        _ => panic!("invalid SHOW INDEXES statement"),
+    }
 }
 
 #[test]
@@ -2146,11 +3320,15 @@ fn parse_simple_case_expr() {
     assert_eq!(
         &Case {
             operand: Some(Box::new(Identifier(Ident::new("foo")))),
-            conditions: vec![Expr::Value(number("1"))],
-            results: vec![Expr::Value(Value::SingleQuotedString("Y".to_string())),],
+            when_clauses: vec![WhenClause {
+                condition: Expr::Value(number("1")),
+                result: Expr::Value(Value::SingleQuotedString("Y".to_string())),
+                span: Span::default(),
+            }],
             else_result: Some(Box::new(Expr::Value(Value::SingleQuotedString(
                 "N".to_string()
-            ))))
+            )))),
+            else_span: Span::default(),
         },
         expr_from_projection(only(&select.projection)),
     );
@@ -2162,6 +3340,30 @@ fn parse_from_advanced() {
     let _select = verified_only_select(sql);
 }
 
+#[test]
+fn parse_table_hints_before_cte() {
+    let select = verified_only_select("SELECT * FROM t WITH (NOLOCK, INDEX(i))");
+    match only(select.from).relation {
+        TableFactor::Table { with_hints, .. } => assert_eq!(2, with_hints.len()),
+        _ => unreachable!(),
+    }
+
+    // A WITH that doesn't start hints is left for the next statement
+    let statements =
+        parse_sql_statements("SELECT * FROM t; WITH x AS (SELECT 1) SELECT * FROM x").unwrap();
+    assert_eq!(2, statements.len());
+    let res = parse_sql_statements("SELECT * FROM t WITH x AS (SELECT 1) SELECT * FROM x");
+    assert_eq!(
+        ParserError::ParserError("Expected end of statement, found: WITH".to_string()),
+        res.unwrap_err()
+    );
+    let res = parse_sql_statements("SELECT * FROM t AS a WITH");
+    assert_eq!(
+        ParserError::ParserError("Expected end of statement, found: WITH".to_string()),
+        res.unwrap_err()
+    );
+}
+
 #[test]
 fn parse_implicit_join() {
     let sql = "SELECT * FROM t1, t2";
@@ -2170,19 +3372,27 @@ fn parse_implicit_join() {
         vec![
             TableWithJoins {
                 relation: TableFactor::Table {
+                    only: false,
                     name: ObjectName(vec!["t1".into()]),
+                    descendants: false,
+                    version: None,
                     alias: None,
                     args: vec![],
                     with_hints: vec![],
+                    with_ordinality: false,
                 },
                 joins: vec![],
             },
             TableWithJoins {
                 relation: TableFactor::Table {
+                    only: false,
                     name: ObjectName(vec!["t2".into()]),
+                    descendants: false,
+                    version: None,
                     alias: None,
                     args: vec![],
                     with_hints: vec![],
+                    with_ordinality: false,
                 },
                 joins: vec![],
             }
@@ -2196,34 +3406,50 @@ fn parse_implicit_join() {
         vec![
             TableWithJoins {
                 relation: TableFactor::Table {
+                    only: false,
                     name: ObjectName(vec!["t1a".into()]),
+                    descendants: false,
+                    version: None,
                     alias: None,
                     args: vec![],
                     with_hints: vec![],
+                    with_ordinality: false,
                 },
                 joins: vec![Join {
                     relation: TableFactor::Table {
+                        only: false,
                         name: ObjectName(vec!["t1b".into()]),
+                        descendants: false,
+                        version: None,
                         alias: None,
                         args: vec![],
                         with_hints: vec![],
+                        with_ordinality: false,
                     },
                     join_operator: JoinOperator::Inner(JoinConstraint::Natural),
                 }]
             },
             TableWithJoins {
                 relation: TableFactor::Table {
+                    only: false,
                     name: ObjectName(vec!["t2a".into()]),
+                    descendants: false,
+                    version: None,
                     alias: None,
                     args: vec![],
                     with_hints: vec![],
+                    with_ordinality: false,
                 },
                 joins: vec![Join {
                     relation: TableFactor::Table {
+                        only: false,
                         name: ObjectName(vec!["t2b".into()]),
+                        descendants: false,
+                        version: None,
                         alias: None,
                         args: vec![],
                         with_hints: vec![],
+                        with_ordinality: false,
                     },
                     join_operator: JoinOperator::Inner(JoinConstraint::Natural),
                 }]
@@ -2240,10 +3466,14 @@ fn parse_cross_join() {
     assert_eq!(
         Join {
             relation: TableFactor::Table {
+                only: false,
                 name: ObjectName(vec![Ident::new("t2")]),
+                descendants: false,
+                version: None,
                 alias: None,
                 args: vec![],
                 with_hints: vec![],
+                with_ordinality: false,
             },
             join_operator: JoinOperator::CrossJoin
         },
@@ -2267,10 +3497,14 @@ fn parse_joins_on() {
     ) -> Join {
         Join {
             relation: TableFactor::Table {
+                only: false,
                 name: ObjectName(vec![Ident::new(relation.into())]),
+                descendants: false,
+                version: None,
                 alias,
                 args: vec![],
                 with_hints: vec![],
+                with_ordinality: false,
             },
             join_operator: f(JoinConstraint::On(Expr::BinaryOp {
                 left: Box::new(Expr::Identifier("c1".into())),
@@ -2320,10 +3554,14 @@ fn parse_joins_using() {
     ) -> Join {
         Join {
             relation: TableFactor::Table {
+                only: false,
                 name: ObjectName(vec![Ident::new(relation.into())]),
+                descendants: false,
+                version: None,
                 alias,
                 args: vec![],
                 with_hints: vec![],
+                with_ordinality: false,
             },
             join_operator: f(JoinConstraint::Using(vec!["c1".into()])),
         }
@@ -2360,15 +3598,76 @@ fn parse_joins_using() {
     );
 }
 
+#[test]
+fn parse_join_constraints_with_subqueries_and_quoting() {
+    let sql = "SELECT * FROM t1 JOIN t2 ON (SELECT max(c) FROM t3) = t2.c JOIN t4 USING(c)";
+    let select = verified_only_select(sql);
+    let joins = &only(&select.from).joins;
+    match &joins[0].join_operator {
+        JoinOperator::Inner(JoinConstraint::On(Expr::BinaryOp { left, .. })) => {
+            assert_eq!("(SELECT max(c) FROM t3)", left.to_string());
+            assert_matches!(**left, Expr::Subquery(_));
+        }
+        _ => unreachable!(),
+    }
+    assert_eq!(
+        JoinOperator::Inner(JoinConstraint::Using(vec![Ident::new("c")])),
+        joins[1].join_operator
+    );
+
+    // A parenthesized set operation is a subquery, while a parenthesized
+    // subquery followed by an operator is a nested expression
+    let select =
+        verified_only_select("SELECT * FROM t1 JOIN t2 ON ((SELECT 1) UNION (SELECT 2)) = t2.c");
+    match &only(&select.from).joins[0].join_operator {
+        JoinOperator::Inner(JoinConstraint::On(Expr::BinaryOp { left, .. })) => {
+            assert_matches!(**left, Expr::Subquery(_));
+        }
+        _ => unreachable!(),
+    }
+    let select = verified_only_select("SELECT * FROM t1 JOIN t2 ON ((SELECT 1) = t2.c)");
+    match &only(&select.from).joins[0].join_operator {
+        JoinOperator::Inner(JoinConstraint::On(expr)) => {
+            assert_matches!(expr, Expr::Nested(_));
+        }
+        _ => unreachable!(),
+    }
+    assert_matches!(
+        verified_expr("((SELECT 1))"),
+        Expr::Nested(inner) if matches!(*inner, Expr::Subquery(_))
+    );
+
+    let select = verified_only_select(r#"SELECT * FROM t1 JOIN t2 USING("C", "order")"#);
+    assert_eq!(
+        JoinOperator::Inner(JoinConstraint::Using(vec![
+            Ident::with_quote('"', "C"),
+            Ident::with_quote('"', "order"),
+        ])),
+        only(&select.from).joins[0].join_operator
+    );
+
+    let res = parse_sql_statements("SELECT * FROM t1 JOIN t2 USING(t1.c)");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected an unqualified column name in USING, found: t1.c".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
 #[test]
 fn parse_natural_join() {
     fn natural_join(f: impl Fn(JoinConstraint) -> JoinOperator) -> Join {
         Join {
             relation: TableFactor::Table {
+                only: false,
                 name: ObjectName(vec![Ident::new("t2")]),
+                descendants: false,
+                version: None,
                 alias: None,
                 args: vec![],
                 with_hints: vec![],
+                with_ordinality: false,
             },
             join_operator: f(JoinConstraint::Natural),
         }
@@ -2407,10 +3706,14 @@ fn parse_complex_join() {
 fn parse_join_nesting() {
     fn table(name: impl Into<String>) -> TableFactor {
         TableFactor::Table {
+            only: false,
             name: ObjectName(vec![Ident::new(name.into())]),
+            descendants: false,
+            version: None,
             alias: None,
             args: vec![],
             with_hints: vec![],
+            with_ordinality: false,
         }
     }
 
@@ -2505,7 +3808,7 @@ fn parse_ctes() {
     fn assert_ctes_in_select(expected: &[&str], sel: &Query) {
         let mut i = 0;
         for exp in expected {
-            let Cte { alias, query } = &sel.ctes[i];
+            let Cte { alias, query } = &sel.with.as_ref().unwrap().ctes[i];
             assert_eq!(*exp, query.to_string());
             assert_eq!(
                 if i == 0 {
@@ -2549,7 +3852,25 @@ fn parse_ctes() {
     // CTE in a CTE...
     let sql = &format!("WITH outer_cte AS ({}) SELECT * FROM outer_cte", with);
     let select = verified_query(sql);
-    assert_ctes_in_select(&cte_sqls, &only(&select.ctes).query);
+    assert_ctes_in_select(&cte_sqls, &only(&select.with.unwrap().ctes).query);
+}
+
+#[test]
+fn parse_with_recursive() {
+    let sql = "WITH RECURSIVE t (n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM t WHERE n < 5) \
+               SELECT n FROM t";
+    let query = verified_query(sql);
+    let with = query.with.unwrap();
+    assert!(with.recursive);
+    assert_eq!("t", only(&with.ctes).alias.name.value);
+    assert_eq!(
+        "WITH RECURSIVE t (n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM t WHERE n < 5)",
+        &sql[with.span.start..with.span.end]
+    );
+
+    let query = verified_query("WITH t AS (SELECT 1) SELECT * FROM t");
+    assert!(!query.with.unwrap().recursive);
+    assert_eq!(None, verified_query("SELECT 1").with);
 }
 
 #[test]
@@ -2557,8 +3878,11 @@ fn parse_cte_renamed_columns() {
     let sql = "WITH cte (col1, col2) AS (SELECT foo, bar FROM baz) SELECT * FROM cte";
     let query = all_dialects().verified_query(sql);
     assert_eq!(
-        vec![Ident::new("col1"), Ident::new("col2")],
-        query.ctes.first().unwrap().alias.columns
+        vec![
+            TableAliasColumn::from(Ident::new("col1")),
+            TableAliasColumn::from(Ident::new("col2"))
+        ],
+        query.with.unwrap().ctes[0].alias.columns
     );
 }
 
@@ -2598,10 +3922,14 @@ fn parse_derived_tables() {
             },
             joins: vec![Join {
                 relation: TableFactor::Table {
+                    only: false,
                     name: ObjectName(vec!["t2".into()]),
+                    descendants: false,
+                    version: None,
                     alias: None,
                     args: vec![],
                     with_hints: vec![],
+                    with_ordinality: false,
                 },
                 join_operator: JoinOperator::Inner(JoinConstraint::Natural),
             }],
@@ -2615,6 +3943,32 @@ fn parse_derived_tables() {
     );
 }
 
+#[test]
+fn parse_derived_table_errors() {
+    // The error comes from whichever of derived table or nested join got
+    // further...
+    let res = parse_sql_statements("SELECT * FROM (SELECT 1 FROM)");
+    assert_eq!(
+        ParserError::ParserError("Expected identifier, found: )".to_string()),
+        res.unwrap_err()
+    );
+    let res = parse_sql_statements("SELECT * FROM (a JOIN b ON)");
+    assert_eq!(
+        ParserError::ParserError("Expected an expression, found: )".to_string()),
+        res.unwrap_err()
+    );
+
+    // ...or lists what both expected, if they got equally far
+    let res = parse_sql_statements("SELECT * FROM (");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected SELECT, VALUES, or a subquery in the query body, or identifier, found: EOF"
+                .to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
 #[test]
 fn parse_union() {
     // TODO: add assertions
@@ -2632,6 +3986,125 @@ fn parse_union() {
     verified_stmt("SELECT 1 UNION SELECT 2 INTERSECT SELECT 3"); // Union[1, Intersect[2,3]]
     verified_stmt("SELECT foo FROM tab UNION SELECT bar FROM TAB");
     verified_stmt("(SELECT * FROM new EXCEPT SELECT * FROM old) UNION ALL (SELECT * FROM old EXCEPT SELECT * FROM new) ORDER BY 1");
+    verified_stmt("(SELECT 1 UNION SELECT 2) LIMIT 1");
+    verified_stmt("((SELECT 1 UNION SELECT 2) INTERSECT SELECT 3) LIMIT 1");
+    verified_stmt("SELECT 1 EXCEPT (SELECT 2 EXCEPT SELECT 3) ORDER BY 1");
+    verified_stmt("(SELECT 1 UNION (SELECT 2 UNION SELECT 3)) UNION ALL SELECT 4");
+    verified_stmt("SELECT * FROM ((SELECT 1 UNION SELECT 2) EXCEPT SELECT 3) AS t");
+}
+
+#[test]
+fn set_operation_display_parenthesizes() {
+    let select = |n: u8| SetExpr::Select(Box::new(verified_only_select(&format!("SELECT {}", n))));
+    let set_op = |left: SetExpr, op: SetOperator, right: SetExpr| SetExpr::SetOperation {
+        op,
+        all: false,
+        distinct: false,
+        left: Box::new(left),
+        right: Box::new(right),
+    };
+
+    // Nestings that standard precedence reproduces
+    let body = set_op(
+        select(1),
+        SetOperator::Union,
+        set_op(select(2), SetOperator::Intersect, select(3)),
+    );
+    assert_eq!(
+        "SELECT 1 UNION SELECT 2 INTERSECT SELECT 3",
+        body.to_string()
+    );
+    let body = set_op(
+        set_op(select(1), SetOperator::Except, select(2)),
+        SetOperator::Union,
+        select(3),
+    );
+    assert_eq!("SELECT 1 EXCEPT SELECT 2 UNION SELECT 3", body.to_string());
+
+    // Nestings that it doesn't
+    let body = set_op(
+        set_op(select(1), SetOperator::Union, select(2)),
+        SetOperator::Intersect,
+        select(3),
+    );
+    assert_eq!(
+        "(SELECT 1 UNION SELECT 2) INTERSECT SELECT 3",
+        body.to_string()
+    );
+    let body = set_op(
+        select(1),
+        SetOperator::Except,
+        set_op(select(2), SetOperator::Except, select(3)),
+    );
+    assert_eq!(
+        "SELECT 1 EXCEPT (SELECT 2 EXCEPT SELECT 3)",
+        body.to_string()
+    );
+}
+
+#[test]
+fn parse_order_by_ordinals() {
+    let query = verified_query("SELECT a, b FROM t ORDER BY 2 DESC, 1 + 1, (1)");
+    assert_eq!(
+        vec![
+            Expr::Ordinal(2),
+            Expr::BinaryOp {
+                left: Box::new(Expr::Value(number("1"))),
+                op: BinaryOperator::Plus,
+                right: Box::new(Expr::Value(number("1"))),
+            },
+            Expr::Nested(Box::new(Expr::Value(number("1")))),
+        ],
+        query
+            .order_by
+            .into_iter()
+            .map(|o| o.expr)
+            .collect::<Vec<_>>()
+    );
+
+    // Integers in window specifications are constants
+    let select = verified_only_select("SELECT rank() OVER (ORDER BY 1) FROM t");
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(Function {
+            over: Some(over), ..
+        }) => {
+            assert_eq!(Expr::Value(number("1")), over.order_by[0].expr)
+        }
+        e => panic!("expected a window function, got {:?}", e),
+    }
+}
+
+#[test]
+fn parse_set_operation_distinct() {
+    verified_stmt("SELECT 1 UNION DISTINCT SELECT 2");
+    verified_stmt("SELECT 1 EXCEPT DISTINCT SELECT 2");
+    verified_stmt("SELECT 1 INTERSECT DISTINCT SELECT 2 UNION SELECT 3");
+
+    // DISTINCT is the default, but it is kept apart from an operator that
+    // doesn't spell it out
+    let distinct = verified_query("SELECT 1 UNION DISTINCT SELECT 2");
+    let implicit = verified_query("SELECT 1 UNION SELECT 2");
+    match (&distinct.body, &implicit.body) {
+        (
+            SetExpr::SetOperation {
+                distinct: true,
+                all: false,
+                ..
+            },
+            SetExpr::SetOperation {
+                distinct: false,
+                all: false,
+                ..
+            },
+        ) => (),
+        _ => panic!("{:?} / {:?}", distinct.body, implicit.body),
+    }
+
+    let res = parse_sql_statements("SELECT 1 UNION ALL DISTINCT SELECT 2");
+    assert_eq!(
+        ParserError::ParserError("Cannot specify both ALL and DISTINCT in UNION".to_string()),
+        res.unwrap_err()
+    );
 }
 
 #[test]
@@ -2639,6 +4112,54 @@ fn parse_values() {
     verified_stmt("SELECT * FROM (VALUES (1), (2), (3))");
     verified_stmt("SELECT * FROM (VALUES (1), (2), (3)), (VALUES (1, 2, 3))");
     verified_stmt("SELECT * FROM (VALUES (1)) UNION VALUES (1)");
+
+    let query = verified_query("VALUES (1, 'a'), (2, 'b') ORDER BY 1 LIMIT 1");
+    let values = query.as_values().unwrap();
+    assert_eq!(2, values.0.len());
+    assert_eq!(
+        vec![
+            Expr::Value(number("1")),
+            Expr::Value(Value::SingleQuotedString("a".into()))
+        ],
+        values.0[0]
+    );
+    assert_eq!(1, query.order_by.len());
+    assert_eq!(Some(Expr::Value(number("1"))), query.limit);
+
+    assert_eq!(None, verified_query("SELECT 1").as_values());
+    assert_eq!(
+        None,
+        verified_query("VALUES (1) UNION VALUES (2)").as_values()
+    );
+}
+
+#[test]
+fn parse_unparenthesized_values_in_from() {
+    let select = match one_statement_parses_to(
+        "SELECT * FROM VALUES (1, 2), (3, 4) AS v (a, b)",
+        "SELECT * FROM (VALUES (1, 2), (3, 4)) AS v (a, b)",
+    ) {
+        Statement::Query(query) => match query.body {
+            SetExpr::Select(select) => select,
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+    match &only(&select.from).relation {
+        TableFactor::Derived {
+            subquery, alias, ..
+        } => {
+            assert_eq!(2, subquery.as_values().unwrap().0.len());
+            assert_eq!(
+                Some(TableAlias {
+                    name: "v".into(),
+                    columns: vec!["a".into(), "b".into()],
+                }),
+                *alias
+            );
+        }
+        _ => unreachable!(),
+    }
 }
 
 #[test]
@@ -2746,16 +4267,36 @@ fn parse_exists_subquery() {
     let sql = "SELECT * FROM t WHERE EXISTS (SELECT 1)";
     let select = verified_only_select(sql);
     assert_eq!(
-        Expr::Exists(Box::new(expected_inner.clone())),
+        Expr::Exists {
+            subquery: Box::new(expected_inner.clone()),
+            negated: false,
+        },
         select.selection.unwrap(),
     );
 
     let sql = "SELECT * FROM t WHERE NOT EXISTS (SELECT 1)";
     let select = verified_only_select(sql);
+    assert_eq!(
+        Expr::Exists {
+            subquery: Box::new(expected_inner.clone()),
+            negated: true,
+        },
+        select.selection.unwrap(),
+    );
+
+    // NOT binds more loosely than the comparison
+    let select = verified_only_select("SELECT * FROM t WHERE NOT EXISTS (SELECT 1) = false");
     assert_eq!(
         Expr::UnaryOp {
             op: UnaryOperator::Not,
-            expr: Box::new(Expr::Exists(Box::new(expected_inner))),
+            expr: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::Exists {
+                    subquery: Box::new(expected_inner),
+                    negated: false,
+                }),
+                op: BinaryOperator::Eq,
+                right: Box::new(Expr::Value(Value::Boolean(false))),
+            }),
         },
         select.selection.unwrap(),
     );
@@ -2788,12 +4329,14 @@ fn parse_create_view() {
             name,
             columns,
             query,
+            temporary,
             materialized,
             with_options,
         } => {
             assert_eq!("myschema.myview", name.to_string());
-            assert_eq!(Vec::<Ident>::new(), columns);
+            assert_eq!(Vec::<ViewColumnDef>::new(), columns);
             assert_eq!("SELECT foo FROM bar", query.to_string());
+            assert!(!temporary);
             assert!(!materialized);
             assert_eq!(with_options, vec![]);
         }
@@ -2808,11 +4351,11 @@ fn parse_create_view_with_options() {
         Statement::CreateView { with_options, .. } => {
             assert_eq!(
                 vec![
-                    SqlOption {
+                    SqlOption::Value {
                         name: "foo".into(),
                         value: Value::SingleQuotedString("bar".into())
                     },
-                    SqlOption {
+                    SqlOption::Value {
                         name: "a".into(),
                         value: number("123")
                     },
@@ -2833,16 +4376,87 @@ fn parse_create_view_with_columns() {
             columns,
             with_options,
             query,
+            temporary,
             materialized,
         } => {
             assert_eq!("v", name.to_string());
-            assert_eq!(columns, vec![Ident::new("has"), Ident::new("cols")]);
+            assert_eq!(
+                columns,
+                vec![
+                    ViewColumnDef {
+                        name: Ident::new("has"),
+                        data_type: None
+                    },
+                    ViewColumnDef {
+                        name: Ident::new("cols"),
+                        data_type: None
+                    },
+                ]
+            );
             assert_eq!(with_options, vec![]);
             assert_eq!("SELECT 1, 2", query.to_string());
+            assert!(!temporary);
+            assert!(!materialized);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_create_view_with_columns_and_options() {
+    let sql = "CREATE VIEW v (a, b int) WITH (foo = 'bar') AS SELECT 1, 2";
+    match verified_stmt(sql) {
+        Statement::CreateView {
+            columns,
+            with_options,
+            ..
+        } => {
+            assert_eq!(
+                columns,
+                vec![
+                    ViewColumnDef {
+                        name: Ident::new("a"),
+                        data_type: None
+                    },
+                    ViewColumnDef {
+                        name: Ident::new("b"),
+                        data_type: Some(DataType::Int)
+                    },
+                ]
+            );
+            assert_eq!(
+                with_options,
+                vec![SqlOption::Value {
+                    name: "foo".into(),
+                    value: Value::SingleQuotedString("bar".into())
+                }]
+            );
+        }
+        _ => unreachable!(),
+    }
+    one_statement_parses_to(
+        "CREATE VIEW v WITH (foo = 'bar') (a, b int) AS SELECT 1, 2",
+        sql,
+    );
+}
+
+#[test]
+fn parse_create_temporary_view() {
+    match verified_stmt("CREATE TEMPORARY VIEW v AS SELECT 1") {
+        Statement::CreateView {
+            temporary,
+            materialized,
+            ..
+        } => {
+            assert!(temporary);
             assert!(!materialized);
         }
         _ => unreachable!(),
     }
+    one_statement_parses_to(
+        "CREATE TEMP VIEW v AS SELECT 1",
+        "CREATE TEMPORARY VIEW v AS SELECT 1",
+    );
 }
 
 #[test]
@@ -2853,12 +4467,14 @@ fn parse_create_materialized_view() {
             name,
             columns,
             query,
+            temporary,
             materialized,
             with_options,
         } => {
             assert_eq!("myschema.myview", name.to_string());
-            assert_eq!(Vec::<Ident>::new(), columns);
+            assert_eq!(Vec::<ViewColumnDef>::new(), columns);
             assert_eq!("SELECT foo FROM bar", query.to_string());
+            assert!(!temporary);
             assert!(materialized);
             assert_eq!(with_options, vec![]);
         }
@@ -2881,7 +4497,7 @@ fn parse_create_source_raw_schema() {
             assert_eq!(SourceSchema::RawOrPath("baz".into()), schema.unwrap());
             assert_eq!(
                 with_options,
-                vec![SqlOption {
+                vec![SqlOption::Value {
                     name: "name".into(),
                     value: Value::SingleQuotedString("val".into())
                 },]
@@ -2907,11 +4523,11 @@ fn parse_create_source_path_schema_multiple_args() {
             assert_eq!(
                 with_options,
                 vec![
-                    SqlOption {
+                    SqlOption::Value {
                         name: "format".into(),
                         value: Value::SingleQuotedString("someformat".into())
                     },
-                    SqlOption {
+                    SqlOption::Value {
                         name: "message_name".into(),
                         value: Value::SingleQuotedString("somemessage".into())
                     },
@@ -3000,7 +4616,7 @@ fn parse_create_sink() {
             assert_eq!("baz", url);
             assert_eq!(
                 with_options,
-                vec![SqlOption {
+                vec![SqlOption::Value {
                     name: "name".into(),
                     value: Value::SingleQuotedString("val".into())
                 },]
@@ -3047,7 +4663,7 @@ fn parse_create_index() {
                 Expr::IsNotNull(Box::new(Expr::Identifier(Ident::new("a"))))
             );
             if let Expr::Nested(expr) = &key_parts[2] {
-                assert_matches!(**expr, Expr::Exists(..));
+                assert_matches!(**expr, Expr::Exists { .. });
             } else {
                 assert!(false);
             }
@@ -3221,6 +4837,64 @@ fn parse_drop_index() {
     }
 }
 
+#[test]
+fn parse_drop_other_objects() {
+    let trials = [
+        ("SCHEMA", ObjectType::Schema),
+        ("DATABASE", ObjectType::Database),
+        ("TYPE", ObjectType::Type),
+        ("ROLE", ObjectType::Role),
+    ];
+
+    for (s, ot) in &trials {
+        let sql = format!("DROP {} IF EXISTS foo, bar CASCADE", s);
+        assert_eq!(
+            verified_stmt(&sql),
+            Statement::Drop {
+                object_type: ot.clone(),
+                if_exists: true,
+                names: vec![
+                    ObjectName(vec!["foo".into()]),
+                    ObjectName(vec!["bar".into()])
+                ],
+                cascade: true,
+            }
+        )
+    }
+
+    let res = parse_sql_statements("DROP FUNCTION foo");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected TABLE, VIEW, SOURCE, SINK, INDEX, SCHEMA, DATABASE, TYPE, ROLE, SEQUENCE, or EXTENSION after DROP, found: FUNCTION".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_display_round_trip() {
+    // Serializing a parsed statement and parsing the result again must
+    // produce the same AST and the same serialization.
+    let statements = [
+        "select a , b from t where a=1 order by b",
+        "insert into t(a,b) values(1,2)",
+        "INSERT INTO t (SELECT 1 UNION SELECT 2)",
+        "create view v(a) with (x=1) as select 1",
+        "(SELECT 1 UNION SELECT 2) UNION ALL SELECT 3 LIMIT 1",
+        "WITH a AS (SELECT 1) SELECT * FROM a, (b CROSS JOIN c)",
+        "update t set a=1 where b=2",
+        "delete from t where a=1",
+        "create table t (a int not null default 1, constraint pk primary key (a))",
+        "show full columns from t like 'x'",
+        "drop schema if exists s cascade",
+    ];
+    for sql in &statements {
+        let ast = one_statement_parses_to(sql, "");
+        let serialized = ast.to_string();
+        assert_eq!(ast, verified_stmt(&serialized));
+    }
+}
+
 #[test]
 fn parse_peek() {
     let sql = "PEEK foo.bar";
@@ -3253,6 +4927,190 @@ fn parse_tail() {
     }
 }
 
+#[test]
+fn parse_declare() {
+    match verified_stmt("DECLARE c CURSOR FOR SELECT 1") {
+        Statement::Declare { name, hold, stmt } => {
+            assert_eq!(Ident::new("c"), name);
+            assert!(!hold);
+            assert_eq!("SELECT 1", stmt.to_string());
+        }
+        _ => unreachable!(),
+    }
+    verified_stmt("DECLARE c CURSOR WITH HOLD FOR TAIL v");
+    one_statement_parses_to(
+        "DECLARE c CURSOR WITHOUT HOLD FOR PEEK v",
+        "DECLARE c CURSOR FOR PEEK v",
+    );
+
+    let res = parse_sql_statements("DECLARE c CURSOR FOR INSERT INTO t VALUES (1)");
+    assert_eq!(
+        ParserError::ParserError("Expected a query or TAIL, found: INSERT".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_fetch_cursor() {
+    match verified_stmt("FETCH 10 FROM c") {
+        Statement::Fetch { name, direction } => {
+            assert_eq!(Ident::new("c"), name);
+            assert_eq!(Some(FetchDirection::Count(10)), direction);
+        }
+        _ => unreachable!(),
+    }
+    verified_stmt("FETCH ALL FROM c");
+    verified_stmt("FETCH NEXT FROM c");
+    verified_stmt("FETCH c");
+    one_statement_parses_to("FETCH FORWARD ALL IN c", "FETCH ALL FROM c");
+    one_statement_parses_to("FETCH FORWARD c", "FETCH NEXT FROM c");
+    one_statement_parses_to("FETCH FROM c", "FETCH c");
+}
+
+#[test]
+fn parse_close() {
+    match verified_stmt("CLOSE c") {
+        Statement::Close { name } => assert_eq!(Ident::new("c"), name),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_fragments_from_str() {
+    let expr: Expr = "a + 1".parse().unwrap();
+    assert_eq!(verified_expr("a + 1"), expr);
+
+    let query: Query = "SELECT a FROM t".parse().unwrap();
+    assert_eq!(verified_query("SELECT a FROM t"), query);
+
+    let name: ObjectName = "db.\"Table\"".parse().unwrap();
+    assert_eq!(
+        ObjectName(vec![Ident::new("db"), Ident::with_quote('"', "Table")]),
+        name
+    );
+
+    assert_eq!(
+        ParserError::ParserError("Expected end of input, found: b".to_string()),
+        "a b".parse::<Expr>().unwrap_err()
+    );
+}
+
+#[test]
+fn parse_meta_commands() {
+    let sql = "#!/usr/bin/env psql\n\
+               \\connect db\n\
+               SELECT 1;\n  \\set x 1 -- comment\n\
+               SELECT 2";
+    let parse = |meta_commands| {
+        Parser::parse_sql_with_options(
+            &GenericDialect {},
+            sql.to_string(),
+            &ParseOptions {
+                meta_commands,
+                ..ParseOptions::default()
+            },
+        )
+    };
+    let statements = parse(MetaCommands::Capture).unwrap();
+    assert_eq!(
+        vec!["\\connect db", "SELECT 1", "\\set x 1", "SELECT 2"],
+        statements.iter().map(|s| s.to_string()).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        Statement::MetaCommand("\\connect db".to_string()),
+        statements[0]
+    );
+    let statements = parse(MetaCommands::Skip).unwrap();
+    assert_eq!(
+        vec!["SELECT 1", "SELECT 2"],
+        statements.iter().map(|s| s.to_string()).collect::<Vec<_>>()
+    );
+    assert!(parse(MetaCommands::Reject).is_err());
+
+    // Meta-commands must start their own line
+    let res = Parser::parse_sql_with_options(
+        &GenericDialect {},
+        "SELECT 1; \\connect db".to_string(),
+        &ParseOptions {
+            meta_commands: MetaCommands::Capture,
+            ..ParseOptions::default()
+        },
+    );
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected a keyword at the beginning of a statement, found: \\".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_preserving_type_names() {
+    let sql = "CREATE TABLE t (a INTEGER, b int, c CHARACTER VARYING(45), \
+               d timestamp  without\ttime zone, e INT[])";
+    let parse = |preserve_type_names| {
+        let options = ParseOptions {
+            preserve_type_names,
+            ..ParseOptions::default()
+        };
+        match Parser::parse_sql_with_options(&GenericDialect {}, sql.to_string(), &options)
+            .unwrap()
+            .pop()
+            .unwrap()
+        {
+            Statement::CreateTable { columns, .. } => {
+                columns.into_iter().map(|c| c.data_type).collect::<Vec<_>>()
+            }
+            _ => unreachable!(),
+        }
+    };
+
+    let spelled =
+        |data_type, spelling: &str| DataType::Spelled(Box::new(data_type), spelling.to_string());
+    let types = parse(true);
+    assert_eq!(
+        vec![
+            spelled(DataType::Int, "INTEGER"),
+            DataType::Int,
+            spelled(
+                DataType::Varchar(Some(CharacterLength::IntegerLength {
+                    length: 45,
+                    unit: None
+                })),
+                "CHARACTER VARYING(45)"
+            ),
+            spelled(
+                DataType::Timestamp {
+                    precision: None,
+                    with_time_zone: false
+                },
+                "timestamp without time zone"
+            ),
+            DataType::Array(Box::new(spelled(DataType::Int, "INT")), None),
+        ],
+        types
+    );
+    assert_eq!(&DataType::Int, types[0].without_spelling());
+    assert_eq!(&DataType::Int, types[1].without_spelling());
+    assert_eq!(
+        "CHARACTER VARYING(45), INT[]",
+        format!("{}, {}", types[2], types[4])
+    );
+
+    assert_eq!(
+        parse(false),
+        types
+            .iter()
+            .map(|t| match t {
+                DataType::Array(t, size) => {
+                    DataType::Array(Box::new(t.without_spelling().clone()), *size)
+                }
+                t => t.without_spelling().clone(),
+            })
+            .collect::<Vec<_>>()
+    );
+}
+
 #[test]
 fn parse_invalid_subquery_without_parens() {
     let res = parse_sql_statements("SELECT SELECT 1 FROM bar WHERE 1=1 FROM baz");
@@ -3396,6 +5254,95 @@ fn parse_fetch_variations() {
     );
 }
 
+#[test]
+fn statement_classification() {
+    let cases = vec![
+        ("SELECT * FROM t", StatementKind::Query, true),
+        ("SELECT * FROM t FOR UPDATE", StatementKind::Query, false),
+        ("PEEK v", StatementKind::Query, true),
+        ("INSERT INTO t VALUES (1)", StatementKind::Dml, false),
+        ("UPDATE t SET a = 1", StatementKind::Dml, false),
+        ("DELETE FROM t", StatementKind::Dml, false),
+        ("CREATE TABLE t (a int)", StatementKind::Ddl, false),
+        ("DROP VIEW v", StatementKind::Ddl, false),
+        ("ALTER TABLE t ADD UNIQUE (a)", StatementKind::Ddl, false),
+        ("START TRANSACTION", StatementKind::Transaction, false),
+        ("COMMIT", StatementKind::Transaction, false),
+        ("SET search_path = public", StatementKind::Utility, false),
+        ("SHOW TABLES", StatementKind::Utility, true),
+        ("EXPLAIN PLAN FOR SELECT 1", StatementKind::Utility, true),
+        ("DECLARE c CURSOR FOR PEEK v", StatementKind::Utility, true),
+        ("FETCH 10 FROM c", StatementKind::Query, true),
+        ("CLOSE c", StatementKind::Utility, true),
+    ];
+    for (sql, kind, readonly) in cases {
+        let stmt = verified_stmt(sql);
+        assert_eq!(kind, stmt.classification(), "{}", sql);
+        assert_eq!(readonly, stmt.is_readonly(), "{}", sql);
+    }
+}
+
+#[test]
+fn parse_lock_clauses() {
+    let ast = verified_query("SELECT * FROM t FOR UPDATE");
+    assert_eq!(
+        ast.locks,
+        vec![LockClause {
+            lock_type: LockType::Update,
+            of: vec![],
+            nonblock: None,
+        }]
+    );
+
+    let ast = verified_query(
+        "SELECT * FROM t, u ORDER BY a LIMIT 1 FOR NO KEY UPDATE OF t, s.u NOWAIT FOR KEY SHARE SKIP LOCKED",
+    );
+    assert_eq!(
+        ast.locks,
+        vec![
+            LockClause {
+                lock_type: LockType::NoKeyUpdate,
+                of: vec![
+                    ObjectName(vec!["t".into()]),
+                    ObjectName(vec!["s".into(), "u".into()]),
+                ],
+                nonblock: Some(NonBlock::Nowait),
+            },
+            LockClause {
+                lock_type: LockType::KeyShare,
+                of: vec![],
+                nonblock: Some(NonBlock::SkipLocked),
+            },
+        ]
+    );
+    verified_query("SELECT * FROM t FOR SHARE OF t");
+    verified_query("(SELECT * FROM t FOR UPDATE) UNION SELECT 1");
+
+    // the locking clauses may also precede LIMIT
+    one_statement_parses_to(
+        "SELECT * FROM t ORDER BY a FOR UPDATE LIMIT 1",
+        "SELECT * FROM t ORDER BY a LIMIT 1 FOR UPDATE",
+    );
+
+    let res = parse_sql_statements("SELECT * FROM t FOR UPDATE LIMIT 1 FOR SHARE");
+    assert_eq!(
+        ParserError::ParserError("Expected end of statement, found: FOR".to_string()),
+        res.unwrap_err()
+    );
+    let res = parse_sql_statements("SELECT * FROM t FOR KEY UPDATE");
+    assert_eq!(
+        ParserError::ParserError("Expected SHARE, found: UPDATE".to_string()),
+        res.unwrap_err()
+    );
+    let res = parse_sql_statements("SELECT * FROM t FOR");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected one of UPDATE or NO or SHARE or KEY, found: EOF".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
 #[test]
 fn lateral_derived() {
     fn chk(lateral_in: bool) {
@@ -3498,6 +5445,13 @@ fn parse_start_transaction() {
         res.unwrap_err()
     );
 
+    assert_eq!(
+        2,
+        parse_sql_statements("START TRANSACTION; COMMIT")
+            .unwrap()
+            .len()
+    );
+
     let res = parse_sql_statements("START TRANSACTION BAD");
     assert_eq!(
         ParserError::ParserError("Expected transaction mode, found: BAD".to_string()),
@@ -3511,6 +5465,41 @@ fn parse_start_transaction() {
     );
 }
 
+#[test]
+fn parse_quoted_keywords_as_identifiers() {
+    // quoted words are never treated as keywords, and keep their case
+    let select = verified_only_select(r#"SELECT "select", "FROM" FROM "where" AS "order""#);
+    assert_eq!(
+        &Expr::Identifier(Ident::with_quote('"', "select")),
+        expr_from_projection(&select.projection[0]),
+    );
+    assert_eq!(
+        &Expr::Identifier(Ident::with_quote('"', "FROM")),
+        expr_from_projection(&select.projection[1]),
+    );
+    match only(select.from).relation {
+        TableFactor::Table { name, alias, .. } => {
+            assert_eq!(vec![Ident::with_quote('"', "where")], name.0);
+            assert_eq!(Ident::with_quote('"', "order"), alias.unwrap().name);
+        }
+        _ => panic!("Expecting TableFactor::Table"),
+    }
+
+    // a quoted keyword is not reserved as an implicit alias either
+    one_statement_parses_to(
+        r#"SELECT a "from" FROM t "where""#,
+        r#"SELECT a AS "from" FROM t AS "where""#,
+    );
+
+    let res = parse_sql_statements(r#""SELECT" 1"#);
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected a keyword at the beginning of a statement, found: \"SELECT\"".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
 #[test]
 fn parse_set_transaction() {
     // SET TRANSACTION shares transaction mode parsing code with START
@@ -3527,6 +5516,12 @@ fn parse_set_transaction() {
         ),
         _ => unreachable!(),
     }
+
+    one_statement_parses_to("set transaction read only", "SET TRANSACTION READ ONLY");
+    match verified_stmt(r#"SET "transaction" = 1"#) {
+        Statement::SetVariable { .. } => (),
+        _ => unreachable!(),
+    }
 }
 
 #[test]
@@ -3587,7 +5582,7 @@ fn parse_explain() {
         ast,
         Statement::Explain {
             stage: Stage::Dataflow,
-            query: Box::new(verified_query("SELECT 665")),
+            statement: Box::new(verified_stmt("SELECT 665")),
         }
     );
 
@@ -3596,9 +5591,28 @@ fn parse_explain() {
         ast,
         Statement::Explain {
             stage: Stage::Plan,
-            query: Box::new(verified_query("SELECT 665")),
+            statement: Box::new(verified_stmt("SELECT 665")),
+        }
+    );
+
+    let ast = verified_stmt("EXPLAIN PLAN FOR INSERT INTO t VALUES (1)");
+    assert_eq!(
+        ast,
+        Statement::Explain {
+            stage: Stage::Plan,
+            statement: Box::new(verified_stmt("INSERT INTO t VALUES (1)")),
         }
     );
+    verified_stmt("EXPLAIN PLAN FOR UPDATE t SET a = 1");
+    verified_stmt("EXPLAIN DATAFLOW FOR DELETE FROM t");
+
+    let res = parse_sql_statements("EXPLAIN PLAN FOR DROP TABLE t");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected a query, INSERT, UPDATE or DELETE, found: DROP".to_string()
+        ),
+        res.unwrap_err()
+    );
 }
 
 #[test]
@@ -3668,8 +5682,8 @@ fn verify_interval(
                     // XXX: technically the duration debug format is unstable,
                     // so this might break for no reason, but it's very
                     // convenient for sanity-checking that our math is right.
-                    Interval::Duration { duration, .. } => {
-                        assert_eq!(expected_dur, &format!("{:?}", duration))
+                    Interval::Duration(duration) => {
+                        assert_eq!(expected_dur, &format!("{:?}", duration.unsigned_abs()))
                     }
                     other => panic!("unexpected computed for {}: {:?}", sql, other),
                 }
@@ -3697,11 +5711,6 @@ fn single_iv() -> IntervalValue {
     }
 }
 
-fn dur_secs(n: u64) -> Interval {
-    use std::time::Duration;
-
-    Interval::Duration {
-        is_positive: true,
-        duration: Duration::from_secs(n),
-    }
+fn dur_secs(n: i64) -> Interval {
+    Interval::Duration(SignedDuration::from_secs(n))
 }
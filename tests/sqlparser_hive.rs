@@ -0,0 +1,112 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![warn(clippy::all)]
+
+//! Test SQL syntax specific to Hive and Spark SQL. The parser based on the
+//! generic dialect is also tested (on the inputs it can handle).
+
+use sqlparser::ast::*;
+use sqlparser::dialect::{GenericDialect, HiveDialect, DIALECT_NAMES};
+use sqlparser::parser::ParserError;
+use sqlparser::test_utils::*;
+
+#[test]
+fn parse_cluster_by() {
+    let select = hive_and_generic().verified_only_select("SELECT a, b FROM t CLUSTER BY a, b");
+    assert_eq!(
+        vec![
+            Expr::Identifier(Ident::new("a")),
+            Expr::Identifier(Ident::new("b")),
+        ],
+        select.cluster_by
+    );
+    assert!(select.distribute_by.is_empty());
+    assert!(select.sort_by.is_empty());
+
+    let res = hive().parse_sql_statements("SELECT a FROM t CLUSTER BY a SORT BY a");
+    assert_eq!(
+        ParserError::ParserError("Expected end of statement, found: SORT".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_distribute_by_sort_by() {
+    let select = hive_and_generic()
+        .verified_only_select("SELECT a, b FROM t WHERE c > 0 DISTRIBUTE BY a SORT BY b DESC, a");
+    assert!(select.cluster_by.is_empty());
+    assert_eq!(
+        vec![Expr::Identifier(Ident::new("a"))],
+        select.distribute_by
+    );
+    assert_eq!(
+        vec![
+            OrderByExpr {
+                expr: Expr::Identifier(Ident::new("b")),
+                asc: Some(false),
+            },
+            OrderByExpr {
+                expr: Expr::Identifier(Ident::new("a")),
+                asc: None,
+            },
+        ],
+        select.sort_by
+    );
+
+    let select = hive_and_generic().verified_only_select("SELECT a FROM t DISTRIBUTE BY a");
+    assert_eq!(
+        vec![Expr::Identifier(Ident::new("a"))],
+        select.distribute_by
+    );
+    assert!(select.sort_by.is_empty());
+
+    let query = hive_and_generic().verified_query("SELECT a FROM t SORT BY a LIMIT 10");
+    assert_eq!(Some(Expr::Value(number("10"))), query.limit);
+}
+
+#[test]
+fn parse_clause_keywords_as_identifiers() {
+    // The clauses are Hive's, so other dialects can use their keywords as
+    // aliases
+    let sql = "SELECT a sort FROM t cluster";
+    TestedDialects::named(&["postgres"])
+        .one_statement_parses_to(sql, "SELECT a AS sort FROM t AS cluster");
+    hive().assert_parse_error(sql, "Expected end of statement, found: sort");
+
+    // Only Hive, and the generic dialect, which knows every keyword, parse
+    // the clauses
+    let others = hive_and_generic().others();
+    assert_eq!(DIALECT_NAMES.len() - 2, others.dialects.len());
+    hive_and_generic().verified_stmt_rejected_by(
+        "SELECT a FROM t DISTRIBUTE BY a SORT BY b",
+        &others,
+        "Expected end of statement, found: BY",
+    );
+}
+
+#[test]
+fn parse_backtick_identifiers() {
+    hive().verified_stmt("SELECT `a b` FROM `db`.`t`");
+}
+
+fn hive() -> TestedDialects {
+    TestedDialects {
+        dialects: vec![Box::new(HiveDialect {})],
+    }
+}
+
+fn hive_and_generic() -> TestedDialects {
+    TestedDialects {
+        dialects: vec![Box::new(HiveDialect {}), Box::new(GenericDialect {})],
+    }
+}
@@ -42,6 +42,20 @@ fn parse_mssql_identifiers() {
 #[test]
 fn parse_mssql_single_quoted_aliases() {
     let _ = ms_and_generic().one_statement_parses_to("SELECT foo 'alias'", "SELECT foo AS 'alias'");
+    let select = ms().verified_only_select("SELECT foo AS 'it''s', bar AS [a]]b]");
+    assert_eq!(
+        vec![
+            SelectItem::ExprWithAlias {
+                expr: Expr::Identifier(Ident::new("foo")),
+                alias: Ident::with_quote('\'', "it's"),
+            },
+            SelectItem::ExprWithAlias {
+                expr: Expr::Identifier(Ident::new("bar")),
+                alias: Ident::with_quote('[', "a]b"),
+            },
+        ],
+        select.projection
+    );
 }
 
 #[test]
@@ -50,6 +64,11 @@ fn parse_mssql_delimited_identifiers() {
         "SELECT [a.b!] [FROM] FROM foo [WHERE]",
         "SELECT [a.b!] AS [FROM] FROM foo AS [WHERE]",
     );
+    let select = ms().verified_only_select("SELECT [sıze] FROM [ﬁle]");
+    assert_eq!(
+        &Expr::Identifier(Ident::with_quote('[', "sıze")),
+        expr_from_projection(only(&select.projection))
+    );
 }
 
 #[test]
@@ -68,6 +87,142 @@ fn parse_mssql_apply_join() {
     );
 }
 
+#[test]
+fn parse_mssql_insert_with_hints_and_output() {
+    let sql =
+        "INSERT INTO t WITH (TABLOCK) (a, b) OUTPUT inserted.id, inserted.a AS x VALUES (1, 2)";
+    match ms_and_generic().verified_stmt(sql) {
+        Statement::Insert {
+            table_name,
+            with_hints,
+            columns,
+            output,
+            ..
+        } => {
+            assert_eq!("t", table_name.to_string());
+            assert_eq!(vec![Expr::Identifier("TABLOCK".into())], with_hints);
+            assert_eq!(vec![Ident::new("a"), Ident::new("b")], columns);
+            assert_eq!(
+                vec![
+                    SelectItem::UnnamedExpr(Expr::CompoundIdentifier(vec![
+                        "inserted".into(),
+                        "id".into()
+                    ])),
+                    SelectItem::ExprWithAlias {
+                        expr: Expr::CompoundIdentifier(vec!["inserted".into(), "a".into()]),
+                        alias: "x".into(),
+                    },
+                ],
+                output
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    ms_and_generic().verified_stmt("INSERT INTO t OUTPUT inserted.* SELECT * FROM u");
+    // WITH not followed by hints starts a CTE instead
+    ms_and_generic().verified_stmt("INSERT INTO t WITH u AS (SELECT 1) SELECT * FROM u");
+}
+
+#[test]
+fn parse_mssql_update_delete_output() {
+    match ms_and_generic()
+        .verified_stmt("UPDATE t SET a = 1 OUTPUT deleted.a, inserted.a WHERE b = 2")
+    {
+        Statement::Update {
+            output, selection, ..
+        } => {
+            assert_eq!(2, output.len());
+            assert!(selection.is_some());
+        }
+        _ => unreachable!(),
+    }
+
+    match ms_and_generic().verified_stmt("DELETE FROM t OUTPUT deleted.* WHERE a = 1") {
+        Statement::Delete { output, .. } => {
+            assert_eq!(
+                vec![SelectItem::QualifiedWildcard(
+                    ObjectName(vec!["deleted".into()]),
+                    WildcardOptions::default()
+                )],
+                output
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_group_by_constant() {
+    // MS SQL has no GROUP BY ordinals, but does have ORDER BY ordinals
+    let query = ms().verified_query("SELECT a FROM t GROUP BY 1 ORDER BY 1");
+    match query.body {
+        SetExpr::Select(select) => assert_eq!(vec![Expr::Value(number("1"))], select.group_by),
+        _ => unreachable!(),
+    }
+    assert_eq!(Expr::Ordinal(1), query.order_by[0].expr);
+}
+
+#[test]
+fn parse_string_literal_alias() {
+    // Adjacent string literals aren't concatenated: the second is an alias
+    ms().one_statement_parses_to("SELECT 'a' 'b'", "SELECT 'a' AS 'b'");
+}
+
+#[test]
+fn parse_convert() {
+    // MS SQL puts the target type first, and takes an optional style
+    let select = ms().verified_only_select("SELECT CONVERT(character varying(10), d, 121)");
+    assert_eq!(
+        &Expr::Convert {
+            expr: Box::new(Expr::Identifier(Ident::new("d"))),
+            data_type: Some(DataType::Varchar(Some(CharacterLength::IntegerLength {
+                length: 10,
+                unit: None,
+            }))),
+            charset: None,
+            target_before_value: true,
+            styles: vec![Expr::Value(number("121"))],
+        },
+        expr_from_projection(only(&select.projection)),
+    );
+    ms().verified_stmt("SELECT CONVERT(int, '1')");
+}
+
+#[test]
+fn parse_varchar_max() {
+    let select = ms().verified_only_select("SELECT CAST(a AS nvarchar(max))");
+    assert_eq!(
+        &Expr::Cast {
+            expr: Box::new(Expr::Identifier(Ident::new("a"))),
+            data_type: DataType::Nvarchar(Some(CharacterLength::Max)),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+    ms().one_statement_parses_to(
+        "CREATE TABLE t (a VARCHAR(MAX))",
+        "CREATE TABLE t (a character varying(max))",
+    );
+}
+
+#[test]
+fn parse_caret_as_xor() {
+    // `^` is bitwise XOR, with the precedence of `+`
+    let select = ms().verified_only_select("SELECT a ^ b * c");
+    assert_eq!(
+        &Expr::BinaryOp {
+            left: Box::new(Expr::Identifier(Ident::new("a"))),
+            op: BinaryOperator::BitwiseXor,
+            right: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("b"))),
+                op: BinaryOperator::Multiply,
+                right: Box::new(Expr::Identifier(Ident::new("c"))),
+            }),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+}
+
 fn ms() -> TestedDialects {
     TestedDialects {
         dialects: vec![Box::new(MsSqlDialect {})],
@@ -22,6 +22,22 @@ use sqlparser::test_utils::*;
 #[test]
 fn parse_identifiers() {
     mysql().verified_stmt("SELECT $a$, àà");
+
+    // Words that only spell a keyword under Unicode case mapping, like
+    // `lımıt` with dotless `ı`, are identifiers
+    let select = mysql().verified_only_select("SELECT lımıt, ſelect FROM ﬁle");
+    assert_eq!(
+        vec![
+            SelectItem::UnnamedExpr(Expr::Identifier(Ident::new("lımıt"))),
+            SelectItem::UnnamedExpr(Expr::Identifier(Ident::new("ſelect"))),
+        ],
+        select.projection
+    );
+}
+
+#[test]
+fn parse_hash_comments() {
+    mysql().one_statement_parses_to("SELECT a # the first column\nFROM t", "SELECT a FROM t");
 }
 
 #[test]
@@ -97,6 +113,265 @@ fn parse_show_columns() {
     }
 }
 
+#[test]
+fn parse_convert() {
+    let select = mysql_and_generic().verified_only_select("SELECT CONVERT(a USING utf8mb4)");
+    assert_eq!(
+        &Expr::Convert {
+            expr: Box::new(Expr::Identifier(Ident::new("a"))),
+            data_type: None,
+            charset: Some(ObjectName(vec![Ident::new("utf8mb4")])),
+            target_before_value: false,
+            styles: vec![],
+        },
+        expr_from_projection(only(&select.projection)),
+    );
+
+    let select = mysql_and_generic().verified_only_select("SELECT CONVERT(a + 1, char)");
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Convert {
+            data_type,
+            charset,
+            target_before_value,
+            ..
+        } => {
+            assert_eq!(&Some(DataType::Char(None)), data_type);
+            assert_eq!(&None, charset);
+            assert!(!target_before_value);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_pipes_as_or() {
+    let sql = "SELECT a = 1 || b = 2";
+    mysql().one_statement_parses_to(sql, "SELECT a = 1 OR b = 2");
+    let select = mysql().unverified_only_select(sql);
+    assert_eq!(
+        &Expr::BinaryOp {
+            left: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("a"))),
+                op: BinaryOperator::Eq,
+                right: Box::new(Expr::Value(number("1"))),
+            }),
+            op: BinaryOperator::Or,
+            right: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("b"))),
+                op: BinaryOperator::Eq,
+                right: Box::new(Expr::Value(number("2"))),
+            }),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+}
+
+#[test]
+fn parse_caret_as_xor() {
+    let select = mysql().verified_only_select("SELECT a ^ b * c");
+    assert_eq!(
+        &Expr::BinaryOp {
+            left: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("a"))),
+                op: BinaryOperator::BitwiseXor,
+                right: Box::new(Expr::Identifier(Ident::new("b"))),
+            }),
+            op: BinaryOperator::Multiply,
+            right: Box::new(Expr::Identifier(Ident::new("c"))),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+}
+
+#[test]
+fn parse_interval_expression() {
+    let select = mysql_and_generic().verified_only_select("SELECT d + INTERVAL 1 DAY");
+    assert_eq!(
+        &Expr::BinaryOp {
+            left: Box::new(Expr::Identifier(Ident::new("d"))),
+            op: BinaryOperator::Plus,
+            right: Box::new(Expr::Interval {
+                value: Box::new(Expr::Value(number("1"))),
+                field: DateTimeField::Day,
+            }),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    mysql().verified_only_select("SELECT DATE_ADD(d, INTERVAL n * 2 HOUR)");
+    mysql().verified_only_select("SELECT d - INTERVAL '1' MONTH");
+}
+
+#[test]
+fn parse_constraint_enforced() {
+    let sql = "CREATE TABLE t (a int, CHECK (a > 0) NOT ENFORCED, UNIQUE (a) ENFORCED)";
+    match mysql_and_generic().verified_stmt(sql) {
+        Statement::CreateTable { constraints, .. } => {
+            match &constraints[0] {
+                TableConstraint::Check {
+                    no_inherit,
+                    characteristics,
+                    ..
+                } => {
+                    assert!(!no_inherit);
+                    assert_eq!(
+                        &Some(ConstraintCharacteristics {
+                            deferrable: None,
+                            initially: None,
+                            enforced: Some(false),
+                        }),
+                        characteristics
+                    );
+                }
+                _ => unreachable!(),
+            }
+            match &constraints[1] {
+                TableConstraint::Unique {
+                    characteristics, ..
+                } => assert_eq!(Some(true), characteristics.unwrap().enforced),
+                _ => unreachable!(),
+            }
+        }
+        _ => unreachable!(),
+    }
+
+    let sql =
+        "CREATE TABLE t (a int CHECK (a > 0) NOT ENFORCED NOT NULL, b int CHECK (b > 0) ENFORCED)";
+    match mysql_and_generic().verified_stmt(sql) {
+        Statement::CreateTable { columns, .. } => {
+            assert_eq!(
+                vec![
+                    ColumnOption::Check {
+                        expr: Expr::BinaryOp {
+                            left: Box::new(Expr::Identifier(Ident::new("a"))),
+                            op: BinaryOperator::Gt,
+                            right: Box::new(Expr::Value(number("0"))),
+                        },
+                        characteristics: Some(ConstraintCharacteristics {
+                            deferrable: None,
+                            initially: None,
+                            enforced: Some(false),
+                        }),
+                    },
+                    ColumnOption::NotNull,
+                ],
+                columns[0]
+                    .options
+                    .iter()
+                    .map(|option| option.option.clone())
+                    .collect::<Vec<_>>()
+            );
+            match &columns[1].options[0].option {
+                ColumnOption::Check {
+                    characteristics, ..
+                } => assert_eq!(Some(true), characteristics.unwrap().enforced),
+                _ => unreachable!(),
+            }
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_backslash_escapes() {
+    let sql = r"SELECT 'it\'s', 'a\\b', 'c\nd', 'e\%'";
+    let statement = mysql().one_statement_parses_to(sql, "");
+    // Strings with backslashes are displayed with them escaped, so that
+    // MySQL reads them back the same
+    assert_eq!(
+        "SELECT 'it''s', 'a\\\\b', 'c\nd', 'e\\\\%'",
+        statement.to_string()
+    );
+    let select = match statement {
+        Statement::Query(query) => match query.body {
+            SetExpr::Select(select) => select,
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+    let values: Vec<_> = select
+        .projection
+        .iter()
+        .map(|item| match expr_from_projection(item) {
+            Expr::Value(value) => value.clone(),
+            _ => unreachable!(),
+        })
+        .collect();
+    assert_eq!(
+        vec![
+            Value::SingleQuotedString("it's".into()),
+            Value::BackslashEscapedString(r"a\b".into()),
+            Value::SingleQuotedString("c\nd".into()),
+            Value::BackslashEscapedString(r"e\%".into()),
+        ],
+        values
+    );
+    // In other dialects, backslashes stand for themselves
+    let select =
+        TestedDialects::named(&["generic", "postgres"]).verified_only_select(r"SELECT 'a\b'");
+    assert_eq!(
+        &Expr::Value(Value::SingleQuotedString(r"a\b".into())),
+        expr_from_projection(only(&select.projection))
+    );
+
+    // `escape_string_literal` writes literals that MySQL reads back
+    let escaped: Vec<_> = ["it's", r"a\b", "c\nd", r"e\%"]
+        .iter()
+        .map(|s| escape_string_literal(s, &MySqlDialect {}).to_string())
+        .collect();
+    assert_eq!(vec!["it''s", r"a\\b", "c\nd", r"e\\%"], escaped);
+}
+
+#[test]
+fn parse_insert_ignore_and_replace() {
+    match mysql_and_generic().verified_stmt("INSERT IGNORE INTO t (a, b) VALUES (1, 2)") {
+        Statement::Insert {
+            conflict,
+            table_name,
+            columns,
+            ..
+        } => {
+            assert_eq!(Some(InsertConflict::Ignore), conflict);
+            assert_eq!("t", table_name.to_string());
+            assert_eq!(vec![Ident::new("a"), Ident::new("b")], columns);
+        }
+        _ => unreachable!(),
+    }
+
+    match mysql_and_generic().verified_stmt("REPLACE INTO t SELECT * FROM u") {
+        Statement::Insert {
+            conflict, source, ..
+        } => {
+            assert_eq!(Some(InsertConflict::Replace), conflict);
+            assert_eq!("SELECT * FROM u", source.to_string());
+        }
+        _ => unreachable!(),
+    }
+
+    match mysql_and_generic().verified_stmt("INSERT INTO t VALUES (1)") {
+        Statement::Insert { conflict, .. } => assert_eq!(None, conflict),
+        _ => unreachable!(),
+    }
+
+    // `REPLACE` is also a function
+    mysql_and_generic().verified_stmt("SELECT REPLACE(a, 'x', 'y') FROM t");
+    assert!(mysql()
+        .parse_sql_statements("REPLACE IGNORE INTO t VALUES (1)")
+        .is_err());
+}
+
+#[test]
+fn parse_copy_option_names_ascii_only() {
+    // Like keywords, COPY option names only match under ASCII case folding
+    mysql().assert_parse_error(
+        "COPY t FROM stdin WITH (DELıMıTER '|')",
+        "Unknown COPY option: DELıMıTER",
+    );
+    assert!(mysql()
+        .parse_sql_statements("COPY t FROM stdin WITH (FORMAT cſv)")
+        .is_err());
+}
+
 fn mysql() -> TestedDialects {
     TestedDialects {
         dialects: vec![Box::new(MySqlDialect {})],
@@ -44,6 +44,9 @@ fn parse_create_table_with_defaults() {
             external: false,
             file_format: None,
             location: None,
+            without_rowid: false,
+            engine: None,
+            order_by: _,
         } => {
             assert_eq!("public.customer", name.to_string());
             assert_eq!(
@@ -71,7 +74,10 @@ fn parse_create_table_with_defaults() {
                     },
                     ColumnDef {
                         name: "first_name".into(),
-                        data_type: DataType::Varchar(Some(45)),
+                        data_type: DataType::Varchar(Some(CharacterLength::IntegerLength {
+                            length: 45,
+                            unit: None,
+                        })),
                         collation: None,
                         options: vec![ColumnOptionDef {
                             name: None,
@@ -80,7 +86,10 @@ fn parse_create_table_with_defaults() {
                     },
                     ColumnDef {
                         name: "last_name".into(),
-                        data_type: DataType::Varchar(Some(45)),
+                        data_type: DataType::Varchar(Some(CharacterLength::IntegerLength {
+                            length: 45,
+                            unit: None,
+                        })),
                         collation: Some(ObjectName(vec![Ident::with_quote('"', "es_ES")])),
                         options: vec![ColumnOptionDef {
                             name: None,
@@ -89,7 +98,10 @@ fn parse_create_table_with_defaults() {
                     },
                     ColumnDef {
                         name: "email".into(),
-                        data_type: DataType::Varchar(Some(50)),
+                        data_type: DataType::Varchar(Some(CharacterLength::IntegerLength {
+                            length: 50,
+                            unit: None,
+                        })),
                         collation: None,
                         options: vec![],
                     },
@@ -136,7 +148,10 @@ fn parse_create_table_with_defaults() {
                     },
                     ColumnDef {
                         name: "last_update".into(),
-                        data_type: DataType::Timestamp,
+                        data_type: DataType::Timestamp {
+                            precision: None,
+                            with_time_zone: false,
+                        },
                         collation: None,
                         options: vec![
                             ColumnOptionDef {
@@ -151,7 +166,10 @@ fn parse_create_table_with_defaults() {
                     },
                     ColumnDef {
                         name: "last_update_tz".into(),
-                        data_type: DataType::TimestampTz,
+                        data_type: DataType::Timestamp {
+                            precision: None,
+                            with_time_zone: true,
+                        },
                         collation: None,
                         options: vec![],
                     },
@@ -170,15 +188,15 @@ fn parse_create_table_with_defaults() {
             assert_eq!(
                 with_options,
                 vec![
-                    SqlOption {
+                    SqlOption::Value {
                         name: "fillfactor".into(),
                         value: number("20")
                     },
-                    SqlOption {
+                    SqlOption::Value {
                         name: "user_catalog_table".into(),
                         value: Value::Boolean(true)
                     },
-                    SqlOption {
+                    SqlOption::Value {
                         name: "autovacuum_vacuum_threshold".into(),
                         value: number("100")
                     },
@@ -233,7 +251,572 @@ fn parse_create_table_with_inherit() {
     pg().verified_stmt(sql);
 }
 
-#[ignore] // NOTE(benesch): this test is doomed. COPY data should not be tokenized/parsed.
+#[test]
+fn parse_constraint_characteristics() {
+    let sql = "ALTER TABLE orders ADD CONSTRAINT orders_customer_fkey FOREIGN KEY (customer_id) \
+               REFERENCES customers(id) ON DELETE CASCADE DEFERRABLE INITIALLY DEFERRED";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::AlterTable {
+            operation:
+                AlterTableOperation::AddConstraint(TableConstraint::ForeignKey {
+                    characteristics, ..
+                }),
+            ..
+        } => assert_eq!(
+            Some(ConstraintCharacteristics {
+                deferrable: Some(true),
+                initially: Some(DeferrableInitial::Deferred),
+                enforced: None,
+            }),
+            characteristics
+        ),
+        _ => unreachable!(),
+    }
+
+    let sql = "CREATE TABLE t (\
+               a int UNIQUE NOT DEFERRABLE, \
+               b int REFERENCES o (b) INITIALLY IMMEDIATE NOT NULL, \
+               PRIMARY KEY (a) DEFERRABLE)";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::CreateTable {
+            columns,
+            constraints,
+            ..
+        } => {
+            assert_eq!(
+                ColumnOption::Unique {
+                    is_primary: false,
+                    characteristics: Some(ConstraintCharacteristics {
+                        deferrable: Some(false),
+                        initially: None,
+                        enforced: None,
+                    }),
+                },
+                columns[0].options[0].option
+            );
+            match &columns[1].options[0].option {
+                ColumnOption::ForeignKey {
+                    characteristics, ..
+                } => assert_eq!(
+                    &Some(ConstraintCharacteristics {
+                        deferrable: None,
+                        initially: Some(DeferrableInitial::Immediate),
+                        enforced: None,
+                    }),
+                    characteristics
+                ),
+                _ => unreachable!(),
+            }
+            assert_eq!(ColumnOption::NotNull, columns[1].options[1].option);
+            assert_eq!("PRIMARY KEY (a) DEFERRABLE", constraints[0].to_string());
+        }
+        _ => unreachable!(),
+    }
+
+    pg_and_generic().one_statement_parses_to(
+        "CREATE TABLE t (a int UNIQUE INITIALLY DEFERRED DEFERRABLE)",
+        "CREATE TABLE t (a int UNIQUE DEFERRABLE INITIALLY DEFERRED)",
+    );
+    pg_and_generic()
+        .verified_stmt("CREATE TABLE t (a int UNIQUE NOT DEFERRABLE INITIALLY IMMEDIATE)");
+
+    for (characteristics, error) in &[
+        (
+            "DEFERRABLE NOT DEFERRABLE",
+            "Cannot specify both DEFERRABLE and NOT DEFERRABLE",
+        ),
+        (
+            "INITIALLY DEFERRED DEFERRABLE INITIALLY IMMEDIATE",
+            "Cannot specify both INITIALLY DEFERRED and INITIALLY IMMEDIATE",
+        ),
+        (
+            "NOT DEFERRABLE INITIALLY DEFERRED",
+            "Cannot specify both NOT DEFERRABLE and INITIALLY DEFERRED",
+        ),
+        (
+            "INITIALLY DEFERRED NOT DEFERRABLE",
+            "Cannot specify both NOT DEFERRABLE and INITIALLY DEFERRED",
+        ),
+        (
+            "DEFERRABLE DEFERRABLE",
+            "Cannot specify DEFERRABLE more than once",
+        ),
+        (
+            "INITIALLY IMMEDIATE INITIALLY IMMEDIATE",
+            "Cannot specify INITIALLY IMMEDIATE more than once",
+        ),
+        (
+            "NOT ENFORCED NOT ENFORCED",
+            "Cannot specify NOT ENFORCED more than once",
+        ),
+    ] {
+        let sql = format!("CREATE TABLE t (a int UNIQUE {})", characteristics);
+        assert_eq!(
+            ParserError::ParserError(error.to_string()),
+            pg_and_generic().parse_sql_statements(&sql).unwrap_err(),
+            "{}",
+            sql
+        );
+    }
+}
+
+#[test]
+fn parse_create_sequence() {
+    let sql = "CREATE SEQUENCE IF NOT EXISTS public.t_id_seq AS int START WITH 1 INCREMENT BY 1 \
+               NO MINVALUE MAXVALUE 1000 CACHE 1 NO CYCLE OWNED BY public.t.id";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::CreateSequence {
+            name,
+            if_not_exists,
+            options,
+        } => {
+            assert_eq!("public.t_id_seq", name.to_string());
+            assert!(if_not_exists);
+            assert_eq!(
+                vec![
+                    SequenceOption::As(DataType::Int),
+                    SequenceOption::StartWith(Expr::Value(number("1"))),
+                    SequenceOption::IncrementBy(Expr::Value(number("1"))),
+                    SequenceOption::MinValue(None),
+                    SequenceOption::MaxValue(Some(Expr::Value(number("1000")))),
+                    SequenceOption::Cache(Expr::Value(number("1"))),
+                    SequenceOption::Cycle(false),
+                    SequenceOption::OwnedBy(Some(ObjectName(vec![
+                        Ident::new("public"),
+                        Ident::new("t"),
+                        Ident::new("id"),
+                    ]))),
+                ],
+                options
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    pg_and_generic().verified_stmt("CREATE SEQUENCE s");
+    pg_and_generic().one_statement_parses_to(
+        "CREATE SEQUENCE s INCREMENT -1 START 10 MINVALUE -100 CYCLE",
+        "CREATE SEQUENCE s INCREMENT BY - 1 START WITH 10 MINVALUE - 100 CYCLE",
+    );
+    assert_eq!(
+        ParserError::ParserError("RESTART is only valid in ALTER SEQUENCE".to_string()),
+        pg_and_generic()
+            .parse_sql_statements("CREATE SEQUENCE s RESTART")
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn parse_create_drop_extension() {
+    let sql = r#"CREATE EXTENSION IF NOT EXISTS "uuid-ossp" WITH SCHEMA public"#;
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::CreateExtension {
+            name,
+            if_not_exists,
+            schema,
+            version,
+            cascade,
+        } => {
+            assert_eq!(Ident::with_quote('"', "uuid-ossp"), name);
+            assert!(if_not_exists);
+            assert_eq!(Some(Ident::new("public")), schema);
+            assert_eq!(None, version);
+            assert!(!cascade);
+        }
+        _ => unreachable!(),
+    }
+    pg_and_generic().verified_stmt("CREATE EXTENSION hstore");
+    pg_and_generic().one_statement_parses_to(
+        "CREATE EXTENSION postgis SCHEMA gis VERSION v3 CASCADE",
+        "CREATE EXTENSION postgis WITH SCHEMA gis VERSION 'v3' CASCADE",
+    );
+
+    match pg_and_generic().verified_stmt("DROP EXTENSION IF EXISTS hstore, postgis CASCADE") {
+        Statement::Drop {
+            object_type,
+            if_exists,
+            names,
+            cascade,
+        } => {
+            assert_eq!(ObjectType::Extension, object_type);
+            assert!(if_exists);
+            assert_eq!(2, names.len());
+            assert!(cascade);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_alter_sequence() {
+    match pg_and_generic().verified_stmt("ALTER SEQUENCE IF EXISTS s RESTART WITH 5 OWNED BY NONE")
+    {
+        Statement::AlterSequence {
+            name,
+            if_exists,
+            options,
+        } => {
+            assert_eq!("s", name.to_string());
+            assert!(if_exists);
+            assert_eq!(
+                vec![
+                    SequenceOption::Restart(Some(Expr::Value(number("5")))),
+                    SequenceOption::OwnedBy(None),
+                ],
+                options
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    pg_and_generic().verified_stmt("ALTER SEQUENCE s RESTART NO MAXVALUE");
+    pg_and_generic().one_statement_parses_to(
+        "ALTER SEQUENCE s RESTART 5",
+        "ALTER SEQUENCE s RESTART WITH 5",
+    );
+    assert_eq!(
+        ParserError::ParserError("Expected an option in ALTER SEQUENCE, found: EOF".to_string()),
+        pg_and_generic()
+            .parse_sql_statements("ALTER SEQUENCE s")
+            .unwrap_err()
+    );
+    pg_and_generic().verified_stmt("DROP SEQUENCE IF EXISTS s CASCADE");
+    pg_and_generic().verified_stmt("SHOW SEQUENCES");
+}
+
+#[test]
+fn parse_serial_column_from_pg_dump() {
+    let sql = "CREATE TABLE public.t (id int NOT NULL);
+               CREATE SEQUENCE public.t_id_seq AS integer START WITH 1 INCREMENT BY 1
+                   NO MINVALUE NO MAXVALUE CACHE 1;
+               ALTER SEQUENCE public.t_id_seq OWNED BY public.t.id;
+               ALTER TABLE ONLY public.t ALTER COLUMN id SET DEFAULT nextval('public.t_id_seq'::regclass);
+               SELECT pg_catalog.setval('public.t_id_seq', 1, false);";
+    let statements = pg().parse_sql_statements(sql).unwrap();
+    assert_eq!(5, statements.len());
+    match &statements[3] {
+        Statement::AlterTable {
+            operation: AlterTableOperation::AlterColumn { column_name, op },
+            ..
+        } => {
+            assert_eq!(&Ident::new("id"), column_name);
+            assert_eq!(
+                "SET DEFAULT nextval(CAST('public.t_id_seq' AS regclass))",
+                op.to_string()
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    pg_and_generic().verified_stmt("ALTER TABLE t ALTER COLUMN id DROP DEFAULT");
+    pg_and_generic().one_statement_parses_to(
+        "ALTER TABLE t ALTER id SET DEFAULT 0",
+        "ALTER TABLE t ALTER COLUMN id SET DEFAULT 0",
+    );
+}
+
+#[test]
+fn parse_create_role() {
+    let sql = "CREATE ROLE analyst LOGIN NOSUPERUSER NOCREATEDB INHERIT CONNECTION LIMIT 5 \
+               PASSWORD 'it''s secret' VALID UNTIL '2030-01-01' IN ROLE readers, writers ADMIN dba";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::CreateRole {
+            name,
+            is_user,
+            options,
+        } => {
+            assert_eq!(Ident::new("analyst"), name);
+            assert!(!is_user);
+            assert_eq!(
+                vec![
+                    RoleOption::Login(true),
+                    RoleOption::SuperUser(false),
+                    RoleOption::CreateDb(false),
+                    RoleOption::Inherit(true),
+                    RoleOption::ConnectionLimit(Expr::Value(number("5"))),
+                    RoleOption::Password {
+                        encrypted: false,
+                        password: Some("it's secret".to_string()),
+                    },
+                    RoleOption::ValidUntil("2030-01-01".to_string()),
+                    RoleOption::InRole(vec![Ident::new("readers"), Ident::new("writers")]),
+                    RoleOption::Admin(vec![Ident::new("dba")]),
+                ],
+                options
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    pg_and_generic().verified_stmt("CREATE ROLE r");
+    pg_and_generic().verified_stmt("CREATE USER u ENCRYPTED PASSWORD 'md5abc' ROLE a, b");
+    pg_and_generic().one_statement_parses_to(
+        "CREATE ROLE r WITH SUPERUSER CREATEROLE REPLICATION BYPASSRLS",
+        "CREATE ROLE r SUPERUSER CREATEROLE REPLICATION BYPASSRLS",
+    );
+}
+
+#[test]
+fn parse_alter_role() {
+    match pg_and_generic().verified_stmt("ALTER USER u NOLOGIN PASSWORD NULL") {
+        Statement::AlterRole {
+            name,
+            is_user,
+            options,
+        } => {
+            assert_eq!(Ident::new("u"), name);
+            assert!(is_user);
+            assert_eq!(
+                vec![
+                    RoleOption::Login(false),
+                    RoleOption::Password {
+                        encrypted: false,
+                        password: None,
+                    },
+                ],
+                options
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    pg_and_generic().verified_stmt("ALTER ROLE r NOINHERIT NOREPLICATION NOBYPASSRLS NOCREATEROLE");
+    assert_eq!(
+        ParserError::ParserError("Expected an option in ALTER ROLE, found: EOF".to_string()),
+        pg_and_generic()
+            .parse_sql_statements("ALTER ROLE r")
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn parse_copy() {
+    let sql = "COPY t (a, b, c) FROM stdin;\n1\t\\N\tfoo bar\n\\.";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::Copy {
+            relation: CopyRelation::Table { name, columns },
+            target,
+            options,
+            values,
+        } => {
+            assert_eq!("t", name.to_string());
+            assert_eq!(
+                vec![Ident::new("a"), Ident::new("b"), Ident::new("c")],
+                columns
+            );
+            assert_eq!(CopyTarget::Stdin, target);
+            assert!(options.is_empty());
+            assert_eq!(
+                vec![vec![Some("1".into()), None, Some("foo bar".into())]],
+                values
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    match pg_and_generic().verified_stmt("COPY t FROM stdin;\n\\.") {
+        Statement::Copy { values, .. } => assert!(values.is_empty()),
+        _ => unreachable!(),
+    }
+
+    // As in pg_dump's output, no semicolon follows the end of the payload
+    let statements = pg_and_generic()
+        .parse_sql_statements("COPY t FROM stdin;\n1\n\\.\n\nCOPY u FROM stdin;\n\\.\nSELECT 1")
+        .unwrap();
+    assert_eq!(3, statements.len());
+}
+
+#[test]
+fn parse_copy_multiple_rows_with_escapes() {
+    let sql =
+        "COPY t (a, b) FROM stdin;\n1\ttab\\there\n2\tline\\nbreak\n3\tback\\\\slash\n4\t\n\\.";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::Copy { values, .. } => {
+            assert_eq!(
+                vec![
+                    vec![Some("1".into()), Some("tab\there".into())],
+                    vec![Some("2".into()), Some("line\nbreak".into())],
+                    vec![Some("3".into()), Some("back\\slash".into())],
+                    vec![Some("4".into()), Some("".into())],
+                ],
+                values
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    // Escapes that are not produced when serializing are decoded as well
+    let sql = "COPY t FROM stdin;\n\\101\\x42\\C\tvertical\\vtab\n\\.";
+    match pg_and_generic().one_statement_parses_to(sql, "") {
+        Statement::Copy { values, .. } => {
+            assert_eq!(
+                vec![vec![Some("ABC".into()), Some("vertical\u{b}tab".into())]],
+                values
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_copy_csv() {
+    let sql = "COPY t (a, b) FROM stdin WITH (FORMAT csv, HEADER);\n\
+               a,b\n\
+               1,\"x, \"\"y\"\"\"\n\
+               2,\"multi\nline\"\n\
+               ,\"\"\n\
+               \\.";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::Copy {
+            options, values, ..
+        } => {
+            assert_eq!(
+                vec![
+                    CopyOption::Format(CopyFormatName::Csv),
+                    CopyOption::Header(true)
+                ],
+                options
+            );
+            assert_eq!(
+                vec![
+                    vec![Some("a".into()), Some("b".into())],
+                    vec![Some("1".into()), Some("x, \"y\"".into())],
+                    vec![Some("2".into()), Some("multi\nline".into())],
+                    vec![None, Some("".into())],
+                ],
+                values
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    // A custom null string, and a quoted value that would otherwise be it
+    let sql = "COPY t FROM stdin (FORMAT csv, NULL 'NULL', QUOTE '''');\n\
+               NULL,'NULL','a,b'\n\
+               \\.";
+    let expected = "COPY t FROM stdin WITH (FORMAT csv, NULL 'NULL', QUOTE '''');\n\
+                    NULL,'NULL','a,b'\n\
+                    \\.";
+    match pg_and_generic().one_statement_parses_to(sql, expected) {
+        Statement::Copy { values, .. } => assert_eq!(
+            vec![vec![None, Some("NULL".into()), Some("a,b".into())]],
+            values
+        ),
+        _ => unreachable!(),
+    }
+
+    // The text format honors a custom delimiter and null string as well
+    let sql = "COPY t FROM stdin WITH (DELIMITER ',', NULL '');\na\\,b,,c\n\\.";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::Copy { values, .. } => assert_eq!(
+            vec![vec![Some("a,b".into()), None, Some("c".into())]],
+            values
+        ),
+        _ => unreachable!(),
+    }
+
+    // Unquoted values are taken as written, even where they aren't SQL
+    let sql = "COPY t FROM stdin WITH (FORMAT csv, DELIMITER '|');\n\
+               O'Brien|1e3|2E5x|-- x|/* y\n\
+               \\.";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::Copy { values, .. } => assert_eq!(
+            vec![vec![
+                Some("O'Brien".into()),
+                Some("1e3".into()),
+                Some("2E5x".into()),
+                Some("-- x".into()),
+                Some("/* y".into())
+            ]],
+            values
+        ),
+        _ => unreachable!(),
+    }
+    let sql = "COPY t FROM stdin WITH (DELIMITER '|');\nO'Brien|a\\|b|$$\n\\.";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::Copy { values, .. } => assert_eq!(
+            vec![vec![
+                Some("O'Brien".into()),
+                Some("a|b".into()),
+                Some("$$".into())
+            ]],
+            values
+        ),
+        _ => unreachable!(),
+    }
+
+    assert_eq!(
+        ParserError::ParserError("COPY DELIMITER must be a single character, found: ';;'".into()),
+        pg_and_generic()
+            .parse_sql_statements("COPY t FROM stdin WITH (DELIMITER ';;')")
+            .unwrap_err()
+    );
+    assert_eq!(
+        ParserError::ParserError("Unknown COPY option: FREEZE".into()),
+        pg_and_generic()
+            .parse_sql_statements("COPY t FROM stdin WITH (FREEZE)")
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn parse_copy_query_to_stdout() {
+    let sql = "COPY (SELECT a FROM t WHERE b > 1) TO STDOUT WITH (FORMAT csv)";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::Copy {
+            relation: CopyRelation::Query(query),
+            target,
+            options,
+            values,
+        } => {
+            assert_eq!("SELECT a FROM t WHERE b > 1", query.to_string());
+            assert_eq!(CopyTarget::Stdout, target);
+            assert_eq!(vec![CopyOption::Format(CopyFormatName::Csv)], options);
+            assert!(values.is_empty());
+        }
+        _ => unreachable!(),
+    }
+    assert!(pg_and_generic().verified_stmt(sql).is_readonly());
+
+    pg_and_generic().verified_stmt("COPY t (a, b) TO STDOUT");
+    pg_and_generic().one_statement_parses_to(
+        "COPY (SELECT 1) TO STDOUT (FORMAT text)",
+        "COPY (SELECT 1) TO STDOUT WITH (FORMAT text)",
+    );
+
+    // Neither a query nor a table copied to STDOUT is followed by a payload
+    let statements = pg_and_generic()
+        .parse_sql_statements("COPY (SELECT 1) TO STDOUT; COPY t TO STDOUT; SELECT 1")
+        .unwrap();
+    assert_eq!(3, statements.len());
+
+    assert_eq!(
+        ParserError::ParserError("Expected TO, found: FROM".into()),
+        pg_and_generic()
+            .parse_sql_statements("COPY (SELECT 1) FROM stdin")
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn parse_copy_binary() {
+    // The binary format can't be written in SQL, so no payload follows it
+    let statements = pg_and_generic()
+        .parse_sql_statements("COPY t FROM stdin WITH (FORMAT binary); SELECT 1")
+        .unwrap();
+    assert_eq!(2, statements.len());
+    match &statements[0] {
+        Statement::Copy {
+            options, values, ..
+        } => {
+            assert_eq!(&vec![CopyOption::Format(CopyFormatName::Binary)], options);
+            assert!(values.is_empty());
+        }
+        _ => unreachable!(),
+    }
+    pg_and_generic().verified_stmt("COPY t FROM stdin WITH (FORMAT binary)");
+}
+
 #[test]
 fn parse_copy_example() {
     let sql = r#"COPY public.actor (actor_id, first_name, last_name, last_update, value) FROM stdin;
@@ -256,9 +839,117 @@ Kwara & Kogi
 PHP	₱ USD $
 \N  Some other value
 \\."#;
-    let ast = pg_and_generic().one_statement_parses_to(sql, "");
-    println!("{:#?}", ast);
-    //assert_eq!(sql, ast.to_string());
+    match pg_and_generic().one_statement_parses_to(sql, "") {
+        Statement::Copy { values, .. } => {
+            assert_eq!(19, values.len());
+            assert_eq!(
+                vec![
+                    Some("12".into()),
+                    Some("KARL".into()),
+                    Some("BERRY".into()),
+                    Some("2017-11-02 19:15:42.308637+08 11.001".into())
+                ],
+                values[11]
+            );
+            assert_eq!(vec![Some("PHP".into()), Some("₱ USD $".into())], values[16]);
+            // `\N` is NULL only as a whole value
+            assert_eq!(vec![Some("N  Some other value".into())], values[17]);
+            // Without a `\.` line, the rows end with the input
+            assert_eq!(vec![Some("\\.".into())], values[18]);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_copy_payload_is_not_sql() {
+    // The rows are data, which needn't tokenize as SQL
+    let sql = "COPY t (a, b) FROM stdin;\n\
+               it's\t-- no comment\n\
+               /* no comment\t$$ no string\n\
+               2e5x\t1e3\n\
+               'a''b\t\"c\n\
+               \\.";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::Copy { values, .. } => assert_eq!(
+            vec![
+                vec![Some("it's".into()), Some("-- no comment".into())],
+                vec![Some("/* no comment".into()), Some("$$ no string".into())],
+                vec![Some("2e5x".into()), Some("1e3".into())],
+                vec![Some("'a''b".into()), Some("\"c".into())],
+            ],
+            values
+        ),
+        _ => unreachable!(),
+    }
+
+    // A `\.` ends the rows only on a line of its own
+    let statements = pg_and_generic()
+        .parse_sql_statements("COPY t FROM stdin;\na\\.\n\\.x\n\\.\r\nSELECT ';'")
+        .unwrap();
+    assert_eq!(2, statements.len());
+    match &statements[0] {
+        Statement::Copy { values, .. } => assert_eq!(
+            &vec![vec![Some("a.".into())], vec![Some(".x".into())]],
+            values
+        ),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_insert_with_alias_and_overriding() {
+    let sql = "INSERT INTO t AS x (a, b) OVERRIDING SYSTEM VALUE SELECT 1, 2";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::Insert {
+            table_name,
+            alias,
+            columns,
+            overriding,
+            ..
+        } => {
+            assert_eq!("t", table_name.to_string());
+            assert_eq!(Some(Ident::new("x")), alias);
+            assert_eq!(vec![Ident::new("a"), Ident::new("b")], columns);
+            assert_eq!(Some(InsertOverriding::System), overriding);
+        }
+        _ => unreachable!(),
+    }
+    pg_and_generic().verified_stmt("INSERT INTO t OVERRIDING USER VALUE VALUES (1)");
+    pg_and_generic().verified_stmt("INSERT INTO t AS x DEFAULT VALUES");
+
+    let res = pg().parse_sql_statements("INSERT INTO t OVERRIDING SYSTEM VALUES (1)");
+    assert_eq!(
+        ParserError::ParserError("Expected VALUE, found: VALUES".to_string()),
+        res.unwrap_err()
+    );
+    let res = pg().parse_sql_statements("INSERT INTO t OVERRIDING DEFAULT VALUE VALUES (1)");
+    assert_eq!(
+        ParserError::ParserError("Expected one of SYSTEM or USER, found: DEFAULT".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_delete_with_output_alias() {
+    // `OUTPUT` only introduces a clause in MSSQL, so here it is an alias
+    match pg().one_statement_parses_to(
+        "DELETE FROM t output WHERE output.a = 1",
+        "DELETE FROM t AS output WHERE output.a = 1",
+    ) {
+        Statement::Delete { alias, output, .. } => {
+            assert_eq!(Some(Ident::new("output")), alias);
+            assert!(output.is_empty());
+        }
+        _ => unreachable!(),
+    }
+    pg().verified_stmt("UPDATE t AS output SET a = 1 WHERE output.b = 2");
+
+    let res = pg().parse_sql_statements("DELETE FROM t OUTPUT deleted.* WHERE a = 1");
+    assert_eq!(
+        ParserError::ParserError("Expected end of statement, found: deleted".to_string()),
+        res.unwrap_err()
+    );
 }
 
 #[test]
@@ -391,6 +1082,20 @@ fn parse_array() {
     );
 }
 
+#[test]
+fn parse_array_subquery() {
+    let sql = "SELECT ARRAY(SELECT x FROM t ORDER BY x)";
+    let select = pg_and_generic().verified_only_select(sql);
+    assert_eq!(
+        expr_from_projection(only(&select.projection)),
+        &Expr::ArraySubquery(Box::new(
+            pg_and_generic().verified_query("SELECT x FROM t ORDER BY x")
+        ))
+    );
+
+    pg_and_generic().verified_stmt("SELECT cardinality(ARRAY(SELECT c FROM u)) FROM t");
+}
+
 #[test]
 fn parse_array_datatype() {
     let sql = "SELECT '{{1,2},{3,4}}'::int[][]";
@@ -400,12 +1105,64 @@ fn parse_array_datatype() {
             expr: Box::new(Expr::Value(Value::SingleQuotedString(
                 "{{1,2},{3,4}}".to_owned()
             ))),
-            data_type: DataType::Array(Box::new(DataType::Array(Box::new(DataType::Int)))),
+            data_type: DataType::Array(
+                Box::new(DataType::Array(Box::new(DataType::Int), None)),
+                None
+            ),
         },
         expr_from_projection(only(&select.projection))
     );
 }
 
+#[test]
+fn parse_cast_to_sized_array_and_custom_types() {
+    let select = pg_and_generic().verified_only_select("SELECT CAST(x AS int[3][])");
+    assert_eq!(
+        &Expr::Cast {
+            expr: Box::new(Expr::Identifier(Ident::new("x"))),
+            data_type: DataType::Array(
+                Box::new(DataType::Array(Box::new(DataType::Int), Some(3))),
+                None
+            ),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+    pg_and_generic().one_statement_parses_to(
+        "SELECT x::int ARRAY[3], y::text ARRAY",
+        "SELECT CAST(x AS int[3]), CAST(y AS text[])",
+    );
+
+    let select = pg_and_generic()
+        .verified_only_select(r#"SELECT CAST(x AS geometry(point, 4326)), CAST(y AS "My Type"[])"#);
+    assert_eq!(
+        &Expr::Cast {
+            expr: Box::new(Expr::Identifier(Ident::new("x"))),
+            data_type: DataType::Custom(
+                ObjectName(vec![Ident::new("geometry")]),
+                vec!["point".to_string(), "4326".to_string()]
+            ),
+        },
+        expr_from_projection(&select.projection[0])
+    );
+    assert_eq!(
+        &Expr::Cast {
+            expr: Box::new(Expr::Identifier(Ident::new("y"))),
+            data_type: DataType::Array(
+                Box::new(DataType::Custom(
+                    ObjectName(vec![Ident::with_quote('"', "My Type")]),
+                    vec![]
+                )),
+                None
+            ),
+        },
+        expr_from_projection(&select.projection[1])
+    );
+    pg_and_generic().one_statement_parses_to(
+        "SELECT x::myschema.mytype('a', 3)",
+        "SELECT CAST(x AS myschema.mytype('a', 3))",
+    );
+}
+
 #[test]
 fn parse_json_ops() {
     use self::BinaryOperator::*;
@@ -434,6 +1191,494 @@ fn parse_json_ops() {
     }
 }
 
+#[test]
+fn parse_group_by_ordinals() {
+    let select =
+        pg_and_generic().verified_only_select("SELECT a, b, count(*) FROM t GROUP BY 1, b, 2 + 0");
+    assert_eq!(
+        vec![
+            Expr::Ordinal(1),
+            Expr::Identifier("b".into()),
+            Expr::BinaryOp {
+                left: Box::new(Expr::Value(number("2"))),
+                op: BinaryOperator::Plus,
+                right: Box::new(Expr::Value(number("0"))),
+            },
+        ],
+        select.group_by
+    );
+}
+
+#[test]
+fn parse_string_literal_concatenation() {
+    let sql =
+        "SELECT 'foo'\n    'bar' -- comment\n 'baz', 'it''s'\n' fine' FROM t WHERE x = 'a'\n'b'";
+    let select = match pg_and_generic().one_statement_parses_to(
+        sql,
+        "SELECT 'foobarbaz', 'it''s fine' FROM t WHERE x = 'ab'",
+    ) {
+        Statement::Query(query) => match query.body {
+            SetExpr::Select(select) => select,
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        &Expr::Value(Value::SingleQuotedString("foobarbaz".into())),
+        expr_from_projection(&select.projection[0])
+    );
+
+    // Literals on the same line are only concatenated by the dialects that
+    // don't follow the standard's requirement of a line break between them
+    let sql = "SELECT 'foo' /* comment */ 'bar'";
+    TestedDialects::named(&["postgres", "ansi"])
+        .one_statement_parses_to(sql, "SELECT 'foo' AS 'bar'");
+    TestedDialects::named(&["generic", "mysql"]).one_statement_parses_to(sql, "SELECT 'foobar'");
+    pg().one_statement_parses_to("SELECT 'foo' /* a\nb */ 'bar'", "SELECT 'foobar'");
+}
+
+#[test]
+fn parse_table_function_with_ordinality() {
+    let sql = "SELECT * FROM generate_series(1, 3) WITH ORDINALITY AS t (x, n)";
+    let select = pg_and_generic().verified_only_select(sql);
+    match only(select.from).relation {
+        TableFactor::Table {
+            args,
+            alias,
+            with_ordinality,
+            ..
+        } => {
+            assert_eq!(2, args.len());
+            assert!(with_ordinality);
+            assert_eq!(
+                vec![TableAliasColumn::from("x"), TableAliasColumn::from("n")],
+                alias.unwrap().columns
+            );
+        }
+        _ => unreachable!(),
+    }
+    pg_and_generic().verified_stmt("SELECT * FROM unnest(a) WITH ORDINALITY");
+}
+
+#[test]
+fn parse_table_alias_with_column_types() {
+    let sql = "SELECT * FROM jsonb_to_recordset(x) AS t (a int, b text, c)";
+    let select = pg_and_generic().verified_only_select(sql);
+    match only(select.from).relation {
+        TableFactor::Table { alias, .. } => assert_eq!(
+            Some(TableAlias {
+                name: Ident::new("t"),
+                columns: vec![
+                    TableAliasColumn {
+                        name: Ident::new("a"),
+                        data_type: Some(DataType::Int),
+                    },
+                    TableAliasColumn {
+                        name: Ident::new("b"),
+                        data_type: Some(DataType::Text),
+                    },
+                    TableAliasColumn::from("c"),
+                ],
+            }),
+            alias
+        ),
+        _ => unreachable!(),
+    }
+    pg_and_generic().one_statement_parses_to(
+        "SELECT * FROM json_to_record(x) AS t(a INT, b CHARACTER VARYING(10))",
+        "SELECT * FROM json_to_record(x) AS t (a int, b character varying(10))",
+    );
+}
+
+#[test]
+fn parse_dialect_specific_keywords_as_identifiers() {
+    // QUALIFY and FORMAT only introduce clauses in other dialects
+    let query = pg().verified_query("SELECT a AS format FROM t AS qualify");
+    assert_eq!(None, query.format);
+    pg().one_statement_parses_to(
+        "SELECT format, a format FROM qualify qualify",
+        "SELECT format, a AS format FROM qualify AS qualify",
+    );
+}
+
+#[test]
+fn parse_do_block() {
+    let sql = "DO $$BEGIN RAISE NOTICE 'hi'; END$$ LANGUAGE plpgsql";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::DoBlock { language, body } => {
+            assert_eq!(Some(Ident::new("plpgsql")), language);
+            assert_eq!(
+                Value::DollarQuotedString(DollarQuotedString {
+                    value: "BEGIN RAISE NOTICE 'hi'; END".to_string(),
+                    tag: None,
+                }),
+                body
+            );
+        }
+        _ => unreachable!(),
+    }
+    pg_and_generic().verified_stmt("DO 'BEGIN NULL; END'");
+    pg_and_generic().one_statement_parses_to(
+        "DO LANGUAGE plpgsql $body$BEGIN NULL; END$body$",
+        "DO $body$BEGIN NULL; END$body$ LANGUAGE plpgsql",
+    );
+
+    // A migration can mix code blocks with other statements
+    let statements = pg()
+        .parse_sql_statements("DO $$BEGIN PERFORM 1; END$$; SELECT 1")
+        .unwrap();
+    assert_eq!(2, statements.len());
+
+    let res = pg().parse_sql_statements("DO plpgsql");
+    assert_eq!(
+        ParserError::ParserError("Expected string literal, found: plpgsql".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_listen_notify_unlisten() {
+    match pg_and_generic().verified_stmt("LISTEN events") {
+        Statement::Listen { channel } => assert_eq!(Ident::new("events"), channel),
+        _ => unreachable!(),
+    }
+
+    match pg_and_generic().verified_stmt("NOTIFY events, 'it''s done'") {
+        Statement::Notify { channel, payload } => {
+            assert_eq!(Ident::new("events"), channel);
+            assert_eq!(Some("it's done".to_string()), payload);
+        }
+        _ => unreachable!(),
+    }
+    pg_and_generic().verified_stmt("NOTIFY events");
+
+    match pg_and_generic().verified_stmt("UNLISTEN *") {
+        Statement::Unlisten { channel } => assert_eq!(None, channel),
+        _ => unreachable!(),
+    }
+    pg_and_generic().verified_stmt("UNLISTEN events");
+
+    let res = pg().parse_sql_statements("NOTIFY events, 1");
+    assert_eq!(
+        ParserError::ParserError("Expected literal string, found: 1".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_exponent_precedence() {
+    use self::Expr::*;
+    let num = |n: &'static str| Box::new(Value(number(n)));
+    let exp = |left, right| {
+        Box::new(BinaryOp {
+            left,
+            op: BinaryOperator::Exponent,
+            right,
+        })
+    };
+    // `^` binds more tightly than `*` and is left-associative
+    assert_eq!(
+        BinaryOp {
+            left: num("2"),
+            op: BinaryOperator::Multiply,
+            right: exp(exp(num("3"), num("2")), num("2")),
+        },
+        pg_and_generic().verified_expr("2 * 3 ^ 2 ^ 2")
+    );
+    // but less tightly than unary minus
+    assert_eq!(
+        *exp(
+            Box::new(UnaryOp {
+                op: UnaryOperator::Minus,
+                expr: num("2"),
+            }),
+            num("2")
+        ),
+        pg_and_generic().verified_expr("- 2 ^ 2")
+    );
+}
+
+#[test]
+fn parse_json_operator_precedence() {
+    let sql = "SELECT a ->> 'x' = 'y'";
+    let select = pg().verified_only_select(sql);
+    assert_eq!(
+        &Expr::BinaryOp {
+            left: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("a"))),
+                op: BinaryOperator::JsonGetAsText,
+                right: Box::new(Expr::Value(Value::SingleQuotedString("x".into()))),
+            }),
+            op: BinaryOperator::Eq,
+            right: Box::new(Expr::Value(Value::SingleQuotedString("y".into()))),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    let sql = "SELECT a || b + 1";
+    let select = pg().verified_only_select(sql);
+    assert_eq!(
+        &Expr::BinaryOp {
+            left: Box::new(Expr::Identifier(Ident::new("a"))),
+            op: BinaryOperator::JsonConcat,
+            right: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("b"))),
+                op: BinaryOperator::Plus,
+                right: Box::new(Expr::Value(number("1"))),
+            }),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+}
+
+#[test]
+fn normalize_identifiers() {
+    let name: ObjectName = "Sch.\"MyTable\"".parse().unwrap();
+    assert_eq!(
+        ObjectName(vec![Ident::new("sch"), Ident::with_quote('"', "MyTable")]),
+        name.normalized(&PostgreSqlDialect {})
+    );
+    // Non-ASCII letters are left alone rather than folded
+    let name = ObjectName(vec![Ident::new("SIZE_İ"), Ident::with_quote('"', "sıze")]);
+    assert_eq!(
+        ObjectName(vec![Ident::new("size_İ"), Ident::with_quote('"', "sıze")]),
+        name.normalized(&PostgreSqlDialect {})
+    );
+}
+
+#[test]
+fn resolve_object_names() {
+    let name =
+        ObjectName::parse_with_dialect(r#"Db."My.Schema".t"#, &PostgreSqlDialect {}).unwrap();
+    assert_eq!(Some(&Ident::new("t")), name.item());
+    assert_eq!(Some(&Ident::with_quote('"', "My.Schema")), name.schema());
+    assert_eq!(Some(&Ident::new("Db")), name.database());
+
+    let table: ObjectName = "T".parse().unwrap();
+    assert_eq!(None, table.schema());
+    assert_eq!(None, table.database());
+    assert!(table.matches(&name, &PostgreSqlDialect {}));
+    let quoted: ObjectName = r#""T""#.parse().unwrap();
+    assert!(!quoted.matches(&name, &PostgreSqlDialect {}));
+    let qualified: ObjectName = "other.t".parse().unwrap();
+    assert!(!qualified.matches(&name, &PostgreSqlDialect {}));
+
+    let schema: ObjectName = r#"db."My.Schema""#.parse().unwrap();
+    assert_eq!(r#"db."My.Schema".T"#, schema.join(&table).to_string());
+}
+
+#[test]
+fn parse_check_constraint_no_inherit() {
+    let sql = "ALTER TABLE t ADD CONSTRAINT positive CHECK (a > 0) NO INHERIT";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::AlterTable {
+            operation:
+                AlterTableOperation::AddConstraint(TableConstraint::Check {
+                    name,
+                    no_inherit,
+                    characteristics,
+                    ..
+                }),
+            ..
+        } => {
+            assert_eq!(Some(Ident::new("positive")), name);
+            assert!(no_inherit);
+            assert_eq!(None, characteristics);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_alter_owner() {
+    match pg_and_generic().verified_stmt("ALTER TABLE public.t OWNER TO postgres") {
+        Statement::AlterTable {
+            only: false,
+            name,
+            operation,
+        } => {
+            assert_eq!("public.t", name.to_string());
+            assert_eq!(
+                AlterTableOperation::OwnerTo {
+                    new_owner: Ident::new("postgres")
+                },
+                operation
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    match pg_and_generic().verified_stmt("ALTER SEQUENCE IF EXISTS public.s OWNER TO postgres") {
+        Statement::AlterOwner {
+            object_type,
+            if_exists,
+            name,
+            new_owner,
+        } => {
+            assert_eq!(ObjectType::Sequence, object_type);
+            assert!(if_exists);
+            assert_eq!("public.s", name.to_string());
+            assert_eq!(Ident::new("postgres"), new_owner);
+        }
+        _ => unreachable!(),
+    }
+    pg_and_generic().verified_stmt("ALTER VIEW v OWNER TO r");
+    pg_and_generic().verified_stmt("ALTER SCHEMA public OWNER TO r");
+    pg_and_generic().verified_stmt("ALTER TYPE t OWNER TO r");
+
+    let res = pg().parse_sql_statements("ALTER VIEW v RENAME TO w");
+    assert_eq!(
+        ParserError::ParserError("Expected OWNER, found: RENAME".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_prepare() {
+    match pg().verified_stmt("PREPARE p (int, text) AS SELECT $1, $2") {
+        Statement::Prepare {
+            name,
+            data_types,
+            statement,
+        } => {
+            assert_eq!(Ident::new("p"), name);
+            assert_eq!(vec![DataType::Int, DataType::Text], data_types);
+            assert_eq!("SELECT $1, $2", statement.to_string());
+        }
+        _ => unreachable!(),
+    }
+    pg().verified_stmt("PREPARE p AS SELECT 1");
+    pg().verified_stmt("PREPARE p (int) AS UPDATE t SET a = $1");
+    pg().verified_stmt("PREPARE p AS DELETE FROM t");
+
+    let res = pg().parse_sql_statements("PREPARE p AS PREPARE q AS SELECT 1");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected a query, INSERT, UPDATE or DELETE, found: PREPARE".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_create_view_with_options() {
+    let sql = "CREATE VIEW v WITH (check_option = local, security_barrier = true) AS SELECT 1";
+    match pg().verified_stmt(sql) {
+        Statement::CreateView { with_options, .. } => assert_eq!(
+            vec![
+                SqlOption::ObjectName {
+                    name: "check_option".into(),
+                    object_name: ObjectName(vec!["local".into()]),
+                },
+                SqlOption::Value {
+                    name: "security_barrier".into(),
+                    value: Value::Boolean(true),
+                },
+            ],
+            with_options
+        ),
+        _ => unreachable!(),
+    }
+    match pg().verified_stmt("CREATE VIEW v WITH (security_barrier) AS SELECT 1") {
+        Statement::CreateView { with_options, .. } => assert_eq!(
+            vec![SqlOption::Flag {
+                name: "security_barrier".into(),
+            }],
+            with_options
+        ),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_alter_view_options() {
+    match pg().verified_stmt(
+        "ALTER VIEW IF EXISTS v SET (check_option = cascaded, security_barrier = false)",
+    ) {
+        Statement::AlterViewOptions {
+            if_exists,
+            name,
+            operation: AlterOptionsOperation::Set(options),
+        } => {
+            assert!(if_exists);
+            assert_eq!("v", name.to_string());
+            assert_eq!(
+                vec!["check_option = cascaded", "security_barrier = false"],
+                options.iter().map(|o| o.to_string()).collect::<Vec<_>>()
+            );
+        }
+        _ => unreachable!(),
+    }
+    match pg().verified_stmt("ALTER VIEW v RESET (check_option, security_barrier)") {
+        Statement::AlterViewOptions {
+            if_exists,
+            operation: AlterOptionsOperation::Reset(names),
+            ..
+        } => {
+            assert!(!if_exists);
+            assert_eq!(
+                vec![Ident::new("check_option"), Ident::new("security_barrier")],
+                names
+            );
+        }
+        _ => unreachable!(),
+    }
+    pg().verified_stmt("ALTER VIEW v OWNER TO r");
+
+    let res = pg().parse_sql_statements("ALTER VIEW v SET (check_option local)");
+    assert_eq!(
+        ParserError::ParserError("Expected =, found: local".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_table_inheritance_markers() {
+    let select = pg_and_generic().verified_only_select("SELECT * FROM ONLY parent AS p");
+    match only(select.from).relation {
+        TableFactor::Table {
+            only,
+            name,
+            descendants,
+            alias,
+            ..
+        } => {
+            assert!(only);
+            assert_eq!("parent", name.to_string());
+            assert!(!descendants);
+            assert_eq!(Ident::new("p"), alias.unwrap().name);
+        }
+        _ => unreachable!(),
+    }
+
+    let select =
+        pg_and_generic().verified_only_select("SELECT * FROM parent * JOIN child * ON true");
+    let from = only(select.from);
+    match (&from.relation, &from.joins[0].relation) {
+        (
+            TableFactor::Table {
+                only: false,
+                descendants: true,
+                ..
+            },
+            TableFactor::Table {
+                only: false,
+                descendants: true,
+                ..
+            },
+        ) => (),
+        _ => unreachable!(),
+    }
+
+    match pg_and_generic().verified_stmt("ALTER TABLE ONLY parent OWNER TO postgres") {
+        Statement::AlterTable { only, name, .. } => {
+            assert!(only);
+            assert_eq!("parent", name.to_string());
+        }
+        _ => unreachable!(),
+    }
+}
+
 fn pg() -> TestedDialects {
     TestedDialects {
         dialects: vec![Box::new(PostgreSqlDialect {})],
@@ -0,0 +1,69 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![warn(clippy::all)]
+
+//! Test SQL syntax specific to Redshift. The parser based on the generic
+//! dialect is also tested (on the inputs it can handle).
+
+use sqlparser::ast::*;
+use sqlparser::dialect::{GenericDialect, RedshiftSqlDialect};
+use sqlparser::test_utils::*;
+
+#[test]
+fn parse_create_temp_table_with_identity() {
+    let sql = "CREATE TABLE #staging (id bigint IDENTITY(1,1) NOT NULL, name text)";
+    let canonical = "CREATE TABLE #staging (id bigint IDENTITY(1, 1) NOT NULL, name text)";
+    match redshift_and_generic().one_statement_parses_to(sql, canonical) {
+        Statement::CreateTable { name, columns, .. } => {
+            assert_eq!(ObjectName(vec![Ident::new("#staging")]), name);
+            assert_eq!(
+                vec![
+                    ColumnOptionDef {
+                        name: None,
+                        option: ColumnOption::Identity {
+                            seed: 1,
+                            increment: 1,
+                        },
+                    },
+                    ColumnOptionDef {
+                        name: None,
+                        option: ColumnOption::NotNull,
+                    },
+                ],
+                columns[0].options
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_select_from_temp_table() {
+    let select = redshift().verified_only_select("SELECT #t.a FROM #t");
+    assert_eq!(
+        &Expr::CompoundIdentifier(vec![Ident::new("#t"), Ident::new("a")]),
+        expr_from_projection(only(&select.projection))
+    );
+}
+
+fn redshift() -> TestedDialects {
+    TestedDialects {
+        dialects: vec![Box::new(RedshiftSqlDialect {})],
+    }
+}
+
+fn redshift_and_generic() -> TestedDialects {
+    TestedDialects {
+        dialects: vec![Box::new(RedshiftSqlDialect {}), Box::new(GenericDialect {})],
+    }
+}
@@ -0,0 +1,100 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![warn(clippy::all)]
+
+//! Test SQL syntax specific to Snowflake. The parser based on the generic
+//! dialect is also tested (on the inputs it can handle).
+
+use sqlparser::ast::*;
+use sqlparser::dialect::{GenericDialect, SnowflakeDialect};
+use sqlparser::test_utils::*;
+
+#[test]
+fn parse_variant_access() {
+    let select = snowflake_and_generic().verified_only_select("SELECT src:customer[0].name FROM t");
+    assert_eq!(
+        expr_from_projection(only(&select.projection)),
+        &Expr::VariantAccess {
+            expr: Box::new(Expr::Identifier(Ident::new("src"))),
+            path: vec![
+                VariantPathElement::Field(Ident::new("customer")),
+                VariantPathElement::Index(Expr::Value(number("0"))),
+                VariantPathElement::Field(Ident::new("name")),
+            ],
+        }
+    );
+
+    // the path binds more tightly than a cast
+    let query = snowflake_and_generic().unverified_query(r#"SELECT t.src:"Name"::TEXT FROM t"#);
+    assert_eq!(
+        r#"SELECT CAST(t.src:"Name" AS text) FROM t"#,
+        query.to_string()
+    );
+    let select = match query.body {
+        SetExpr::Select(select) => select,
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        expr_from_projection(only(&select.projection)),
+        &Expr::Cast {
+            expr: Box::new(Expr::VariantAccess {
+                expr: Box::new(Expr::CompoundIdentifier(vec![
+                    Ident::new("t"),
+                    Ident::new("src")
+                ])),
+                path: vec![VariantPathElement::Field(Ident::with_quote('"', "Name"))],
+            }),
+            data_type: DataType::Text,
+        }
+    );
+
+    snowflake_and_generic().verified_stmt_rejected_by(
+        "SELECT a:b FROM t",
+        &snowflake_and_generic().others(),
+        "Expected end of statement, found: :",
+    );
+}
+
+#[test]
+fn parse_qualify() {
+    let sql = "SELECT a, row_number() OVER (PARTITION BY a ORDER BY b) AS rn FROM t \
+               WHERE b > 0 QUALIFY rn = 1";
+    let select = snowflake_and_generic().verified_only_select(sql);
+    assert_eq!(
+        select.qualify,
+        Some(Expr::BinaryOp {
+            left: Box::new(Expr::Identifier(Ident::new("rn"))),
+            op: BinaryOperator::Eq,
+            right: Box::new(Expr::Value(number("1"))),
+        })
+    );
+
+    snowflake_and_generic().verified_stmt(
+        "SELECT a FROM t GROUP BY a HAVING count(*) > 1 QUALIFY rank() OVER (ORDER BY a) < 3",
+    );
+}
+
+#[test]
+fn normalize_identifiers() {
+    let name: ObjectName = "Sch.\"MyTable\"".parse().unwrap();
+    assert_eq!(
+        ObjectName(vec![Ident::new("SCH"), Ident::with_quote('"', "MyTable")]),
+        name.normalized(&SnowflakeDialect {})
+    );
+}
+
+fn snowflake_and_generic() -> TestedDialects {
+    TestedDialects {
+        dialects: vec![Box::new(SnowflakeDialect {}), Box::new(GenericDialect {})],
+    }
+}
@@ -0,0 +1,134 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![warn(clippy::all)]
+
+//! Test SQL syntax specific to SQLite. The parser based on the generic
+//! dialect is also tested (on the inputs it can handle).
+
+use sqlparser::ast::*;
+use sqlparser::dialect::{GenericDialect, SqliteDialect};
+use sqlparser::test_utils::*;
+
+#[test]
+fn parse_create_table_without_rowid() {
+    let sql = "CREATE TABLE t (a int PRIMARY KEY AUTOINCREMENT, b text) WITHOUT ROWID";
+    match sqlite_and_generic().verified_stmt(sql) {
+        Statement::CreateTable {
+            name,
+            columns,
+            without_rowid,
+            ..
+        } => {
+            assert_eq!("t", name.to_string());
+            assert!(without_rowid);
+            assert_eq!(
+                vec![
+                    ColumnOptionDef {
+                        name: None,
+                        option: ColumnOption::Unique {
+                            is_primary: true,
+                            characteristics: None,
+                        },
+                    },
+                    ColumnOptionDef {
+                        name: None,
+                        option: ColumnOption::Autoincrement,
+                    },
+                ],
+                columns[0].options
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    match sqlite_and_generic().verified_stmt("CREATE TABLE t (a int)") {
+        Statement::CreateTable { without_rowid, .. } => assert!(!without_rowid),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_create_table_with_keyword_column_names() {
+    sqlite_and_generic().verified_stmt(
+        "CREATE TABLE log (key text, value text, date text, time text, timestamp int)",
+    );
+    let select = sqlite_and_generic()
+        .verified_only_select("SELECT date, time, timestamp FROM log WHERE date > '2020-01-01'");
+    assert_eq!(
+        &Expr::Identifier(Ident::new("date")),
+        expr_from_projection(&select.projection[0])
+    );
+    assert_eq!(
+        &Expr::Identifier(Ident::new("timestamp")),
+        expr_from_projection(&select.projection[2])
+    );
+
+    // typed string literals keep working
+    let select = sqlite_and_generic().verified_only_select("SELECT DATE '2020-01-01'");
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Value(Value::Date(..)) => (),
+        e => panic!("expected a date literal, got {:?}", e),
+    }
+}
+
+#[test]
+fn parse_quoted_identifiers() {
+    let select = sqlite().verified_only_select("SELECT [a b], `c`, \"d\" FROM [t]");
+    assert_eq!(
+        &Expr::Identifier(Ident::with_quote('[', "a b")),
+        expr_from_projection(&select.projection[0])
+    );
+    assert_eq!(
+        &Expr::Identifier(Ident::with_quote('`', "c")),
+        expr_from_projection(&select.projection[1])
+    );
+}
+
+#[test]
+fn parse_set_operations_left_to_right() {
+    // Unlike in standard SQL, INTERSECT doesn't bind more tightly than UNION,
+    // so the canonical form needs parentheses
+    let query = match sqlite().one_statement_parses_to(
+        "SELECT 1 UNION SELECT 2 INTERSECT SELECT 3",
+        "(SELECT 1 UNION SELECT 2) INTERSECT SELECT 3",
+    ) {
+        Statement::Query(query) => query,
+        _ => unreachable!(),
+    };
+    match query.body {
+        SetExpr::SetOperation {
+            op: SetOperator::Intersect,
+            left,
+            ..
+        } => match *left {
+            SetExpr::SetOperation {
+                op: SetOperator::Union,
+                ..
+            } => (),
+            _ => panic!("expected UNION on the left, got {:?}", left),
+        },
+        _ => panic!("expected INTERSECT at the top, got {:?}", query.body),
+    }
+}
+
+fn sqlite() -> TestedDialects {
+    TestedDialects {
+        dialects: vec![Box::new(SqliteDialect {})],
+    }
+}
+
+fn sqlite_and_generic() -> TestedDialects {
+    TestedDialects {
+        dialects: vec![Box::new(SqliteDialect {}), Box::new(GenericDialect {})],
+    }
+}